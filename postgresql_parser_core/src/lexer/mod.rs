@@ -1,5 +1,7 @@
 pub mod token;
 use std::collections::HashSet;
+use std::iter::Peekable;
+use std::str::Chars;
 use token::Token;
 use token::TokenPosition;
 
@@ -16,14 +18,96 @@ lazy_static! {
         delimiters.insert('.');
         delimiters.insert(';');
         delimiters.insert(',');
+        delimiters.insert('(');
+        delimiters.insert(')');
+        delimiters.insert('[');
+        delimiters.insert(']');
         return delimiters;
     };
+    pub static ref OPERATOR_CHARS: HashSet<char> = {
+        let mut operators: HashSet<char> = HashSet::new();
+        for character in "+-*/<>=~!@#%^&|`?".chars() {
+            operators.insert(character);
+        }
+        return operators;
+    };
+    // A multiple character operator may only end in + or - if it also
+    // contains one of these, so that e.g. a=-1 lexes as a, =, -, 1
+    pub static ref TRAILING_SIGN_OPERATOR_CHARS: HashSet<char> = {
+        let mut operators: HashSet<char> = HashSet::new();
+        for character in "~!@#%^&|`?".chars() {
+            operators.insert(character);
+        }
+        return operators;
+    };
+    pub static ref QUOTE_CHARS: HashSet<char> = {
+        let mut quotes: HashSet<char> = HashSet::new();
+        quotes.insert('"');
+        quotes.insert('\'');
+        return quotes;
+    };
 }
 
 pub fn char_is_whitespace(character: char) -> bool {
     return WHITESPACE_CHARS.contains(&character);
 }
 
+// Comments are lexed as part of whitespace tokens, so a token is whitespace if
+// it starts with either
+pub fn token_value_is_whitespace(token_value: &str) -> bool {
+    return token_value.starts_with(char_is_whitespace)
+        || token_value.starts_with("--")
+        || token_value.starts_with("/*");
+}
+
+// -- starts a comment running to the end of the line, and /* one running to
+// the matching */, as block comments can be nested
+fn comment_starts_with(character: char, next_character: Option<&char>) -> bool {
+    return (character == '-' && next_character == Some(&'-'))
+        || (character == '/' && next_character == Some(&'*'));
+}
+
+pub fn char_is_operator(character: char) -> bool {
+    return OPERATOR_CHARS.contains(&character);
+}
+
+fn char_is_word(character: char) -> bool {
+    return !char_is_whitespace(character)
+        && !char_is_operator(character)
+        && !TOKEN_TERMINATORS.contains(&character)
+        && !QUOTE_CHARS.contains(&character)
+        && character != ':';
+}
+
+fn token_is_number(token_value: &str) -> bool {
+    return token_value.starts_with(|c: char| c.is_ascii_digit())
+        && token_value.chars().all(|c| c.is_ascii_digit() || c == '.');
+}
+
+fn token_continues_with(token: &Token, character: char) -> bool {
+    let last_char = token.value.chars().last().unwrap();
+    if token.value == ":" {
+        // :: casts, := assignments and :name psql variables
+        return character == ':' || character == '=' || char_is_word(character);
+    } else if token.value == "::" || token.value == ":=" {
+        return false;
+    } else if token_is_number(&token.value) {
+        return character.is_ascii_digit()
+            || (character == '.' && !token.value.contains('.'))
+            || char_is_word(character);
+    } else if token_value_is_whitespace(&token.value) {
+        return char_is_whitespace(character);
+    } else if char_is_operator(last_char) {
+        return char_is_operator(character)
+            && (!(character == '+' || character == '-')
+                || token
+                    .value
+                    .chars()
+                    .any(|c| TRAILING_SIGN_OPERATOR_CHARS.contains(&c)));
+    }
+    return char_is_word(last_char) && char_is_word(character);
+}
+
 fn push_if_some<T>(mut opt_val: Option<T>, mut push_to: Vec<T>) -> (Option<T>, Vec<T>) {
     match opt_val.take() {
         Some(some_val) => {
@@ -34,73 +118,225 @@ fn push_if_some<T>(mut opt_val: Option<T>, mut push_to: Vec<T>) -> (Option<T>, V
     return (opt_val, push_to);
 }
 
+fn position_after(position: &TokenPosition, character: char) -> TokenPosition {
+    if character == '\n' {
+        return TokenPosition {
+            line: position.line + 1,
+            column: 0,
+        };
+    }
+    return TokenPosition {
+        line: position.line,
+        column: position.column + 1,
+    };
+}
+
+// A quote directly after one of these continues the current token rather
+// than starting a new one, e.g. the psql variable forms :'name' and :"name"
+fn token_is_quote_prefix(token: &Option<Token>) -> bool {
+    return match token {
+        Some(token) => token.value == ":",
+        None => false,
+    };
+}
+
+// The $tag$ that opens a dollar quoted string, given the characters
+// following its first $, e.g. $$ or $body$. The tag follows the rules of an
+// unquoted identifier, so $1 is a parameter rather than the start of a string.
+fn dollar_quote_tag(characters: &Peekable<Chars>) -> Option<String> {
+    let mut tag = String::from("$");
+    for character in characters.clone() {
+        if character == '$' {
+            tag.push(character);
+            return Some(tag);
+        }
+        let is_tag_start = tag.len() == 1;
+        if !(character == '_'
+            || character.is_alphabetic()
+            || (!is_tag_start && character.is_ascii_digit()))
+        {
+            return None;
+        }
+        tag.push(character);
+    }
+    return None;
+}
+
+// Whether the statement ended by the last token, a semicolon, is a
+// COPY ... FROM STDIN, which in psql scripts and pg_dump output is followed by
+// its data rather than by more SQL
+fn statement_is_copy_from_stdin(tokens: &Vec<Token>) -> bool {
+    let semicolon_idx = tokens.len() - 1;
+    let statement_start_idx = match tokens[..semicolon_idx]
+        .iter()
+        .rposition(|token| token.value == ";")
+    {
+        Some(idx) => idx + 1,
+        None => 0,
+    };
+    let words: Vec<String> = tokens[statement_start_idx..semicolon_idx]
+        .iter()
+        .filter(|token| !token_value_is_whitespace(&token.value))
+        .map(|token| token.value.to_ascii_lowercase())
+        .collect();
+    if words.first().map(String::as_str) != Some("copy") {
+        return false;
+    }
+    // FROM inside a parenthesized query is not the FROM of the COPY
+    let mut depth = 0;
+    for (idx, word) in words.iter().enumerate() {
+        match word.as_str() {
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            "from" if depth == 0 => {
+                return words.get(idx + 1).map(String::as_str) == Some("stdin");
+            }
+            _ => {}
+        }
+    }
+    return false;
+}
+
+// Skips the data of a COPY ... FROM STDIN, up to and including the \. line
+// that ends it, returning the position after the data
+fn skip_copy_data(
+    characters: &mut Peekable<Chars>,
+    start_position: TokenPosition,
+) -> TokenPosition {
+    let mut position = start_position;
+    let mut line = String::new();
+    while let Some(character) = characters.next() {
+        position = position_after(&position, character);
+        if character == '\n' {
+            line.clear();
+            continue;
+        }
+        line.push(character);
+        if line == "\\." && matches!(characters.peek(), Some('\n') | Some('\r') | None) {
+            break;
+        }
+    }
+    return position;
+}
+
 pub fn tokenize_postgresql(text: &str) -> Vec<Token> {
     let mut tokens: Vec<Token> = Vec::new();
     let mut current_token: Option<Token> = None;
-    let mut current_token_is_whitespace: bool = false;
-    let mut current_token_is_quoted_identifier: bool = false;
-    let mut current_line: usize = 0;
-    let mut current_column: usize = 0;
-
-    for character in text.chars() {
-        let changing_between_whitespace_and_non =
-            current_token_is_whitespace != char_is_whitespace(character);
-        if character == '"' {
-            if current_token_is_quoted_identifier {
-                tokens.push(current_token.take().unwrap().append(character));
-                current_token_is_quoted_identifier = false;
+    let mut current_quote_char: Option<char> = None;
+    let mut current_dollar_quote_tag: Option<String> = None;
+    let mut in_line_comment = false;
+    let mut block_comment_depth = 0;
+    let mut position = TokenPosition { line: 0, column: 0 };
+    let mut characters = text.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        if in_line_comment {
+            current_token = Some(current_token.take().unwrap().append(character));
+            in_line_comment = character != '\n';
+        } else if block_comment_depth > 0 {
+            current_token = Some(current_token.take().unwrap().append(character));
+            let next_character = characters.peek().copied();
+            if (character == '/' && next_character == Some('*'))
+                || (character == '*' && next_character == Some('/'))
+            {
+                // Both characters are taken, so that in /*/ the * doesn't
+                // also start a */
+                if character == '/' {
+                    block_comment_depth += 1;
+                } else {
+                    block_comment_depth -= 1;
+                }
+                position = position_after(&position, character);
+                current_token = Some(
+                    current_token
+                        .take()
+                        .unwrap()
+                        .append(characters.next().unwrap()),
+                );
+            }
+        } else if let Some(dollar_quote_tag) = &current_dollar_quote_tag {
+            let token = current_token.take().unwrap().append(character);
+            // The opening tag itself is not also taken as the closing tag
+            if token.value.len() >= 2 * dollar_quote_tag.len()
+                && token.value.ends_with(dollar_quote_tag.as_str())
+            {
+                tokens.push(token);
+                current_dollar_quote_tag = None;
+            } else {
+                current_token = Some(token);
+            }
+        } else if let Some(quote_char) = current_quote_char {
+            current_token = Some(current_token.take().unwrap().append(character));
+            if character == quote_char {
+                if characters.peek() == Some(&quote_char) {
+                    // A doubled quote is an escaped quote, not the end of the token
+                    position = position_after(&position, character);
+                    current_token = Some(current_token.take().unwrap().append(quote_char));
+                    characters.next();
+                } else {
+                    tokens.push(current_token.take().unwrap());
+                    current_quote_char = None;
+                }
+            }
+        } else if QUOTE_CHARS.contains(&character) {
+            if token_is_quote_prefix(&current_token) {
+                current_token = Some(current_token.take().unwrap().append(character));
             } else {
                 (_, tokens) = push_if_some(current_token, tokens);
-                current_token = Some(Token::new(
-                    TokenPosition {
-                        line: current_line,
-                        column: current_column,
-                    },
-                    character,
-                ));
-                current_token_is_quoted_identifier = true;
+                current_token = Some(Token::new(position.clone(), character));
             }
-        } else if current_token_is_quoted_identifier {
-            current_token = Some(current_token.unwrap().append(character));
+            current_quote_char = Some(character);
+        } else if comment_starts_with(character, characters.peek()) {
+            if current_token
+                .as_ref()
+                .is_some_and(|token| token_value_is_whitespace(&token.value))
+            {
+                current_token = Some(current_token.take().unwrap().append(character));
+            } else {
+                (_, tokens) = push_if_some(current_token, tokens);
+                current_token = Some(Token::new(position.clone(), character));
+            }
+            if character == '-' {
+                in_line_comment = true;
+            } else {
+                block_comment_depth = 1;
+            }
+            position = position_after(&position, character);
+            current_token = Some(
+                current_token
+                    .take()
+                    .unwrap()
+                    .append(characters.next().unwrap()),
+            );
+        } else if character == '.' && characters.peek() == Some(&'.') {
+            // The .. of a PL/pgSQL integer FOR loop, which also ends a number
+            // as in 1..10
+            (current_token, tokens) = push_if_some(current_token, tokens);
+            tokens.push(Token::new(position.clone(), character).append('.'));
+            position = position_after(&position, character);
+            characters.next();
+        } else if current_token
+            .as_ref()
+            .is_some_and(|token| token_continues_with(token, character))
+        {
+            current_token = Some(current_token.take().unwrap().append(character));
         } else if TOKEN_TERMINATORS.contains(&character) {
             (current_token, tokens) = push_if_some(current_token, tokens);
-            tokens.push(Token::new(
-                TokenPosition {
-                    line: current_line,
-                    column: current_column,
-                },
-                character,
-            ));
-            current_token_is_whitespace = false;
-        } else if changing_between_whitespace_and_non {
-            (_, tokens) = push_if_some(current_token, tokens);
-            current_token = Some(Token::new(
-                TokenPosition {
-                    line: current_line,
-                    column: current_column,
-                },
-                character,
-            ));
-            current_token_is_whitespace = !current_token_is_whitespace;
+            tokens.push(Token::new(position.clone(), character));
+            if character == ';' && statement_is_copy_from_stdin(&tokens) {
+                position = position_after(&position, character);
+                position = skip_copy_data(&mut characters, position);
+                continue;
+            }
         } else {
-            current_token = match current_token {
-                Some(token) => Some(token.append(character)),
-                None => Some(Token::new(
-                    TokenPosition {
-                        line: current_line,
-                        column: current_column,
-                    },
-                    character,
-                )),
-            };
+            (_, tokens) = push_if_some(current_token, tokens);
+            current_token = Some(Token::new(position.clone(), character));
+            if character == '$' {
+                current_dollar_quote_tag = dollar_quote_tag(&characters);
+            }
         }
 
-        if character == '\n' {
-            current_line += 1;
-            current_column = 0;
-        } else {
-            current_column += 1;
-        }
+        position = position_after(&position, character);
     }
 
     match current_token.take() {
@@ -208,4 +444,180 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn lexer_string_literal() {
+        assert_eq!(
+            tokenize_postgresql("'it''s; a, b'x"),
+            vec![
+                Token {
+                    position: TokenPosition { line: 0, column: 0 },
+                    value: String::from("'it''s; a, b'")
+                },
+                Token {
+                    position: TokenPosition {
+                        line: 0,
+                        column: 13
+                    },
+                    value: String::from("x")
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn lexer_parameters() {
+        assert_eq!(
+            tokenize_postgresql("$1,:'a',:\"b\",:c"),
+            vec![
+                Token {
+                    position: TokenPosition { line: 0, column: 0 },
+                    value: String::from("$1")
+                },
+                Token {
+                    position: TokenPosition { line: 0, column: 2 },
+                    value: String::from(",")
+                },
+                Token {
+                    position: TokenPosition { line: 0, column: 3 },
+                    value: String::from(":'a'")
+                },
+                Token {
+                    position: TokenPosition { line: 0, column: 7 },
+                    value: String::from(",")
+                },
+                Token {
+                    position: TokenPosition { line: 0, column: 8 },
+                    value: String::from(":\"b\"")
+                },
+                Token {
+                    position: TokenPosition {
+                        line: 0,
+                        column: 12
+                    },
+                    value: String::from(",")
+                },
+                Token {
+                    position: TokenPosition {
+                        line: 0,
+                        column: 13
+                    },
+                    value: String::from(":c")
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn lexer_operators_and_parentheses() {
+        assert_eq!(
+            tokenize_postgresql("f(a>=-1.5)::b")
+                .into_iter()
+                .map(|token| token.value)
+                .collect::<Vec<String>>(),
+            vec!["f", "(", "a", ">=", "-", "1.5", ")", "::", "b"]
+        );
+    }
+
+    #[test]
+    fn lexer_operator_trailing_sign_rules() {
+        assert_eq!(
+            tokenize_postgresql("a@-b<-c")
+                .into_iter()
+                .map(|token| token.value)
+                .collect::<Vec<String>>(),
+            vec!["a", "@-", "b", "<", "-", "c"]
+        );
+    }
+
+    #[test]
+    fn lexer_dollar_quoted_strings() {
+        assert_eq!(
+            tokenize_postgresql("$$it's; $a$ b$$,$body$ $$ $body$ $1")
+                .into_iter()
+                .map(|token| token.value)
+                .collect::<Vec<String>>(),
+            vec!["$$it's; $a$ b$$", ",", "$body$ $$ $body$", " ", "$1"]
+        );
+    }
+
+    #[test]
+    fn lexer_integer_range() {
+        assert_eq!(
+            tokenize_postgresql("1..10 a.b")
+                .into_iter()
+                .map(|token| token.value)
+                .collect::<Vec<String>>(),
+            vec!["1", "..", "10", " ", "a", ".", "b"]
+        );
+    }
+
+    #[test]
+    fn lexer_copy_from_stdin_data() {
+        let tokens = tokenize_postgresql("copy t from stdin;\n1\t'a;\n\\.\nend;");
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|token| token.value.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["copy", " ", "t", " ", "from", " ", "stdin", ";", "\n", "end", ";"]
+        );
+        assert_eq!(tokens[8].position, TokenPosition { line: 2, column: 2 });
+    }
+
+    #[test]
+    fn lexer_comments_are_whitespace() {
+        let tokens =
+            tokenize_postgresql("a -- one; two\n/* x /* nested; */ */b/**/c--d\n-e 'f -- g'");
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|token| token.value.as_str())
+                .collect::<Vec<&str>>(),
+            vec![
+                "a",
+                " -- one; two\n/* x /* nested; */ */",
+                "b",
+                "/**/",
+                "c",
+                "--d\n",
+                "-",
+                "e",
+                " ",
+                "'f -- g'"
+            ]
+        );
+        assert_eq!(
+            tokens[2].position,
+            TokenPosition {
+                line: 1,
+                column: 21
+            }
+        );
+        assert_eq!(tokens[6].position, TokenPosition { line: 2, column: 0 });
+    }
+
+    #[test]
+    fn lexer_copy_from_stdin_after_comments() {
+        let tokens =
+            tokenize_postgresql("-- Name: t; Type: TABLE\ncopy /* ; */ t from stdin;\n1\n\\.\n");
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|token| token.value.as_str())
+                .collect::<Vec<&str>>(),
+            vec![
+                "-- Name: t; Type: TABLE\n",
+                "copy",
+                " /* ; */ ",
+                "t",
+                " ",
+                "from",
+                " ",
+                "stdin",
+                ";",
+                "\n"
+            ]
+        );
+    }
 }
@@ -3,6 +3,7 @@ extern crate lazy_static;
 mod lexer;
 use lexer::tokenize_postgresql;
 mod parser;
+pub mod plpgsql;
 pub use parser::ast;
 use parser::ast::PostgresqlAbstractSyntaxTree;
 use parser::parse_postgresql_tokens;
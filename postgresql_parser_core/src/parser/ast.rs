@@ -1,3 +1,8 @@
+pub use crate::lexer::token::TokenPosition;
+use crate::lexer::{token_value_is_whitespace, tokenize_postgresql};
+use crate::parser::parameters::collect_command_parameters;
+use crate::plpgsql::ast::Block;
+
 #[derive(Debug, PartialEq)]
 pub struct PostgresqlAbstractSyntaxTree {
     pub commands: Vec<Command>,
@@ -18,12 +23,26 @@ impl PostgresqlAbstractSyntaxTree {
         self.commands.push(command);
         return self;
     }
+
+    // Every positional ($1) and psql variable (:name) parameter in the tree,
+    // in the order they appear in the source
+    pub fn parameters(&self) -> Vec<&Parameter> {
+        let mut parameters: Vec<&Parameter> = Vec::new();
+        for command in &self.commands {
+            collect_command_parameters(command, &mut parameters);
+        }
+        return parameters;
+    }
 }
 
 #[derive(Debug, PartialEq)]
 pub enum Command {
     Empty(EmptyCommand),
     DataManipulation(DataManipulationCommand),
+    DataDefinition(DataDefinitionCommand),
+    DataControl(DataControlCommand),
+    TransactionControl(TransactionControlCommand),
+    Utility(UtilityCommand),
 }
 
 #[derive(Debug, PartialEq)]
@@ -32,18 +51,108 @@ pub struct EmptyCommand {}
 #[derive(Debug, PartialEq)]
 pub enum DataManipulationCommand {
     Select(SelectCommand),
+    Values(ValuesCommand),
+    SetOperation(SetOperation),
+    Insert(InsertCommand),
+    Update(UpdateCommand),
+    Delete(DeleteCommand),
+    Merge(MergeCommand),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Query {
+    Select(SelectCommand),
+    Values(ValuesCommand),
+    SetOperation(SetOperation),
 }
 
 #[derive(Debug, PartialEq)]
 pub struct SelectCommand {
     pub selected_expressions: Vec<SelectedExpression>,
-    pub from_item: FromItem,
+    pub from_items: Vec<FromItem>,
+    pub where_clause: Option<Expression>,
+    pub order_by: Vec<SortExpression>,
+    pub limit: Option<Expression>,
+    pub offset: Option<Expression>,
+    pub locking: Vec<LockingClause>,
+}
+
+// FOR lock_strength [OF table_name, ...] [NOWAIT | SKIP LOCKED]
+#[derive(Debug, PartialEq)]
+pub struct LockingClause {
+    pub strength: LockStrength,
+    // Empty when the lock applies to every table in the query
+    pub tables: Vec<QualifiedName>,
+    pub wait_policy: Option<LockWaitPolicy>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LockStrength {
+    Update,
+    NoKeyUpdate,
+    Share,
+    KeyShare,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LockWaitPolicy {
+    NoWait,
+    SkipLocked,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ValuesCommand {
+    pub rows: Vec<Vec<Expression>>,
+    pub order_by: Vec<SortExpression>,
+    pub limit: Option<Expression>,
+    pub offset: Option<Expression>,
+}
+
+// UNION, INTERSECT or EXCEPT. ORDER BY, LIMIT and OFFSET after the last
+// operand apply to the result of the whole operation.
+#[derive(Debug, PartialEq)]
+pub struct SetOperation {
+    pub left: Box<Query>,
+    pub operator: SetOperator,
+    // Duplicate rows are removed unless ALL is given
+    pub all: bool,
+    pub right: Box<Query>,
+    pub order_by: Vec<SortExpression>,
+    pub limit: Option<Expression>,
+    pub offset: Option<Expression>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SetOperator {
+    Union,
+    Intersect,
+    Except,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SortExpression {
+    pub expression: Expression,
+    pub direction: Option<SortDirection>,
+    pub nulls: Option<NullsOrder>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum NullsOrder {
+    First,
+    Last,
 }
 
 #[derive(Debug, PartialEq)]
 pub enum SelectedExpression {
     AllColumns(AllColumnsSelectedExpression),
     Column(ColumnSelectedExpression),
+    Expression(Expression),
 }
 
 // Maybe this should be smarter to distinguish from aliased names
@@ -54,6 +163,13 @@ pub struct AllColumnsSelectedExpression {
     pub table_name: Option<Identifier>,
 }
 
+// output_expression [[AS] output_name] of a RETURNING clause
+#[derive(Debug, PartialEq)]
+pub struct OutputExpression {
+    pub expression: SelectedExpression,
+    pub output_name: Option<Identifier>,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ColumnSelectedExpression {
     pub schema_name: Option<Identifier>,
@@ -62,9 +178,1850 @@ pub struct ColumnSelectedExpression {
 }
 
 #[derive(Debug, PartialEq)]
-pub struct FromItem {
-    pub schema_name: Option<Identifier>,
-    pub table_name: Identifier,
+pub struct InsertCommand {
+    pub table_name: QualifiedName,
+    pub alias: Option<Identifier>,
+    pub column_names: Vec<Identifier>,
+    pub overriding: Option<InsertOverriding>,
+    pub source: InsertSource,
+    pub on_conflict: Option<OnConflictClause>,
+    pub returning: Vec<OutputExpression>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertOverriding {
+    SystemValue,
+    UserValue,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertSource {
+    Values(Vec<Vec<Expression>>),
+    Query(Query),
+    DefaultValues,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct OnConflictClause {
+    pub target: Option<ConflictTarget>,
+    pub action: ConflictAction,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ConflictTarget {
+    // ON CONFLICT (column, (expression), ...) [WHERE index_predicate]
+    IndexExpressions {
+        expressions: Vec<Expression>,
+        index_predicate: Option<Expression>,
+    },
+    // ON CONFLICT ON CONSTRAINT constraint_name
+    Constraint(Identifier),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ConflictAction {
+    DoNothing,
+    DoUpdate {
+        assignments: Vec<Assignment>,
+        where_clause: Option<Expression>,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UpdateCommand {
+    pub target: TargetTable,
+    pub assignments: Vec<Assignment>,
+    pub from_items: Vec<FromItem>,
+    pub where_clause: Option<WhereClause>,
+    pub returning: Vec<OutputExpression>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DeleteCommand {
+    pub target: TargetTable,
+    pub using_items: Vec<FromItem>,
+    // None when every row of the table is deleted
+    pub where_clause: Option<WhereClause>,
+    pub returning: Vec<OutputExpression>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MergeCommand {
+    pub target: TargetTable,
+    pub source: MergeSource,
+    pub join_condition: Expression,
+    pub when_clauses: Vec<MergeWhenClause>,
+    pub returning: Vec<OutputExpression>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MergeSource {
+    Table(TargetTable),
+    Query {
+        query: Query,
+        alias: Option<TableAlias>,
+    },
+}
+
+// WHEN [NOT] MATCHED [BY {SOURCE | TARGET}] [AND condition] THEN action
+#[derive(Debug, PartialEq)]
+pub struct MergeWhenClause {
+    pub match_kind: MergeMatchKind,
+    pub condition: Option<Expression>,
+    pub action: MergeAction,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MergeMatchKind {
+    Matched,
+    NotMatchedBySource,
+    // Plain WHEN NOT MATCHED is the same as WHEN NOT MATCHED BY TARGET
+    NotMatchedByTarget,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MergeAction {
+    Update(Vec<Assignment>),
+    Delete,
+    Insert(MergeInsert),
+    DoNothing,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MergeInsert {
+    pub column_names: Vec<Identifier>,
+    pub overriding: Option<InsertOverriding>,
+    // None for INSERT DEFAULT VALUES
+    pub values: Option<Vec<Expression>>,
+}
+
+// [ONLY] table_name [*] [[AS] alias], as modified by an UPDATE, DELETE or
+// MERGE, or read as the source of a MERGE
+#[derive(Debug, PartialEq)]
+pub struct TargetTable {
+    // ONLY excludes tables inheriting from this one
+    pub only: bool,
+    pub table_name: QualifiedName,
+    pub alias: Option<Identifier>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DataDefinitionCommand {
+    CreateTable(CreateTableCommand),
+    AlterTable(AlterTableCommand),
+    CreateIndex(CreateIndexCommand),
+    CreateView(CreateViewCommand),
+    CreateMaterializedView(CreateMaterializedViewCommand),
+    RefreshMaterializedView(RefreshMaterializedViewCommand),
+    CreateFunction(CreateFunctionCommand),
+    CreateTrigger(CreateTriggerCommand),
+    CreateEventTrigger(CreateEventTriggerCommand),
+    CreateType(CreateTypeCommand),
+    CreateDomain(CreateDomainCommand),
+    AlterType(AlterTypeCommand),
+    CreateSequence(CreateSequenceCommand),
+    AlterSequence(AlterSequenceCommand),
+    CreateSchema(CreateSchemaCommand),
+    CreateExtension(CreateExtensionCommand),
+    AlterExtension(AlterExtensionCommand),
+    CreateDatabase(CreateDatabaseCommand),
+    AlterDatabase(AlterDatabaseCommand),
+    CreateTablespace(CreateTablespaceCommand),
+    AlterTablespace(AlterTablespaceCommand),
+    CreateRole(CreateRoleCommand),
+    AlterRole(AlterRoleCommand),
+    AlterDefaultPrivileges(AlterDefaultPrivilegesCommand),
+    CreatePolicy(CreatePolicyCommand),
+    AlterPolicy(AlterPolicyCommand),
+    Drop(DropCommand),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CreateTableCommand {
+    pub persistence: Option<TablePersistence>,
+    pub if_not_exists: bool,
+    pub table_name: QualifiedName,
+    pub elements: Vec<TableElement>,
+    pub inherits: Vec<QualifiedName>,
+    pub partition_of: Option<PartitionOf>,
+    pub partition_by: Option<PartitionBy>,
+    pub storage_parameters: Vec<StorageParameter>,
+    pub tablespace: Option<Identifier>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TablePersistence {
+    Temporary,
+    Unlogged,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TableElement {
+    Column(ColumnDefinition),
+    Constraint(TableConstraint),
+    Like(TableLikeClause),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ColumnDefinition {
+    pub name: Identifier,
+    // None for the columns of a PARTITION OF table, which only add
+    // constraints to the columns of the parent
+    pub type_name: Option<TypeName>,
+    pub collation: Option<QualifiedName>,
+    pub constraints: Vec<ColumnConstraint>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ColumnConstraint {
+    pub name: Option<Identifier>,
+    pub kind: ColumnConstraintKind,
+    pub deferrable: Option<bool>,
+    pub initially_deferred: Option<bool>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ColumnConstraintKind {
+    NotNull,
+    Null,
+    Default(Expression),
+    Check {
+        expression: Expression,
+        no_inherit: bool,
+    },
+    Unique {
+        nulls_not_distinct: bool,
+    },
+    PrimaryKey,
+    References(ForeignKeyReference),
+    // GENERATED {ALWAYS | BY DEFAULT} AS IDENTITY [(sequence_option ...)]
+    Identity {
+        always: bool,
+        sequence_options: Vec<SequenceOption>,
+    },
+    // GENERATED ALWAYS AS (expression) STORED
+    Generated(Expression),
+}
+
+// [CONSTRAINT name] {UNIQUE | PRIMARY KEY} USING INDEX index_name
+// [[NOT] DEFERRABLE] [INITIALLY {DEFERRED | IMMEDIATE}], which turns an
+// existing unique index into a constraint. Only ALTER TABLE accepts it.
+#[derive(Debug, PartialEq)]
+pub struct TableConstraintUsingIndex {
+    pub name: Option<Identifier>,
+    // PRIMARY KEY rather than UNIQUE
+    pub primary_key: bool,
+    pub index_name: Identifier,
+    pub deferrable: Option<bool>,
+    pub initially_deferred: Option<bool>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TableConstraint {
+    pub name: Option<Identifier>,
+    pub kind: TableConstraintKind,
+    pub deferrable: Option<bool>,
+    pub initially_deferred: Option<bool>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TableConstraintKind {
+    Check {
+        expression: Expression,
+        no_inherit: bool,
+    },
+    Unique {
+        nulls_not_distinct: bool,
+        columns: Vec<Identifier>,
+        include: Vec<Identifier>,
+    },
+    PrimaryKey {
+        columns: Vec<Identifier>,
+        include: Vec<Identifier>,
+    },
+    ForeignKey {
+        columns: Vec<Identifier>,
+        reference: ForeignKeyReference,
+    },
+    Exclude {
+        method: Option<Identifier>,
+        elements: Vec<ExclusionElement>,
+        include: Vec<Identifier>,
+        where_clause: Option<Expression>,
+    },
+}
+
+// index_element WITH operator, e.g. the (room WITH =) of an EXCLUDE constraint
+#[derive(Debug, PartialEq)]
+pub struct ExclusionElement {
+    pub element: IndexElement,
+    pub operator: String,
+}
+
+// REFERENCES table [(column, ...)] [MATCH type] [ON DELETE action] [ON UPDATE action]
+#[derive(Debug, PartialEq)]
+pub struct ForeignKeyReference {
+    pub table_name: QualifiedName,
+    pub columns: Vec<Identifier>,
+    pub match_type: Option<ForeignKeyMatch>,
+    pub on_delete: Option<ReferentialAction>,
+    pub on_update: Option<ReferentialAction>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ForeignKeyMatch {
+    Full,
+    Partial,
+    Simple,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    // SET NULL and SET DEFAULT may be limited to some of the columns
+    SetNull(Vec<Identifier>),
+    SetDefault(Vec<Identifier>),
+}
+
+// An option of a sequence, or of the sequence behind an identity column
+#[derive(Debug, PartialEq)]
+pub enum SequenceOption {
+    As(TypeName),
+    IncrementBy(Expression),
+    // None for NO MINVALUE and NO MAXVALUE
+    MinValue(Option<Expression>),
+    MaxValue(Option<Expression>),
+    StartWith(Expression),
+    Cache(Expression),
+    Cycle(bool),
+    // RESTART [[WITH] value], which is only valid when altering a sequence.
+    // None to restart from the start value.
+    Restart(Option<Expression>),
+    // None for OWNED BY NONE
+    OwnedBy(Option<ColumnSelectedExpression>),
+    // Only for identity columns
+    SequenceName(QualifiedName),
+}
+
+// LIKE source_table [{INCLUDING | EXCLUDING} property ...]
+#[derive(Debug, PartialEq)]
+pub struct TableLikeClause {
+    pub source_table: QualifiedName,
+    pub options: Vec<TableLikeOption>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TableLikeOption {
+    pub including: bool,
+    pub property: TableLikeProperty,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TableLikeProperty {
+    Comments,
+    Compression,
+    Constraints,
+    Defaults,
+    Generated,
+    Identity,
+    Indexes,
+    Statistics,
+    Storage,
+    All,
+}
+
+// PARTITION BY {RANGE | LIST | HASH} (key, ...)
+#[derive(Debug, PartialEq)]
+pub struct PartitionBy {
+    pub strategy: PartitionStrategy,
+    pub keys: Vec<PartitionKey>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PartitionStrategy {
+    Range,
+    List,
+    Hash,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PartitionKey {
+    // A column name, or an expression in parentheses
+    pub expression: Expression,
+    pub collation: Option<QualifiedName>,
+    pub operator_class: Option<QualifiedName>,
+}
+
+// PARTITION OF parent {FOR VALUES ... | DEFAULT}
+#[derive(Debug, PartialEq)]
+pub struct PartitionOf {
+    pub parent_table: QualifiedName,
+    pub bound: PartitionBound,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PartitionBound {
+    In(Vec<Expression>),
+    Range {
+        from: Vec<PartitionRangeValue>,
+        to: Vec<PartitionRangeValue>,
+    },
+    Hash {
+        modulus: Expression,
+        remainder: Expression,
+    },
+    Default,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PartitionRangeValue {
+    MinValue,
+    MaxValue,
+    Value(Expression),
+}
+
+// name [= value] in WITH (...), e.g. fillfactor = 70 or
+// toast.autovacuum_enabled = false
+#[derive(Debug, PartialEq)]
+pub struct StorageParameter {
+    pub namespace: Option<Identifier>,
+    pub name: Identifier,
+    pub value: Option<Expression>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AlterTableCommand {
+    pub if_exists: bool,
+    pub only: bool,
+    pub table_name: QualifiedName,
+    pub actions: Vec<AlterTableAction>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AlterTableAction {
+    AddColumn {
+        if_not_exists: bool,
+        column: ColumnDefinition,
+    },
+    DropColumn {
+        if_exists: bool,
+        name: Identifier,
+        behavior: Option<DropBehavior>,
+    },
+    AlterColumn {
+        name: Identifier,
+        action: AlterColumnAction,
+    },
+    AddConstraint {
+        constraint: TableConstraint,
+        not_valid: bool,
+    },
+    AddConstraintUsingIndex(TableConstraintUsingIndex),
+    DropConstraint {
+        if_exists: bool,
+        name: Identifier,
+        behavior: Option<DropBehavior>,
+    },
+    ValidateConstraint(Identifier),
+    RenameConstraint {
+        name: Identifier,
+        new_name: Identifier,
+    },
+    RenameColumn {
+        name: Identifier,
+        new_name: Identifier,
+    },
+    RenameTo(Identifier),
+    SetSchema(Identifier),
+    AttachPartition {
+        partition: QualifiedName,
+        bound: PartitionBound,
+    },
+    DetachPartition {
+        partition: QualifiedName,
+        mode: Option<DetachPartitionMode>,
+    },
+    // ENABLE [REPLICA | ALWAYS] TRIGGER or DISABLE TRIGGER
+    SetTriggerState {
+        state: TriggerState,
+        trigger: TriggerSelection,
+    },
+    RowLevelSecurity(RowLevelSecurityAction),
+    OwnerTo(RoleSpecification),
+    SetStorageParameters(Vec<StorageParameter>),
+    // RESET (name, ...) only lists the parameters, without values
+    ResetStorageParameters(Vec<StorageParameter>),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AlterColumnAction {
+    SetDataType {
+        type_name: TypeName,
+        collation: Option<QualifiedName>,
+        using: Option<Expression>,
+    },
+    SetDefault(Expression),
+    DropDefault,
+    SetNotNull,
+    DropNotNull,
+    AddIdentity {
+        always: bool,
+        sequence_options: Vec<SequenceOption>,
+    },
+    DropIdentity {
+        if_exists: bool,
+    },
+    SetStatistics(Expression),
+    SetStorage(ColumnStorage),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ColumnStorage {
+    Plain,
+    External,
+    Extended,
+    Main,
+    Default,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DropBehavior {
+    Cascade,
+    Restrict,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DetachPartitionMode {
+    Concurrently,
+    Finalize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TriggerState {
+    Enabled,
+    EnabledReplica,
+    EnabledAlways,
+    Disabled,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TriggerSelection {
+    Name(Identifier),
+    All,
+    // All triggers except the internally generated constraint triggers
+    User,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RowLevelSecurityAction {
+    Enable,
+    Disable,
+    Force,
+    NoForce,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RoleSpecification {
+    Name(Identifier),
+    // Only valid as a grantee, where it stands for all roles
+    Public,
+    CurrentRole,
+    CurrentUser,
+    SessionUser,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CreateIndexCommand {
+    pub unique: bool,
+    pub concurrently: bool,
+    pub if_not_exists: bool,
+    // A name is chosen by the server when it is left out
+    pub name: Option<Identifier>,
+    pub only: bool,
+    pub table_name: QualifiedName,
+    pub method: Option<Identifier>,
+    pub elements: Vec<IndexElement>,
+    pub include: Vec<Identifier>,
+    pub nulls_not_distinct: bool,
+    pub storage_parameters: Vec<StorageParameter>,
+    pub tablespace: Option<Identifier>,
+    // The predicate of a partial index
+    pub where_clause: Option<Expression>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct IndexElement {
+    // A column name, or an expression in parentheses
+    pub expression: Expression,
+    pub collation: Option<QualifiedName>,
+    pub operator_class: Option<QualifiedName>,
+    pub direction: Option<SortDirection>,
+    pub nulls: Option<NullsOrder>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CreateViewCommand {
+    pub or_replace: bool,
+    pub temporary: bool,
+    pub recursive: bool,
+    pub name: QualifiedName,
+    pub columns: Vec<Identifier>,
+    pub options: Vec<StorageParameter>,
+    pub query: Query,
+    pub check_option: Option<ViewCheckOption>,
+}
+
+// WITH CHECK OPTION on its own means CASCADED
+#[derive(Debug, PartialEq)]
+pub enum ViewCheckOption {
+    Cascaded,
+    Local,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CreateMaterializedViewCommand {
+    pub if_not_exists: bool,
+    pub name: QualifiedName,
+    pub columns: Vec<Identifier>,
+    pub method: Option<Identifier>,
+    pub storage_parameters: Vec<StorageParameter>,
+    pub tablespace: Option<Identifier>,
+    pub query: Query,
+    // WITH NO DATA leaves the view unscannable until it is refreshed
+    pub with_data: bool,
+}
+
+// REFRESH MATERIALIZED VIEW [CONCURRENTLY] name [WITH [NO] DATA]
+#[derive(Debug, PartialEq)]
+pub struct RefreshMaterializedViewCommand {
+    pub concurrently: bool,
+    pub name: QualifiedName,
+    pub with_data: bool,
+}
+
+// CREATE [OR REPLACE] {FUNCTION | PROCEDURE} name ([parameter, ...])
+//     [RETURNS return_type] option ... [sql_body]
+#[derive(Debug, PartialEq)]
+pub struct CreateFunctionCommand {
+    pub or_replace: bool,
+    pub kind: RoutineKind,
+    pub name: QualifiedName,
+    pub parameters: Vec<FunctionParameter>,
+    // Left out for procedures, and for functions with OUT parameters
+    pub returns: Option<FunctionReturnType>,
+    pub options: Vec<FunctionOption>,
+    pub body: Option<FunctionBody>,
+}
+
+impl CreateFunctionCommand {
+    pub fn language(&self) -> Option<&Identifier> {
+        return self.options.iter().find_map(|option| match option {
+            FunctionOption::Language(language) => Some(language),
+            _ => None,
+        });
+    }
+
+    // The commands of a LANGUAGE sql body given as a string, which is
+    // otherwise kept as written. None for other languages and body forms.
+    pub fn parse_sql_body(&self) -> Option<PostgresqlAbstractSyntaxTree> {
+        let is_sql = self
+            .language()
+            .is_some_and(|language| language.value.eq_ignore_ascii_case("sql"));
+        return match &self.body {
+            Some(FunctionBody::Definition(definition)) if is_sql => {
+                // The semicolon after the last command of a body is optional.
+                // It goes on its own line so a trailing -- comment can't
+                // swallow it.
+                let tokens = tokenize_postgresql(definition);
+                let last_token = tokens
+                    .iter()
+                    .rev()
+                    .find(|token| !token_value_is_whitespace(&token.value));
+                let mut definition = definition.to_string();
+                if last_token.is_some_and(|token| token.value != ";") {
+                    definition.push_str("\n;");
+                }
+                Some(crate::parse_postgresql(&definition))
+            }
+            _ => None,
+        };
+    }
+
+    // The block of a LANGUAGE plpgsql body, or None for other languages and
+    // bodies that fail to parse
+    pub fn parse_plpgsql_body(&self) -> Option<Block> {
+        let is_plpgsql = self
+            .language()
+            .is_some_and(|language| language.value.eq_ignore_ascii_case("plpgsql"));
+        return match &self.body {
+            Some(FunctionBody::Definition(definition)) if is_plpgsql => {
+                crate::plpgsql::parse_plpgsql(definition)
+            }
+            _ => None,
+        };
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RoutineKind {
+    Function,
+    Procedure,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FunctionReturnType {
+    Type(TypeName),
+    SetOf(TypeName),
+    Table(Vec<FunctionColumnDefinition>),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FunctionOption {
+    Language(Identifier),
+    Volatility(FunctionVolatility),
+    // STRICT and RETURNS NULL ON NULL INPUT, or CALLED ON NULL INPUT
+    Strict(bool),
+    // [EXTERNAL] SECURITY DEFINER, or SECURITY INVOKER
+    SecurityDefiner(bool),
+    Leakproof(bool),
+    Parallel(FunctionParallelSafety),
+    Cost(Expression),
+    Rows(Expression),
+    Set(ConfigurationSetting),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FunctionVolatility {
+    Immutable,
+    Stable,
+    Volatile,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FunctionParallelSafety {
+    Unsafe,
+    Restricted,
+    Safe,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FunctionBody {
+    // AS 'definition', usually dollar quoted, in the function's language
+    Definition(String),
+    // AS 'object_file', 'link_symbol', for functions written in C
+    ObjectFile {
+        object_file: String,
+        link_symbol: String,
+    },
+    // The SQL-standard RETURN expression
+    Return(Expression),
+    // The SQL-standard BEGIN ATOMIC command; ... END
+    Atomic(Vec<Command>),
+}
+
+// name {TO | =} {value, ... | DEFAULT}, or name FROM CURRENT
+#[derive(Debug, PartialEq)]
+pub struct ConfigurationSetting {
+    pub name: QualifiedName,
+    pub value: ConfigurationValue,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ConfigurationValue {
+    Values(Vec<Expression>),
+    Default,
+    FromCurrent,
+}
+
+// CREATE [OR REPLACE] [CONSTRAINT] TRIGGER name {BEFORE | AFTER | INSTEAD OF}
+//     event [OR ...] ON table [FROM referenced_table] [deferrability]
+//     [REFERENCING {OLD | NEW} TABLE [AS] name ...]
+//     [FOR [EACH] {ROW | STATEMENT}] [WHEN (condition)]
+//     EXECUTE {FUNCTION | PROCEDURE} function_name([argument, ...])
+#[derive(Debug, PartialEq)]
+pub struct CreateTriggerCommand {
+    pub or_replace: bool,
+    pub constraint: bool,
+    pub name: Identifier,
+    pub timing: TriggerTiming,
+    pub events: Vec<TriggerEvent>,
+    pub table_name: QualifiedName,
+    // The table referenced by a constraint trigger's foreign key
+    pub referenced_table: Option<QualifiedName>,
+    pub deferrable: Option<bool>,
+    pub initially_deferred: Option<bool>,
+    pub transition_relations: Vec<TriggerTransitionRelation>,
+    // FOR EACH STATEMENT when not given
+    pub level: TriggerLevel,
+    pub condition: Option<Expression>,
+    pub function_name: QualifiedName,
+    pub arguments: Vec<Expression>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TriggerTiming {
+    Before,
+    After,
+    InsteadOf,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TriggerEvent {
+    Insert,
+    // UPDATE [OF column, ...]
+    Update(Vec<Identifier>),
+    Delete,
+    Truncate,
+}
+
+// {OLD | NEW} TABLE [AS] name
+#[derive(Debug, PartialEq)]
+pub struct TriggerTransitionRelation {
+    pub kind: TransitionRelationKind,
+    pub name: Identifier,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TransitionRelationKind {
+    Old,
+    New,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TriggerLevel {
+    Row,
+    Statement,
+}
+
+// CREATE EVENT TRIGGER name ON event [WHEN variable IN ('value', ...) [AND ...]]
+//     EXECUTE {FUNCTION | PROCEDURE} function_name()
+#[derive(Debug, PartialEq)]
+pub struct CreateEventTriggerCommand {
+    pub name: Identifier,
+    // e.g. ddl_command_start or sql_drop
+    pub event: Identifier,
+    pub filters: Vec<EventTriggerFilter>,
+    pub function_name: QualifiedName,
+}
+
+// variable IN ('value', ...), where the variable is TAG
+#[derive(Debug, PartialEq)]
+pub struct EventTriggerFilter {
+    pub variable: Identifier,
+    pub values: Vec<String>,
+}
+
+// CREATE TYPE name [AS definition], where a type without a definition is a
+// shell type to be filled in later
+#[derive(Debug, PartialEq)]
+pub struct CreateTypeCommand {
+    pub name: QualifiedName,
+    pub definition: Option<TypeDefinition>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TypeDefinition {
+    // AS ([attribute type [COLLATE collation], ...])
+    Composite(Vec<CompositeTypeAttribute>),
+    // AS ENUM (['label', ...])
+    Enum(Vec<String>),
+    // AS RANGE (SUBTYPE = subtype [, option = value ...])
+    Range(Vec<TypeOption>),
+    // (INPUT = input_function, OUTPUT = output_function [, option [= value] ...])
+    Base(Vec<TypeOption>),
+}
+
+// option [= value] of a range or base type, e.g. SUBTYPE = float8 or
+// PASSEDBYVALUE, where the value is None
+#[derive(Debug, PartialEq)]
+pub struct TypeOption {
+    pub name: Identifier,
+    pub value: Option<TypeOptionValue>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TypeOptionValue {
+    // The value of SUBTYPE, MULTIRANGE_TYPE_NAME, ELEMENT and LIKE
+    Type(TypeName),
+    // e.g. a function name, a number or a string
+    Expression(Expression),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CompositeTypeAttribute {
+    pub name: Identifier,
+    pub type_name: TypeName,
+    pub collation: Option<QualifiedName>,
+}
+
+// CREATE DOMAIN name [AS] type [COLLATE collation] [DEFAULT expression]
+//     [constraint ...]
+#[derive(Debug, PartialEq)]
+pub struct CreateDomainCommand {
+    pub name: QualifiedName,
+    pub type_name: TypeName,
+    pub collation: Option<QualifiedName>,
+    pub default: Option<Expression>,
+    pub constraints: Vec<DomainConstraint>,
+}
+
+// [CONSTRAINT name] {NOT NULL | NULL | CHECK (expression)}
+#[derive(Debug, PartialEq)]
+pub struct DomainConstraint {
+    pub name: Option<Identifier>,
+    pub kind: DomainConstraintKind,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DomainConstraintKind {
+    NotNull,
+    Null,
+    Check(Expression),
+}
+
+// ALTER TYPE name action
+#[derive(Debug, PartialEq)]
+pub struct AlterTypeCommand {
+    pub name: QualifiedName,
+    pub action: AlterTypeAction,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AlterTypeAction {
+    // ADD VALUE [IF NOT EXISTS] 'label' [{BEFORE | AFTER} 'neighbor']
+    AddValue {
+        if_not_exists: bool,
+        value: String,
+        position: Option<EnumValuePosition>,
+    },
+    // RENAME VALUE 'label' TO 'new_label'
+    RenameValue {
+        value: String,
+        new_value: String,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum EnumValuePosition {
+    Before(String),
+    After(String),
+}
+
+// CREATE [{TEMPORARY | TEMP} | UNLOGGED] SEQUENCE [IF NOT EXISTS] name
+//     [sequence_option ...]
+#[derive(Debug, PartialEq)]
+pub struct CreateSequenceCommand {
+    pub persistence: Option<TablePersistence>,
+    pub if_not_exists: bool,
+    pub name: QualifiedName,
+    pub options: Vec<SequenceOption>,
+}
+
+// ALTER SEQUENCE [IF EXISTS] name sequence_option ...
+#[derive(Debug, PartialEq)]
+pub struct AlterSequenceCommand {
+    pub if_exists: bool,
+    pub name: QualifiedName,
+    pub options: Vec<SequenceOption>,
+}
+
+// CREATE SCHEMA [IF NOT EXISTS] name [AUTHORIZATION role] [schema_element ...]
+// or CREATE SCHEMA [IF NOT EXISTS] AUTHORIZATION role [schema_element ...]
+#[derive(Debug, PartialEq)]
+pub struct CreateSchemaCommand {
+    pub if_not_exists: bool,
+    pub name: Option<Identifier>,
+    pub authorization: Option<RoleSpecification>,
+    // CREATE TABLE, VIEW, INDEX, SEQUENCE and TRIGGER commands and GRANT
+    // commands, without their terminating semicolons
+    pub elements: Vec<Command>,
+}
+
+// CREATE EXTENSION [IF NOT EXISTS] name [WITH] [SCHEMA schema_name]
+//     [VERSION version] [CASCADE]
+#[derive(Debug, PartialEq)]
+pub struct CreateExtensionCommand {
+    pub if_not_exists: bool,
+    pub name: Identifier,
+    pub schema: Option<Identifier>,
+    pub version: Option<String>,
+    pub cascade: bool,
+}
+
+// ALTER EXTENSION name action
+#[derive(Debug, PartialEq)]
+pub struct AlterExtensionCommand {
+    pub name: Identifier,
+    pub action: AlterExtensionAction,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AlterExtensionAction {
+    // UPDATE [TO new_version]
+    Update(Option<String>),
+    SetSchema(Identifier),
+}
+
+// CREATE DATABASE name [WITH] [option [=] value ...]
+#[derive(Debug, PartialEq)]
+pub struct CreateDatabaseCommand {
+    pub name: Identifier,
+    pub options: Vec<DatabaseOption>,
+}
+
+// An option such as OWNER, TEMPLATE, ENCODING or CONNECTION LIMIT
+#[derive(Debug, PartialEq)]
+pub struct DatabaseOption {
+    // The lowercased option name, with words separated by single spaces
+    pub name: String,
+    // None when the value is DEFAULT
+    pub value: Option<Expression>,
+}
+
+// ALTER DATABASE name action
+#[derive(Debug, PartialEq)]
+pub struct AlterDatabaseCommand {
+    pub name: Identifier,
+    pub action: AlterDatabaseAction,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AlterDatabaseAction {
+    // [WITH] option [=] value ...
+    Options(Vec<DatabaseOption>),
+    RenameTo(Identifier),
+    OwnerTo(RoleSpecification),
+    SetTablespace(Identifier),
+    Set(ConfigurationSetting),
+    // RESET configuration_parameter, or RESET ALL when None
+    Reset(Option<QualifiedName>),
+}
+
+// CREATE TABLESPACE name [OWNER role] LOCATION 'directory'
+//     [WITH (tablespace_option = value, ...)]
+#[derive(Debug, PartialEq)]
+pub struct CreateTablespaceCommand {
+    pub name: Identifier,
+    pub owner: Option<RoleSpecification>,
+    pub location: String,
+    pub options: Vec<StorageParameter>,
+}
+
+// ALTER TABLESPACE name action
+#[derive(Debug, PartialEq)]
+pub struct AlterTablespaceCommand {
+    pub name: Identifier,
+    pub action: AlterTablespaceAction,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AlterTablespaceAction {
+    RenameTo(Identifier),
+    OwnerTo(RoleSpecification),
+    SetOptions(Vec<StorageParameter>),
+    ResetOptions(Vec<StorageParameter>),
+}
+
+// CREATE {ROLE | USER | GROUP} name [[WITH] role_option ...]
+#[derive(Debug, PartialEq)]
+pub struct CreateRoleCommand {
+    pub kind: RoleKind,
+    pub name: Identifier,
+    pub options: Vec<RoleOption>,
+}
+
+// USER is ROLE with LOGIN as the default, and GROUP is an alias of ROLE
+#[derive(Debug, PartialEq)]
+pub enum RoleKind {
+    Role,
+    User,
+    Group,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RoleOption {
+    // e.g. SUPERUSER is Superuser(true) and NOSUPERUSER is Superuser(false)
+    Superuser(bool),
+    CreateDatabase(bool),
+    CreateRole(bool),
+    Inherit(bool),
+    Login(bool),
+    Replication(bool),
+    BypassRowLevelSecurity(bool),
+    ConnectionLimit(Expression),
+    // [ENCRYPTED] PASSWORD 'password', or PASSWORD NULL when None
+    Password(Option<String>),
+    ValidUntil(String),
+    // IN ROLE or IN GROUP role, ...
+    InRole(Vec<Identifier>),
+    // ROLE or USER role, ...
+    Role(Vec<Identifier>),
+    Admin(Vec<Identifier>),
+}
+
+// ALTER {ROLE | USER} {role | ALL} action
+#[derive(Debug, PartialEq)]
+pub struct AlterRoleCommand {
+    pub kind: RoleKind,
+    // None for ALL, which can only be used to SET or RESET parameters
+    pub role: Option<RoleSpecification>,
+    pub action: AlterRoleAction,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AlterRoleAction {
+    // [WITH] role_option ...
+    Options(Vec<RoleOption>),
+    RenameTo(Identifier),
+    // [IN DATABASE database] SET configuration_parameter {TO | =} value
+    Set {
+        database: Option<Identifier>,
+        setting: ConfigurationSetting,
+    },
+    // [IN DATABASE database] RESET {configuration_parameter | ALL}, where
+    // ALL is None
+    Reset {
+        database: Option<Identifier>,
+        name: Option<QualifiedName>,
+    },
+}
+
+// ALTER DEFAULT PRIVILEGES [FOR {ROLE | USER} role, ...] [IN SCHEMA schema, ...]
+//     {GRANT ... | REVOKE ...}
+#[derive(Debug, PartialEq)]
+pub struct AlterDefaultPrivilegesCommand {
+    pub roles: Vec<RoleSpecification>,
+    pub schemas: Vec<Identifier>,
+    pub action: DefaultPrivilegesAction,
+}
+
+// GRANT and REVOKE on the kind of objects created in the future, one of
+// TABLES, SEQUENCES, FUNCTIONS, ROUTINES, TYPES or SCHEMAS
+#[derive(Debug, PartialEq)]
+pub enum DefaultPrivilegesAction {
+    Grant {
+        privileges: Privileges,
+        object_type: PrivilegeObjectType,
+        grantees: Vec<RoleSpecification>,
+        with_grant_option: bool,
+    },
+    Revoke {
+        grant_option_for: bool,
+        privileges: Privileges,
+        object_type: PrivilegeObjectType,
+        grantees: Vec<RoleSpecification>,
+        behavior: Option<DropBehavior>,
+    },
+}
+
+// CREATE POLICY name ON table [AS {PERMISSIVE | RESTRICTIVE}]
+//     [FOR {ALL | SELECT | INSERT | UPDATE | DELETE}] [TO role, ...]
+//     [USING (expression)] [WITH CHECK (expression)]
+#[derive(Debug, PartialEq)]
+pub struct CreatePolicyCommand {
+    pub name: Identifier,
+    pub table_name: QualifiedName,
+    // None when AS is omitted, which means PERMISSIVE
+    pub kind: Option<PolicyKind>,
+    // None when FOR is omitted, which means ALL
+    pub command: Option<PolicyCommand>,
+    // Empty when TO is omitted, which means PUBLIC
+    pub roles: Vec<RoleSpecification>,
+    pub using: Option<Expression>,
+    pub with_check: Option<Expression>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PolicyKind {
+    Permissive,
+    Restrictive,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PolicyCommand {
+    All,
+    Select,
+    Insert,
+    Update,
+    Delete,
+}
+
+// ALTER POLICY name ON table action
+#[derive(Debug, PartialEq)]
+pub struct AlterPolicyCommand {
+    pub name: Identifier,
+    pub table_name: QualifiedName,
+    pub action: AlterPolicyAction,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AlterPolicyAction {
+    RenameTo(Identifier),
+    // [TO role, ...] [USING (expression)] [WITH CHECK (expression)], where at
+    // least one clause is present
+    Update {
+        roles: Vec<RoleSpecification>,
+        using: Option<Expression>,
+        with_check: Option<Expression>,
+    },
+}
+
+// DROP object_type [CONCURRENTLY] [IF EXISTS] object, ... [CASCADE | RESTRICT]
+// or DROP DATABASE [IF EXISTS] name [[WITH] (FORCE)]
+#[derive(Debug, PartialEq)]
+pub struct DropCommand {
+    pub object_type: DropObjectType,
+    pub concurrently: bool,
+    pub if_exists: bool,
+    pub objects: Vec<DropObject>,
+    pub behavior: Option<DropBehavior>,
+    pub force: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DropObjectType {
+    Aggregate,
+    Collation,
+    Database,
+    Domain,
+    EventTrigger,
+    Extension,
+    ForeignTable,
+    Function,
+    Group,
+    Index,
+    MaterializedView,
+    Policy,
+    Procedure,
+    Publication,
+    Role,
+    Routine,
+    Rule,
+    Schema,
+    Sequence,
+    Subscription,
+    Table,
+    Tablespace,
+    Trigger,
+    Type,
+    User,
+    View,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DropObject {
+    pub name: QualifiedName,
+    // The signature of a function, procedure, routine or aggregate, which
+    // may be left out when the name is unique
+    pub parameters: Option<Vec<FunctionParameter>>,
+    // The table of a trigger, policy or rule, as in DROP TRIGGER t ON table
+    pub table_name: Option<QualifiedName>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DataControlCommand {
+    Grant(GrantCommand),
+    Revoke(RevokeCommand),
+    GrantRole(GrantRoleCommand),
+    RevokeRole(RevokeRoleCommand),
+}
+
+// GRANT privileges ON target TO grantee, ... [WITH GRANT OPTION]
+//     [GRANTED BY role]
+#[derive(Debug, PartialEq)]
+pub struct GrantCommand {
+    pub privileges: Privileges,
+    pub target: PrivilegeTarget,
+    pub grantees: Vec<RoleSpecification>,
+    pub with_grant_option: bool,
+    pub granted_by: Option<RoleSpecification>,
+}
+
+// REVOKE [GRANT OPTION FOR] privileges ON target FROM grantee, ...
+//     [GRANTED BY role] [CASCADE | RESTRICT]
+#[derive(Debug, PartialEq)]
+pub struct RevokeCommand {
+    pub grant_option_for: bool,
+    pub privileges: Privileges,
+    pub target: PrivilegeTarget,
+    pub grantees: Vec<RoleSpecification>,
+    pub granted_by: Option<RoleSpecification>,
+    pub behavior: Option<DropBehavior>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Privileges {
+    // ALL [PRIVILEGES] [(column, ...)]
+    All(Vec<Identifier>),
+    List(Vec<Privilege>),
+}
+
+// privilege [(column, ...)], where columns may only be given for table
+// privileges
+#[derive(Debug, PartialEq)]
+pub struct Privilege {
+    pub kind: PrivilegeKind,
+    pub columns: Vec<Identifier>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PrivilegeKind {
+    Select,
+    Insert,
+    Update,
+    Delete,
+    Truncate,
+    References,
+    Trigger,
+    Maintain,
+    Create,
+    Connect,
+    Temporary,
+    Execute,
+    Usage,
+    Set,
+    AlterSystem,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PrivilegeTarget {
+    // [object_type] object, ..., where TABLE is the default object type
+    Objects {
+        object_type: PrivilegeObjectType,
+        objects: Vec<PrivilegeObject>,
+    },
+    // ALL {TABLES | SEQUENCES | FUNCTIONS | PROCEDURES | ROUTINES} IN SCHEMA
+    //     schema, ...
+    AllInSchema {
+        object_type: PrivilegeObjectType,
+        schemas: Vec<Identifier>,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PrivilegeObjectType {
+    Table,
+    Sequence,
+    Database,
+    Domain,
+    Function,
+    Procedure,
+    Routine,
+    Language,
+    Schema,
+    Tablespace,
+    Type,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PrivilegeObject {
+    pub name: QualifiedName,
+    // The signature of a function, procedure or routine, which may be left
+    // out when the name is unique
+    pub parameters: Option<Vec<FunctionParameter>>,
+}
+
+// GRANT role, ... TO role, ... [WITH {ADMIN | INHERIT | SET} {OPTION | TRUE | FALSE}, ...]
+//     [GRANTED BY role]
+#[derive(Debug, PartialEq)]
+pub struct GrantRoleCommand {
+    pub roles: Vec<Identifier>,
+    pub grantees: Vec<RoleSpecification>,
+    pub options: Vec<RoleGrantOption>,
+    pub granted_by: Option<RoleSpecification>,
+}
+
+// e.g. WITH ADMIN OPTION is { kind: Admin, value: true }
+#[derive(Debug, PartialEq)]
+pub struct RoleGrantOption {
+    pub kind: RoleGrantOptionKind,
+    pub value: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RoleGrantOptionKind {
+    Admin,
+    Inherit,
+    Set,
+}
+
+// REVOKE [{ADMIN | INHERIT | SET} OPTION FOR] role, ... FROM role, ...
+//     [GRANTED BY role] [CASCADE | RESTRICT]
+#[derive(Debug, PartialEq)]
+pub struct RevokeRoleCommand {
+    pub option_for: Option<RoleGrantOptionKind>,
+    pub roles: Vec<Identifier>,
+    pub grantees: Vec<RoleSpecification>,
+    pub granted_by: Option<RoleSpecification>,
+    pub behavior: Option<DropBehavior>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TransactionControlCommand {
+    // BEGIN [WORK | TRANSACTION] [mode, ...] or START TRANSACTION [mode, ...]
+    Begin(Vec<TransactionMode>),
+    // {COMMIT | END} [WORK | TRANSACTION] [AND [NO] CHAIN]
+    Commit {
+        and_chain: bool,
+    },
+    // {ROLLBACK | ABORT} [WORK | TRANSACTION] [AND [NO] CHAIN]
+    Rollback {
+        and_chain: bool,
+    },
+    // ROLLBACK [WORK | TRANSACTION] TO [SAVEPOINT] name
+    RollbackToSavepoint(Identifier),
+    Savepoint(Identifier),
+    // RELEASE [SAVEPOINT] name
+    ReleaseSavepoint(Identifier),
+    // SET TRANSACTION mode, ... or, when session is true,
+    // SET SESSION CHARACTERISTICS AS TRANSACTION mode, ...
+    SetTransaction {
+        session: bool,
+        modes: Vec<TransactionMode>,
+    },
+    // SET TRANSACTION SNAPSHOT snapshot_id
+    SetTransactionSnapshot(String),
+    // SET CONSTRAINTS {ALL | name, ...} {DEFERRED | IMMEDIATE}, where ALL is
+    // None
+    SetConstraints {
+        constraints: Option<Vec<QualifiedName>>,
+        deferred: bool,
+    },
+    // PREPARE TRANSACTION transaction_id
+    PrepareTransaction(String),
+    // COMMIT PREPARED transaction_id
+    CommitPrepared(String),
+    // ROLLBACK PREPARED transaction_id
+    RollbackPrepared(String),
+}
+
+// Modes are separated by commas or whitespace
+#[derive(Debug, PartialEq)]
+pub enum TransactionMode {
+    IsolationLevel(IsolationLevel),
+    // READ ONLY is ReadOnly(true) and READ WRITE is ReadOnly(false)
+    ReadOnly(bool),
+    Deferrable(bool),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum IsolationLevel {
+    Serializable,
+    RepeatableRead,
+    ReadCommitted,
+    ReadUncommitted,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UtilityCommand {
+    Do(DoCommand),
+    Set(SetCommand),
+    // RESET parameter
+    Reset(ConfigurationParameter),
+    // SHOW parameter
+    Show(ConfigurationParameter),
+    Explain(ExplainCommand),
+    Copy(CopyCommand),
+}
+
+// SET [SESSION | LOCAL] action
+#[derive(Debug, PartialEq)]
+pub struct SetCommand {
+    // None when omitted, which means SESSION
+    pub scope: Option<SetScope>,
+    pub action: SetAction,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SetScope {
+    Session,
+    Local,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SetAction {
+    // configuration_parameter {TO | =} {value, ... | DEFAULT}, e.g.
+    // search_path
+    Configuration(ConfigurationSetting),
+    // TIME ZONE {value | LOCAL | DEFAULT}, where LOCAL and DEFAULT are None
+    TimeZone(Option<Expression>),
+    // ROLE {role | NONE}, where NONE is None
+    Role(Option<IdentifierOrString>),
+    // SESSION AUTHORIZATION {user | DEFAULT}, where DEFAULT is None
+    SessionAuthorization(Option<IdentifierOrString>),
+}
+
+// A name that may also be written as a string literal, as in
+// SET SESSION AUTHORIZATION 'bob'
+#[derive(Debug, PartialEq)]
+pub enum IdentifierOrString {
+    Identifier(Identifier),
+    String(String),
+}
+
+// The configuration parameter of RESET or SHOW
+#[derive(Debug, PartialEq)]
+pub enum ConfigurationParameter {
+    Name(QualifiedName),
+    TimeZone,
+    // TRANSACTION ISOLATION LEVEL
+    TransactionIsolationLevel,
+    // SESSION AUTHORIZATION
+    SessionAuthorization,
+    All,
+}
+
+// EXPLAIN [(option, ...)] statement or EXPLAIN [ANALYZE] [VERBOSE] statement
+// The statement is a query, INSERT, UPDATE, DELETE, MERGE or CREATE
+// MATERIALIZED VIEW, the only parsed commands with an execution plan
+#[derive(Debug, PartialEq)]
+pub struct ExplainCommand {
+    pub options: Vec<ExplainOption>,
+    pub statement: Box<Command>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ExplainOption {
+    // e.g. ANALYZE is Analyze(true) and ANALYZE false is Analyze(false)
+    Analyze(bool),
+    Verbose(bool),
+    Costs(bool),
+    Settings(bool),
+    GenericPlan(bool),
+    Buffers(bool),
+    Wal(bool),
+    Timing(bool),
+    Summary(bool),
+    Memory(bool),
+    // SERIALIZE without a value is SERIALIZE TEXT
+    Serialize(ExplainSerialize),
+    Format(ExplainFormat),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ExplainSerialize {
+    None,
+    Text,
+    Binary,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ExplainFormat {
+    Text,
+    Xml,
+    Json,
+    Yaml,
+}
+
+// COPY {table [(column, ...)] | (query)} {FROM | TO}
+//     {'filename' | PROGRAM 'command' | STDIN | STDOUT} [[WITH] (option, ...)]
+#[derive(Debug, PartialEq)]
+pub struct CopyCommand {
+    pub relation: CopyRelation,
+    pub direction: CopyDirection,
+    pub target: CopyTarget,
+    pub options: Vec<CopyOption>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CopyRelation {
+    Table {
+        table_name: QualifiedName,
+        columns: Vec<Identifier>,
+    },
+    // Only with COPY TO
+    Query(Query),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CopyDirection {
+    From,
+    To,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CopyTarget {
+    File(String),
+    Program(String),
+    // Only with COPY FROM
+    Stdin,
+    // Only with COPY TO
+    Stdout,
+}
+
+// The legacy options written without parentheses, e.g. CSV HEADER, are read
+// as their parenthesized equivalents
+#[derive(Debug, PartialEq)]
+pub enum CopyOption {
+    Format(CopyFormat),
+    Freeze(bool),
+    Delimiter(String),
+    Null(String),
+    Default(String),
+    Header(CopyHeader),
+    Quote(String),
+    Escape(String),
+    // None for *, which means all columns
+    ForceQuote(Option<Vec<Identifier>>),
+    ForceNotNull(Option<Vec<Identifier>>),
+    ForceNull(Option<Vec<Identifier>>),
+    // e.g. stop or ignore
+    OnError(Identifier),
+    RejectLimit(Expression),
+    Encoding(String),
+    LogVerbosity(Identifier),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CopyFormat {
+    Text,
+    Csv,
+    Binary,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CopyHeader {
+    True,
+    False,
+    // HEADER MATCH, only with COPY FROM
+    Match,
+}
+
+// DO [LANGUAGE language] code, where LANGUAGE may also follow the code
+#[derive(Debug, PartialEq)]
+pub struct DoCommand {
+    pub language: Option<Identifier>,
+    pub code: String,
+}
+
+impl DoCommand {
+    // The block of the code when written in PL/pgSQL, the default language
+    pub fn parse_plpgsql_body(&self) -> Option<Block> {
+        return match &self.language {
+            Some(language) if !language.value.eq_ignore_ascii_case("plpgsql") => None,
+            _ => crate::plpgsql::parse_plpgsql(&self.code),
+        };
+    }
+}
+
+// [mode] [name] type [DEFAULT expression]
+#[derive(Debug, PartialEq)]
+pub struct FunctionParameter {
+    pub mode: Option<FunctionParameterMode>,
+    pub name: Option<Identifier>,
+    pub type_name: TypeName,
+    pub default: Option<Expression>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FunctionParameterMode {
+    In,
+    Out,
+    InOut,
+    Variadic,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum WhereClause {
+    Condition(Expression),
+    // WHERE CURRENT OF cursor_name
+    CurrentOf(Identifier),
+}
+
+// col = value, or (a, b) = (value, value) / (subquery) when several columns
+// are assigned at once
+#[derive(Debug, PartialEq)]
+pub struct Assignment {
+    pub column_names: Vec<Identifier>,
+    pub value: Expression,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Expression {
+    Column(ColumnSelectedExpression),
+    Parameter(Parameter),
+    Literal(Literal),
+    // The DEFAULT placeholder in VALUES lists and SET assignments
+    Default,
+    FunctionCall(FunctionCall),
+    UnaryOperation(UnaryOperation),
+    BinaryOperation(BinaryOperation),
+    Row(Vec<Expression>),
+    Subquery(Box<Query>),
+    Exists(Box<Query>),
+    Cast(Cast),
+    // rel.*, or a bare * as the argument of a function such as count(*)
+    AllColumns(AllColumnsSelectedExpression),
+    Between(Between),
+    Case(Case),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Literal {
+    String(String),
+    Number(String),
+    Boolean(bool),
+    Null,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct FunctionCall {
+    pub function_name: QualifiedName,
+    pub arguments: Vec<Expression>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UnaryOperation {
+    pub operator: String,
+    pub operand: Box<Expression>,
+}
+
+// expression::type_name
+#[derive(Debug, PartialEq)]
+pub struct Cast {
+    pub expression: Box<Expression>,
+    pub type_name: TypeName,
+}
+
+// expression [NOT] BETWEEN [SYMMETRIC] low AND high
+#[derive(Debug, PartialEq)]
+pub struct Between {
+    pub expression: Box<Expression>,
+    pub negated: bool,
+    pub symmetric: bool,
+    pub low: Box<Expression>,
+    pub high: Box<Expression>,
+}
+
+// CASE [operand] WHEN condition THEN result [...] [ELSE result] END
+#[derive(Debug, PartialEq)]
+pub struct Case {
+    // Set for the simple form, where each WHEN value is compared to it
+    pub operand: Option<Box<Expression>>,
+    pub when_clauses: Vec<CaseWhen>,
+    pub else_result: Option<Box<Expression>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CaseWhen {
+    pub condition: Expression,
+    pub result: Expression,
+}
+
+// Keyword operators are stored lowercased with single spaces between words,
+// e.g. "and", "not like", "is not distinct from"
+#[derive(Debug, PartialEq)]
+pub struct BinaryOperation {
+    pub left: Box<Expression>,
+    pub operator: String,
+    pub right: Box<Expression>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Parameter {
+    pub position: TokenPosition,
+    pub kind: ParameterKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParameterKind {
+    // $1, $2, ...
+    Positional(usize),
+    // psql variables, which are substituted by the client before the query
+    // is sent: :name as-is, :'name' as a literal and :"name" as an identifier
+    Named(String),
+    NamedAsLiteral(String),
+    NamedAsIdentifier(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FromItem {
+    Table(TableFromItem),
+    Subquery(SubqueryFromItem),
+    Function(FunctionFromItem),
+}
+
+// [ONLY] table_name [*] [alias] [TABLESAMPLE ...]
+#[derive(Debug, PartialEq)]
+pub struct TableFromItem {
+    pub only: bool,
+    pub schema_name: Option<Identifier>,
+    pub table_name: Identifier,
+    pub alias: Option<TableAlias>,
+    pub tablesample: Option<TableSample>,
+}
+
+// TABLESAMPLE method (argument, ...) [REPEATABLE (seed)]
+#[derive(Debug, PartialEq)]
+pub struct TableSample {
+    pub method: QualifiedName,
+    pub arguments: Vec<Expression>,
+    pub repeatable_seed: Option<Expression>,
+}
+
+// [LATERAL] (query) [alias]
+#[derive(Debug, PartialEq)]
+pub struct SubqueryFromItem {
+    pub lateral: bool,
+    pub query: Box<Query>,
+    pub alias: Option<TableAlias>,
+}
+
+// [LATERAL] function_call [WITH ORDINALITY] [alias], where the alias may
+// define the types of the returned columns for functions returning record
+#[derive(Debug, PartialEq)]
+pub struct FunctionFromItem {
+    pub lateral: bool,
+    pub function: FromFunction,
+    pub with_ordinality: bool,
+    pub alias: Option<TableAlias>,
+    pub column_definitions: Vec<FunctionColumnDefinition>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FromFunction {
+    Call(FunctionCall),
+    // ROWS FROM (function_call [AS (column_definition, ...)], ...)
+    RowsFrom(Vec<RowsFromFunction>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RowsFromFunction {
+    pub function_call: FunctionCall,
+    pub column_definitions: Vec<FunctionColumnDefinition>,
+}
+
+// column_name data_type, in the column definition list of a function
+// returning record
+#[derive(Debug, PartialEq)]
+pub struct FunctionColumnDefinition {
+    pub name: Identifier,
+    pub type_name: TypeName,
+}
+
+// A data type such as integer, public.my_type, varchar(255) or text[]. Names
+// of built-in types made of several keywords, such as double precision or
+// timestamp with time zone, are lowercased and joined with single spaces.
+#[derive(Debug, PartialEq)]
+pub struct TypeName {
+    pub name: QualifiedName,
+    pub modifiers: Vec<Expression>,
+    pub array_dimensions: usize,
+}
+
+// alias [(column_alias, ...)]
+#[derive(Debug, PartialEq)]
+pub struct TableAlias {
+    pub name: Identifier,
+    pub column_names: Vec<Identifier>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QualifiedName {
+    pub schema_name: Option<Identifier>,
+    pub name: Identifier,
 }
 
 #[derive(Clone, Debug, PartialEq)]
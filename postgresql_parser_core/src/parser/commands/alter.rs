@@ -0,0 +1,101 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Command, DataDefinitionCommand};
+use crate::parser::commands::alter_database::parse_alter_database;
+use crate::parser::commands::alter_default_privileges::parse_alter_default_privileges;
+use crate::parser::commands::alter_extension::parse_alter_extension;
+use crate::parser::commands::alter_policy::parse_alter_policy;
+use crate::parser::commands::alter_role::parse_alter_role;
+use crate::parser::commands::alter_sequence::parse_alter_sequence;
+use crate::parser::commands::alter_table::parse_alter_table;
+use crate::parser::commands::alter_tablespace::parse_alter_tablespace;
+use crate::parser::commands::alter_type::parse_alter_type;
+use crate::parser::commands::parse_section::ParseCommandSectionResult;
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_alter_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_alter, |alter_command| {
+        return Command::DataDefinition(alter_command);
+    });
+}
+
+// Parses everything after the ALTER keyword
+pub fn parse_alter(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DataDefinitionCommand> {
+    let idx = idx_after_optional_whitespace(tokens, start_idx);
+    if let ParseCommandSectionResult::Valid(idx_after, alter_table_command) =
+        parse_alter_table(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::AlterTable(alter_table_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, alter_type_command) =
+        parse_alter_type(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::AlterType(alter_type_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, alter_sequence_command) =
+        parse_alter_sequence(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::AlterSequence(alter_sequence_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, alter_extension_command) =
+        parse_alter_extension(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::AlterExtension(alter_extension_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, alter_database_command) =
+        parse_alter_database(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::AlterDatabase(alter_database_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, alter_tablespace_command) =
+        parse_alter_tablespace(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::AlterTablespace(alter_tablespace_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, alter_role_command) =
+        parse_alter_role(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::AlterRole(alter_role_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, alter_default_privileges_command) =
+        parse_alter_default_privileges(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::AlterDefaultPrivileges(alter_default_privileges_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, alter_policy_command) =
+        parse_alter_policy(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::AlterPolicy(alter_policy_command),
+        );
+    }
+    return ParseCommandSectionResult::Invalid;
+}
@@ -0,0 +1,117 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{AlterDatabaseAction, AlterDatabaseCommand};
+use crate::parser::commands::create_database::parse_database_options;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::configuration_setting::parse_configuration_setting;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, ALL_KEYWORD, DATABASE_KEYWORD, OWNER_KEYWORD, RENAME_KEYWORD,
+    RESET_KEYWORD, SET_KEYWORD, TABLESPACE_KEYWORD, TO_KEYWORD,
+};
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::role_specification::parse_role_specification;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the ALTER keyword of an ALTER DATABASE command
+pub fn parse_alter_database(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterDatabaseCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, DATABASE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let name;
+    (idx, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let action;
+    (idx, action) = parse_section_from_section!(parse_alter_database_action(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        AlterDatabaseCommand {
+            name: name,
+            action: action,
+        },
+    );
+}
+
+fn parse_alter_database_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterDatabaseAction> {
+    if let ParseCommandSectionResult::Valid(idx_after_rename_to, _) =
+        parse_keywords(tokens, start_idx, &[RENAME_KEYWORD, TO_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_rename_to);
+        let (idx_after, new_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            AlterDatabaseAction::RenameTo(new_name),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_owner_to, _) =
+        parse_keywords(tokens, start_idx, &[OWNER_KEYWORD, TO_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_owner_to);
+        let (idx_after, role) =
+            parse_section_from_section!(parse_role_specification(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, AlterDatabaseAction::OwnerTo(role));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_set, _) =
+        parse_keyword(tokens, start_idx, SET_KEYWORD)
+    {
+        return parse_set_action(tokens, idx_after_optional_whitespace(tokens, idx_after_set));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_reset, _) =
+        parse_keyword(tokens, start_idx, RESET_KEYWORD)
+    {
+        return parse_reset_action(
+            tokens,
+            idx_after_optional_whitespace(tokens, idx_after_reset),
+        );
+    }
+
+    let (idx_after, options) =
+        parse_section_from_section!(parse_database_options(tokens, start_idx));
+    if options.is_empty() {
+        return ParseCommandSectionResult::Invalid;
+    }
+    return ParseCommandSectionResult::Valid(idx_after, AlterDatabaseAction::Options(options));
+}
+
+// SET TABLESPACE new_tablespace or SET configuration_parameter {TO | =} value
+fn parse_set_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterDatabaseAction> {
+    if let ParseCommandSectionResult::Valid(idx_after_tablespace, _) =
+        parse_keyword(tokens, start_idx, TABLESPACE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_tablespace);
+        let (idx_after, tablespace) =
+            parse_section_from_section!(parse_identifier(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            AlterDatabaseAction::SetTablespace(tablespace),
+        );
+    }
+    let (idx_after, setting) =
+        parse_section_from_section!(parse_configuration_setting(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, AlterDatabaseAction::Set(setting));
+}
+
+// RESET configuration_parameter or RESET ALL
+fn parse_reset_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterDatabaseAction> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, ALL_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, AlterDatabaseAction::Reset(None));
+    }
+    let (idx_after, name) = parse_section_from_section!(parse_qualified_name(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, AlterDatabaseAction::Reset(Some(name)));
+}
@@ -0,0 +1,171 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{AlterDefaultPrivilegesCommand, DefaultPrivilegesAction};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::drop_behavior::parse_drop_behavior;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, DEFAULT_KEYWORD, FOR_KEYWORD,
+    FROM_KEYWORD, FUNCTIONS_KEYWORD, GRANT_KEYWORD, IN_KEYWORD, ON_KEYWORD, OPTION_KEYWORD,
+    PRIVILEGES_KEYWORD, REVOKE_KEYWORD, ROLE_KEYWORD, ROUTINES_KEYWORD, SCHEMAS_KEYWORD,
+    SCHEMA_KEYWORD, SEQUENCES_KEYWORD, TABLES_KEYWORD, TO_KEYWORD, TYPES_KEYWORD, USER_KEYWORD,
+    WITH_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::commands::sections::privileges::{
+    parse_grantees, parse_plural_object_type, parse_privileges,
+};
+use crate::parser::commands::sections::role_specification::parse_role_specification;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the ALTER keyword of an ALTER DEFAULT PRIVILEGES
+// command
+pub fn parse_alter_default_privileges(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterDefaultPrivilegesCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        idx,
+        &[DEFAULT_KEYWORD, PRIVILEGES_KEYWORD]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut roles = Vec::new();
+    if let ParseCommandSectionResult::Valid(idx_after_for, _) =
+        parse_keyword(tokens, idx, FOR_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_for);
+        (idx, _) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            idx,
+            &[ROLE_KEYWORD, USER_KEYWORD]
+        ));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        (idx, roles) = parse_section_from_section!(parse_comma_separated_list(
+            tokens,
+            idx,
+            parse_role_specification
+        ));
+        idx = idx_after_optional_whitespace(tokens, idx);
+    }
+
+    let mut schemas = Vec::new();
+    if let ParseCommandSectionResult::Valid(idx_after_in_schema, _) =
+        parse_keywords(tokens, idx, &[IN_KEYWORD, SCHEMA_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_in_schema);
+        (idx, schemas) =
+            parse_section_from_section!(parse_comma_separated_list(tokens, idx, parse_identifier));
+        idx = idx_after_optional_whitespace(tokens, idx);
+    }
+
+    let action;
+    (idx, action) = parse_section_from_section!(parse_default_privileges_action(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        AlterDefaultPrivilegesCommand {
+            roles: roles,
+            schemas: schemas,
+            action: action,
+        },
+    );
+}
+
+// GRANT privileges ON object_type TO grantee, ... [WITH GRANT OPTION] or
+// REVOKE [GRANT OPTION FOR] privileges ON object_type FROM grantee, ...
+// [CASCADE | RESTRICT]
+fn parse_default_privileges_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DefaultPrivilegesAction> {
+    let (mut idx, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[GRANT_KEYWORD, REVOKE_KEYWORD]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut grant_option_for = false;
+    if keyword == REVOKE_KEYWORD {
+        if let ParseCommandSectionResult::Valid(idx_after_grant_option_for, _) =
+            parse_keywords(tokens, idx, &[GRANT_KEYWORD, OPTION_KEYWORD, FOR_KEYWORD])
+        {
+            idx = idx_after_optional_whitespace(tokens, idx_after_grant_option_for);
+            grant_option_for = true;
+        }
+    }
+
+    let privileges;
+    (idx, privileges) = parse_section_from_section!(parse_privileges(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, ON_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let object_type;
+    (idx, object_type) = parse_section_from_section!(parse_plural_object_type(
+        tokens,
+        idx,
+        &[
+            TABLES_KEYWORD,
+            SEQUENCES_KEYWORD,
+            FUNCTIONS_KEYWORD,
+            ROUTINES_KEYWORD,
+            TYPES_KEYWORD,
+            SCHEMAS_KEYWORD,
+        ]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    if keyword == GRANT_KEYWORD {
+        (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TO_KEYWORD));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        let grantees;
+        (idx, grantees) = parse_section_from_section!(parse_grantees(tokens, idx));
+
+        let mut with_grant_option = false;
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_grant_option, _) = parse_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[WITH_KEYWORD, GRANT_KEYWORD, OPTION_KEYWORD],
+        ) {
+            idx = idx_after_grant_option;
+            with_grant_option = true;
+        }
+        return ParseCommandSectionResult::Valid(
+            idx,
+            DefaultPrivilegesAction::Grant {
+                privileges: privileges,
+                object_type: object_type,
+                grantees: grantees,
+                with_grant_option: with_grant_option,
+            },
+        );
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, FROM_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let grantees;
+    (idx, grantees) = parse_section_from_section!(parse_grantees(tokens, idx));
+
+    let mut behavior = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_behavior, drop_behavior) =
+        parse_drop_behavior(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_behavior;
+        behavior = Some(drop_behavior);
+    }
+    return ParseCommandSectionResult::Valid(
+        idx,
+        DefaultPrivilegesAction::Revoke {
+            grant_option_for: grant_option_for,
+            privileges: privileges,
+            object_type: object_type,
+            grantees: grantees,
+            behavior: behavior,
+        },
+    );
+}
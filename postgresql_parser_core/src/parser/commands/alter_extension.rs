@@ -0,0 +1,66 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{AlterExtensionAction, AlterExtensionCommand};
+use crate::parser::commands::create_extension::parse_version;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, EXTENSION_KEYWORD, SCHEMA_KEYWORD, SET_KEYWORD, TO_KEYWORD,
+    UPDATE_KEYWORD,
+};
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the ALTER keyword of an ALTER EXTENSION command
+pub fn parse_alter_extension(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterExtensionCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, EXTENSION_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let name;
+    (idx, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let action;
+    (idx, action) = parse_section_from_section!(parse_alter_extension_action(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        AlterExtensionCommand {
+            name: name,
+            action: action,
+        },
+    );
+}
+
+// UPDATE [TO new_version] or SET SCHEMA new_schema
+fn parse_alter_extension_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterExtensionAction> {
+    if let ParseCommandSectionResult::Valid(idx_after_set_schema, _) =
+        parse_keywords(tokens, start_idx, &[SET_KEYWORD, SCHEMA_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_set_schema);
+        let (idx_after, schema_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            AlterExtensionAction::SetSchema(schema_name),
+        );
+    }
+
+    let (mut idx, _) =
+        parse_section_from_section!(parse_keyword(tokens, start_idx, UPDATE_KEYWORD));
+    let mut version = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_to, _) =
+        parse_keyword(tokens, idx_after_whitespace, TO_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_to);
+        let new_version;
+        (idx, new_version) = parse_section_from_section!(parse_version(tokens, idx));
+        version = Some(new_version);
+    }
+    return ParseCommandSectionResult::Valid(idx, AlterExtensionAction::Update(version));
+}
@@ -0,0 +1,50 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{AlterPolicyAction, AlterPolicyCommand};
+use crate::parser::commands::create_policy::{parse_policy_clauses, parse_policy_name_on_table};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{parse_keywords, RENAME_KEYWORD, TO_KEYWORD};
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the ALTER keyword of an ALTER POLICY command
+pub fn parse_alter_policy(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterPolicyCommand> {
+    let (mut idx, (name, table_name)) =
+        parse_section_from_section!(parse_policy_name_on_table(tokens, start_idx));
+
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    let action;
+    if let ParseCommandSectionResult::Valid(idx_after_rename_to, _) =
+        parse_keywords(tokens, idx_after_whitespace, &[RENAME_KEYWORD, TO_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_rename_to);
+        let new_name;
+        (idx, new_name) = parse_section_from_section!(parse_identifier(tokens, idx));
+        action = AlterPolicyAction::RenameTo(new_name);
+    } else {
+        let (roles, using, with_check);
+        (idx, (roles, using, with_check)) =
+            parse_section_from_section!(parse_policy_clauses(tokens, idx));
+        if roles.is_empty() && using.is_none() && with_check.is_none() {
+            return ParseCommandSectionResult::Invalid;
+        }
+        action = AlterPolicyAction::Update {
+            roles: roles,
+            using: using,
+            with_check: with_check,
+        };
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        AlterPolicyCommand {
+            name: name,
+            table_name: table_name,
+            action: action,
+        },
+    );
+}
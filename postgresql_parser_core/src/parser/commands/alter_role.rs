@@ -0,0 +1,129 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{AlterRoleAction, AlterRoleCommand, RoleKind};
+use crate::parser::commands::create_role::{parse_role_kind, parse_role_options};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::configuration_setting::parse_configuration_setting;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, ALL_KEYWORD, DATABASE_KEYWORD, IN_KEYWORD, RENAME_KEYWORD,
+    RESET_KEYWORD, SET_KEYWORD, TO_KEYWORD,
+};
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::role_specification::parse_role_specification;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the ALTER keyword of an ALTER ROLE or ALTER USER
+// command
+pub fn parse_alter_role(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterRoleCommand> {
+    let (mut idx, kind) = parse_section_from_section!(parse_role_kind(tokens, start_idx));
+    // ALTER GROUP has its own ADD USER and DROP USER actions
+    if kind == RoleKind::Group {
+        return ParseCommandSectionResult::Invalid;
+    }
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut role = None;
+    if let ParseCommandSectionResult::Valid(idx_after_all, _) =
+        parse_keyword(tokens, idx, ALL_KEYWORD)
+    {
+        idx = idx_after_all;
+    } else {
+        let role_specification;
+        (idx, role_specification) =
+            parse_section_from_section!(parse_role_specification(tokens, idx));
+        role = Some(role_specification);
+    }
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let action;
+    (idx, action) = parse_section_from_section!(parse_alter_role_action(tokens, idx));
+    if role.is_none() {
+        match action {
+            AlterRoleAction::Set { .. } | AlterRoleAction::Reset { .. } => {}
+            _ => return ParseCommandSectionResult::Invalid,
+        }
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        AlterRoleCommand {
+            kind: kind,
+            role: role,
+            action: action,
+        },
+    );
+}
+
+fn parse_alter_role_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterRoleAction> {
+    if let ParseCommandSectionResult::Valid(idx_after_rename_to, _) =
+        parse_keywords(tokens, start_idx, &[RENAME_KEYWORD, TO_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_rename_to);
+        let (idx_after, new_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, AlterRoleAction::RenameTo(new_name));
+    }
+
+    let mut idx = start_idx;
+    let mut database = None;
+    if let ParseCommandSectionResult::Valid(idx_after_in_database, _) =
+        parse_keywords(tokens, idx, &[IN_KEYWORD, DATABASE_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_in_database);
+        let database_name;
+        (idx, database_name) = parse_section_from_section!(parse_identifier(tokens, idx));
+        database = Some(database_name);
+        idx = idx_after_optional_whitespace(tokens, idx);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_set, _) =
+        parse_keyword(tokens, idx, SET_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_set);
+        let (idx_after, setting) =
+            parse_section_from_section!(parse_configuration_setting(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            AlterRoleAction::Set {
+                database: database,
+                setting: setting,
+            },
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_reset, _) =
+        parse_keyword(tokens, idx, RESET_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_reset);
+        let (idx_after, name) = match parse_keyword(tokens, idx_after_whitespace, ALL_KEYWORD) {
+            ParseCommandSectionResult::Valid(idx_after_all, _) => (idx_after_all, None),
+            _ => {
+                let (idx_after_name, name) =
+                    parse_section_from_section!(parse_qualified_name(tokens, idx_after_whitespace));
+                (idx_after_name, Some(name))
+            }
+        };
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            AlterRoleAction::Reset {
+                database: database,
+                name: name,
+            },
+        );
+    }
+    if database.is_some() {
+        return ParseCommandSectionResult::Invalid;
+    }
+
+    let (idx_after, options) = parse_section_from_section!(parse_role_options(tokens, start_idx));
+    if options.is_empty() {
+        return ParseCommandSectionResult::Invalid;
+    }
+    return ParseCommandSectionResult::Valid(idx_after, AlterRoleAction::Options(options));
+}
@@ -0,0 +1,44 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::AlterSequenceCommand;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::if_exists::parse_if_exists;
+use crate::parser::commands::sections::keywords::{parse_keyword, SEQUENCE_KEYWORD};
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::sequence_options::parse_sequence_options;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the ALTER keyword of an ALTER SEQUENCE command
+pub fn parse_alter_sequence(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterSequenceCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, SEQUENCE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut if_exists = false;
+    if let ParseCommandSectionResult::Valid(idx_after_if_exists, _) = parse_if_exists(tokens, idx) {
+        idx = idx_after_optional_whitespace(tokens, idx_after_if_exists);
+        if_exists = true;
+    }
+
+    let name;
+    (idx, name) = parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let options;
+    (idx, options) = parse_section_from_section!(parse_sequence_options(tokens, idx));
+    if options.is_empty() {
+        return ParseCommandSectionResult::Invalid;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        AlterSequenceCommand {
+            if_exists: if_exists,
+            name: name,
+            options: options,
+        },
+    );
+}
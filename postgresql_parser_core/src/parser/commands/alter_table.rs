@@ -0,0 +1,680 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    AlterColumnAction, AlterTableAction, AlterTableCommand, ColumnConstraintKind, ColumnStorage,
+    DetachPartitionMode, RowLevelSecurityAction, TriggerSelection, TriggerState,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::column_definition::{
+    parse_column_definition, parse_generated_column_constraint,
+};
+use crate::parser::commands::sections::drop_behavior::parse_drop_behavior;
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::if_exists::{parse_if_exists, parse_if_not_exists};
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, ADD_KEYWORD, ALL_KEYWORD, ALTER_KEYWORD,
+    ALWAYS_KEYWORD, ATTACH_KEYWORD, COLLATE_KEYWORD, COLUMN_KEYWORD, CONCURRENTLY_KEYWORD,
+    CONSTRAINT_KEYWORD, DATA_KEYWORD, DEFAULT_KEYWORD, DETACH_KEYWORD, DISABLE_KEYWORD,
+    DROP_KEYWORD, ENABLE_KEYWORD, EXTENDED_KEYWORD, EXTERNAL_KEYWORD, FINALIZE_KEYWORD,
+    FORCE_KEYWORD, IDENTITY_KEYWORD, LEVEL_KEYWORD, MAIN_KEYWORD, NOT_KEYWORD, NO_KEYWORD,
+    NULL_KEYWORD, ONLY_KEYWORD, OWNER_KEYWORD, PARTITION_KEYWORD, PLAIN_KEYWORD, RENAME_KEYWORD,
+    REPLICA_KEYWORD, RESET_KEYWORD, ROW_KEYWORD, SCHEMA_KEYWORD, SECURITY_KEYWORD, SET_KEYWORD,
+    STATISTICS_KEYWORD, STORAGE_KEYWORD, TABLE_KEYWORD, TO_KEYWORD, TRIGGER_KEYWORD, TYPE_KEYWORD,
+    USER_KEYWORD, USING_KEYWORD, VALIDATE_KEYWORD, VALID_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::commands::sections::partition_bound::parse_partition_bound;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::role_specification::parse_role_specification;
+use crate::parser::commands::sections::storage_parameters::{
+    parse_storage_parameter_names, parse_storage_parameters,
+};
+use crate::parser::commands::sections::table_constraint::{
+    parse_table_constraint, parse_table_constraint_using_index,
+};
+use crate::parser::commands::sections::type_name::parse_type_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the ALTER keyword of an ALTER TABLE command
+pub fn parse_alter_table(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTableCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TABLE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut if_exists = false;
+    if let ParseCommandSectionResult::Valid(idx_after_if_exists, _) = parse_if_exists(tokens, idx) {
+        idx = idx_after_optional_whitespace(tokens, idx_after_if_exists);
+        if_exists = true;
+    }
+
+    let mut only = false;
+    if let ParseCommandSectionResult::Valid(idx_after_only, _) =
+        parse_keyword(tokens, idx, ONLY_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_only);
+        only = true;
+    }
+
+    let (idx_after_table_name, table_name) =
+        parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_table_name);
+
+    let (idx_after_actions, actions) = parse_section_from_section!(parse_comma_separated_list(
+        tokens,
+        idx,
+        parse_alter_table_action
+    ));
+
+    return ParseCommandSectionResult::Valid(
+        idx_after_actions,
+        AlterTableCommand {
+            if_exists: if_exists,
+            only: only,
+            table_name: table_name,
+            actions: actions,
+        },
+    );
+}
+
+fn parse_alter_table_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    let (idx_after_keyword, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[
+            ADD_KEYWORD,
+            DROP_KEYWORD,
+            ALTER_KEYWORD,
+            VALIDATE_KEYWORD,
+            RENAME_KEYWORD,
+            SET_KEYWORD,
+            RESET_KEYWORD,
+            ATTACH_KEYWORD,
+            DETACH_KEYWORD,
+            ENABLE_KEYWORD,
+            DISABLE_KEYWORD,
+            FORCE_KEYWORD,
+            NO_KEYWORD,
+            OWNER_KEYWORD,
+        ]
+    ));
+    let idx = idx_after_optional_whitespace(tokens, idx_after_keyword);
+    return match keyword.as_str() {
+        ADD_KEYWORD => parse_add_action(tokens, idx),
+        DROP_KEYWORD => parse_drop_action(tokens, idx),
+        ALTER_KEYWORD => parse_alter_column_action(tokens, idx),
+        VALIDATE_KEYWORD => parse_validate_constraint_action(tokens, idx),
+        RENAME_KEYWORD => parse_rename_action(tokens, idx),
+        SET_KEYWORD => parse_set_action(tokens, idx),
+        RESET_KEYWORD => parse_reset_action(tokens, idx),
+        ATTACH_KEYWORD => parse_attach_partition_action(tokens, idx),
+        DETACH_KEYWORD => parse_detach_partition_action(tokens, idx),
+        ENABLE_KEYWORD | DISABLE_KEYWORD => parse_enable_or_disable_action(tokens, idx, &keyword),
+        FORCE_KEYWORD => parse_row_level_security(tokens, idx, RowLevelSecurityAction::Force),
+        NO_KEYWORD => parse_no_force_row_level_security(tokens, idx),
+        _ => parse_owner_to_action(tokens, idx),
+    };
+}
+
+// ADD table_constraint [NOT VALID], ADD table_constraint_using_index or
+// ADD [COLUMN] [IF NOT EXISTS] column_definition
+fn parse_add_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    if let ParseCommandSectionResult::Valid(idx_after_constraint, constraint) =
+        parse_table_constraint_using_index(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after_constraint,
+            AlterTableAction::AddConstraintUsingIndex(constraint),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_constraint, constraint) =
+        parse_table_constraint(tokens, start_idx)
+    {
+        let mut idx = idx_after_constraint;
+        let mut not_valid = false;
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_not_valid, _) =
+            parse_keywords(tokens, idx_after_whitespace, &[NOT_KEYWORD, VALID_KEYWORD])
+        {
+            idx = idx_after_not_valid;
+            not_valid = true;
+        }
+        return ParseCommandSectionResult::Valid(
+            idx,
+            AlterTableAction::AddConstraint {
+                constraint: constraint,
+                not_valid: not_valid,
+            },
+        );
+    }
+
+    let mut idx = idx_after_optional_column_keyword(tokens, start_idx);
+    let mut if_not_exists = false;
+    if let ParseCommandSectionResult::Valid(idx_after_if_not_exists, _) =
+        parse_if_not_exists(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_if_not_exists);
+        if_not_exists = true;
+    }
+    let (idx_after_column, column) =
+        parse_section_from_section!(parse_column_definition(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after_column,
+        AlterTableAction::AddColumn {
+            if_not_exists: if_not_exists,
+            column: column,
+        },
+    );
+}
+
+// DROP CONSTRAINT [IF EXISTS] name [CASCADE | RESTRICT]
+// or DROP [COLUMN] [IF EXISTS] name [CASCADE | RESTRICT]
+fn parse_drop_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    let mut idx = start_idx;
+    let mut constraint = false;
+    if let ParseCommandSectionResult::Valid(idx_after_constraint, _) =
+        parse_keyword(tokens, idx, CONSTRAINT_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_constraint);
+        constraint = true;
+    } else {
+        idx = idx_after_optional_column_keyword(tokens, idx);
+    }
+
+    let mut if_exists = false;
+    if let ParseCommandSectionResult::Valid(idx_after_if_exists, _) = parse_if_exists(tokens, idx) {
+        idx = idx_after_optional_whitespace(tokens, idx_after_if_exists);
+        if_exists = true;
+    }
+    let (idx_after_name, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+    idx = idx_after_name;
+
+    let mut behavior = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_behavior, drop_behavior) =
+        parse_drop_behavior(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_behavior;
+        behavior = Some(drop_behavior);
+    }
+
+    let action = match constraint {
+        true => AlterTableAction::DropConstraint {
+            if_exists: if_exists,
+            name: name,
+            behavior: behavior,
+        },
+        false => AlterTableAction::DropColumn {
+            if_exists: if_exists,
+            name: name,
+            behavior: behavior,
+        },
+    };
+    return ParseCommandSectionResult::Valid(idx, action);
+}
+
+// ALTER [COLUMN] name action
+fn parse_alter_column_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    let mut idx = idx_after_optional_column_keyword(tokens, start_idx);
+    let (idx_after_name, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_name);
+    let (idx_after_action, action) = parse_section_from_section!(parse_column_action(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after_action,
+        AlterTableAction::AlterColumn {
+            name: name,
+            action: action,
+        },
+    );
+}
+
+fn parse_column_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterColumnAction> {
+    if let ParseCommandSectionResult::Valid(idx_after_type, _) = parse_keywords(
+        tokens,
+        start_idx,
+        &[SET_KEYWORD, DATA_KEYWORD, TYPE_KEYWORD],
+    ) {
+        return parse_set_data_type(tokens, idx_after_type);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_type, _) =
+        parse_keyword(tokens, start_idx, TYPE_KEYWORD)
+    {
+        return parse_set_data_type(tokens, idx_after_type);
+    }
+
+    if let ParseCommandSectionResult::Valid(idx_after_set, _) =
+        parse_keyword(tokens, start_idx, SET_KEYWORD)
+    {
+        let idx = idx_after_optional_whitespace(tokens, idx_after_set);
+        if let ParseCommandSectionResult::Valid(idx_after_default, _) =
+            parse_keyword(tokens, idx, DEFAULT_KEYWORD)
+        {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_default);
+            let (idx_after, expression) =
+                parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+            return ParseCommandSectionResult::Valid(
+                idx_after,
+                AlterColumnAction::SetDefault(expression),
+            );
+        }
+        if let ParseCommandSectionResult::Valid(idx_after, _) =
+            parse_keywords(tokens, idx, &[NOT_KEYWORD, NULL_KEYWORD])
+        {
+            return ParseCommandSectionResult::Valid(idx_after, AlterColumnAction::SetNotNull);
+        }
+        if let ParseCommandSectionResult::Valid(idx_after_statistics, _) =
+            parse_keyword(tokens, idx, STATISTICS_KEYWORD)
+        {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_statistics);
+            let (idx_after, target) =
+                parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+            return ParseCommandSectionResult::Valid(
+                idx_after,
+                AlterColumnAction::SetStatistics(target),
+            );
+        }
+        let (idx_after_storage, _) =
+            parse_section_from_section!(parse_keyword(tokens, idx, STORAGE_KEYWORD));
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_storage);
+        let (idx_after, storage) =
+            parse_section_from_section!(parse_column_storage(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, AlterColumnAction::SetStorage(storage));
+    }
+
+    if let ParseCommandSectionResult::Valid(idx_after_drop, _) =
+        parse_keyword(tokens, start_idx, DROP_KEYWORD)
+    {
+        let idx = idx_after_optional_whitespace(tokens, idx_after_drop);
+        if let ParseCommandSectionResult::Valid(idx_after, _) =
+            parse_keyword(tokens, idx, DEFAULT_KEYWORD)
+        {
+            return ParseCommandSectionResult::Valid(idx_after, AlterColumnAction::DropDefault);
+        }
+        if let ParseCommandSectionResult::Valid(idx_after, _) =
+            parse_keywords(tokens, idx, &[NOT_KEYWORD, NULL_KEYWORD])
+        {
+            return ParseCommandSectionResult::Valid(idx_after, AlterColumnAction::DropNotNull);
+        }
+        let (mut idx_after, _) =
+            parse_section_from_section!(parse_keyword(tokens, idx, IDENTITY_KEYWORD));
+        let mut if_exists = false;
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after);
+        if let ParseCommandSectionResult::Valid(idx_after_if_exists, _) =
+            parse_if_exists(tokens, idx_after_whitespace)
+        {
+            idx_after = idx_after_if_exists;
+            if_exists = true;
+        }
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            AlterColumnAction::DropIdentity {
+                if_exists: if_exists,
+            },
+        );
+    }
+
+    // ADD GENERATED {ALWAYS | BY DEFAULT} AS IDENTITY [(sequence_option ...)]
+    let (idx_after_add, _) =
+        parse_section_from_section!(parse_keyword(tokens, start_idx, ADD_KEYWORD));
+    let idx = idx_after_optional_whitespace(tokens, idx_after_add);
+    return match parse_generated_column_constraint(tokens, idx) {
+        ParseCommandSectionResult::Valid(
+            idx_after,
+            ColumnConstraintKind::Identity {
+                always,
+                sequence_options,
+            },
+        ) => ParseCommandSectionResult::Valid(
+            idx_after,
+            AlterColumnAction::AddIdentity {
+                always: always,
+                sequence_options: sequence_options,
+            },
+        ),
+        ParseCommandSectionResult::EndOfInput => ParseCommandSectionResult::EndOfInput,
+        _ => ParseCommandSectionResult::Invalid,
+    };
+}
+
+// Parses everything after [SET DATA] TYPE: type [COLLATE collation] [USING expression]
+fn parse_set_data_type(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterColumnAction> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    let (idx_after_type, type_name) = parse_section_from_section!(parse_type_name(tokens, idx));
+    idx = idx_after_type;
+
+    let mut collation = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_collate, _) =
+        parse_keyword(tokens, idx_after_whitespace, COLLATE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_collate);
+        let (idx_after_collation, collation_name) =
+            parse_section_from_section!(parse_qualified_name(tokens, idx_after_whitespace));
+        idx = idx_after_collation;
+        collation = Some(collation_name);
+    }
+
+    let mut using = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_using, _) =
+        parse_keyword(tokens, idx_after_whitespace, USING_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_using);
+        let (idx_after_expression, expression) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        idx = idx_after_expression;
+        using = Some(expression);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        AlterColumnAction::SetDataType {
+            type_name: type_name,
+            collation: collation,
+            using: using,
+        },
+    );
+}
+
+fn parse_column_storage(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ColumnStorage> {
+    let (idx_after, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[
+            PLAIN_KEYWORD,
+            EXTERNAL_KEYWORD,
+            EXTENDED_KEYWORD,
+            MAIN_KEYWORD,
+            DEFAULT_KEYWORD,
+        ]
+    ));
+    let storage = match keyword.as_str() {
+        PLAIN_KEYWORD => ColumnStorage::Plain,
+        EXTERNAL_KEYWORD => ColumnStorage::External,
+        EXTENDED_KEYWORD => ColumnStorage::Extended,
+        MAIN_KEYWORD => ColumnStorage::Main,
+        _ => ColumnStorage::Default,
+    };
+    return ParseCommandSectionResult::Valid(idx_after, storage);
+}
+
+// VALIDATE CONSTRAINT name
+fn parse_validate_constraint_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    let (mut idx, _) =
+        parse_section_from_section!(parse_keyword(tokens, start_idx, CONSTRAINT_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx_after, AlterTableAction::ValidateConstraint(name));
+}
+
+// RENAME TO new_name, RENAME CONSTRAINT name TO new_name
+// or RENAME [COLUMN] name TO new_name
+fn parse_rename_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    if let ParseCommandSectionResult::Valid(idx_after_to, _) =
+        parse_keyword(tokens, start_idx, TO_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_to);
+        let (idx_after, new_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, AlterTableAction::RenameTo(new_name));
+    }
+
+    let mut idx = start_idx;
+    let mut constraint = false;
+    if let ParseCommandSectionResult::Valid(idx_after_constraint, _) =
+        parse_keyword(tokens, idx, CONSTRAINT_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_constraint);
+        constraint = true;
+    } else {
+        idx = idx_after_optional_column_keyword(tokens, idx);
+    }
+    let (idx_after_name, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_name);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TO_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_new_name, new_name) = parse_section_from_section!(parse_identifier(tokens, idx));
+
+    let action = match constraint {
+        true => AlterTableAction::RenameConstraint {
+            name: name,
+            new_name: new_name,
+        },
+        false => AlterTableAction::RenameColumn {
+            name: name,
+            new_name: new_name,
+        },
+    };
+    return ParseCommandSectionResult::Valid(idx_after_new_name, action);
+}
+
+// SET SCHEMA name or SET (storage_parameter, ...)
+fn parse_set_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    if let ParseCommandSectionResult::Valid(idx_after_schema, _) =
+        parse_keyword(tokens, start_idx, SCHEMA_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_schema);
+        let (idx_after, schema_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            AlterTableAction::SetSchema(schema_name),
+        );
+    }
+    let (idx_after, parameters) =
+        parse_section_from_section!(parse_storage_parameters(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        AlterTableAction::SetStorageParameters(parameters),
+    );
+}
+
+// RESET (name, ...)
+fn parse_reset_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    let (idx_after, parameters) =
+        parse_section_from_section!(parse_storage_parameter_names(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        AlterTableAction::ResetStorageParameters(parameters),
+    );
+}
+
+// ATTACH PARTITION name {FOR VALUES ... | DEFAULT}
+fn parse_attach_partition_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    let (mut idx, _) =
+        parse_section_from_section!(parse_keyword(tokens, start_idx, PARTITION_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_partition, partition) =
+        parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_partition);
+    let (idx_after_bound, bound) = parse_section_from_section!(parse_partition_bound(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after_bound,
+        AlterTableAction::AttachPartition {
+            partition: partition,
+            bound: bound,
+        },
+    );
+}
+
+// DETACH PARTITION name [CONCURRENTLY | FINALIZE]
+fn parse_detach_partition_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    let (mut idx, _) =
+        parse_section_from_section!(parse_keyword(tokens, start_idx, PARTITION_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_partition, partition) =
+        parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_partition;
+
+    let mut mode = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_mode, keyword) = parse_one_of_keywords(
+        tokens,
+        idx_after_whitespace,
+        &[CONCURRENTLY_KEYWORD, FINALIZE_KEYWORD],
+    ) {
+        idx = idx_after_mode;
+        mode = match keyword.as_str() {
+            CONCURRENTLY_KEYWORD => Some(DetachPartitionMode::Concurrently),
+            _ => Some(DetachPartitionMode::Finalize),
+        };
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        AlterTableAction::DetachPartition {
+            partition: partition,
+            mode: mode,
+        },
+    );
+}
+
+// Parses everything after ENABLE or DISABLE: [REPLICA | ALWAYS] TRIGGER
+// {name | ALL | USER}, or ROW LEVEL SECURITY
+fn parse_enable_or_disable_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    keyword: &str,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    let enable = keyword == ENABLE_KEYWORD;
+    if let ParseCommandSectionResult::Valid(idx_after, action) = parse_row_level_security(
+        tokens,
+        start_idx,
+        match enable {
+            true => RowLevelSecurityAction::Enable,
+            false => RowLevelSecurityAction::Disable,
+        },
+    ) {
+        return ParseCommandSectionResult::Valid(idx_after, action);
+    }
+
+    let mut idx = start_idx;
+    let mut state = match enable {
+        true => TriggerState::Enabled,
+        false => TriggerState::Disabled,
+    };
+    if enable {
+        if let ParseCommandSectionResult::Valid(idx_after_mode, mode) =
+            parse_one_of_keywords(tokens, idx, &[REPLICA_KEYWORD, ALWAYS_KEYWORD])
+        {
+            idx = idx_after_optional_whitespace(tokens, idx_after_mode);
+            state = match mode.as_str() {
+                REPLICA_KEYWORD => TriggerState::EnabledReplica,
+                _ => TriggerState::EnabledAlways,
+            };
+        }
+    }
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TRIGGER_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let trigger: TriggerSelection;
+    if let ParseCommandSectionResult::Valid(idx_after, selection) =
+        parse_one_of_keywords(tokens, idx, &[ALL_KEYWORD, USER_KEYWORD])
+    {
+        idx = idx_after;
+        trigger = match selection.as_str() {
+            ALL_KEYWORD => TriggerSelection::All,
+            _ => TriggerSelection::User,
+        };
+    } else {
+        let (idx_after, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+        idx = idx_after;
+        trigger = TriggerSelection::Name(name);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        AlterTableAction::SetTriggerState {
+            state: state,
+            trigger: trigger,
+        },
+    );
+}
+
+// ROW LEVEL SECURITY, after ENABLE, DISABLE, FORCE or NO FORCE
+fn parse_row_level_security(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    action: RowLevelSecurityAction,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    let (idx_after, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        start_idx,
+        &[ROW_KEYWORD, LEVEL_KEYWORD, SECURITY_KEYWORD]
+    ));
+    return ParseCommandSectionResult::Valid(idx_after, AlterTableAction::RowLevelSecurity(action));
+}
+
+// Parses everything after NO: FORCE ROW LEVEL SECURITY
+fn parse_no_force_row_level_security(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    let (mut idx, _) = parse_section_from_section!(parse_keyword(tokens, start_idx, FORCE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    return parse_row_level_security(tokens, idx, RowLevelSecurityAction::NoForce);
+}
+
+// Parses everything after OWNER: TO role
+fn parse_owner_to_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTableAction> {
+    let (mut idx, _) = parse_section_from_section!(parse_keyword(tokens, start_idx, TO_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after, role) = parse_section_from_section!(parse_role_specification(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx_after, AlterTableAction::OwnerTo(role));
+}
+
+// Skips the optional COLUMN keyword in ADD, DROP, ALTER and RENAME [COLUMN]
+fn idx_after_optional_column_keyword(tokens: &Vec<Token>, idx: usize) -> usize {
+    return match parse_keyword(tokens, idx, COLUMN_KEYWORD) {
+        ParseCommandSectionResult::Valid(idx_after_column, _) => {
+            idx_after_optional_whitespace(tokens, idx_after_column)
+        }
+        _ => idx,
+    };
+}
@@ -0,0 +1,84 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{AlterTablespaceAction, AlterTablespaceCommand};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, OWNER_KEYWORD, RENAME_KEYWORD, RESET_KEYWORD, SET_KEYWORD,
+    TABLESPACE_KEYWORD, TO_KEYWORD,
+};
+use crate::parser::commands::sections::role_specification::parse_role_specification;
+use crate::parser::commands::sections::storage_parameters::{
+    parse_storage_parameter_names, parse_storage_parameters,
+};
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the ALTER keyword of an ALTER TABLESPACE command
+pub fn parse_alter_tablespace(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTablespaceCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TABLESPACE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let name;
+    (idx, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let action;
+    (idx, action) = parse_section_from_section!(parse_alter_tablespace_action(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        AlterTablespaceCommand {
+            name: name,
+            action: action,
+        },
+    );
+}
+
+// RENAME TO new_name, OWNER TO role, SET (option = value, ...) or
+// RESET (option, ...)
+fn parse_alter_tablespace_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTablespaceAction> {
+    if let ParseCommandSectionResult::Valid(idx_after_rename_to, _) =
+        parse_keywords(tokens, start_idx, &[RENAME_KEYWORD, TO_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_rename_to);
+        let (idx_after, new_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            AlterTablespaceAction::RenameTo(new_name),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_owner_to, _) =
+        parse_keywords(tokens, start_idx, &[OWNER_KEYWORD, TO_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_owner_to);
+        let (idx_after, role) =
+            parse_section_from_section!(parse_role_specification(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, AlterTablespaceAction::OwnerTo(role));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_set, _) =
+        parse_keyword(tokens, start_idx, SET_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_set);
+        let (idx_after, options) =
+            parse_section_from_section!(parse_storage_parameters(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            AlterTablespaceAction::SetOptions(options),
+        );
+    }
+    let (idx_after_reset, _) =
+        parse_section_from_section!(parse_keyword(tokens, start_idx, RESET_KEYWORD));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_reset);
+    let (idx_after, options) =
+        parse_section_from_section!(parse_storage_parameter_names(tokens, idx_after_whitespace));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        AlterTablespaceAction::ResetOptions(options),
+    );
+}
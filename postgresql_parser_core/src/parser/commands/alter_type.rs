@@ -0,0 +1,102 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{AlterTypeAction, AlterTypeCommand, EnumValuePosition};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_string_literal;
+use crate::parser::commands::sections::if_exists::parse_if_not_exists;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, ADD_KEYWORD, AFTER_KEYWORD,
+    BEFORE_KEYWORD, RENAME_KEYWORD, TO_KEYWORD, TYPE_KEYWORD, VALUE_KEYWORD,
+};
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the ALTER keyword of an ALTER TYPE command
+pub fn parse_alter_type(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTypeCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TYPE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let name;
+    (idx, name) = parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let action;
+    (idx, action) = parse_section_from_section!(parse_alter_type_action(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        AlterTypeCommand {
+            name: name,
+            action: action,
+        },
+    );
+}
+
+fn parse_alter_type_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AlterTypeAction> {
+    if let ParseCommandSectionResult::Valid(idx_after_rename, _) =
+        parse_keywords(tokens, start_idx, &[RENAME_KEYWORD, VALUE_KEYWORD])
+    {
+        let mut idx = idx_after_optional_whitespace(tokens, idx_after_rename);
+        let value;
+        (idx, value) = parse_section_from_section!(parse_string_literal(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TO_KEYWORD));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        let new_value;
+        (idx, new_value) = parse_section_from_section!(parse_string_literal(tokens, idx));
+        return ParseCommandSectionResult::Valid(
+            idx,
+            AlterTypeAction::RenameValue {
+                value: value,
+                new_value: new_value,
+            },
+        );
+    }
+
+    let mut idx = start_idx;
+    (idx, _) =
+        parse_section_from_section!(parse_keywords(tokens, idx, &[ADD_KEYWORD, VALUE_KEYWORD]));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut if_not_exists = false;
+    if let ParseCommandSectionResult::Valid(idx_after_if_not_exists, _) =
+        parse_if_not_exists(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_if_not_exists);
+        if_not_exists = true;
+    }
+
+    let value;
+    (idx, value) = parse_section_from_section!(parse_string_literal(tokens, idx));
+
+    let mut position = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_keyword, keyword) = parse_one_of_keywords(
+        tokens,
+        idx_after_whitespace,
+        &[BEFORE_KEYWORD, AFTER_KEYWORD],
+    ) {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_keyword);
+        let (idx_after_neighbor, neighbor) =
+            parse_section_from_section!(parse_string_literal(tokens, idx_after_whitespace));
+        idx = idx_after_neighbor;
+        position = Some(match keyword.as_str() {
+            BEFORE_KEYWORD => EnumValuePosition::Before(neighbor),
+            _ => EnumValuePosition::After(neighbor),
+        });
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        AlterTypeAction::AddValue {
+            if_not_exists: if_not_exists,
+            value: value,
+            position: position,
+        },
+    );
+}
@@ -0,0 +1,405 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    Command, CopyCommand, CopyDirection, CopyFormat, CopyHeader, CopyOption, CopyRelation,
+    CopyTarget, UtilityCommand,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::boolean::parse_boolean_value;
+use crate::parser::commands::sections::expression::{parse_expression, parse_string_literal};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, AS_KEYWORD, BINARY_KEYWORD, CSV_KEYWORD,
+    DEFAULT_KEYWORD, DELIMITER_KEYWORD, ENCODING_KEYWORD, ESCAPE_KEYWORD, FORCE_KEYWORD,
+    FORCE_NOT_NULL_KEYWORD, FORCE_NULL_KEYWORD, FORCE_QUOTE_KEYWORD, FORMAT_KEYWORD,
+    FREEZE_KEYWORD, FROM_KEYWORD, HEADER_KEYWORD, LOG_VERBOSITY_KEYWORD, MATCH_KEYWORD,
+    NOT_KEYWORD, NULL_KEYWORD, ON_ERROR_KEYWORD, PROGRAM_KEYWORD, QUOTE_KEYWORD,
+    REJECT_LIMIT_KEYWORD, STDIN_KEYWORD, STDOUT_KEYWORD, TEXT_KEYWORD, TO_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::list::{
+    parse_comma_separated_list, parse_parenthesized_list, parse_whitespace_separated_list,
+};
+use crate::parser::commands::sections::operator::parse_operator;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::query::parse_parenthesized_query;
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_copy_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_copy, |copy_command| {
+        return Command::Utility(UtilityCommand::Copy(copy_command));
+    });
+}
+
+// Parses everything after the COPY keyword. The data following a COPY FROM
+// STDIN is skipped by the lexer.
+fn parse_copy(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<CopyCommand> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    let relation;
+    (idx, relation) = parse_section_from_section!(parse_copy_relation(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let keyword;
+    (idx, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        idx,
+        &[FROM_KEYWORD, TO_KEYWORD]
+    ));
+    let direction = match keyword.as_str() {
+        FROM_KEYWORD => CopyDirection::From,
+        _ => CopyDirection::To,
+    };
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let target;
+    (idx, target) = parse_section_from_section!(parse_copy_target(tokens, idx));
+
+    match (&direction, &relation, &target) {
+        (CopyDirection::From, CopyRelation::Query(_), _)
+        | (CopyDirection::From, _, CopyTarget::Stdout)
+        | (CopyDirection::To, _, CopyTarget::Stdin) => return ParseCommandSectionResult::Invalid,
+        _ => {}
+    }
+
+    let mut options = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_options, copy_options) =
+        parse_copy_options(tokens, idx_after_whitespace)
+    {
+        if !copy_options.is_empty() {
+            idx = idx_after_options;
+            options = copy_options;
+        }
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CopyCommand {
+            relation: relation,
+            direction: direction,
+            target: target,
+            options: options,
+        },
+    );
+}
+
+// table [(column, ...)] or (query)
+fn parse_copy_relation(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CopyRelation> {
+    if let ParseCommandSectionResult::Valid(idx_after, query) =
+        parse_parenthesized_query(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, CopyRelation::Query(query));
+    }
+
+    let (mut idx, table_name) =
+        parse_section_from_section!(parse_qualified_name(tokens, start_idx));
+    let mut columns = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_columns, column_names) =
+        parse_parenthesized_list(tokens, idx_after_whitespace, parse_identifier)
+    {
+        idx = idx_after_columns;
+        columns = column_names;
+    }
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CopyRelation::Table {
+            table_name: table_name,
+            columns: columns,
+        },
+    );
+}
+
+// 'filename' | PROGRAM 'command' | STDIN | STDOUT
+fn parse_copy_target(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CopyTarget> {
+    if let ParseCommandSectionResult::Valid(idx_after, keyword) =
+        parse_one_of_keywords(tokens, start_idx, &[STDIN_KEYWORD, STDOUT_KEYWORD])
+    {
+        let target = match keyword.as_str() {
+            STDIN_KEYWORD => CopyTarget::Stdin,
+            _ => CopyTarget::Stdout,
+        };
+        return ParseCommandSectionResult::Valid(idx_after, target);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_program, _) =
+        parse_keyword(tokens, start_idx, PROGRAM_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_program);
+        let (idx_after, command) =
+            parse_section_from_section!(parse_string_literal(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, CopyTarget::Program(command));
+    }
+    let (idx_after, filename) =
+        parse_section_from_section!(parse_string_literal(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, CopyTarget::File(filename));
+}
+
+// [WITH] (option, ...) or [WITH] legacy_option ..., where WITH is only
+// consumed when it is followed by options
+fn parse_copy_options(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<CopyOption>> {
+    let mut idx = start_idx;
+    if let ParseCommandSectionResult::Valid(idx_after_with, _) =
+        parse_keyword(tokens, idx, WITH_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_with);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, options) =
+        parse_parenthesized_list(tokens, idx, parse_copy_option)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, options);
+    }
+    let (idx_after_options, options) = parse_section_from_section!(
+        parse_whitespace_separated_list(tokens, idx, parse_legacy_copy_option)
+    );
+    if options.is_empty() {
+        return ParseCommandSectionResult::Valid(start_idx, options);
+    }
+    return ParseCommandSectionResult::Valid(idx_after_options, options);
+}
+
+fn parse_copy_option(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CopyOption> {
+    if let ParseCommandSectionResult::Valid(idx_after_format, _) =
+        parse_keyword(tokens, start_idx, FORMAT_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_format);
+        let (idx_after, keyword) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[TEXT_KEYWORD, CSV_KEYWORD, BINARY_KEYWORD]
+        ));
+        let format = match keyword.as_str() {
+            TEXT_KEYWORD => CopyFormat::Text,
+            CSV_KEYWORD => CopyFormat::Csv,
+            _ => CopyFormat::Binary,
+        };
+        return ParseCommandSectionResult::Valid(idx_after, CopyOption::Format(format));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_freeze, _) =
+        parse_keyword(tokens, start_idx, FREEZE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_freeze);
+        return match parse_boolean_value(tokens, idx_after_whitespace) {
+            ParseCommandSectionResult::Valid(idx_after, value) => {
+                ParseCommandSectionResult::Valid(idx_after, CopyOption::Freeze(value))
+            }
+            _ => ParseCommandSectionResult::Valid(idx_after_freeze, CopyOption::Freeze(true)),
+        };
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_header, _) =
+        parse_keyword(tokens, start_idx, HEADER_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_header);
+        if let ParseCommandSectionResult::Valid(idx_after, _) =
+            parse_keyword(tokens, idx_after_whitespace, MATCH_KEYWORD)
+        {
+            return ParseCommandSectionResult::Valid(
+                idx_after,
+                CopyOption::Header(CopyHeader::Match),
+            );
+        }
+        return match parse_boolean_value(tokens, idx_after_whitespace) {
+            ParseCommandSectionResult::Valid(idx_after, true) => {
+                ParseCommandSectionResult::Valid(idx_after, CopyOption::Header(CopyHeader::True))
+            }
+            ParseCommandSectionResult::Valid(idx_after, false) => {
+                ParseCommandSectionResult::Valid(idx_after, CopyOption::Header(CopyHeader::False))
+            }
+            _ => ParseCommandSectionResult::Valid(
+                idx_after_header,
+                CopyOption::Header(CopyHeader::True),
+            ),
+        };
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_keyword, keyword) = parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[
+            FORCE_QUOTE_KEYWORD,
+            FORCE_NOT_NULL_KEYWORD,
+            FORCE_NULL_KEYWORD,
+        ],
+    ) {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_keyword);
+        let (idx_after, columns) = match parse_operator(tokens, idx_after_whitespace, "*") {
+            ParseCommandSectionResult::Valid(idx_after_star, _) => (idx_after_star, None),
+            _ => {
+                let (idx_after_columns, columns) = parse_section_from_section!(
+                    parse_parenthesized_list(tokens, idx_after_whitespace, parse_identifier)
+                );
+                (idx_after_columns, Some(columns))
+            }
+        };
+        let option = match keyword.as_str() {
+            FORCE_QUOTE_KEYWORD => CopyOption::ForceQuote(columns),
+            FORCE_NOT_NULL_KEYWORD => CopyOption::ForceNotNull(columns),
+            _ => CopyOption::ForceNull(columns),
+        };
+        return ParseCommandSectionResult::Valid(idx_after, option);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_keyword, keyword) = parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[ON_ERROR_KEYWORD, LOG_VERBOSITY_KEYWORD],
+    ) {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_keyword);
+        let (idx_after, value) =
+            parse_section_from_section!(parse_identifier(tokens, idx_after_whitespace));
+        let option = match keyword.as_str() {
+            ON_ERROR_KEYWORD => CopyOption::OnError(value),
+            _ => CopyOption::LogVerbosity(value),
+        };
+        return ParseCommandSectionResult::Valid(idx_after, option);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_reject_limit, _) =
+        parse_keyword(tokens, start_idx, REJECT_LIMIT_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_reject_limit);
+        let (idx_after, limit) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, CopyOption::RejectLimit(limit));
+    }
+
+    let (mut idx, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[
+            DELIMITER_KEYWORD,
+            NULL_KEYWORD,
+            DEFAULT_KEYWORD,
+            QUOTE_KEYWORD,
+            ESCAPE_KEYWORD,
+            ENCODING_KEYWORD,
+        ]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let value;
+    (idx, value) = parse_section_from_section!(parse_string_literal(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx, string_copy_option(&keyword, value));
+}
+
+// BINARY | FREEZE | CSV | HEADER | {DELIMITER | NULL | QUOTE | ESCAPE} [AS] 'string'
+// | FORCE QUOTE {* | column, ...} | FORCE [NOT] NULL column, ... | ENCODING 'encoding'
+fn parse_legacy_copy_option(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CopyOption> {
+    if let ParseCommandSectionResult::Valid(idx_after, keyword) = parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[BINARY_KEYWORD, FREEZE_KEYWORD, CSV_KEYWORD, HEADER_KEYWORD],
+    ) {
+        let option = match keyword.as_str() {
+            BINARY_KEYWORD => CopyOption::Format(CopyFormat::Binary),
+            FREEZE_KEYWORD => CopyOption::Freeze(true),
+            CSV_KEYWORD => CopyOption::Format(CopyFormat::Csv),
+            _ => CopyOption::Header(CopyHeader::True),
+        };
+        return ParseCommandSectionResult::Valid(idx_after, option);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_force, _) =
+        parse_keyword(tokens, start_idx, FORCE_KEYWORD)
+    {
+        return parse_legacy_force_option(
+            tokens,
+            idx_after_optional_whitespace(tokens, idx_after_force),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_encoding, _) =
+        parse_keyword(tokens, start_idx, ENCODING_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_encoding);
+        let (idx_after, encoding) =
+            parse_section_from_section!(parse_string_literal(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, CopyOption::Encoding(encoding));
+    }
+
+    let (mut idx, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[
+            DELIMITER_KEYWORD,
+            NULL_KEYWORD,
+            QUOTE_KEYWORD,
+            ESCAPE_KEYWORD
+        ]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_as, _) =
+        parse_keyword(tokens, idx, AS_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_as);
+    }
+    let value;
+    (idx, value) = parse_section_from_section!(parse_string_literal(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx, string_copy_option(&keyword, value));
+}
+
+// The rest of FORCE QUOTE {* | column, ...} or FORCE [NOT] NULL column, ...
+fn parse_legacy_force_option(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CopyOption> {
+    if let ParseCommandSectionResult::Valid(idx_after_quote, _) =
+        parse_keyword(tokens, start_idx, QUOTE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_quote);
+        if let ParseCommandSectionResult::Valid(idx_after, _) =
+            parse_operator(tokens, idx_after_whitespace, "*")
+        {
+            return ParseCommandSectionResult::Valid(idx_after, CopyOption::ForceQuote(None));
+        }
+        let (idx_after, columns) = parse_section_from_section!(parse_comma_separated_list(
+            tokens,
+            idx_after_whitespace,
+            parse_identifier
+        ));
+        return ParseCommandSectionResult::Valid(idx_after, CopyOption::ForceQuote(Some(columns)));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_not_null, _) =
+        parse_keywords(tokens, start_idx, &[NOT_KEYWORD, NULL_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_not_null);
+        let (idx_after, columns) = parse_section_from_section!(parse_comma_separated_list(
+            tokens,
+            idx_after_whitespace,
+            parse_identifier
+        ));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            CopyOption::ForceNotNull(Some(columns)),
+        );
+    }
+    let (idx_after_null, _) =
+        parse_section_from_section!(parse_keyword(tokens, start_idx, NULL_KEYWORD));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_null);
+    let (idx_after, columns) = parse_section_from_section!(parse_comma_separated_list(
+        tokens,
+        idx_after_whitespace,
+        parse_identifier
+    ));
+    return ParseCommandSectionResult::Valid(idx_after, CopyOption::ForceNull(Some(columns)));
+}
+
+// The option for one of the keywords that take a string value
+fn string_copy_option(keyword: &str, value: String) -> CopyOption {
+    return match keyword {
+        DELIMITER_KEYWORD => CopyOption::Delimiter(value),
+        NULL_KEYWORD => CopyOption::Null(value),
+        DEFAULT_KEYWORD => CopyOption::Default(value),
+        QUOTE_KEYWORD => CopyOption::Quote(value),
+        ESCAPE_KEYWORD => CopyOption::Escape(value),
+        _ => CopyOption::Encoding(value),
+    };
+}
@@ -0,0 +1,161 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Command, DataDefinitionCommand};
+use crate::parser::commands::create_database::parse_create_database;
+use crate::parser::commands::create_extension::parse_create_extension;
+use crate::parser::commands::create_function::parse_create_function;
+use crate::parser::commands::create_index::parse_create_index;
+use crate::parser::commands::create_policy::parse_create_policy;
+use crate::parser::commands::create_role::parse_create_role;
+use crate::parser::commands::create_schema::parse_create_schema;
+use crate::parser::commands::create_sequence::parse_create_sequence;
+use crate::parser::commands::create_table::parse_create_table;
+use crate::parser::commands::create_tablespace::parse_create_tablespace;
+use crate::parser::commands::create_trigger::{parse_create_event_trigger, parse_create_trigger};
+use crate::parser::commands::create_type::{parse_create_domain, parse_create_type};
+use crate::parser::commands::create_view::{parse_create_materialized_view, parse_create_view};
+use crate::parser::commands::parse_section::ParseCommandSectionResult;
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_create_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_create, |create_command| {
+        return Command::DataDefinition(create_command);
+    });
+}
+
+// Parses everything after the CREATE keyword
+pub fn parse_create(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DataDefinitionCommand> {
+    let idx = idx_after_optional_whitespace(tokens, start_idx);
+    if let ParseCommandSectionResult::Valid(idx_after, create_table_command) =
+        parse_create_table(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateTable(create_table_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_index_command) =
+        parse_create_index(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateIndex(create_index_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_view_command) =
+        parse_create_view(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateView(create_view_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_materialized_view_command) =
+        parse_create_materialized_view(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateMaterializedView(create_materialized_view_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_function_command) =
+        parse_create_function(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateFunction(create_function_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_trigger_command) =
+        parse_create_trigger(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateTrigger(create_trigger_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_event_trigger_command) =
+        parse_create_event_trigger(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateEventTrigger(create_event_trigger_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_type_command) =
+        parse_create_type(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateType(create_type_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_domain_command) =
+        parse_create_domain(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateDomain(create_domain_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_sequence_command) =
+        parse_create_sequence(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateSequence(create_sequence_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_schema_command) =
+        parse_create_schema(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateSchema(create_schema_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_extension_command) =
+        parse_create_extension(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateExtension(create_extension_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_database_command) =
+        parse_create_database(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateDatabase(create_database_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_tablespace_command) =
+        parse_create_tablespace(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateTablespace(create_tablespace_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_role_command) =
+        parse_create_role(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreateRole(create_role_command),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, create_policy_command) =
+        parse_create_policy(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataDefinitionCommand::CreatePolicy(create_policy_command),
+        );
+    }
+    return ParseCommandSectionResult::Invalid;
+}
@@ -0,0 +1,111 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{CreateDatabaseCommand, DatabaseOption};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, CONNECTION_KEYWORD, DATABASE_KEYWORD, DEFAULT_KEYWORD,
+    LIMIT_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_whitespace_separated_list;
+use crate::parser::commands::sections::operator::parse_operator;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the CREATE keyword of a CREATE DATABASE command
+pub fn parse_create_database(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateDatabaseCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, DATABASE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let name;
+    (idx, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+
+    let mut options = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_options, database_options) =
+        parse_database_options(tokens, idx_after_whitespace)
+    {
+        if !database_options.is_empty() {
+            idx = idx_after_options;
+            options = database_options;
+        }
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateDatabaseCommand {
+            name: name,
+            options: options,
+        },
+    );
+}
+
+// [WITH] option [=] value ..., as in CREATE DATABASE and ALTER DATABASE. WITH
+// is only consumed when it is followed by at least one option.
+pub fn parse_database_options(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<DatabaseOption>> {
+    let mut idx = start_idx;
+    if let ParseCommandSectionResult::Valid(idx_after_with, _) =
+        parse_keyword(tokens, idx, WITH_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_with);
+    }
+    let (idx_after_options, options) = parse_section_from_section!(
+        parse_whitespace_separated_list(tokens, idx, parse_database_option)
+    );
+    if options.is_empty() {
+        return ParseCommandSectionResult::Valid(start_idx, options);
+    }
+    return ParseCommandSectionResult::Valid(idx_after_options, options);
+}
+
+// option [=] {value | DEFAULT}
+fn parse_database_option(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DatabaseOption> {
+    let mut idx;
+    let name;
+    if let ParseCommandSectionResult::Valid(idx_after_connection_limit, _) =
+        parse_keywords(tokens, start_idx, &[CONNECTION_KEYWORD, LIMIT_KEYWORD])
+    {
+        idx = idx_after_connection_limit;
+        name = format!("{} {}", CONNECTION_KEYWORD, LIMIT_KEYWORD);
+    } else {
+        let option_name;
+        (idx, option_name) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+        name = option_name.value.to_lowercase();
+    }
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    if let ParseCommandSectionResult::Valid(idx_after_equals, _) = parse_operator(tokens, idx, "=")
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_equals);
+    }
+
+    if let ParseCommandSectionResult::Valid(idx_after_default, _) =
+        parse_keyword(tokens, idx, DEFAULT_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after_default,
+            DatabaseOption {
+                name: name,
+                value: None,
+            },
+        );
+    }
+    let (idx_after_value, value) = parse_section_from_section!(parse_expression(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after_value,
+        DatabaseOption {
+            name: name,
+            value: Some(value),
+        },
+    );
+}
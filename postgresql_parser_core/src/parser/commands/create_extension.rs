@@ -0,0 +1,94 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::CreateExtensionCommand;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_string_literal;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::if_exists::parse_if_not_exists;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, CASCADE_KEYWORD, EXTENSION_KEYWORD, SCHEMA_KEYWORD, VERSION_KEYWORD,
+    WITH_KEYWORD,
+};
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the CREATE keyword of a CREATE EXTENSION command
+pub fn parse_create_extension(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateExtensionCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, EXTENSION_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut if_not_exists = false;
+    if let ParseCommandSectionResult::Valid(idx_after_if_not_exists, _) =
+        parse_if_not_exists(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_if_not_exists);
+        if_not_exists = true;
+    }
+
+    let name;
+    (idx, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_with, _) =
+        parse_keyword(tokens, idx_after_whitespace, WITH_KEYWORD)
+    {
+        idx = idx_after_with;
+    }
+
+    let mut schema = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_schema, _) =
+        parse_keyword(tokens, idx_after_whitespace, SCHEMA_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_schema);
+        let schema_name;
+        (idx, schema_name) = parse_section_from_section!(parse_identifier(tokens, idx));
+        schema = Some(schema_name);
+    }
+
+    let mut version = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_version, _) =
+        parse_keyword(tokens, idx_after_whitespace, VERSION_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_version);
+        let extension_version;
+        (idx, extension_version) = parse_section_from_section!(parse_version(tokens, idx));
+        version = Some(extension_version);
+    }
+
+    let mut cascade = false;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_cascade, _) =
+        parse_keyword(tokens, idx_after_whitespace, CASCADE_KEYWORD)
+    {
+        idx = idx_after_cascade;
+        cascade = true;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateExtensionCommand {
+            if_not_exists: if_not_exists,
+            name: name,
+            schema: schema,
+            version: version,
+            cascade: cascade,
+        },
+    );
+}
+
+// An extension version, either a string such as '1.2' or a plain word
+pub fn parse_version(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<String> {
+    if let ParseCommandSectionResult::Valid(idx_after, version) =
+        parse_string_literal(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, version);
+    }
+    let (idx_after, version) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, version.value);
+}
@@ -0,0 +1,343 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    CreateFunctionCommand, FunctionBody, FunctionOption, FunctionParallelSafety,
+    FunctionReturnType, FunctionVolatility, RoutineKind,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::comma::parse_comma;
+use crate::parser::commands::sections::configuration_setting::parse_configuration_setting;
+use crate::parser::commands::sections::expression::{parse_expression, parse_string_literal};
+use crate::parser::commands::sections::from_item::parse_column_definitions;
+use crate::parser::commands::sections::function_parameter::parse_function_signature;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, AS_KEYWORD, ATOMIC_KEYWORD,
+    BEGIN_KEYWORD, CALLED_KEYWORD, COST_KEYWORD, DEFINER_KEYWORD, END_KEYWORD, EXTERNAL_KEYWORD,
+    FUNCTION_KEYWORD, IMMUTABLE_KEYWORD, INPUT_KEYWORD, INVOKER_KEYWORD, LANGUAGE_KEYWORD,
+    LEAKPROOF_KEYWORD, NOT_KEYWORD, NULL_KEYWORD, ON_KEYWORD, OR_KEYWORD, PARALLEL_KEYWORD,
+    PROCEDURE_KEYWORD, REPLACE_KEYWORD, RESTRICTED_KEYWORD, RETURNS_KEYWORD, RETURN_KEYWORD,
+    ROWS_KEYWORD, SAFE_KEYWORD, SECURITY_KEYWORD, SETOF_KEYWORD, SET_KEYWORD, STABLE_KEYWORD,
+    STRICT_KEYWORD, TABLE_KEYWORD, UNSAFE_KEYWORD, VOLATILE_KEYWORD,
+};
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::type_name::parse_type_name;
+use crate::parser::parse_command;
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the CREATE keyword of a CREATE FUNCTION or
+// CREATE PROCEDURE command
+pub fn parse_create_function(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateFunctionCommand> {
+    let mut idx = start_idx;
+
+    let mut or_replace = false;
+    if let ParseCommandSectionResult::Valid(idx_after_or_replace, _) =
+        parse_keywords(tokens, idx, &[OR_KEYWORD, REPLACE_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_or_replace);
+        or_replace = true;
+    }
+
+    let (idx_after_kind, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        idx,
+        &[FUNCTION_KEYWORD, PROCEDURE_KEYWORD]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx_after_kind);
+    let kind = match keyword.as_str() {
+        FUNCTION_KEYWORD => RoutineKind::Function,
+        _ => RoutineKind::Procedure,
+    };
+
+    let (idx_after_name, name) = parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_name);
+    let parameters;
+    (idx, parameters) = parse_section_from_section!(parse_function_signature(tokens, idx));
+
+    // RETURNS NULL ON NULL INPUT is an option rather than a return type
+    let mut returns = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if !matches!(
+        parse_function_option(tokens, idx_after_whitespace),
+        ParseCommandSectionResult::Valid(_, _)
+    ) {
+        if let ParseCommandSectionResult::Valid(idx_after_returns, _) =
+            parse_keyword(tokens, idx_after_whitespace, RETURNS_KEYWORD)
+        {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_returns);
+            let (idx_after_return_type, return_type) =
+                parse_section_from_section!(parse_return_type(tokens, idx_after_whitespace));
+            idx = idx_after_return_type;
+            returns = Some(return_type);
+        }
+    }
+
+    // The options may come in any order, and the body given with AS is
+    // one of them
+    let mut options = Vec::new();
+    let mut body = None;
+    loop {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_option, option) =
+            parse_function_option(tokens, idx_after_whitespace)
+        {
+            idx = idx_after_option;
+            options.push(option);
+        } else if let (None, ParseCommandSectionResult::Valid(idx_after_body, definition)) = (
+            &body,
+            parse_function_definition(tokens, idx_after_whitespace),
+        ) {
+            idx = idx_after_body;
+            body = Some(definition);
+        } else {
+            break;
+        }
+    }
+
+    if body.is_none() {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_body, sql_body) =
+            parse_sql_standard_body(tokens, idx_after_whitespace)
+        {
+            idx = idx_after_body;
+            body = Some(sql_body);
+        }
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateFunctionCommand {
+            or_replace: or_replace,
+            kind: kind,
+            name: name,
+            parameters: parameters,
+            returns: returns,
+            options: options,
+            body: body,
+        },
+    );
+}
+
+// TABLE (column_name type, ...), SETOF type or type
+fn parse_return_type(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<FunctionReturnType> {
+    if let ParseCommandSectionResult::Valid(idx_after_table, _) =
+        parse_keyword(tokens, start_idx, TABLE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_table);
+        let (idx_after_columns, columns) =
+            parse_section_from_section!(parse_column_definitions(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after_columns,
+            FunctionReturnType::Table(columns),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_setof, _) =
+        parse_keyword(tokens, start_idx, SETOF_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_setof);
+        let (idx_after_type, type_name) =
+            parse_section_from_section!(parse_type_name(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after_type,
+            FunctionReturnType::SetOf(type_name),
+        );
+    }
+    let (idx_after_type, type_name) =
+        parse_section_from_section!(parse_type_name(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after_type, FunctionReturnType::Type(type_name));
+}
+
+fn parse_function_option(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<FunctionOption> {
+    let (idx_after_keyword, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[
+            LANGUAGE_KEYWORD,
+            IMMUTABLE_KEYWORD,
+            STABLE_KEYWORD,
+            VOLATILE_KEYWORD,
+            STRICT_KEYWORD,
+            CALLED_KEYWORD,
+            RETURNS_KEYWORD,
+            EXTERNAL_KEYWORD,
+            SECURITY_KEYWORD,
+            NOT_KEYWORD,
+            LEAKPROOF_KEYWORD,
+            PARALLEL_KEYWORD,
+            COST_KEYWORD,
+            ROWS_KEYWORD,
+            SET_KEYWORD,
+        ]
+    ));
+    let idx = idx_after_optional_whitespace(tokens, idx_after_keyword);
+    return match keyword.as_str() {
+        LANGUAGE_KEYWORD => {
+            let (idx_after, language) = parse_section_from_section!(parse_identifier(tokens, idx));
+            ParseCommandSectionResult::Valid(idx_after, FunctionOption::Language(language))
+        }
+        IMMUTABLE_KEYWORD => ParseCommandSectionResult::Valid(
+            idx_after_keyword,
+            FunctionOption::Volatility(FunctionVolatility::Immutable),
+        ),
+        STABLE_KEYWORD => ParseCommandSectionResult::Valid(
+            idx_after_keyword,
+            FunctionOption::Volatility(FunctionVolatility::Stable),
+        ),
+        VOLATILE_KEYWORD => ParseCommandSectionResult::Valid(
+            idx_after_keyword,
+            FunctionOption::Volatility(FunctionVolatility::Volatile),
+        ),
+        STRICT_KEYWORD => {
+            ParseCommandSectionResult::Valid(idx_after_keyword, FunctionOption::Strict(true))
+        }
+        CALLED_KEYWORD => {
+            let (idx_after, _) = parse_section_from_section!(parse_keywords(
+                tokens,
+                idx,
+                &[ON_KEYWORD, NULL_KEYWORD, INPUT_KEYWORD]
+            ));
+            ParseCommandSectionResult::Valid(idx_after, FunctionOption::Strict(false))
+        }
+        RETURNS_KEYWORD => {
+            let (idx_after, _) = parse_section_from_section!(parse_keywords(
+                tokens,
+                idx,
+                &[NULL_KEYWORD, ON_KEYWORD, NULL_KEYWORD, INPUT_KEYWORD]
+            ));
+            ParseCommandSectionResult::Valid(idx_after, FunctionOption::Strict(true))
+        }
+        EXTERNAL_KEYWORD | SECURITY_KEYWORD => {
+            let mut idx = idx;
+            if keyword == EXTERNAL_KEYWORD {
+                (idx, _) =
+                    parse_section_from_section!(parse_keyword(tokens, idx, SECURITY_KEYWORD));
+                idx = idx_after_optional_whitespace(tokens, idx);
+            }
+            let (idx_after, security) = parse_section_from_section!(parse_one_of_keywords(
+                tokens,
+                idx,
+                &[DEFINER_KEYWORD, INVOKER_KEYWORD]
+            ));
+            ParseCommandSectionResult::Valid(
+                idx_after,
+                FunctionOption::SecurityDefiner(security == DEFINER_KEYWORD),
+            )
+        }
+        NOT_KEYWORD => {
+            let (idx_after, _) =
+                parse_section_from_section!(parse_keyword(tokens, idx, LEAKPROOF_KEYWORD));
+            ParseCommandSectionResult::Valid(idx_after, FunctionOption::Leakproof(false))
+        }
+        LEAKPROOF_KEYWORD => {
+            ParseCommandSectionResult::Valid(idx_after_keyword, FunctionOption::Leakproof(true))
+        }
+        PARALLEL_KEYWORD => {
+            let (idx_after, safety) = parse_section_from_section!(parse_one_of_keywords(
+                tokens,
+                idx,
+                &[UNSAFE_KEYWORD, RESTRICTED_KEYWORD, SAFE_KEYWORD]
+            ));
+            let safety = match safety.as_str() {
+                UNSAFE_KEYWORD => FunctionParallelSafety::Unsafe,
+                RESTRICTED_KEYWORD => FunctionParallelSafety::Restricted,
+                _ => FunctionParallelSafety::Safe,
+            };
+            ParseCommandSectionResult::Valid(idx_after, FunctionOption::Parallel(safety))
+        }
+        COST_KEYWORD => {
+            let (idx_after, cost) = parse_section_from_section!(parse_expression(tokens, idx));
+            ParseCommandSectionResult::Valid(idx_after, FunctionOption::Cost(cost))
+        }
+        ROWS_KEYWORD => {
+            let (idx_after, rows) = parse_section_from_section!(parse_expression(tokens, idx));
+            ParseCommandSectionResult::Valid(idx_after, FunctionOption::Rows(rows))
+        }
+        _ => {
+            let (idx_after, setting) =
+                parse_section_from_section!(parse_configuration_setting(tokens, idx));
+            ParseCommandSectionResult::Valid(idx_after, FunctionOption::Set(setting))
+        }
+    };
+}
+
+// AS 'definition' or AS 'object_file', 'link_symbol'
+fn parse_function_definition(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<FunctionBody> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, AS_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let definition;
+    (idx, definition) = parse_section_from_section!(parse_string_literal(tokens, idx));
+
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_comma, _) =
+        parse_comma(tokens, idx_after_whitespace)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_comma);
+        let (idx_after_symbol, link_symbol) =
+            parse_section_from_section!(parse_string_literal(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after_symbol,
+            FunctionBody::ObjectFile {
+                object_file: definition,
+                link_symbol: link_symbol,
+            },
+        );
+    }
+    return ParseCommandSectionResult::Valid(idx, FunctionBody::Definition(definition));
+}
+
+// RETURN expression, or BEGIN ATOMIC command; ... END
+fn parse_sql_standard_body(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<FunctionBody> {
+    if let ParseCommandSectionResult::Valid(idx_after_return, _) =
+        parse_keyword(tokens, start_idx, RETURN_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_return);
+        let (idx_after_expression, expression) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after_expression,
+            FunctionBody::Return(expression),
+        );
+    }
+
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        idx,
+        &[BEGIN_KEYWORD, ATOMIC_KEYWORD]
+    ));
+    let mut commands = Vec::new();
+    loop {
+        idx = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_end, _) =
+            parse_keyword(tokens, idx, END_KEYWORD)
+        {
+            return ParseCommandSectionResult::Valid(idx_after_end, FunctionBody::Atomic(commands));
+        }
+        match parse_command(tokens, idx) {
+            ParseCommandResult::Valid(command, idx_after_command) => {
+                commands.push(command);
+                idx = idx_after_command;
+            }
+            ParseCommandResult::Invalid(_) => return ParseCommandSectionResult::Invalid,
+            ParseCommandResult::EndOfInput => return ParseCommandSectionResult::EndOfInput,
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::CreateIndexCommand;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::if_exists::parse_if_not_exists;
+use crate::parser::commands::sections::index_element::parse_index_element;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, CONCURRENTLY_KEYWORD, DISTINCT_KEYWORD, INCLUDE_KEYWORD, INDEX_KEYWORD,
+    NOT_KEYWORD, NULLS_KEYWORD, ONLY_KEYWORD, ON_KEYWORD, TABLESPACE_KEYWORD, UNIQUE_KEYWORD,
+    USING_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_parenthesized_list;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::storage_parameters::parse_storage_parameters;
+use crate::parser::commands::sections::where_clause::parse_where_clause;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the CREATE keyword of a CREATE INDEX command
+pub fn parse_create_index(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateIndexCommand> {
+    let mut idx = start_idx;
+
+    let mut unique = false;
+    if let ParseCommandSectionResult::Valid(idx_after_unique, _) =
+        parse_keyword(tokens, idx, UNIQUE_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_unique);
+        unique = true;
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, INDEX_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut concurrently = false;
+    if let ParseCommandSectionResult::Valid(idx_after_concurrently, _) =
+        parse_keyword(tokens, idx, CONCURRENTLY_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_concurrently);
+        concurrently = true;
+    }
+
+    let mut if_not_exists = false;
+    if let ParseCommandSectionResult::Valid(idx_after_if_not_exists, _) =
+        parse_if_not_exists(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_if_not_exists);
+        if_not_exists = true;
+    }
+
+    // The name can be left out, in which case ON follows directly
+    let mut name = None;
+    if let ParseCommandSectionResult::Invalid = parse_keyword(tokens, idx, ON_KEYWORD) {
+        let (idx_after_name, index_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx_after_name);
+        name = Some(index_name);
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, ON_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut only = false;
+    if let ParseCommandSectionResult::Valid(idx_after_only, _) =
+        parse_keyword(tokens, idx, ONLY_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_only);
+        only = true;
+    }
+
+    let (idx_after_table_name, table_name) =
+        parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_table_name);
+
+    let mut method = None;
+    if let ParseCommandSectionResult::Valid(idx_after_using, _) =
+        parse_keyword(tokens, idx, USING_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_using);
+        let (idx_after_method, method_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx_after_method);
+        method = Some(method_name);
+    }
+
+    let elements;
+    (idx, elements) =
+        parse_section_from_section!(parse_parenthesized_list(tokens, idx, parse_index_element));
+
+    let mut include = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_include, _) =
+        parse_keyword(tokens, idx_after_whitespace, INCLUDE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_include);
+        let (idx_after_columns, columns) = parse_section_from_section!(parse_parenthesized_list(
+            tokens,
+            idx_after_whitespace,
+            parse_identifier
+        ));
+        idx = idx_after_columns;
+        include = columns;
+    }
+
+    let mut nulls_not_distinct = false;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_nulls, _) =
+        parse_keyword(tokens, idx_after_whitespace, NULLS_KEYWORD)
+    {
+        let mut idx_after_not = idx_after_optional_whitespace(tokens, idx_after_nulls);
+        if let ParseCommandSectionResult::Valid(idx_after, _) =
+            parse_keyword(tokens, idx_after_not, NOT_KEYWORD)
+        {
+            idx_after_not = idx_after_optional_whitespace(tokens, idx_after);
+            nulls_not_distinct = true;
+        }
+        (idx, _) =
+            parse_section_from_section!(parse_keyword(tokens, idx_after_not, DISTINCT_KEYWORD));
+    }
+
+    let mut storage_parameters = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_with, _) =
+        parse_keyword(tokens, idx_after_whitespace, WITH_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_with);
+        let (idx_after_parameters, parameters) =
+            parse_section_from_section!(parse_storage_parameters(tokens, idx_after_whitespace));
+        idx = idx_after_parameters;
+        storage_parameters = parameters;
+    }
+
+    let mut tablespace = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_tablespace, _) =
+        parse_keyword(tokens, idx_after_whitespace, TABLESPACE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_tablespace);
+        let (idx_after_name, tablespace_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx_after_whitespace));
+        idx = idx_after_name;
+        tablespace = Some(tablespace_name);
+    }
+
+    let mut where_clause = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_where, predicate) =
+        parse_where_clause(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_where;
+        where_clause = Some(predicate);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateIndexCommand {
+            unique: unique,
+            concurrently: concurrently,
+            if_not_exists: if_not_exists,
+            name: name,
+            only: only,
+            table_name: table_name,
+            method: method,
+            elements: elements,
+            include: include,
+            nulls_not_distinct: nulls_not_distinct,
+            storage_parameters: storage_parameters,
+            tablespace: tablespace,
+            where_clause: where_clause,
+        },
+    );
+}
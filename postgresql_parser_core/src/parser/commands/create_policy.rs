@@ -0,0 +1,156 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    CreatePolicyCommand, Expression, Identifier, PolicyCommand, PolicyKind, QualifiedName,
+    RoleSpecification,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_parenthesized_expression;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, ALL_KEYWORD, AS_KEYWORD, CHECK_KEYWORD,
+    DELETE_KEYWORD, FOR_KEYWORD, INSERT_KEYWORD, ON_KEYWORD, PERMISSIVE_KEYWORD, POLICY_KEYWORD,
+    RESTRICTIVE_KEYWORD, SELECT_KEYWORD, TO_KEYWORD, UPDATE_KEYWORD, USING_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::privileges::parse_grantees;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the CREATE keyword of a CREATE POLICY command
+pub fn parse_create_policy(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreatePolicyCommand> {
+    let (mut idx, (name, table_name)) =
+        parse_section_from_section!(parse_policy_name_on_table(tokens, start_idx));
+
+    let mut kind = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_as, _) =
+        parse_keyword(tokens, idx_after_whitespace, AS_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_as);
+        let keyword;
+        (idx, keyword) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            idx,
+            &[PERMISSIVE_KEYWORD, RESTRICTIVE_KEYWORD]
+        ));
+        kind = Some(match keyword.as_str() {
+            PERMISSIVE_KEYWORD => PolicyKind::Permissive,
+            _ => PolicyKind::Restrictive,
+        });
+    }
+
+    let mut command = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_for, _) =
+        parse_keyword(tokens, idx_after_whitespace, FOR_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_for);
+        let keyword;
+        (idx, keyword) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            idx,
+            &[
+                ALL_KEYWORD,
+                SELECT_KEYWORD,
+                INSERT_KEYWORD,
+                UPDATE_KEYWORD,
+                DELETE_KEYWORD,
+            ]
+        ));
+        command = Some(match keyword.as_str() {
+            ALL_KEYWORD => PolicyCommand::All,
+            SELECT_KEYWORD => PolicyCommand::Select,
+            INSERT_KEYWORD => PolicyCommand::Insert,
+            UPDATE_KEYWORD => PolicyCommand::Update,
+            _ => PolicyCommand::Delete,
+        });
+    }
+
+    let (roles, using, with_check);
+    (idx, (roles, using, with_check)) =
+        parse_section_from_section!(parse_policy_clauses(tokens, idx));
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreatePolicyCommand {
+            name: name,
+            table_name: table_name,
+            kind: kind,
+            command: command,
+            roles: roles,
+            using: using,
+            with_check: with_check,
+        },
+    );
+}
+
+// POLICY name ON table, as in CREATE POLICY and ALTER POLICY
+pub fn parse_policy_name_on_table(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<(Identifier, QualifiedName)> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, POLICY_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let name;
+    (idx, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, ON_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let table_name;
+    (idx, table_name) = parse_section_from_section!(parse_qualified_name(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx, (name, table_name));
+}
+
+// [TO role, ...] [USING (expression)] [WITH CHECK (expression)], where every
+// clause is optional. Like the other optional clauses, leading whitespace is
+// only consumed when a clause follows it.
+pub fn parse_policy_clauses(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<(
+    Vec<RoleSpecification>,
+    Option<Expression>,
+    Option<Expression>,
+)> {
+    let mut idx = start_idx;
+
+    let mut roles = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_to, _) =
+        parse_keyword(tokens, idx_after_whitespace, TO_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_to);
+        (idx, roles) = parse_section_from_section!(parse_grantees(tokens, idx));
+    }
+
+    let mut using = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_using, _) =
+        parse_keyword(tokens, idx_after_whitespace, USING_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_using);
+        let expression;
+        (idx, expression) =
+            parse_section_from_section!(parse_parenthesized_expression(tokens, idx));
+        using = Some(expression);
+    }
+
+    let mut with_check = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_with_check, _) =
+        parse_keywords(tokens, idx_after_whitespace, &[WITH_KEYWORD, CHECK_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_with_check);
+        let expression;
+        (idx, expression) =
+            parse_section_from_section!(parse_parenthesized_expression(tokens, idx));
+        with_check = Some(expression);
+    }
+
+    return ParseCommandSectionResult::Valid(idx, (roles, using, with_check));
+}
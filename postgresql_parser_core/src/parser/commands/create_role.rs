@@ -0,0 +1,202 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{CreateRoleCommand, RoleKind, RoleOption};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::{parse_expression, parse_string_literal};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, ADMIN_KEYWORD, BYPASSRLS_KEYWORD,
+    CONNECTION_KEYWORD, CREATEDB_KEYWORD, CREATEROLE_KEYWORD, ENCRYPTED_KEYWORD, GROUP_KEYWORD,
+    INHERIT_KEYWORD, IN_KEYWORD, LIMIT_KEYWORD, LOGIN_KEYWORD, NOBYPASSRLS_KEYWORD,
+    NOCREATEDB_KEYWORD, NOCREATEROLE_KEYWORD, NOINHERIT_KEYWORD, NOLOGIN_KEYWORD,
+    NOREPLICATION_KEYWORD, NOSUPERUSER_KEYWORD, NULL_KEYWORD, PASSWORD_KEYWORD,
+    REPLICATION_KEYWORD, ROLE_KEYWORD, SUPERUSER_KEYWORD, UNTIL_KEYWORD, USER_KEYWORD,
+    VALID_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::list::{
+    parse_comma_separated_list, parse_whitespace_separated_list,
+};
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the CREATE keyword of a CREATE ROLE, CREATE USER or
+// CREATE GROUP command
+pub fn parse_create_role(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateRoleCommand> {
+    let (mut idx, kind) = parse_section_from_section!(parse_role_kind(tokens, start_idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let name;
+    (idx, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+
+    let mut options = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_options, role_options) =
+        parse_role_options(tokens, idx_after_whitespace)
+    {
+        if !role_options.is_empty() {
+            idx = idx_after_options;
+            options = role_options;
+        }
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateRoleCommand {
+            kind: kind,
+            name: name,
+            options: options,
+        },
+    );
+}
+
+// ROLE | USER | GROUP
+pub fn parse_role_kind(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<RoleKind> {
+    let (idx_after, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[ROLE_KEYWORD, USER_KEYWORD, GROUP_KEYWORD]
+    ));
+    let kind = match keyword.as_str() {
+        ROLE_KEYWORD => RoleKind::Role,
+        USER_KEYWORD => RoleKind::User,
+        _ => RoleKind::Group,
+    };
+    return ParseCommandSectionResult::Valid(idx_after, kind);
+}
+
+// [WITH] role_option ..., as in CREATE ROLE and ALTER ROLE. WITH is only
+// consumed when it is followed by at least one option.
+pub fn parse_role_options(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<RoleOption>> {
+    let mut idx = start_idx;
+    if let ParseCommandSectionResult::Valid(idx_after_with, _) =
+        parse_keyword(tokens, idx, WITH_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_with);
+    }
+    let (idx_after_options, options) = parse_section_from_section!(
+        parse_whitespace_separated_list(tokens, idx, parse_role_option)
+    );
+    if options.is_empty() {
+        return ParseCommandSectionResult::Valid(start_idx, options);
+    }
+    return ParseCommandSectionResult::Valid(idx_after_options, options);
+}
+
+fn parse_role_option(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<RoleOption> {
+    if let ParseCommandSectionResult::Valid(idx_after, keyword) = parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[
+            SUPERUSER_KEYWORD,
+            NOSUPERUSER_KEYWORD,
+            CREATEDB_KEYWORD,
+            NOCREATEDB_KEYWORD,
+            CREATEROLE_KEYWORD,
+            NOCREATEROLE_KEYWORD,
+            INHERIT_KEYWORD,
+            NOINHERIT_KEYWORD,
+            LOGIN_KEYWORD,
+            NOLOGIN_KEYWORD,
+            REPLICATION_KEYWORD,
+            NOREPLICATION_KEYWORD,
+            BYPASSRLS_KEYWORD,
+            NOBYPASSRLS_KEYWORD,
+        ],
+    ) {
+        let option = match keyword.as_str() {
+            SUPERUSER_KEYWORD => RoleOption::Superuser(true),
+            NOSUPERUSER_KEYWORD => RoleOption::Superuser(false),
+            CREATEDB_KEYWORD => RoleOption::CreateDatabase(true),
+            NOCREATEDB_KEYWORD => RoleOption::CreateDatabase(false),
+            CREATEROLE_KEYWORD => RoleOption::CreateRole(true),
+            NOCREATEROLE_KEYWORD => RoleOption::CreateRole(false),
+            INHERIT_KEYWORD => RoleOption::Inherit(true),
+            NOINHERIT_KEYWORD => RoleOption::Inherit(false),
+            LOGIN_KEYWORD => RoleOption::Login(true),
+            NOLOGIN_KEYWORD => RoleOption::Login(false),
+            REPLICATION_KEYWORD => RoleOption::Replication(true),
+            NOREPLICATION_KEYWORD => RoleOption::Replication(false),
+            BYPASSRLS_KEYWORD => RoleOption::BypassRowLevelSecurity(true),
+            _ => RoleOption::BypassRowLevelSecurity(false),
+        };
+        return ParseCommandSectionResult::Valid(idx_after, option);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_connection_limit, _) =
+        parse_keywords(tokens, start_idx, &[CONNECTION_KEYWORD, LIMIT_KEYWORD])
+    {
+        let idx_after_whitespace =
+            idx_after_optional_whitespace(tokens, idx_after_connection_limit);
+        let (idx_after, limit) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, RoleOption::ConnectionLimit(limit));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_valid_until, _) =
+        parse_keywords(tokens, start_idx, &[VALID_KEYWORD, UNTIL_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_valid_until);
+        let (idx_after, timestamp) =
+            parse_section_from_section!(parse_string_literal(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, RoleOption::ValidUntil(timestamp));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_in, _) =
+        parse_keyword(tokens, start_idx, IN_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_in);
+        let (idx_after_keyword, _) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[ROLE_KEYWORD, GROUP_KEYWORD]
+        ));
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_keyword);
+        let (idx_after, roles) = parse_section_from_section!(parse_comma_separated_list(
+            tokens,
+            idx_after_whitespace,
+            parse_identifier
+        ));
+        return ParseCommandSectionResult::Valid(idx_after, RoleOption::InRole(roles));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_keyword, keyword) = parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[ROLE_KEYWORD, USER_KEYWORD, ADMIN_KEYWORD],
+    ) {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_keyword);
+        let (idx_after, roles) = parse_section_from_section!(parse_comma_separated_list(
+            tokens,
+            idx_after_whitespace,
+            parse_identifier
+        ));
+        let option = match keyword.as_str() {
+            ADMIN_KEYWORD => RoleOption::Admin(roles),
+            _ => RoleOption::Role(roles),
+        };
+        return ParseCommandSectionResult::Valid(idx_after, option);
+    }
+
+    let mut idx = start_idx;
+    if let ParseCommandSectionResult::Valid(idx_after_encrypted, _) =
+        parse_keyword(tokens, idx, ENCRYPTED_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_encrypted);
+    }
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, PASSWORD_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_null, _) =
+        parse_keyword(tokens, idx, NULL_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after_null, RoleOption::Password(None));
+    }
+    let (idx_after, password) = parse_section_from_section!(parse_string_literal(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx_after, RoleOption::Password(Some(password)));
+}
@@ -0,0 +1,107 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Command, CreateSchemaCommand, DataDefinitionCommand};
+use crate::parser::commands::create::parse_create;
+use crate::parser::commands::grant::parse_grant;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::if_exists::parse_if_not_exists;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, AUTHORIZATION_KEYWORD, CREATE_KEYWORD, GRANT_KEYWORD, SCHEMA_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_whitespace_separated_list;
+use crate::parser::commands::sections::role_specification::parse_role_specification;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the CREATE keyword of a CREATE SCHEMA command
+pub fn parse_create_schema(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateSchemaCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, SCHEMA_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut if_not_exists = false;
+    if let ParseCommandSectionResult::Valid(idx_after_if_not_exists, _) =
+        parse_if_not_exists(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_if_not_exists);
+        if_not_exists = true;
+    }
+
+    // The name may be left out when AUTHORIZATION is given, in which case the
+    // schema is named after the role
+    let mut name = None;
+    if !matches!(
+        parse_keyword(tokens, idx, AUTHORIZATION_KEYWORD),
+        ParseCommandSectionResult::Valid(_, _)
+    ) {
+        let schema_name;
+        (idx, schema_name) = parse_section_from_section!(parse_identifier(tokens, idx));
+        name = Some(schema_name);
+    }
+
+    let mut authorization = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_authorization, _) =
+        parse_keyword(tokens, idx_after_whitespace, AUTHORIZATION_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_authorization);
+        let role;
+        (idx, role) = parse_section_from_section!(parse_role_specification(tokens, idx));
+        authorization = Some(role);
+    } else if name.is_none() {
+        return ParseCommandSectionResult::Invalid;
+    }
+
+    let mut elements = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_elements, schema_elements) =
+        parse_whitespace_separated_list(tokens, idx_after_whitespace, parse_schema_element)
+    {
+        if !schema_elements.is_empty() {
+            idx = idx_after_elements;
+            elements = schema_elements;
+        }
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateSchemaCommand {
+            if_not_exists: if_not_exists,
+            name: name,
+            authorization: authorization,
+            elements: elements,
+        },
+    );
+}
+
+// A CREATE TABLE, VIEW, INDEX, SEQUENCE or TRIGGER command or a GRANT command
+// that is part of the CREATE SCHEMA command, without a terminating semicolon
+fn parse_schema_element(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Command> {
+    if let ParseCommandSectionResult::Valid(idx_after_grant, _) =
+        parse_keyword(tokens, start_idx, GRANT_KEYWORD)
+    {
+        let (idx_after, command) =
+            parse_section_from_section!(parse_grant(tokens, idx_after_grant));
+        return ParseCommandSectionResult::Valid(idx_after, Command::DataControl(command));
+    }
+    let (idx_after_create, _) =
+        parse_section_from_section!(parse_keyword(tokens, start_idx, CREATE_KEYWORD));
+    let (idx_after, command) = parse_section_from_section!(parse_create(tokens, idx_after_create));
+    return match command {
+        DataDefinitionCommand::CreateTable(_)
+        | DataDefinitionCommand::CreateView(_)
+        | DataDefinitionCommand::CreateIndex(_)
+        | DataDefinitionCommand::CreateSequence(_)
+        | DataDefinitionCommand::CreateTrigger(_) => {
+            ParseCommandSectionResult::Valid(idx_after, Command::DataDefinition(command))
+        }
+        _ => ParseCommandSectionResult::Invalid,
+    };
+}
@@ -0,0 +1,62 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::CreateSequenceCommand;
+use crate::parser::commands::create_table::parse_table_persistence;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::if_exists::parse_if_not_exists;
+use crate::parser::commands::sections::keywords::{parse_keyword, SEQUENCE_KEYWORD};
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::sequence_options::parse_sequence_options;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the CREATE keyword of a CREATE SEQUENCE command
+pub fn parse_create_sequence(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateSequenceCommand> {
+    let mut idx = start_idx;
+
+    let mut persistence = None;
+    if let ParseCommandSectionResult::Valid(idx_after_persistence, sequence_persistence) =
+        parse_table_persistence(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_persistence);
+        persistence = Some(sequence_persistence);
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, SEQUENCE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut if_not_exists = false;
+    if let ParseCommandSectionResult::Valid(idx_after_if_not_exists, _) =
+        parse_if_not_exists(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_if_not_exists);
+        if_not_exists = true;
+    }
+
+    let name;
+    (idx, name) = parse_section_from_section!(parse_qualified_name(tokens, idx));
+
+    let mut options = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_options, sequence_options) =
+        parse_sequence_options(tokens, idx_after_whitespace)
+    {
+        if !sequence_options.is_empty() {
+            idx = idx_after_options;
+            options = sequence_options;
+        }
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateSequenceCommand {
+            persistence: persistence,
+            if_not_exists: if_not_exists,
+            name: name,
+            options: options,
+        },
+    );
+}
@@ -0,0 +1,382 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    CreateTableCommand, PartitionBy, PartitionKey, PartitionOf, PartitionStrategy, TableElement,
+    TableLikeClause, TableLikeOption, TableLikeProperty, TablePersistence,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::column_definition::{
+    parse_column_definition, parse_untyped_column_definition,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::if_exists::parse_if_not_exists;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, ALL_KEYWORD, BY_KEYWORD, COLLATE_KEYWORD,
+    COMMENTS_KEYWORD, COMPRESSION_KEYWORD, CONSTRAINTS_KEYWORD, DEFAULTS_KEYWORD,
+    EXCLUDING_KEYWORD, GENERATED_KEYWORD, GLOBAL_KEYWORD, HASH_KEYWORD, IDENTITY_KEYWORD,
+    INCLUDING_KEYWORD, INDEXES_KEYWORD, INHERITS_KEYWORD, LIKE_KEYWORD, LIST_KEYWORD,
+    LOCAL_KEYWORD, OF_KEYWORD, PARTITION_KEYWORD, RANGE_KEYWORD, STATISTICS_KEYWORD,
+    STORAGE_KEYWORD, TABLESPACE_KEYWORD, TABLE_KEYWORD, TEMPORARY_KEYWORD, TEMP_KEYWORD,
+    UNLOGGED_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::list::{
+    parse_optionally_empty_parenthesized_list, parse_parenthesized_list,
+    parse_whitespace_separated_list,
+};
+use crate::parser::commands::sections::partition_bound::parse_partition_bound;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::storage_parameters::parse_storage_parameters;
+use crate::parser::commands::sections::table_constraint::parse_table_constraint;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the CREATE keyword of a CREATE TABLE command
+pub fn parse_create_table(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateTableCommand> {
+    let mut idx = start_idx;
+
+    let mut persistence = None;
+    if let ParseCommandSectionResult::Valid(idx_after_persistence, table_persistence) =
+        parse_table_persistence(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_persistence);
+        persistence = Some(table_persistence);
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TABLE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut if_not_exists = false;
+    if let ParseCommandSectionResult::Valid(idx_after_if_not_exists, _) =
+        parse_if_not_exists(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_if_not_exists);
+        if_not_exists = true;
+    }
+
+    let (idx_after_table_name, table_name) =
+        parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_table_name);
+
+    let elements: Vec<TableElement>;
+    let mut partition_of = None;
+    let mut inherits = Vec::new();
+    if let ParseCommandSectionResult::Valid(idx_after_partition_of, _) =
+        parse_keywords(tokens, idx, &[PARTITION_KEYWORD, OF_KEYWORD])
+    {
+        let (idx_after_partition, (partition, partition_elements)) =
+            parse_section_from_section!(parse_partition_of(tokens, idx_after_partition_of));
+        idx = idx_after_partition;
+        partition_of = Some(partition);
+        elements = partition_elements;
+    } else {
+        (idx, elements) = parse_section_from_section!(parse_optionally_empty_parenthesized_list(
+            tokens,
+            idx,
+            parse_table_element
+        ));
+
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_inherits, _) =
+            parse_keyword(tokens, idx_after_whitespace, INHERITS_KEYWORD)
+        {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_inherits);
+            let (idx_after_parents, parents) = parse_section_from_section!(
+                parse_parenthesized_list(tokens, idx_after_whitespace, parse_qualified_name)
+            );
+            idx = idx_after_parents;
+            inherits = parents;
+        }
+    }
+
+    let mut partition_by = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_partition_by, partition) =
+        parse_partition_by(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_partition_by;
+        partition_by = Some(partition);
+    }
+
+    let mut storage_parameters = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_with, _) =
+        parse_keyword(tokens, idx_after_whitespace, WITH_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_with);
+        let (idx_after_parameters, parameters) =
+            parse_section_from_section!(parse_storage_parameters(tokens, idx_after_whitespace));
+        idx = idx_after_parameters;
+        storage_parameters = parameters;
+    }
+
+    let mut tablespace = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_tablespace, _) =
+        parse_keyword(tokens, idx_after_whitespace, TABLESPACE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_tablespace);
+        let (idx_after_name, tablespace_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx_after_whitespace));
+        idx = idx_after_name;
+        tablespace = Some(tablespace_name);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateTableCommand {
+            persistence: persistence,
+            if_not_exists: if_not_exists,
+            table_name: table_name,
+            elements: elements,
+            inherits: inherits,
+            partition_of: partition_of,
+            partition_by: partition_by,
+            storage_parameters: storage_parameters,
+            tablespace: tablespace,
+        },
+    );
+}
+
+// [GLOBAL | LOCAL] {TEMPORARY | TEMP} or UNLOGGED
+pub fn parse_table_persistence(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TablePersistence> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, UNLOGGED_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, TablePersistence::Unlogged);
+    }
+    let mut idx = start_idx;
+    // GLOBAL and LOCAL are accepted for compatibility, and have no effect
+    if let ParseCommandSectionResult::Valid(idx_after_scope, _) =
+        parse_one_of_keywords(tokens, idx, &[GLOBAL_KEYWORD, LOCAL_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_scope);
+    }
+    (idx, _) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        idx,
+        &[TEMPORARY_KEYWORD, TEMP_KEYWORD]
+    ));
+    return ParseCommandSectionResult::Valid(idx, TablePersistence::Temporary);
+}
+
+fn parse_table_element(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TableElement> {
+    // Constraints are tried first, since their keywords are reserved and
+    // could not start a column definition
+    if let ParseCommandSectionResult::Valid(idx_after, constraint) =
+        parse_table_constraint(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, TableElement::Constraint(constraint));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, like_clause) =
+        parse_table_like_clause(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, TableElement::Like(like_clause));
+    }
+    let (idx_after, column) =
+        parse_section_from_section!(parse_column_definition(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, TableElement::Column(column));
+}
+
+// LIKE source_table [{INCLUDING | EXCLUDING} property ...]
+fn parse_table_like_clause(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TableLikeClause> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, LIKE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_source, source_table) =
+        parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_source;
+
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_options, options) = parse_section_from_section!(
+        parse_whitespace_separated_list(tokens, idx_after_whitespace, parse_table_like_option)
+    );
+    if options.len() > 0 {
+        idx = idx_after_options;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TableLikeClause {
+            source_table: source_table,
+            options: options,
+        },
+    );
+}
+
+fn parse_table_like_option(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TableLikeOption> {
+    let (mut idx, including_or_excluding) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[INCLUDING_KEYWORD, EXCLUDING_KEYWORD]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_property, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        idx,
+        &[
+            COMMENTS_KEYWORD,
+            COMPRESSION_KEYWORD,
+            CONSTRAINTS_KEYWORD,
+            DEFAULTS_KEYWORD,
+            GENERATED_KEYWORD,
+            IDENTITY_KEYWORD,
+            INDEXES_KEYWORD,
+            STATISTICS_KEYWORD,
+            STORAGE_KEYWORD,
+            ALL_KEYWORD,
+        ]
+    ));
+    let property = match keyword.as_str() {
+        COMMENTS_KEYWORD => TableLikeProperty::Comments,
+        COMPRESSION_KEYWORD => TableLikeProperty::Compression,
+        CONSTRAINTS_KEYWORD => TableLikeProperty::Constraints,
+        DEFAULTS_KEYWORD => TableLikeProperty::Defaults,
+        GENERATED_KEYWORD => TableLikeProperty::Generated,
+        IDENTITY_KEYWORD => TableLikeProperty::Identity,
+        INDEXES_KEYWORD => TableLikeProperty::Indexes,
+        STATISTICS_KEYWORD => TableLikeProperty::Statistics,
+        STORAGE_KEYWORD => TableLikeProperty::Storage,
+        _ => TableLikeProperty::All,
+    };
+    return ParseCommandSectionResult::Valid(
+        idx_after_property,
+        TableLikeOption {
+            including: including_or_excluding == INCLUDING_KEYWORD,
+            property: property,
+        },
+    );
+}
+
+// Parses everything after PARTITION OF: parent [(element, ...)] bound
+fn parse_partition_of(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<(PartitionOf, Vec<TableElement>)> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    let (idx_after_parent, parent_table) =
+        parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_parent);
+
+    let mut elements = Vec::new();
+    if let ParseCommandSectionResult::Valid(idx_after_elements, partition_elements) =
+        parse_parenthesized_list(tokens, idx, parse_partition_element)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_elements);
+        elements = partition_elements;
+    }
+
+    let (idx_after_bound, bound) = parse_section_from_section!(parse_partition_bound(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after_bound,
+        (
+            PartitionOf {
+                parent_table: parent_table,
+                bound: bound,
+            },
+            elements,
+        ),
+    );
+}
+
+// The columns of a partition take their types from the parent table
+fn parse_partition_element(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TableElement> {
+    if let ParseCommandSectionResult::Valid(idx_after, constraint) =
+        parse_table_constraint(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, TableElement::Constraint(constraint));
+    }
+    let (idx_after, column) =
+        parse_section_from_section!(parse_untyped_column_definition(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, TableElement::Column(column));
+}
+
+// PARTITION BY {RANGE | LIST | HASH} (key, ...)
+fn parse_partition_by(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<PartitionBy> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        idx,
+        &[PARTITION_KEYWORD, BY_KEYWORD]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_strategy, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        idx,
+        &[RANGE_KEYWORD, LIST_KEYWORD, HASH_KEYWORD]
+    ));
+    let strategy = match keyword.as_str() {
+        RANGE_KEYWORD => PartitionStrategy::Range,
+        LIST_KEYWORD => PartitionStrategy::List,
+        _ => PartitionStrategy::Hash,
+    };
+    idx = idx_after_optional_whitespace(tokens, idx_after_strategy);
+    let (idx_after_keys, keys) =
+        parse_section_from_section!(parse_parenthesized_list(tokens, idx, parse_partition_key));
+    return ParseCommandSectionResult::Valid(
+        idx_after_keys,
+        PartitionBy {
+            strategy: strategy,
+            keys: keys,
+        },
+    );
+}
+
+// {column | (expression) | function_call} [COLLATE collation] [operator_class]
+fn parse_partition_key(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<PartitionKey> {
+    let (mut idx, expression) = parse_section_from_section!(parse_expression(tokens, start_idx));
+
+    let mut collation = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_collate, _) =
+        parse_keyword(tokens, idx_after_whitespace, COLLATE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_collate);
+        let (idx_after_collation, collation_name) =
+            parse_section_from_section!(parse_qualified_name(tokens, idx_after_whitespace));
+        idx = idx_after_collation;
+        collation = Some(collation_name);
+    }
+
+    let mut operator_class = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_operator_class, operator_class_name) =
+        parse_qualified_name(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_operator_class;
+        operator_class = Some(operator_class_name);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        PartitionKey {
+            expression: expression,
+            collation: collation,
+            operator_class: operator_class,
+        },
+    );
+}
@@ -0,0 +1,61 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::CreateTablespaceCommand;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_string_literal;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, LOCATION_KEYWORD, OWNER_KEYWORD, TABLESPACE_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::role_specification::parse_role_specification;
+use crate::parser::commands::sections::storage_parameters::parse_storage_parameters;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the CREATE keyword of a CREATE TABLESPACE command
+pub fn parse_create_tablespace(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateTablespaceCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TABLESPACE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let name;
+    (idx, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut owner = None;
+    if let ParseCommandSectionResult::Valid(idx_after_owner, _) =
+        parse_keyword(tokens, idx, OWNER_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_owner);
+        let role;
+        (idx, role) = parse_section_from_section!(parse_role_specification(tokens, idx));
+        owner = Some(role);
+        idx = idx_after_optional_whitespace(tokens, idx);
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, LOCATION_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let location;
+    (idx, location) = parse_section_from_section!(parse_string_literal(tokens, idx));
+
+    let mut options = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_with, _) =
+        parse_keyword(tokens, idx_after_whitespace, WITH_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_with);
+        (idx, options) = parse_section_from_section!(parse_storage_parameters(tokens, idx));
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateTablespaceCommand {
+            name: name,
+            owner: owner,
+            location: location,
+            options: options,
+        },
+    );
+}
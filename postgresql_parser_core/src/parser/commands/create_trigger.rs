@@ -0,0 +1,377 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    CreateEventTriggerCommand, CreateTriggerCommand, EventTriggerFilter, Expression, QualifiedName,
+    TransitionRelationKind, TriggerEvent, TriggerLevel, TriggerTiming, TriggerTransitionRelation,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::{
+    parse_expression, parse_parenthesized_expression, parse_string_literal,
+};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, AFTER_KEYWORD, AND_KEYWORD, AS_KEYWORD,
+    BEFORE_KEYWORD, CONSTRAINT_KEYWORD, DELETE_KEYWORD, EACH_KEYWORD, EVENT_KEYWORD,
+    EXECUTE_KEYWORD, FOR_KEYWORD, FROM_KEYWORD, FUNCTION_KEYWORD, INSERT_KEYWORD, INSTEAD_KEYWORD,
+    IN_KEYWORD, NEW_KEYWORD, OF_KEYWORD, OLD_KEYWORD, ON_KEYWORD, OR_KEYWORD, PROCEDURE_KEYWORD,
+    REFERENCING_KEYWORD, REPLACE_KEYWORD, ROW_KEYWORD, STATEMENT_KEYWORD, TABLE_KEYWORD,
+    TRIGGER_KEYWORD, TRUNCATE_KEYWORD, UPDATE_KEYWORD, WHEN_KEYWORD,
+};
+use crate::parser::commands::sections::list::{
+    parse_comma_separated_list, parse_optionally_empty_parenthesized_list, parse_parenthesized_list,
+};
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::table_constraint::parse_constraint_characteristics;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the CREATE keyword of a CREATE TRIGGER command
+pub fn parse_create_trigger(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateTriggerCommand> {
+    let mut idx = start_idx;
+
+    let mut or_replace = false;
+    if let ParseCommandSectionResult::Valid(idx_after_or_replace, _) =
+        parse_keywords(tokens, idx, &[OR_KEYWORD, REPLACE_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_or_replace);
+        or_replace = true;
+    }
+
+    let mut constraint = false;
+    if let ParseCommandSectionResult::Valid(idx_after_constraint, _) =
+        parse_keyword(tokens, idx, CONSTRAINT_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_constraint);
+        constraint = true;
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TRIGGER_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let name;
+    (idx, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let timing;
+    (idx, timing) = parse_section_from_section!(parse_trigger_timing(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut events = Vec::new();
+    loop {
+        let (idx_after_event, event) =
+            parse_section_from_section!(parse_trigger_event(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx_after_event);
+        events.push(event);
+        match parse_keyword(tokens, idx, OR_KEYWORD) {
+            ParseCommandSectionResult::Valid(idx_after_or, _) => {
+                idx = idx_after_optional_whitespace(tokens, idx_after_or);
+            }
+            _ => break,
+        }
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, ON_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let table_name;
+    (idx, table_name) = parse_section_from_section!(parse_qualified_name(tokens, idx));
+
+    let mut referenced_table = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_from, _) =
+        parse_keyword(tokens, idx_after_whitespace, FROM_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_from);
+        let (idx_after_table, referenced_table_name) =
+            parse_section_from_section!(parse_qualified_name(tokens, idx_after_whitespace));
+        idx = idx_after_table;
+        referenced_table = Some(referenced_table_name);
+    }
+
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_characteristics, (deferrable, initially_deferred)) = parse_section_from_section!(
+        parse_constraint_characteristics(tokens, idx_after_whitespace)
+    );
+    if deferrable.is_some() || initially_deferred.is_some() {
+        idx = idx_after_characteristics;
+    }
+
+    let mut transition_relations = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_referencing, _) =
+        parse_keyword(tokens, idx_after_whitespace, REFERENCING_KEYWORD)
+    {
+        idx = idx_after_referencing;
+        loop {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+            match parse_transition_relation(tokens, idx_after_whitespace) {
+                ParseCommandSectionResult::Valid(idx_after_relation, relation) => {
+                    idx = idx_after_relation;
+                    transition_relations.push(relation);
+                }
+                _ => break,
+            }
+        }
+        if transition_relations.is_empty() {
+            return ParseCommandSectionResult::Invalid;
+        }
+    }
+
+    let mut level = TriggerLevel::Statement;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_for, _) =
+        parse_keyword(tokens, idx_after_whitespace, FOR_KEYWORD)
+    {
+        let mut idx_before_level = idx_after_optional_whitespace(tokens, idx_after_for);
+        if let ParseCommandSectionResult::Valid(idx_after_each, _) =
+            parse_keyword(tokens, idx_before_level, EACH_KEYWORD)
+        {
+            idx_before_level = idx_after_optional_whitespace(tokens, idx_after_each);
+        }
+        let (idx_after_level, keyword) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            idx_before_level,
+            &[ROW_KEYWORD, STATEMENT_KEYWORD]
+        ));
+        idx = idx_after_level;
+        if keyword == ROW_KEYWORD {
+            level = TriggerLevel::Row;
+        }
+    }
+
+    let mut condition = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_when, _) =
+        parse_keyword(tokens, idx_after_whitespace, WHEN_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_when);
+        let (idx_after_condition, when_condition) = parse_section_from_section!(
+            parse_parenthesized_expression(tokens, idx_after_whitespace)
+        );
+        idx = idx_after_condition;
+        condition = Some(when_condition);
+    }
+
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (function_name, arguments);
+    (idx, (function_name, arguments)) =
+        parse_section_from_section!(parse_trigger_function(tokens, idx));
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateTriggerCommand {
+            or_replace: or_replace,
+            constraint: constraint,
+            name: name,
+            timing: timing,
+            events: events,
+            table_name: table_name,
+            referenced_table: referenced_table,
+            deferrable: deferrable,
+            initially_deferred: initially_deferred,
+            transition_relations: transition_relations,
+            level: level,
+            condition: condition,
+            function_name: function_name,
+            arguments: arguments,
+        },
+    );
+}
+
+// BEFORE, AFTER or INSTEAD OF
+fn parse_trigger_timing(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TriggerTiming> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, start_idx, &[INSTEAD_KEYWORD, OF_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(idx_after, TriggerTiming::InsteadOf);
+    }
+    let (idx_after, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[BEFORE_KEYWORD, AFTER_KEYWORD]
+    ));
+    return match keyword.as_str() {
+        BEFORE_KEYWORD => ParseCommandSectionResult::Valid(idx_after, TriggerTiming::Before),
+        _ => ParseCommandSectionResult::Valid(idx_after, TriggerTiming::After),
+    };
+}
+
+// INSERT, UPDATE [OF column, ...], DELETE or TRUNCATE
+fn parse_trigger_event(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TriggerEvent> {
+    let (idx_after_keyword, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[
+            INSERT_KEYWORD,
+            UPDATE_KEYWORD,
+            DELETE_KEYWORD,
+            TRUNCATE_KEYWORD
+        ]
+    ));
+    return match keyword.as_str() {
+        INSERT_KEYWORD => ParseCommandSectionResult::Valid(idx_after_keyword, TriggerEvent::Insert),
+        UPDATE_KEYWORD => {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_keyword);
+            if let ParseCommandSectionResult::Valid(idx_after_of, _) =
+                parse_keyword(tokens, idx_after_whitespace, OF_KEYWORD)
+            {
+                let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_of);
+                let (idx_after_columns, columns) = parse_section_from_section!(
+                    parse_comma_separated_list(tokens, idx_after_whitespace, parse_identifier)
+                );
+                return ParseCommandSectionResult::Valid(
+                    idx_after_columns,
+                    TriggerEvent::Update(columns),
+                );
+            }
+            ParseCommandSectionResult::Valid(idx_after_keyword, TriggerEvent::Update(Vec::new()))
+        }
+        DELETE_KEYWORD => ParseCommandSectionResult::Valid(idx_after_keyword, TriggerEvent::Delete),
+        _ => ParseCommandSectionResult::Valid(idx_after_keyword, TriggerEvent::Truncate),
+    };
+}
+
+// {OLD | NEW} TABLE [AS] name
+fn parse_transition_relation(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TriggerTransitionRelation> {
+    let (mut idx, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[OLD_KEYWORD, NEW_KEYWORD]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TABLE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_as, _) =
+        parse_keyword(tokens, idx, AS_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_as);
+    }
+    let name;
+    (idx, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TriggerTransitionRelation {
+            kind: match keyword.as_str() {
+                OLD_KEYWORD => TransitionRelationKind::Old,
+                _ => TransitionRelationKind::New,
+            },
+            name: name,
+        },
+    );
+}
+
+// EXECUTE {FUNCTION | PROCEDURE} function_name([argument, ...])
+fn parse_trigger_function(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<(QualifiedName, Vec<Expression>)> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, EXECUTE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        idx,
+        &[FUNCTION_KEYWORD, PROCEDURE_KEYWORD]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let function_name;
+    (idx, function_name) = parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let arguments;
+    (idx, arguments) = parse_section_from_section!(parse_optionally_empty_parenthesized_list(
+        tokens,
+        idx,
+        parse_expression
+    ));
+    return ParseCommandSectionResult::Valid(idx, (function_name, arguments));
+}
+
+// Parses everything after the CREATE keyword of a CREATE EVENT TRIGGER command
+pub fn parse_create_event_trigger(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateEventTriggerCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        idx,
+        &[EVENT_KEYWORD, TRIGGER_KEYWORD]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let name;
+    (idx, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, ON_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let event;
+    (idx, event) = parse_section_from_section!(parse_identifier(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut filters = Vec::new();
+    if let ParseCommandSectionResult::Valid(idx_after_when, _) =
+        parse_keyword(tokens, idx, WHEN_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_when);
+        loop {
+            let (idx_after_filter, filter) =
+                parse_section_from_section!(parse_event_trigger_filter(tokens, idx));
+            idx = idx_after_optional_whitespace(tokens, idx_after_filter);
+            filters.push(filter);
+            match parse_keyword(tokens, idx, AND_KEYWORD) {
+                ParseCommandSectionResult::Valid(idx_after_and, _) => {
+                    idx = idx_after_optional_whitespace(tokens, idx_after_and);
+                }
+                _ => break,
+            }
+        }
+    }
+
+    let (function_name, arguments);
+    (idx, (function_name, arguments)) =
+        parse_section_from_section!(parse_trigger_function(tokens, idx));
+    // Event trigger functions take no arguments
+    if !arguments.is_empty() {
+        return ParseCommandSectionResult::Invalid;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateEventTriggerCommand {
+            name: name,
+            event: event,
+            filters: filters,
+            function_name: function_name,
+        },
+    );
+}
+
+// variable IN ('value', ...)
+fn parse_event_trigger_filter(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<EventTriggerFilter> {
+    let (mut idx, variable) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, IN_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let values;
+    (idx, values) =
+        parse_section_from_section!(parse_parenthesized_list(tokens, idx, parse_string_literal));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        EventTriggerFilter {
+            variable: variable,
+            values: values,
+        },
+    );
+}
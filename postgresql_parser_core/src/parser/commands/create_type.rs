@@ -0,0 +1,286 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    CompositeTypeAttribute, CreateDomainCommand, CreateTypeCommand, DomainConstraint,
+    DomainConstraintKind, TypeDefinition, TypeOption, TypeOptionValue,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::{parse_expression, parse_string_literal};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, AS_KEYWORD, COLLATE_KEYWORD, DEFAULT_KEYWORD, DOMAIN_KEYWORD,
+    ENUM_KEYWORD, NOT_KEYWORD, NULL_KEYWORD, RANGE_KEYWORD, TYPE_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_optionally_empty_parenthesized_list;
+use crate::parser::commands::sections::list::parse_parenthesized_list;
+use crate::parser::commands::sections::operator::parse_operator;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::table_constraint::{parse_check, parse_constraint_name};
+use crate::parser::commands::sections::type_name::parse_type_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Options of range and base types whose values are types rather than
+// functions, names or constants
+const TYPE_VALUED_OPTIONS: [&str; 4] = ["subtype", "multirange_type_name", "element", "like"];
+
+// Parses everything after the CREATE keyword of a CREATE TYPE command
+pub fn parse_create_type(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateTypeCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TYPE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let name;
+    (idx, name) = parse_section_from_section!(parse_qualified_name(tokens, idx));
+
+    let mut definition = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_definition, type_definition) =
+        parse_type_definition(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_definition;
+        definition = Some(type_definition);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateTypeCommand {
+            name: name,
+            definition: definition,
+        },
+    );
+}
+
+fn parse_type_definition(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TypeDefinition> {
+    let idx_after_as = match parse_keyword(tokens, start_idx, AS_KEYWORD) {
+        ParseCommandSectionResult::Valid(idx_after_as, _) => {
+            idx_after_optional_whitespace(tokens, idx_after_as)
+        }
+        _ => {
+            let (idx_after_options, options) =
+                parse_section_from_section!(parse_type_options(tokens, start_idx));
+            return ParseCommandSectionResult::Valid(
+                idx_after_options,
+                TypeDefinition::Base(options),
+            );
+        }
+    };
+
+    if let ParseCommandSectionResult::Valid(idx_after_enum, _) =
+        parse_keyword(tokens, idx_after_as, ENUM_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_enum);
+        let (idx_after_labels, labels) =
+            parse_section_from_section!(parse_optionally_empty_parenthesized_list(
+                tokens,
+                idx_after_whitespace,
+                parse_string_literal
+            ));
+        return ParseCommandSectionResult::Valid(idx_after_labels, TypeDefinition::Enum(labels));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_range, _) =
+        parse_keyword(tokens, idx_after_as, RANGE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_range);
+        let (idx_after_options, options) =
+            parse_section_from_section!(parse_type_options(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after_options, TypeDefinition::Range(options));
+    }
+    let (idx_after_attributes, attributes) = parse_section_from_section!(
+        parse_optionally_empty_parenthesized_list(tokens, idx_after_as, parse_composite_attribute)
+    );
+    return ParseCommandSectionResult::Valid(
+        idx_after_attributes,
+        TypeDefinition::Composite(attributes),
+    );
+}
+
+// (option [= value], ...) of a range or base type
+fn parse_type_options(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<TypeOption>> {
+    return parse_parenthesized_list(tokens, start_idx, parse_type_option);
+}
+
+fn parse_type_option(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TypeOption> {
+    let (mut idx, name) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+
+    let mut value = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_equals, _) =
+        parse_operator(tokens, idx_after_whitespace, "=")
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_equals);
+        let lowered = name.value.to_ascii_lowercase();
+        if TYPE_VALUED_OPTIONS.contains(&lowered.as_str()) {
+            let (idx_after_type, type_name) =
+                parse_section_from_section!(parse_type_name(tokens, idx_after_whitespace));
+            idx = idx_after_type;
+            value = Some(TypeOptionValue::Type(type_name));
+        } else {
+            let (idx_after_expression, expression) =
+                parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+            idx = idx_after_expression;
+            value = Some(TypeOptionValue::Expression(expression));
+        }
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TypeOption {
+            name: name,
+            value: value,
+        },
+    );
+}
+
+// name type [COLLATE collation]
+fn parse_composite_attribute(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CompositeTypeAttribute> {
+    let (mut idx, name) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let type_name;
+    (idx, type_name) = parse_section_from_section!(parse_type_name(tokens, idx));
+
+    let mut collation = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_collate, _) =
+        parse_keyword(tokens, idx_after_whitespace, COLLATE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_collate);
+        let (idx_after_collation, collation_name) =
+            parse_section_from_section!(parse_qualified_name(tokens, idx_after_whitespace));
+        idx = idx_after_collation;
+        collation = Some(collation_name);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CompositeTypeAttribute {
+            name: name,
+            type_name: type_name,
+            collation: collation,
+        },
+    );
+}
+
+// Parses everything after the CREATE keyword of a CREATE DOMAIN command
+pub fn parse_create_domain(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateDomainCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, DOMAIN_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let name;
+    (idx, name) = parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_as, _) =
+        parse_keyword(tokens, idx, AS_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_as);
+    }
+    let type_name;
+    (idx, type_name) = parse_section_from_section!(parse_type_name(tokens, idx));
+
+    // COLLATE and DEFAULT may be mixed in with the constraints
+    let mut collation = None;
+    let mut default = None;
+    let mut constraints = Vec::new();
+    loop {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let (None, ParseCommandSectionResult::Valid(idx_after_collate, _)) = (
+            &collation,
+            parse_keyword(tokens, idx_after_whitespace, COLLATE_KEYWORD),
+        ) {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_collate);
+            let (idx_after_collation, collation_name) =
+                parse_section_from_section!(parse_qualified_name(tokens, idx_after_whitespace));
+            idx = idx_after_collation;
+            collation = Some(collation_name);
+        } else if let (None, ParseCommandSectionResult::Valid(idx_after_default, _)) = (
+            &default,
+            parse_keyword(tokens, idx_after_whitespace, DEFAULT_KEYWORD),
+        ) {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_default);
+            let (idx_after_expression, expression) =
+                parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+            idx = idx_after_expression;
+            default = Some(expression);
+        } else if let ParseCommandSectionResult::Valid(idx_after_constraint, constraint) =
+            parse_domain_constraint(tokens, idx_after_whitespace)
+        {
+            idx = idx_after_constraint;
+            constraints.push(constraint);
+        } else {
+            break;
+        }
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateDomainCommand {
+            name: name,
+            type_name: type_name,
+            collation: collation,
+            default: default,
+            constraints: constraints,
+        },
+    );
+}
+
+// [CONSTRAINT name] {NOT NULL | NULL | CHECK (expression)}
+fn parse_domain_constraint(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DomainConstraint> {
+    let mut idx = start_idx;
+    let mut name = None;
+    if let ParseCommandSectionResult::Valid(idx_after_name, constraint_name) =
+        parse_constraint_name(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_name);
+        name = Some(constraint_name);
+    }
+
+    let kind;
+    if let ParseCommandSectionResult::Valid(idx_after_not_null, _) =
+        parse_keywords(tokens, idx, &[NOT_KEYWORD, NULL_KEYWORD])
+    {
+        idx = idx_after_not_null;
+        kind = DomainConstraintKind::NotNull;
+    } else if let ParseCommandSectionResult::Valid(idx_after_null, _) =
+        parse_keyword(tokens, idx, NULL_KEYWORD)
+    {
+        idx = idx_after_null;
+        kind = DomainConstraintKind::Null;
+    } else {
+        let (idx_after_check, (expression, no_inherit)) =
+            parse_section_from_section!(parse_check(tokens, idx));
+        // Domain checks are always inherited
+        if no_inherit {
+            return ParseCommandSectionResult::Invalid;
+        }
+        idx = idx_after_check;
+        kind = DomainConstraintKind::Check(expression);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        DomainConstraint {
+            name: name,
+            kind: kind,
+        },
+    );
+}
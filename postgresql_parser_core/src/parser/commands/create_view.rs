@@ -0,0 +1,226 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{CreateMaterializedViewCommand, CreateViewCommand, ViewCheckOption};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::if_exists::parse_if_not_exists;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, AS_KEYWORD, CASCADED_KEYWORD,
+    CHECK_KEYWORD, LOCAL_KEYWORD, MATERIALIZED_KEYWORD, OPTION_KEYWORD, OR_KEYWORD,
+    RECURSIVE_KEYWORD, REPLACE_KEYWORD, TABLESPACE_KEYWORD, TEMPORARY_KEYWORD, TEMP_KEYWORD,
+    USING_KEYWORD, VIEW_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_parenthesized_list;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::query::parse_query;
+use crate::parser::commands::sections::storage_parameters::parse_storage_parameters;
+use crate::parser::commands::sections::with_data::parse_with_data;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses everything after the CREATE keyword of a CREATE VIEW command
+pub fn parse_create_view(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateViewCommand> {
+    let mut idx = start_idx;
+
+    let mut or_replace = false;
+    if let ParseCommandSectionResult::Valid(idx_after_or_replace, _) =
+        parse_keywords(tokens, idx, &[OR_KEYWORD, REPLACE_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_or_replace);
+        or_replace = true;
+    }
+
+    let mut temporary = false;
+    if let ParseCommandSectionResult::Valid(idx_after_temporary, _) =
+        parse_one_of_keywords(tokens, idx, &[TEMPORARY_KEYWORD, TEMP_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_temporary);
+        temporary = true;
+    }
+
+    let mut recursive = false;
+    if let ParseCommandSectionResult::Valid(idx_after_recursive, _) =
+        parse_keyword(tokens, idx, RECURSIVE_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_recursive);
+        recursive = true;
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, VIEW_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let (idx_after_name, name) = parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_name);
+
+    let mut columns = Vec::new();
+    if let ParseCommandSectionResult::Valid(idx_after_columns, column_names) =
+        parse_parenthesized_list(tokens, idx, parse_identifier)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_columns);
+        columns = column_names;
+    }
+
+    let mut options = Vec::new();
+    if let ParseCommandSectionResult::Valid(idx_after_with, _) =
+        parse_keyword(tokens, idx, WITH_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_with);
+        let (idx_after_options, view_options) =
+            parse_section_from_section!(parse_storage_parameters(tokens, idx_after_whitespace));
+        idx = idx_after_optional_whitespace(tokens, idx_after_options);
+        options = view_options;
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, AS_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let query;
+    (idx, query) = parse_section_from_section!(parse_query(tokens, idx));
+
+    let mut check_option = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_check_option, option) =
+        parse_check_option(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_check_option;
+        check_option = Some(option);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateViewCommand {
+            or_replace: or_replace,
+            temporary: temporary,
+            recursive: recursive,
+            name: name,
+            columns: columns,
+            options: options,
+            query: query,
+            check_option: check_option,
+        },
+    );
+}
+
+// WITH [CASCADED | LOCAL] CHECK OPTION
+fn parse_check_option(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ViewCheckOption> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, WITH_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut check_option = ViewCheckOption::Cascaded;
+    if let ParseCommandSectionResult::Valid(idx_after_scope, keyword) =
+        parse_one_of_keywords(tokens, idx, &[CASCADED_KEYWORD, LOCAL_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_scope);
+        if keyword == LOCAL_KEYWORD {
+            check_option = ViewCheckOption::Local;
+        }
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        idx,
+        &[CHECK_KEYWORD, OPTION_KEYWORD]
+    ));
+    return ParseCommandSectionResult::Valid(idx, check_option);
+}
+
+// Parses everything after the CREATE keyword of a CREATE MATERIALIZED VIEW
+// command
+pub fn parse_create_materialized_view(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CreateMaterializedViewCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        idx,
+        &[MATERIALIZED_KEYWORD, VIEW_KEYWORD]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut if_not_exists = false;
+    if let ParseCommandSectionResult::Valid(idx_after_if_not_exists, _) =
+        parse_if_not_exists(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_if_not_exists);
+        if_not_exists = true;
+    }
+
+    let (idx_after_name, name) = parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_name);
+
+    let mut columns = Vec::new();
+    if let ParseCommandSectionResult::Valid(idx_after_columns, column_names) =
+        parse_parenthesized_list(tokens, idx, parse_identifier)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_columns);
+        columns = column_names;
+    }
+
+    let mut method = None;
+    if let ParseCommandSectionResult::Valid(idx_after_using, _) =
+        parse_keyword(tokens, idx, USING_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_using);
+        let (idx_after_method, method_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx_after_method);
+        method = Some(method_name);
+    }
+
+    let mut storage_parameters = Vec::new();
+    if let ParseCommandSectionResult::Valid(idx_after_with, _) =
+        parse_keyword(tokens, idx, WITH_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_with);
+        let (idx_after_parameters, parameters) =
+            parse_section_from_section!(parse_storage_parameters(tokens, idx_after_whitespace));
+        idx = idx_after_optional_whitespace(tokens, idx_after_parameters);
+        storage_parameters = parameters;
+    }
+
+    let mut tablespace = None;
+    if let ParseCommandSectionResult::Valid(idx_after_tablespace, _) =
+        parse_keyword(tokens, idx, TABLESPACE_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_tablespace);
+        let (idx_after_name, tablespace_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx_after_name);
+        tablespace = Some(tablespace_name);
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, AS_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let query;
+    (idx, query) = parse_section_from_section!(parse_query(tokens, idx));
+
+    let mut with_data = true;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_with_data, populate) =
+        parse_with_data(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_with_data;
+        with_data = populate;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CreateMaterializedViewCommand {
+            if_not_exists: if_not_exists,
+            name: name,
+            columns: columns,
+            method: method,
+            storage_parameters: storage_parameters,
+            tablespace: tablespace,
+            query: query,
+            with_data: with_data,
+        },
+    );
+}
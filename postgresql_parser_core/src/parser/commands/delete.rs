@@ -0,0 +1,74 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    Command, DataManipulationCommand, DeleteCommand, FromItem, OutputExpression, WhereClause,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::from_item::parse_from_item;
+use crate::parser::commands::sections::keywords::{parse_keyword, FROM_KEYWORD, USING_KEYWORD};
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::commands::sections::returning::parse_returning_clause;
+use crate::parser::commands::sections::target_table::parse_target_table;
+use crate::parser::commands::sections::where_clause::parse_where_or_current_of_clause;
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_delete_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_delete, |delete_command| {
+        return Command::DataManipulation(DataManipulationCommand::Delete(delete_command));
+    });
+}
+
+// Parses everything after the DELETE keyword
+pub fn parse_delete(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DeleteCommand> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, FROM_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_target, target) = parse_section_from_section!(parse_target_table(tokens, idx));
+    idx = idx_after_target;
+
+    let mut using_items: Vec<FromItem> = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_using, _) =
+        parse_keyword(tokens, idx_after_whitespace, USING_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_using);
+        let (idx_after_using_items, parsed_using_items) = parse_section_from_section!(
+            parse_comma_separated_list(tokens, idx_after_whitespace, parse_from_item)
+        );
+        idx = idx_after_using_items;
+        using_items = parsed_using_items;
+    }
+
+    let mut where_clause: Option<WhereClause> = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_where, parsed_where_clause) =
+        parse_where_or_current_of_clause(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_where;
+        where_clause = Some(parsed_where_clause);
+    }
+
+    let mut returning: Vec<OutputExpression> = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_returning, returned_expressions) =
+        parse_returning_clause(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_returning;
+        returning = returned_expressions;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        DeleteCommand {
+            target: target,
+            using_items: using_items,
+            where_clause: where_clause,
+            returning: returning,
+        },
+    );
+}
@@ -0,0 +1,57 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Command, DoCommand, Identifier, UtilityCommand};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_string_literal;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{parse_keyword, LANGUAGE_KEYWORD};
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_do_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_do, |do_command| {
+        return Command::Utility(UtilityCommand::Do(do_command));
+    });
+}
+
+// Parses everything after the DO keyword
+fn parse_do(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<DoCommand> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+
+    let mut language = None;
+    if let ParseCommandSectionResult::Valid(idx_after_language, name) = parse_language(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_language);
+        language = Some(name);
+    }
+
+    let code;
+    (idx, code) = parse_section_from_section!(parse_string_literal(tokens, idx));
+
+    if language.is_none() {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_language, name) =
+            parse_language(tokens, idx_after_whitespace)
+        {
+            idx = idx_after_language;
+            language = Some(name);
+        }
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        DoCommand {
+            language: language,
+            code: code,
+        },
+    );
+}
+
+// LANGUAGE name
+fn parse_language(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Identifier> {
+    let (idx_after_keyword, _) =
+        parse_section_from_section!(parse_keyword(tokens, start_idx, LANGUAGE_KEYWORD));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_keyword);
+    return parse_identifier(tokens, idx_after_whitespace);
+}
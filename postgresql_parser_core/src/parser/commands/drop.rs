@@ -0,0 +1,279 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    Command, DataDefinitionCommand, DropCommand, DropObject, DropObjectType, QualifiedName,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::drop_behavior::parse_drop_behavior;
+use crate::parser::commands::sections::function_parameter::parse_function_signature;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::if_exists::parse_if_exists;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, AGGREGATE_KEYWORD, COLLATION_KEYWORD,
+    CONCURRENTLY_KEYWORD, DATABASE_KEYWORD, DOMAIN_KEYWORD, EVENT_KEYWORD, EXTENSION_KEYWORD,
+    FORCE_KEYWORD, FOREIGN_KEYWORD, FUNCTION_KEYWORD, GROUP_KEYWORD, INDEX_KEYWORD,
+    MATERIALIZED_KEYWORD, ON_KEYWORD, POLICY_KEYWORD, PROCEDURE_KEYWORD, PUBLICATION_KEYWORD,
+    ROLE_KEYWORD, ROUTINE_KEYWORD, RULE_KEYWORD, SCHEMA_KEYWORD, SEQUENCE_KEYWORD,
+    SUBSCRIPTION_KEYWORD, TABLESPACE_KEYWORD, TABLE_KEYWORD, TRIGGER_KEYWORD, TYPE_KEYWORD,
+    USER_KEYWORD, VIEW_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::list::{
+    parse_comma_separated_list, parse_parenthesized_list,
+};
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_drop_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_drop, |drop_command| {
+        return Command::DataDefinition(DataDefinitionCommand::Drop(drop_command));
+    });
+}
+
+// Parses everything after the DROP keyword
+pub fn parse_drop(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<DropCommand> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    let (idx_after_object_type, object_type) =
+        parse_section_from_section!(parse_drop_object_type(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_object_type);
+
+    // Only indexes can be dropped concurrently
+    let mut concurrently = false;
+    if object_type == DropObjectType::Index {
+        if let ParseCommandSectionResult::Valid(idx_after_concurrently, _) =
+            parse_keyword(tokens, idx, CONCURRENTLY_KEYWORD)
+        {
+            idx = idx_after_optional_whitespace(tokens, idx_after_concurrently);
+            concurrently = true;
+        }
+    }
+
+    let mut if_exists = false;
+    if let ParseCommandSectionResult::Valid(idx_after_if_exists, _) = parse_if_exists(tokens, idx) {
+        idx = idx_after_optional_whitespace(tokens, idx_after_if_exists);
+        if_exists = true;
+    }
+
+    let objects: Vec<DropObject>;
+    match object_type {
+        // Objects that belong to a table are dropped one at a time
+        DropObjectType::Trigger | DropObjectType::Policy | DropObjectType::Rule => {
+            let (idx_after_object, object) =
+                parse_section_from_section!(parse_table_object(tokens, idx));
+            idx = idx_after_object;
+            objects = vec![object];
+        }
+        _ => {
+            (idx, objects) = parse_section_from_section!(parse_comma_separated_list(
+                tokens,
+                idx,
+                match object_type {
+                    DropObjectType::Function
+                    | DropObjectType::Procedure
+                    | DropObjectType::Routine
+                    | DropObjectType::Aggregate => parse_routine_object,
+                    DropObjectType::Database
+                    | DropObjectType::EventTrigger
+                    | DropObjectType::Extension
+                    | DropObjectType::Group
+                    | DropObjectType::Publication
+                    | DropObjectType::Role
+                    | DropObjectType::Schema
+                    | DropObjectType::Subscription
+                    | DropObjectType::Tablespace
+                    | DropObjectType::User => parse_unqualified_object,
+                    _ => parse_qualified_object,
+                }
+            ));
+        }
+    }
+
+    let mut behavior = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_behavior, drop_behavior) =
+        parse_drop_behavior(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_behavior;
+        behavior = Some(drop_behavior);
+    }
+
+    // Only databases can be dropped with FORCE
+    let mut force = false;
+    if object_type == DropObjectType::Database {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_force, _) =
+            parse_force_option(tokens, idx_after_whitespace)
+        {
+            idx = idx_after_force;
+            force = true;
+        }
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        DropCommand {
+            object_type: object_type,
+            concurrently: concurrently,
+            if_exists: if_exists,
+            objects: objects,
+            behavior: behavior,
+            force: force,
+        },
+    );
+}
+
+// [WITH] (FORCE)
+fn parse_force_option(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<()> {
+    let mut idx = start_idx;
+    if let ParseCommandSectionResult::Valid(idx_after_with, _) =
+        parse_keyword(tokens, idx, WITH_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_with);
+    }
+    (idx, _) = parse_section_from_section!(parse_parenthesized_list(tokens, idx, |tokens, idx| {
+        return parse_keyword(tokens, idx, FORCE_KEYWORD);
+    }));
+    return ParseCommandSectionResult::Valid(idx, ());
+}
+
+fn parse_drop_object_type(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DropObjectType> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, start_idx, &[MATERIALIZED_KEYWORD, VIEW_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(idx_after, DropObjectType::MaterializedView);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, start_idx, &[EVENT_KEYWORD, TRIGGER_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(idx_after, DropObjectType::EventTrigger);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, start_idx, &[FOREIGN_KEYWORD, TABLE_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(idx_after, DropObjectType::ForeignTable);
+    }
+    let (idx_after, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[
+            AGGREGATE_KEYWORD,
+            COLLATION_KEYWORD,
+            DATABASE_KEYWORD,
+            DOMAIN_KEYWORD,
+            EXTENSION_KEYWORD,
+            FUNCTION_KEYWORD,
+            GROUP_KEYWORD,
+            INDEX_KEYWORD,
+            POLICY_KEYWORD,
+            PROCEDURE_KEYWORD,
+            PUBLICATION_KEYWORD,
+            ROLE_KEYWORD,
+            ROUTINE_KEYWORD,
+            RULE_KEYWORD,
+            SCHEMA_KEYWORD,
+            SEQUENCE_KEYWORD,
+            SUBSCRIPTION_KEYWORD,
+            TABLE_KEYWORD,
+            TABLESPACE_KEYWORD,
+            TRIGGER_KEYWORD,
+            TYPE_KEYWORD,
+            USER_KEYWORD,
+            VIEW_KEYWORD,
+        ]
+    ));
+    let object_type = match keyword.as_str() {
+        AGGREGATE_KEYWORD => DropObjectType::Aggregate,
+        COLLATION_KEYWORD => DropObjectType::Collation,
+        DATABASE_KEYWORD => DropObjectType::Database,
+        DOMAIN_KEYWORD => DropObjectType::Domain,
+        EXTENSION_KEYWORD => DropObjectType::Extension,
+        FUNCTION_KEYWORD => DropObjectType::Function,
+        GROUP_KEYWORD => DropObjectType::Group,
+        INDEX_KEYWORD => DropObjectType::Index,
+        POLICY_KEYWORD => DropObjectType::Policy,
+        PROCEDURE_KEYWORD => DropObjectType::Procedure,
+        PUBLICATION_KEYWORD => DropObjectType::Publication,
+        ROLE_KEYWORD => DropObjectType::Role,
+        ROUTINE_KEYWORD => DropObjectType::Routine,
+        RULE_KEYWORD => DropObjectType::Rule,
+        SCHEMA_KEYWORD => DropObjectType::Schema,
+        SEQUENCE_KEYWORD => DropObjectType::Sequence,
+        SUBSCRIPTION_KEYWORD => DropObjectType::Subscription,
+        TABLE_KEYWORD => DropObjectType::Table,
+        TABLESPACE_KEYWORD => DropObjectType::Tablespace,
+        TRIGGER_KEYWORD => DropObjectType::Trigger,
+        TYPE_KEYWORD => DropObjectType::Type,
+        USER_KEYWORD => DropObjectType::User,
+        _ => DropObjectType::View,
+    };
+    return ParseCommandSectionResult::Valid(idx_after, object_type);
+}
+
+fn drop_object(name: QualifiedName) -> DropObject {
+    return DropObject {
+        name: name,
+        parameters: None,
+        table_name: None,
+    };
+}
+
+// [schema.]name
+fn parse_qualified_object(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DropObject> {
+    let (idx_after, name) = parse_section_from_section!(parse_qualified_name(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, drop_object(name));
+}
+
+// name, for objects such as schemas and roles that do not belong to a schema
+fn parse_unqualified_object(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DropObject> {
+    let (idx_after, name) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        drop_object(QualifiedName {
+            schema_name: None,
+            name: name,
+        }),
+    );
+}
+
+// [schema.]name [([parameter, ...])]
+fn parse_routine_object(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DropObject> {
+    let (mut idx, mut object) =
+        parse_section_from_section!(parse_qualified_object(tokens, start_idx));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_signature, parameters) =
+        parse_function_signature(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_signature;
+        object.parameters = Some(parameters);
+    }
+    return ParseCommandSectionResult::Valid(idx, object);
+}
+
+// name ON [schema.]table
+fn parse_table_object(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DropObject> {
+    let (mut idx, mut object) =
+        parse_section_from_section!(parse_unqualified_object(tokens, start_idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, ON_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_table, table_name) =
+        parse_section_from_section!(parse_qualified_name(tokens, idx));
+    object.table_name = Some(table_name);
+    return ParseCommandSectionResult::Valid(idx_after_table, object);
+}
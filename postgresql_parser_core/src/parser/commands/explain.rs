@@ -0,0 +1,162 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    Command, DataDefinitionCommand, ExplainCommand, ExplainFormat, ExplainOption, ExplainSerialize,
+    UtilityCommand,
+};
+use crate::parser::commands::parse_section::{
+    parse_section, parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::boolean::parse_boolean_value;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_one_of_keywords, ANALYSE_KEYWORD, ANALYZE_KEYWORD, BINARY_KEYWORD,
+    BUFFERS_KEYWORD, COSTS_KEYWORD, FORMAT_KEYWORD, GENERIC_PLAN_KEYWORD, JSON_KEYWORD,
+    MEMORY_KEYWORD, NONE_KEYWORD, SERIALIZE_KEYWORD, SETTINGS_KEYWORD, SUMMARY_KEYWORD,
+    TEXT_KEYWORD, TIMING_KEYWORD, VERBOSE_KEYWORD, WAL_KEYWORD, XML_KEYWORD, YAML_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_parenthesized_list;
+use crate::parser::parse_command;
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Unlike other commands, the statement being explained ends with its own
+// semicolon
+pub fn parse_explain_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    let idx = idx_after_optional_whitespace(tokens, start_idx);
+    let (idx_after_options, options) = parse_section!(parse_explain_options, tokens, idx);
+    return match parse_command(tokens, idx_after_options) {
+        ParseCommandResult::Valid(statement, idx_after) if is_explainable(&statement) => {
+            ParseCommandResult::Valid(
+                Command::Utility(UtilityCommand::Explain(ExplainCommand {
+                    options: options,
+                    statement: Box::new(statement),
+                })),
+                idx_after,
+            )
+        }
+        ParseCommandResult::Valid(_, idx_after) => ParseCommandResult::Invalid(idx_after),
+        result => result,
+    };
+}
+
+// Only statements that have an execution plan can be explained
+fn is_explainable(statement: &Command) -> bool {
+    return matches!(
+        statement,
+        Command::DataManipulation(_)
+            | Command::DataDefinition(DataDefinitionCommand::CreateMaterializedView(_))
+    );
+}
+
+// (option, ...) or the legacy [ANALYZE] [VERBOSE], where no options is valid
+fn parse_explain_options(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<ExplainOption>> {
+    if let ParseCommandSectionResult::Valid(idx_after, options) =
+        parse_parenthesized_list(tokens, start_idx, parse_explain_option)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, options);
+    }
+
+    let mut idx = start_idx;
+    let mut options = Vec::new();
+    if let ParseCommandSectionResult::Valid(idx_after_analyze, _) =
+        parse_one_of_keywords(tokens, idx, &[ANALYZE_KEYWORD, ANALYSE_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_analyze);
+        options.push(ExplainOption::Analyze(true));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_verbose, _) =
+        parse_keyword(tokens, idx, VERBOSE_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_verbose);
+        options.push(ExplainOption::Verbose(true));
+    }
+    return ParseCommandSectionResult::Valid(idx, options);
+}
+
+fn parse_explain_option(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ExplainOption> {
+    if let ParseCommandSectionResult::Valid(idx_after_format, _) =
+        parse_keyword(tokens, start_idx, FORMAT_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_format);
+        let (idx_after, keyword) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[TEXT_KEYWORD, XML_KEYWORD, JSON_KEYWORD, YAML_KEYWORD]
+        ));
+        let format = match keyword.as_str() {
+            TEXT_KEYWORD => ExplainFormat::Text,
+            XML_KEYWORD => ExplainFormat::Xml,
+            JSON_KEYWORD => ExplainFormat::Json,
+            _ => ExplainFormat::Yaml,
+        };
+        return ParseCommandSectionResult::Valid(idx_after, ExplainOption::Format(format));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_serialize, _) =
+        parse_keyword(tokens, start_idx, SERIALIZE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_serialize);
+        if let ParseCommandSectionResult::Valid(idx_after, keyword) = parse_one_of_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[NONE_KEYWORD, TEXT_KEYWORD, BINARY_KEYWORD],
+        ) {
+            let serialize = match keyword.as_str() {
+                NONE_KEYWORD => ExplainSerialize::None,
+                TEXT_KEYWORD => ExplainSerialize::Text,
+                _ => ExplainSerialize::Binary,
+            };
+            return ParseCommandSectionResult::Valid(
+                idx_after,
+                ExplainOption::Serialize(serialize),
+            );
+        }
+        return ParseCommandSectionResult::Valid(
+            idx_after_serialize,
+            ExplainOption::Serialize(ExplainSerialize::Text),
+        );
+    }
+
+    let (mut idx, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[
+            ANALYZE_KEYWORD,
+            ANALYSE_KEYWORD,
+            VERBOSE_KEYWORD,
+            COSTS_KEYWORD,
+            SETTINGS_KEYWORD,
+            GENERIC_PLAN_KEYWORD,
+            BUFFERS_KEYWORD,
+            WAL_KEYWORD,
+            TIMING_KEYWORD,
+            SUMMARY_KEYWORD,
+            MEMORY_KEYWORD,
+        ]
+    ));
+    let mut value = true;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_value, boolean) =
+        parse_boolean_value(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_value;
+        value = boolean;
+    }
+    let option = match keyword.as_str() {
+        ANALYZE_KEYWORD | ANALYSE_KEYWORD => ExplainOption::Analyze(value),
+        VERBOSE_KEYWORD => ExplainOption::Verbose(value),
+        COSTS_KEYWORD => ExplainOption::Costs(value),
+        SETTINGS_KEYWORD => ExplainOption::Settings(value),
+        GENERIC_PLAN_KEYWORD => ExplainOption::GenericPlan(value),
+        BUFFERS_KEYWORD => ExplainOption::Buffers(value),
+        WAL_KEYWORD => ExplainOption::Wal(value),
+        TIMING_KEYWORD => ExplainOption::Timing(value),
+        SUMMARY_KEYWORD => ExplainOption::Summary(value),
+        _ => ExplainOption::Memory(value),
+    };
+    return ParseCommandSectionResult::Valid(idx, option);
+}
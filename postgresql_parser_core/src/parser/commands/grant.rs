@@ -0,0 +1,188 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    Command, DataControlCommand, GrantCommand, GrantRoleCommand, RoleGrantOption,
+    RoleGrantOptionKind,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, ADMIN_KEYWORD, FALSE_KEYWORD,
+    GRANT_KEYWORD, INHERIT_KEYWORD, ON_KEYWORD, OPTION_KEYWORD, SET_KEYWORD, TO_KEYWORD,
+    TRUE_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::commands::sections::privileges::{
+    parse_granted_by, parse_grantees, parse_privilege_target, parse_privileges,
+};
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_grant_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_grant, |grant_command| {
+        return Command::DataControl(grant_command);
+    });
+}
+
+// Parses everything after the GRANT keyword
+pub fn parse_grant(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DataControlCommand> {
+    let idx = idx_after_optional_whitespace(tokens, start_idx);
+    if let ParseCommandSectionResult::Valid(idx_after, grant_command) =
+        parse_grant_privileges(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataControlCommand::Grant(grant_command),
+        );
+    }
+    let (idx_after, grant_role_command) =
+        parse_section_from_section!(parse_grant_role(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        DataControlCommand::GrantRole(grant_role_command),
+    );
+}
+
+// privileges ON target TO grantee, ... [WITH GRANT OPTION] [GRANTED BY role]
+fn parse_grant_privileges(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<GrantCommand> {
+    let (mut idx, privileges) = parse_section_from_section!(parse_privileges(tokens, start_idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, ON_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let target;
+    (idx, target) = parse_section_from_section!(parse_privilege_target(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TO_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let grantees;
+    (idx, grantees) = parse_section_from_section!(parse_grantees(tokens, idx));
+
+    let mut with_grant_option = false;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_grant_option, _) = parse_keywords(
+        tokens,
+        idx_after_whitespace,
+        &[WITH_KEYWORD, GRANT_KEYWORD, OPTION_KEYWORD],
+    ) {
+        idx = idx_after_grant_option;
+        with_grant_option = true;
+    }
+
+    let mut granted_by = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_granted_by, role) =
+        parse_granted_by(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_granted_by;
+        granted_by = Some(role);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        GrantCommand {
+            privileges: privileges,
+            target: target,
+            grantees: grantees,
+            with_grant_option: with_grant_option,
+            granted_by: granted_by,
+        },
+    );
+}
+
+// role, ... TO role, ... [WITH option {OPTION | TRUE | FALSE}, ...]
+// [GRANTED BY role]
+fn parse_grant_role(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<GrantRoleCommand> {
+    let (mut idx, roles) = parse_section_from_section!(parse_comma_separated_list(
+        tokens,
+        start_idx,
+        parse_identifier
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TO_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let grantees;
+    (idx, grantees) = parse_section_from_section!(parse_grantees(tokens, idx));
+
+    let mut options = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_with, _) =
+        parse_keyword(tokens, idx_after_whitespace, WITH_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_with);
+        (idx, options) = parse_section_from_section!(parse_comma_separated_list(
+            tokens,
+            idx,
+            parse_role_grant_option
+        ));
+    }
+
+    let mut granted_by = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_granted_by, role) =
+        parse_granted_by(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_granted_by;
+        granted_by = Some(role);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        GrantRoleCommand {
+            roles: roles,
+            grantees: grantees,
+            options: options,
+            granted_by: granted_by,
+        },
+    );
+}
+
+// {ADMIN | INHERIT | SET} {OPTION | TRUE | FALSE}, where OPTION means TRUE
+fn parse_role_grant_option(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<RoleGrantOption> {
+    let (mut idx, kind) =
+        parse_section_from_section!(parse_role_grant_option_kind(tokens, start_idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let value;
+    (idx, value) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        idx,
+        &[OPTION_KEYWORD, TRUE_KEYWORD, FALSE_KEYWORD]
+    ));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        RoleGrantOption {
+            kind: kind,
+            value: value != FALSE_KEYWORD,
+        },
+    );
+}
+
+// ADMIN | INHERIT | SET
+pub fn parse_role_grant_option_kind(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<RoleGrantOptionKind> {
+    let (idx_after, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[ADMIN_KEYWORD, INHERIT_KEYWORD, SET_KEYWORD]
+    ));
+    let kind = match keyword.as_str() {
+        ADMIN_KEYWORD => RoleGrantOptionKind::Admin,
+        INHERIT_KEYWORD => RoleGrantOptionKind::Inherit,
+        _ => RoleGrantOptionKind::Set,
+    };
+    return ParseCommandSectionResult::Valid(idx_after, kind);
+}
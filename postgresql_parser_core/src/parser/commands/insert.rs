@@ -0,0 +1,263 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    Command, ConflictAction, ConflictTarget, DataManipulationCommand, Expression, InsertCommand,
+    InsertOverriding, InsertSource, OnConflictClause, OutputExpression,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::assignment::parse_assignments;
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, AS_KEYWORD, CONFLICT_KEYWORD,
+    CONSTRAINT_KEYWORD, DEFAULT_KEYWORD, DO_KEYWORD, INTO_KEYWORD, NOTHING_KEYWORD, ON_KEYWORD,
+    OVERRIDING_KEYWORD, SET_KEYWORD, SYSTEM_KEYWORD, UPDATE_KEYWORD, USER_KEYWORD, VALUES_KEYWORD,
+    VALUE_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_parenthesized_list;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::query::parse_query;
+use crate::parser::commands::sections::returning::parse_returning_clause;
+use crate::parser::commands::sections::where_clause::parse_where_clause;
+use crate::parser::commands::values::parse_values;
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_insert_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_insert, |insert_command| {
+        return Command::DataManipulation(DataManipulationCommand::Insert(insert_command));
+    });
+}
+
+// Parses everything after the INSERT keyword
+pub fn parse_insert(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<InsertCommand> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, INTO_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_table_name, table_name) =
+        parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_table_name;
+
+    let mut alias = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_as, _) =
+        parse_keyword(tokens, idx_after_whitespace, AS_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_as);
+        let (idx_after_alias, alias_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx_after_whitespace));
+        idx = idx_after_alias;
+        alias = Some(alias_name);
+    }
+
+    let mut column_names = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_columns, names) =
+        parse_parenthesized_list(tokens, idx_after_whitespace, parse_identifier)
+    {
+        idx = idx_after_columns;
+        column_names = names;
+    }
+
+    let mut overriding = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_overriding, parsed_overriding) =
+        parse_overriding(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_overriding;
+        overriding = Some(parsed_overriding);
+    }
+
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_source, source) = parse_section_from_section!(parse_insert_source(tokens, idx));
+    idx = idx_after_source;
+
+    let mut on_conflict = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_on_conflict, _) = parse_keywords(
+        tokens,
+        idx_after_whitespace,
+        &[ON_KEYWORD, CONFLICT_KEYWORD],
+    ) {
+        let (idx_after_clause, clause) =
+            parse_section_from_section!(parse_on_conflict_clause(tokens, idx_after_on_conflict));
+        idx = idx_after_clause;
+        on_conflict = Some(clause);
+    }
+
+    let mut returning: Vec<OutputExpression> = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_returning, returned_expressions) =
+        parse_returning_clause(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_returning;
+        returning = returned_expressions;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        InsertCommand {
+            table_name: table_name,
+            alias: alias,
+            column_names: column_names,
+            overriding: overriding,
+            source: source,
+            on_conflict: on_conflict,
+            returning: returning,
+        },
+    );
+}
+
+// OVERRIDING { SYSTEM | USER } VALUE
+pub fn parse_overriding(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<InsertOverriding> {
+    let mut idx = start_idx;
+    let kind: String;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, OVERRIDING_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, kind) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        idx,
+        &[SYSTEM_KEYWORD, USER_KEYWORD]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, VALUE_KEYWORD));
+    let overriding = match kind.as_str() {
+        SYSTEM_KEYWORD => InsertOverriding::SystemValue,
+        _ => InsertOverriding::UserValue,
+    };
+    return ParseCommandSectionResult::Valid(idx, overriding);
+}
+
+fn parse_insert_source(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<InsertSource> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, start_idx, &[DEFAULT_KEYWORD, VALUES_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(idx_after, InsertSource::DefaultValues);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_values, _) =
+        parse_keyword(tokens, start_idx, VALUES_KEYWORD)
+    {
+        let (idx_after, values_command) =
+            parse_section_from_section!(parse_values(tokens, idx_after_values));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            InsertSource::Values(values_command.rows),
+        );
+    }
+    let (idx_after, query) = parse_section_from_section!(parse_query(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, InsertSource::Query(query));
+}
+
+// Everything after ON CONFLICT
+fn parse_on_conflict_clause(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<OnConflictClause> {
+    let mut idx = start_idx;
+
+    let mut target = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_target, parsed_target) =
+        parse_conflict_target(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_target;
+        target = Some(parsed_target);
+    }
+
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_action, action) =
+        parse_section_from_section!(parse_conflict_action(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after_action,
+        OnConflictClause {
+            target: target,
+            action: action,
+        },
+    );
+}
+
+fn parse_conflict_target(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ConflictTarget> {
+    if let ParseCommandSectionResult::Valid(idx_after_on_constraint, _) =
+        parse_keywords(tokens, start_idx, &[ON_KEYWORD, CONSTRAINT_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_on_constraint);
+        let (idx_after, constraint_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            ConflictTarget::Constraint(constraint_name),
+        );
+    }
+
+    let (mut idx, expressions) = parse_section_from_section!(parse_parenthesized_list(
+        tokens,
+        start_idx,
+        parse_expression
+    ));
+    let mut index_predicate = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_where, predicate) =
+        parse_where_clause(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_where;
+        index_predicate = Some(predicate);
+    }
+    return ParseCommandSectionResult::Valid(
+        idx,
+        ConflictTarget::IndexExpressions {
+            expressions: expressions,
+            index_predicate: index_predicate,
+        },
+    );
+}
+
+// DO NOTHING | DO UPDATE SET ... [WHERE condition]
+fn parse_conflict_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ConflictAction> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, DO_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, idx, NOTHING_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, ConflictAction::DoNothing);
+    }
+
+    (idx, _) =
+        parse_section_from_section!(parse_keywords(tokens, idx, &[UPDATE_KEYWORD, SET_KEYWORD]));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_assignments, assignments) =
+        parse_section_from_section!(parse_assignments(tokens, idx));
+    idx = idx_after_assignments;
+
+    let mut where_clause: Option<Expression> = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_where, condition) =
+        parse_where_clause(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_where;
+        where_clause = Some(condition);
+    }
+    return ParseCommandSectionResult::Valid(
+        idx,
+        ConflictAction::DoUpdate {
+            assignments: assignments,
+            where_clause: where_clause,
+        },
+    );
+}
@@ -0,0 +1,283 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    Command, DataManipulationCommand, Expression, MergeAction, MergeCommand, MergeInsert,
+    MergeMatchKind, MergeSource, MergeWhenClause, OutputExpression,
+};
+use crate::parser::commands::insert::parse_overriding;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::alias::parse_table_alias;
+use crate::parser::commands::sections::assignment::parse_assignments;
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, AND_KEYWORD, BY_KEYWORD, DEFAULT_KEYWORD,
+    DELETE_KEYWORD, DO_KEYWORD, INSERT_KEYWORD, INTO_KEYWORD, MATCHED_KEYWORD, NOTHING_KEYWORD,
+    NOT_KEYWORD, ON_KEYWORD, SET_KEYWORD, SOURCE_KEYWORD, TARGET_KEYWORD, THEN_KEYWORD,
+    UPDATE_KEYWORD, USING_KEYWORD, VALUES_KEYWORD, WHEN_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_parenthesized_list;
+use crate::parser::commands::sections::query::parse_parenthesized_query;
+use crate::parser::commands::sections::returning::parse_returning_clause;
+use crate::parser::commands::sections::target_table::parse_target_table;
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_merge_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_merge, |merge_command| {
+        return Command::DataManipulation(DataManipulationCommand::Merge(merge_command));
+    });
+}
+
+// Parses everything after the MERGE keyword
+pub fn parse_merge(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<MergeCommand> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, INTO_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_target, target) = parse_section_from_section!(parse_target_table(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_target);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, USING_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_source, source) = parse_section_from_section!(parse_merge_source(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_source);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, ON_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_condition, join_condition) =
+        parse_section_from_section!(parse_expression(tokens, idx));
+    idx = idx_after_condition;
+
+    let mut when_clauses: Vec<MergeWhenClause> = Vec::new();
+    loop {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        match parse_keyword(tokens, idx_after_whitespace, WHEN_KEYWORD) {
+            ParseCommandSectionResult::Valid(idx_after_when, _) => {
+                let (idx_after_clause, when_clause) =
+                    parse_section_from_section!(parse_when_clause(tokens, idx_after_when));
+                idx = idx_after_clause;
+                when_clauses.push(when_clause);
+            }
+            ParseCommandSectionResult::Invalid | ParseCommandSectionResult::EndOfInput => break,
+        }
+    }
+    if when_clauses.is_empty() {
+        return ParseCommandSectionResult::Invalid;
+    }
+
+    let mut returning: Vec<OutputExpression> = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_returning, returned_expressions) =
+        parse_returning_clause(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_returning;
+        returning = returned_expressions;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        MergeCommand {
+            target: target,
+            source: source,
+            join_condition: join_condition,
+            when_clauses: when_clauses,
+            returning: returning,
+        },
+    );
+}
+
+fn parse_merge_source(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<MergeSource> {
+    if let ParseCommandSectionResult::Valid(idx_after_query, query) =
+        parse_parenthesized_query(tokens, start_idx)
+    {
+        let mut idx = idx_after_query;
+        let mut alias = None;
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_alias, parsed_alias) =
+            parse_table_alias(tokens, idx_after_whitespace)
+        {
+            idx = idx_after_alias;
+            alias = Some(parsed_alias);
+        }
+        return ParseCommandSectionResult::Valid(
+            idx,
+            MergeSource::Query {
+                query: query,
+                alias: alias,
+            },
+        );
+    }
+    let (idx_after, table) = parse_section_from_section!(parse_target_table(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, MergeSource::Table(table));
+}
+
+// Everything after WHEN
+fn parse_when_clause(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<MergeWhenClause> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    let (idx_after_match_kind, match_kind) =
+        parse_section_from_section!(parse_match_kind(tokens, idx));
+    idx = idx_after_match_kind;
+
+    let mut condition: Option<Expression> = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_and, _) =
+        parse_keyword(tokens, idx_after_whitespace, AND_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_and);
+        let (idx_after_condition, parsed_condition) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        idx = idx_after_condition;
+        condition = Some(parsed_condition);
+    }
+
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, THEN_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_action, action) = parse_section_from_section!(parse_merge_action(tokens, idx));
+
+    // Rows only in the source can only be inserted, and rows in the target
+    // can only be updated or deleted
+    let action_is_allowed = match (&match_kind, &action) {
+        (_, MergeAction::DoNothing) => true,
+        (MergeMatchKind::NotMatchedByTarget, MergeAction::Insert(_)) => true,
+        (MergeMatchKind::NotMatchedByTarget, _) | (_, MergeAction::Insert(_)) => false,
+        _ => true,
+    };
+    if !action_is_allowed {
+        return ParseCommandSectionResult::Invalid;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx_after_action,
+        MergeWhenClause {
+            match_kind: match_kind,
+            condition: condition,
+            action: action,
+        },
+    );
+}
+
+// MATCHED | NOT MATCHED [BY SOURCE | BY TARGET]
+fn parse_match_kind(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<MergeMatchKind> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, MATCHED_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, MergeMatchKind::Matched);
+    }
+    let (idx_after_not_matched, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        start_idx,
+        &[NOT_KEYWORD, MATCHED_KEYWORD]
+    ));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_not_matched);
+    if let ParseCommandSectionResult::Valid(idx_after_by, _) =
+        parse_keyword(tokens, idx_after_whitespace, BY_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_by);
+        let (idx_after, side) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[SOURCE_KEYWORD, TARGET_KEYWORD]
+        ));
+        let match_kind = match side.as_str() {
+            SOURCE_KEYWORD => MergeMatchKind::NotMatchedBySource,
+            _ => MergeMatchKind::NotMatchedByTarget,
+        };
+        return ParseCommandSectionResult::Valid(idx_after, match_kind);
+    }
+    return ParseCommandSectionResult::Valid(
+        idx_after_not_matched,
+        MergeMatchKind::NotMatchedByTarget,
+    );
+}
+
+fn parse_merge_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<MergeAction> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, start_idx, &[DO_KEYWORD, NOTHING_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(idx_after, MergeAction::DoNothing);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, DELETE_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, MergeAction::Delete);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_update_set, _) =
+        parse_keywords(tokens, start_idx, &[UPDATE_KEYWORD, SET_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_update_set);
+        let (idx_after, assignments) =
+            parse_section_from_section!(parse_assignments(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, MergeAction::Update(assignments));
+    }
+    let (idx_after_insert, _) =
+        parse_section_from_section!(parse_keyword(tokens, start_idx, INSERT_KEYWORD));
+    let (idx_after, merge_insert) =
+        parse_section_from_section!(parse_merge_insert(tokens, idx_after_insert));
+    return ParseCommandSectionResult::Valid(idx_after, MergeAction::Insert(merge_insert));
+}
+
+// Everything after INSERT: [(columns)] [OVERRIDING ...] {VALUES (...) | DEFAULT VALUES}
+fn parse_merge_insert(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<MergeInsert> {
+    let mut idx = start_idx;
+
+    let mut column_names = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_columns, names) =
+        parse_parenthesized_list(tokens, idx_after_whitespace, parse_identifier)
+    {
+        idx = idx_after_columns;
+        column_names = names;
+    }
+
+    let mut overriding = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_overriding, parsed_overriding) =
+        parse_overriding(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_overriding;
+        overriding = Some(parsed_overriding);
+    }
+
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let values: Option<Vec<Expression>>;
+    if let ParseCommandSectionResult::Valid(idx_after_default_values, _) =
+        parse_keywords(tokens, idx, &[DEFAULT_KEYWORD, VALUES_KEYWORD])
+    {
+        idx = idx_after_default_values;
+        values = None;
+    } else {
+        (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, VALUES_KEYWORD));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        let (idx_after_values, row) =
+            parse_section_from_section!(parse_parenthesized_list(tokens, idx, parse_expression));
+        idx = idx_after_values;
+        values = Some(row);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        MergeInsert {
+            column_names: column_names,
+            overriding: overriding,
+            values: values,
+        },
+    );
+}
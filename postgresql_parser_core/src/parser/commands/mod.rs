@@ -1,4 +1,45 @@
+pub mod alter;
+pub mod alter_database;
+pub mod alter_default_privileges;
+pub mod alter_extension;
+pub mod alter_policy;
+pub mod alter_role;
+pub mod alter_sequence;
+pub mod alter_table;
+pub mod alter_tablespace;
+pub mod alter_type;
+pub mod copy;
+pub mod create;
+pub mod create_database;
+pub mod create_extension;
+pub mod create_function;
+pub mod create_index;
+pub mod create_policy;
+pub mod create_role;
+pub mod create_schema;
+pub mod create_sequence;
+pub mod create_table;
+pub mod create_tablespace;
+pub mod create_trigger;
+pub mod create_type;
+pub mod create_view;
+pub mod delete;
+pub mod do_block;
+pub mod drop;
 pub mod empty;
+pub mod explain;
+pub mod grant;
+pub mod insert;
+pub mod merge;
+pub mod parenthesized_query;
 pub mod parse_section;
+pub mod refresh;
+pub mod reset;
+pub mod revoke;
 pub mod sections;
 pub mod select;
+pub mod set;
+pub mod show;
+pub mod transaction;
+pub mod update;
+pub mod values;
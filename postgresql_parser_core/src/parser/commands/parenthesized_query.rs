@@ -0,0 +1,14 @@
+use crate::lexer::token::Token;
+use crate::parser::commands::sections::query::{parse_query, query_into_command};
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::parse_terminated_command;
+
+// A query whose first operand is parenthesized, e.g.
+// (SELECT a FROM t) UNION (SELECT b FROM u)
+pub fn parse_parenthesized_query_command(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandResult {
+    // The opening parenthesis that selected this parser belongs to the query
+    return parse_terminated_command(tokens, start_idx - 1, parse_query, query_into_command);
+}
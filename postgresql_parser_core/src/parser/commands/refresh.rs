@@ -0,0 +1,63 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Command, DataDefinitionCommand, RefreshMaterializedViewCommand};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, CONCURRENTLY_KEYWORD, MATERIALIZED_KEYWORD, VIEW_KEYWORD,
+};
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::with_data::parse_with_data;
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_refresh_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_refresh, |refresh_command| {
+        return Command::DataDefinition(DataDefinitionCommand::RefreshMaterializedView(
+            refresh_command,
+        ));
+    });
+}
+
+// Parses everything after the REFRESH keyword
+fn parse_refresh(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<RefreshMaterializedViewCommand> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    (idx, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        idx,
+        &[MATERIALIZED_KEYWORD, VIEW_KEYWORD]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut concurrently = false;
+    if let ParseCommandSectionResult::Valid(idx_after_concurrently, _) =
+        parse_keyword(tokens, idx, CONCURRENTLY_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_concurrently);
+        concurrently = true;
+    }
+
+    let name;
+    (idx, name) = parse_section_from_section!(parse_qualified_name(tokens, idx));
+
+    let mut with_data = true;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_with_data, populate) =
+        parse_with_data(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_with_data;
+        with_data = populate;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        RefreshMaterializedViewCommand {
+            concurrently: concurrently,
+            name: name,
+            with_data: with_data,
+        },
+    );
+}
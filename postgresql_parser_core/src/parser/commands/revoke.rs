@@ -0,0 +1,164 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Command, DataControlCommand, RevokeCommand, RevokeRoleCommand};
+use crate::parser::commands::grant::parse_role_grant_option_kind;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::drop_behavior::parse_drop_behavior;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, FOR_KEYWORD, FROM_KEYWORD, GRANT_KEYWORD, ON_KEYWORD,
+    OPTION_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::commands::sections::privileges::{
+    parse_granted_by, parse_grantees, parse_privilege_target, parse_privileges,
+};
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_revoke_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_revoke, |revoke_command| {
+        return Command::DataControl(revoke_command);
+    });
+}
+
+// Parses everything after the REVOKE keyword
+fn parse_revoke(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DataControlCommand> {
+    let idx = idx_after_optional_whitespace(tokens, start_idx);
+    if let ParseCommandSectionResult::Valid(idx_after, revoke_command) =
+        parse_revoke_privileges(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            DataControlCommand::Revoke(revoke_command),
+        );
+    }
+    let (idx_after, revoke_role_command) =
+        parse_section_from_section!(parse_revoke_role(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        DataControlCommand::RevokeRole(revoke_role_command),
+    );
+}
+
+// [GRANT OPTION FOR] privileges ON target FROM grantee, ... [GRANTED BY role]
+// [CASCADE | RESTRICT]
+fn parse_revoke_privileges(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<RevokeCommand> {
+    let mut idx = start_idx;
+    let mut grant_option_for = false;
+    if let ParseCommandSectionResult::Valid(idx_after_grant_option_for, _) =
+        parse_keywords(tokens, idx, &[GRANT_KEYWORD, OPTION_KEYWORD, FOR_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_grant_option_for);
+        grant_option_for = true;
+    }
+
+    let privileges;
+    (idx, privileges) = parse_section_from_section!(parse_privileges(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, ON_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let target;
+    (idx, target) = parse_section_from_section!(parse_privilege_target(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, FROM_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let grantees;
+    (idx, grantees) = parse_section_from_section!(parse_grantees(tokens, idx));
+
+    let mut granted_by = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_granted_by, role) =
+        parse_granted_by(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_granted_by;
+        granted_by = Some(role);
+    }
+
+    let mut behavior = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_behavior, drop_behavior) =
+        parse_drop_behavior(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_behavior;
+        behavior = Some(drop_behavior);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        RevokeCommand {
+            grant_option_for: grant_option_for,
+            privileges: privileges,
+            target: target,
+            grantees: grantees,
+            granted_by: granted_by,
+            behavior: behavior,
+        },
+    );
+}
+
+// [{ADMIN | INHERIT | SET} OPTION FOR] role, ... FROM role, ...
+// [GRANTED BY role] [CASCADE | RESTRICT]
+fn parse_revoke_role(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<RevokeRoleCommand> {
+    let mut idx = start_idx;
+    let mut option_for = None;
+    if let ParseCommandSectionResult::Valid(idx_after_kind, kind) =
+        parse_role_grant_option_kind(tokens, idx)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_kind);
+        if let ParseCommandSectionResult::Valid(idx_after_option_for, _) =
+            parse_keywords(tokens, idx_after_whitespace, &[OPTION_KEYWORD, FOR_KEYWORD])
+        {
+            idx = idx_after_optional_whitespace(tokens, idx_after_option_for);
+            option_for = Some(kind);
+        }
+    }
+
+    let roles;
+    (idx, roles) =
+        parse_section_from_section!(parse_comma_separated_list(tokens, idx, parse_identifier));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, FROM_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let grantees;
+    (idx, grantees) = parse_section_from_section!(parse_grantees(tokens, idx));
+
+    let mut granted_by = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_granted_by, role) =
+        parse_granted_by(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_granted_by;
+        granted_by = Some(role);
+    }
+
+    let mut behavior = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_behavior, drop_behavior) =
+        parse_drop_behavior(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_behavior;
+        behavior = Some(drop_behavior);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        RevokeRoleCommand {
+            option_for: option_for,
+            roles: roles,
+            grantees: grantees,
+            granted_by: granted_by,
+            behavior: behavior,
+        },
+    );
+}
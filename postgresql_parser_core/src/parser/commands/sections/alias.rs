@@ -0,0 +1,119 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Identifier, TableAlias};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, AS_KEYWORD, RESERVED_KEYWORDS, SET_KEYWORD, TYPE_FUNCTION_NAME_KEYWORDS,
+};
+use crate::parser::commands::sections::list::parse_parenthesized_list;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// [AS] alias. Without AS, the alias may not be a reserved keyword, so that
+// e.g. the WHERE in "DELETE FROM t WHERE ..." is not mistaken for an alias.
+pub fn parse_alias(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Identifier> {
+    if let ParseCommandSectionResult::Valid(idx_after_as, _) =
+        parse_keyword(tokens, start_idx, AS_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_as);
+        return parse_identifier(tokens, idx_after_whitespace);
+    }
+    return match parse_identifier(tokens, start_idx) {
+        ParseCommandSectionResult::Valid(idx_after, alias) => {
+            if !alias.quoted && token_value_is_alias_keyword(&alias.value) {
+                ParseCommandSectionResult::Invalid
+            } else {
+                ParseCommandSectionResult::Valid(idx_after, alias)
+            }
+        }
+        result => result,
+    };
+}
+
+// [AS] alias [(column_alias, ...)]
+pub fn parse_table_alias(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TableAlias> {
+    let (mut idx, name) = parse_section_from_section!(parse_alias(tokens, start_idx));
+    let mut column_names = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_columns, names) =
+        parse_parenthesized_list(tokens, idx_after_whitespace, parse_identifier)
+    {
+        idx = idx_after_columns;
+        column_names = names;
+    }
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TableAlias {
+            name: name,
+            column_names: column_names,
+        },
+    );
+}
+
+fn token_value_is_alias_keyword(token_value: &String) -> bool {
+    let lowered = token_value.to_ascii_lowercase();
+    // SET is not reserved, but is never taken as an alias in UPDATE t SET ...
+    return lowered == SET_KEYWORD
+        || RESERVED_KEYWORDS.contains(&lowered.as_str())
+        || TYPE_FUNCTION_NAME_KEYWORDS.contains(&lowered.as_str());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::tokens_from_str_vector;
+
+    #[test]
+    fn alias_with_as() {
+        assert_eq!(
+            parse_alias(&tokens_from_str_vector(vec!["AS", " ", "t"]), 0),
+            ParseCommandSectionResult::Valid(
+                3,
+                Identifier {
+                    quoted: false,
+                    value: String::from("t")
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn alias_without_as() {
+        assert_eq!(
+            parse_alias(&tokens_from_str_vector(vec!["t", " "]), 0),
+            ParseCommandSectionResult::Valid(
+                1,
+                Identifier {
+                    quoted: false,
+                    value: String::from("t")
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn alias_reserved_keyword() {
+        assert_eq!(
+            parse_alias(&tokens_from_str_vector(vec!["Where"]), 0),
+            ParseCommandSectionResult::Invalid
+        );
+    }
+
+    #[test]
+    fn alias_quoted_reserved_keyword() {
+        assert_eq!(
+            parse_alias(&tokens_from_str_vector(vec!["\"where\""]), 0),
+            ParseCommandSectionResult::Valid(
+                1,
+                Identifier {
+                    quoted: true,
+                    value: String::from("where")
+                }
+            )
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Assignment, Identifier};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::list::{
+    parse_comma_separated_list, parse_parenthesized_list,
+};
+use crate::parser::commands::sections::operator::parse_operator;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// The assignments of an UPDATE ... SET or ON CONFLICT DO UPDATE SET
+pub fn parse_assignments(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<Assignment>> {
+    return parse_comma_separated_list(tokens, start_idx, parse_assignment);
+}
+
+fn parse_assignment(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Assignment> {
+    let mut idx = start_idx;
+    let column_names: Vec<Identifier>;
+    let value;
+    (idx, column_names) = match parse_identifier(tokens, idx) {
+        ParseCommandSectionResult::Valid(idx_after, column_name) => (idx_after, vec![column_name]),
+        ParseCommandSectionResult::Invalid => {
+            parse_section_from_section!(parse_parenthesized_list(tokens, idx, parse_identifier))
+        }
+        ParseCommandSectionResult::EndOfInput => return ParseCommandSectionResult::EndOfInput,
+    };
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_operator(tokens, idx, "="));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, value) = parse_section_from_section!(parse_expression(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        Assignment {
+            column_names: column_names,
+            value: value,
+        },
+    );
+}
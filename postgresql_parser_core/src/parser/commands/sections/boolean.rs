@@ -0,0 +1,26 @@
+use crate::lexer::token::Token;
+use crate::parser::commands::parse_section::ParseCommandSectionResult;
+use crate::parser::commands::sections::keywords::{
+    parse_one_of_keywords, FALSE_KEYWORD, OFF_KEYWORD, ON_KEYWORD, TRUE_KEYWORD,
+};
+
+// TRUE | FALSE | ON | OFF | 1 | 0, as the value of a boolean option
+pub fn parse_boolean_value(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<bool> {
+    if let ParseCommandSectionResult::Valid(idx_after, keyword) = parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[TRUE_KEYWORD, FALSE_KEYWORD, ON_KEYWORD, OFF_KEYWORD],
+    ) {
+        let value = keyword == TRUE_KEYWORD || keyword == ON_KEYWORD;
+        return ParseCommandSectionResult::Valid(idx_after, value);
+    }
+    return match tokens.get(start_idx) {
+        Some(token) if token.value == "1" => ParseCommandSectionResult::Valid(start_idx + 1, true),
+        Some(token) if token.value == "0" => ParseCommandSectionResult::Valid(start_idx + 1, false),
+        Some(_) => ParseCommandSectionResult::Invalid,
+        None => ParseCommandSectionResult::EndOfInput,
+    };
+}
@@ -0,0 +1,281 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    ColumnConstraint, ColumnConstraintKind, ColumnDefinition, QualifiedName, SequenceOption,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, ALWAYS_KEYWORD, AS_KEYWORD, BY_KEYWORD, COLLATE_KEYWORD,
+    DEFAULT_KEYWORD, GENERATED_KEYWORD, IDENTITY_KEYWORD, KEY_KEYWORD, NOT_KEYWORD, NULL_KEYWORD,
+    PRIMARY_KEYWORD, STORED_KEYWORD,
+};
+use crate::parser::commands::sections::parenthesis::{
+    parse_close_parenthesis, parse_open_parenthesis,
+};
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::sequence_options::parse_sequence_options;
+use crate::parser::commands::sections::table_constraint::{
+    parse_check, parse_constraint_characteristics, parse_constraint_name,
+    parse_foreign_key_reference, parse_unique,
+};
+use crate::parser::commands::sections::type_name::parse_type_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// name type [COLLATE collation] [column_constraint ...]
+pub fn parse_column_definition(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ColumnDefinition> {
+    let (mut idx, name) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_type, type_name) = parse_section_from_section!(parse_type_name(tokens, idx));
+    idx = idx_after_type;
+    let (idx_after_constraints, (collation, constraints)) =
+        parse_section_from_section!(parse_column_constraints(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after_constraints,
+        ColumnDefinition {
+            name: name,
+            type_name: Some(type_name),
+            collation: collation,
+            constraints: constraints,
+        },
+    );
+}
+
+// name [column_constraint ...], for the columns of a PARTITION OF table,
+// which take their types from the parent table
+pub fn parse_untyped_column_definition(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ColumnDefinition> {
+    let (idx, name) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+    let (idx_after_constraints, (collation, constraints)) =
+        parse_section_from_section!(parse_column_constraints(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after_constraints,
+        ColumnDefinition {
+            name: name,
+            type_name: None,
+            collation: collation,
+            constraints: constraints,
+        },
+    );
+}
+
+// Parses the constraints after a column's type, along with a COLLATE clause
+// which may be mixed in with them. Leading whitespace is consumed only if a
+// constraint follows.
+fn parse_column_constraints(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<(Option<QualifiedName>, Vec<ColumnConstraint>)> {
+    let mut idx = start_idx;
+    let mut collation = None;
+    let mut constraints = Vec::new();
+    loop {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_collate, _) =
+            parse_keyword(tokens, idx_after_whitespace, COLLATE_KEYWORD)
+        {
+            if collation.is_some() {
+                return ParseCommandSectionResult::Invalid;
+            }
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_collate);
+            let (idx_after_collation, collation_name) =
+                parse_section_from_section!(parse_qualified_name(tokens, idx_after_whitespace));
+            idx = idx_after_collation;
+            collation = Some(collation_name);
+            continue;
+        }
+        match parse_column_constraint(tokens, idx_after_whitespace) {
+            ParseCommandSectionResult::Valid(idx_after_constraint, constraint) => {
+                idx = idx_after_constraint;
+                constraints.push(constraint);
+            }
+            ParseCommandSectionResult::Invalid | ParseCommandSectionResult::EndOfInput => break,
+        }
+    }
+    return ParseCommandSectionResult::Valid(idx, (collation, constraints));
+}
+
+// [CONSTRAINT name] constraint [[NOT] DEFERRABLE] [INITIALLY {DEFERRED | IMMEDIATE}]
+pub fn parse_column_constraint(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ColumnConstraint> {
+    let mut idx = start_idx;
+
+    let mut name = None;
+    if let ParseCommandSectionResult::Valid(idx_after_name, constraint_name) =
+        parse_constraint_name(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_name);
+        name = Some(constraint_name);
+    }
+
+    let (idx_after_kind, kind) =
+        parse_section_from_section!(parse_column_constraint_kind(tokens, idx));
+    idx = idx_after_kind;
+
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_characteristics, (deferrable, initially_deferred)) = parse_section_from_section!(
+        parse_constraint_characteristics(tokens, idx_after_whitespace)
+    );
+    if idx_after_characteristics != idx_after_whitespace {
+        idx = idx_after_characteristics;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        ColumnConstraint {
+            name: name,
+            kind: kind,
+            deferrable: deferrable,
+            initially_deferred: initially_deferred,
+        },
+    );
+}
+
+fn parse_column_constraint_kind(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ColumnConstraintKind> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, start_idx, &[NOT_KEYWORD, NULL_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(idx_after, ColumnConstraintKind::NotNull);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, NULL_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, ColumnConstraintKind::Null);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_default, _) =
+        parse_keyword(tokens, start_idx, DEFAULT_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_default);
+        let (idx_after, expression) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            ColumnConstraintKind::Default(expression),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, (expression, no_inherit)) =
+        parse_check(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            ColumnConstraintKind::Check {
+                expression: expression,
+                no_inherit: no_inherit,
+            },
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, nulls_not_distinct) =
+        parse_unique(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            ColumnConstraintKind::Unique {
+                nulls_not_distinct: nulls_not_distinct,
+            },
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, start_idx, &[PRIMARY_KEYWORD, KEY_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(idx_after, ColumnConstraintKind::PrimaryKey);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, reference) =
+        parse_foreign_key_reference(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            ColumnConstraintKind::References(reference),
+        );
+    }
+    return parse_generated_column_constraint(tokens, start_idx);
+}
+
+// GENERATED {ALWAYS | BY DEFAULT} AS IDENTITY [(sequence_option ...)]
+// or GENERATED ALWAYS AS (expression) STORED
+pub fn parse_generated_column_constraint(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ColumnConstraintKind> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, GENERATED_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let always = match parse_keyword(tokens, idx, ALWAYS_KEYWORD) {
+        ParseCommandSectionResult::Valid(idx_after_always, _) => {
+            idx = idx_after_always;
+            true
+        }
+        _ => {
+            (idx, _) = parse_section_from_section!(parse_keywords(
+                tokens,
+                idx,
+                &[BY_KEYWORD, DEFAULT_KEYWORD]
+            ));
+            false
+        }
+    };
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, AS_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    if let ParseCommandSectionResult::Valid(idx_after_identity, _) =
+        parse_keyword(tokens, idx, IDENTITY_KEYWORD)
+    {
+        idx = idx_after_identity;
+        let mut sequence_options = Vec::new();
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_options, options) =
+            parse_identity_sequence_options(tokens, idx_after_whitespace)
+        {
+            idx = idx_after_options;
+            sequence_options = options;
+        }
+        return ParseCommandSectionResult::Valid(
+            idx,
+            ColumnConstraintKind::Identity {
+                always: always,
+                sequence_options: sequence_options,
+            },
+        );
+    }
+
+    // Only GENERATED ALWAYS is allowed for generated columns
+    if !always {
+        return ParseCommandSectionResult::Invalid;
+    }
+    (idx, _) = parse_section_from_section!(parse_open_parenthesis(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_expression, expression) =
+        parse_section_from_section!(parse_expression(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_expression);
+    (idx, _) = parse_section_from_section!(parse_close_parenthesis(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, STORED_KEYWORD));
+    return ParseCommandSectionResult::Valid(idx, ColumnConstraintKind::Generated(expression));
+}
+
+// (sequence_option ...)
+fn parse_identity_sequence_options(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<SequenceOption>> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_open_parenthesis(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_options, options) =
+        parse_section_from_section!(parse_sequence_options(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_options);
+    (idx, _) = parse_section_from_section!(parse_close_parenthesis(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx, options);
+}
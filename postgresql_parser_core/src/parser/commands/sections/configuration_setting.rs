@@ -0,0 +1,108 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{ConfigurationParameter, ConfigurationSetting, ConfigurationValue};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, ALL_KEYWORD, AUTHORIZATION_KEYWORD, CURRENT_KEYWORD,
+    DEFAULT_KEYWORD, FROM_KEYWORD, ISOLATION_KEYWORD, LEVEL_KEYWORD, SESSION_KEYWORD, TIME_KEYWORD,
+    TO_KEYWORD, TRANSACTION_KEYWORD, ZONE_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::commands::sections::operator::parse_operator;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// name {TO | =} {value, ... | DEFAULT}, or name FROM CURRENT, as in the SET
+// option of a function
+pub fn parse_configuration_setting(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ConfigurationSetting> {
+    let (mut idx, name) = parse_section_from_section!(parse_qualified_name(tokens, start_idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, idx, &[FROM_KEYWORD, CURRENT_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            ConfigurationSetting {
+                name: name,
+                value: ConfigurationValue::FromCurrent,
+            },
+        );
+    }
+
+    idx = match parse_keyword(tokens, idx, TO_KEYWORD) {
+        ParseCommandSectionResult::Valid(idx_after_to, _) => idx_after_to,
+        _ => {
+            let (idx_after_equals, _) =
+                parse_section_from_section!(parse_operator(tokens, idx, "="));
+            idx_after_equals
+        }
+    };
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    // DEFAULT would otherwise be read as a DEFAULT expression
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, idx, DEFAULT_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            ConfigurationSetting {
+                name: name,
+                value: ConfigurationValue::Default,
+            },
+        );
+    }
+
+    let (idx_after_values, values) =
+        parse_section_from_section!(parse_comma_separated_list(tokens, idx, parse_expression));
+    return ParseCommandSectionResult::Valid(
+        idx_after_values,
+        ConfigurationSetting {
+            name: name,
+            value: ConfigurationValue::Values(values),
+        },
+    );
+}
+
+// {configuration_parameter | TIME ZONE | TRANSACTION ISOLATION LEVEL |
+// SESSION AUTHORIZATION | ALL}, as in RESET and SHOW
+pub fn parse_configuration_parameter(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ConfigurationParameter> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, start_idx, &[TIME_KEYWORD, ZONE_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(idx_after, ConfigurationParameter::TimeZone);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) = parse_keywords(
+        tokens,
+        start_idx,
+        &[TRANSACTION_KEYWORD, ISOLATION_KEYWORD, LEVEL_KEYWORD],
+    ) {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            ConfigurationParameter::TransactionIsolationLevel,
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, start_idx, &[SESSION_KEYWORD, AUTHORIZATION_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            ConfigurationParameter::SessionAuthorization,
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, ALL_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, ConfigurationParameter::All);
+    }
+    let (idx_after, name) = parse_section_from_section!(parse_qualified_name(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, ConfigurationParameter::Name(name));
+}
@@ -0,0 +1,25 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::DropBehavior;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::keywords::{
+    parse_one_of_keywords, CASCADE_KEYWORD, RESTRICT_KEYWORD,
+};
+
+// CASCADE | RESTRICT
+pub fn parse_drop_behavior(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DropBehavior> {
+    let (idx_after, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[CASCADE_KEYWORD, RESTRICT_KEYWORD]
+    ));
+    let behavior = match keyword.as_str() {
+        CASCADE_KEYWORD => DropBehavior::Cascade,
+        _ => DropBehavior::Restrict,
+    };
+    return ParseCommandSectionResult::Valid(idx_after, behavior);
+}
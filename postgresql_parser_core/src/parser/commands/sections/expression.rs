@@ -0,0 +1,894 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    AllColumnsSelectedExpression, Between, BinaryOperation, Case, CaseWhen, Cast,
+    ColumnSelectedExpression, Expression, FunctionCall, Identifier, Literal, QualifiedName,
+    UnaryOperation,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::dot_separated_value::{
+    parse_dot_separated_value, validate_separated_values_len,
+};
+use crate::parser::commands::sections::identifier::{
+    parse_identifiers_from_dot_separated_values, SimpleParseResult,
+};
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, AND_KEYWORD, ASYMMETRIC_KEYWORD,
+    BETWEEN_KEYWORD, CASE_KEYWORD, DEFAULT_KEYWORD, DISTINCT_KEYWORD, ELSE_KEYWORD, END_KEYWORD,
+    EXISTS_KEYWORD, FALSE_KEYWORD, FROM_KEYWORD, ILIKE_KEYWORD, IN_KEYWORD, IS_KEYWORD,
+    LIKE_KEYWORD, NOT_KEYWORD, NULL_KEYWORD, OR_KEYWORD, SYMMETRIC_KEYWORD, THEN_KEYWORD,
+    TRUE_KEYWORD, WHEN_KEYWORD,
+};
+use crate::parser::commands::sections::list::{
+    parse_optionally_empty_parenthesized_list, parse_parenthesized_list,
+};
+use crate::parser::commands::sections::operator::{parse_any_operator, parse_operator};
+use crate::parser::commands::sections::parameter::parse_parameter;
+use crate::parser::commands::sections::parenthesis::{
+    parse_close_parenthesis, parse_open_parenthesis,
+};
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::query::{parse_parenthesized_query, token_starts_query};
+use crate::parser::commands::sections::selected_expressions::parse_all_columns_selected_expression;
+use crate::parser::commands::sections::type_name::parse_type_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Binding strength of operators, loosest first, following the operator
+// precedence table in the PostgreSQL documentation
+const OR_PRECEDENCE: u8 = 1;
+const AND_PRECEDENCE: u8 = 2;
+const NOT_PRECEDENCE: u8 = 3;
+const IS_PRECEDENCE: u8 = 4;
+const COMPARISON_PRECEDENCE: u8 = 5;
+const PATTERN_PRECEDENCE: u8 = 6;
+const OTHER_OPERATOR_PRECEDENCE: u8 = 7;
+const ADDITIVE_PRECEDENCE: u8 = 8;
+const MULTIPLICATIVE_PRECEDENCE: u8 = 9;
+const EXPONENT_PRECEDENCE: u8 = 10;
+const UNARY_SIGN_PRECEDENCE: u8 = 11;
+
+pub fn parse_expression(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Expression> {
+    return parse_expression_with_precedence(tokens, start_idx, OR_PRECEDENCE);
+}
+
+// Parses an expression made up only of operators that bind at least as
+// tightly as min_precedence
+fn parse_expression_with_precedence(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    min_precedence: u8,
+) -> ParseCommandSectionResult<Expression> {
+    let (mut idx, mut expression) =
+        parse_section_from_section!(parse_operand_with_casts(tokens, start_idx));
+    loop {
+        let idx_before_operator = idx_after_optional_whitespace(tokens, idx);
+        let (idx_after_operator, operator, precedence) =
+            match parse_binary_operator(tokens, idx_before_operator) {
+                ParseCommandSectionResult::Valid(idx_after_operator, (operator, precedence))
+                    if precedence >= min_precedence =>
+                {
+                    (idx_after_operator, operator, precedence)
+                }
+                _ => break,
+            };
+        let idx_before_right = idx_after_optional_whitespace(tokens, idx_after_operator);
+        if operator == BETWEEN_KEYWORD || operator == "not between" {
+            (idx, expression) = parse_section_from_section!(parse_between(
+                tokens,
+                idx_before_right,
+                expression,
+                operator != BETWEEN_KEYWORD
+            ));
+            continue;
+        }
+        let right: Expression;
+        if operator == IN_KEYWORD || operator == "not in" {
+            (idx, right) = parse_section_from_section!(parse_in_list(tokens, idx_before_right));
+        } else {
+            (idx, right) = parse_section_from_section!(parse_expression_with_precedence(
+                tokens,
+                idx_before_right,
+                precedence + 1
+            ));
+        }
+        expression = Expression::BinaryOperation(BinaryOperation {
+            left: Box::new(expression),
+            operator: operator,
+            right: Box::new(right),
+        });
+    }
+    return ParseCommandSectionResult::Valid(idx, expression);
+}
+
+fn parse_binary_operator(
+    tokens: &Vec<Token>,
+    idx: usize,
+) -> ParseCommandSectionResult<(String, u8)> {
+    if let ParseCommandSectionResult::Valid(idx_after, operator) = parse_any_operator(tokens, idx) {
+        let precedence = match operator.as_str() {
+            "=" | "<" | ">" | "<=" | ">=" | "<>" | "!=" => COMPARISON_PRECEDENCE,
+            "+" | "-" => ADDITIVE_PRECEDENCE,
+            "*" | "/" | "%" => MULTIPLICATIVE_PRECEDENCE,
+            "^" => EXPONENT_PRECEDENCE,
+            _ => OTHER_OPERATOR_PRECEDENCE,
+        };
+        return ParseCommandSectionResult::Valid(idx_after, (operator, precedence));
+    }
+    let (idx_after_keyword, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        idx,
+        &[
+            OR_KEYWORD,
+            AND_KEYWORD,
+            IS_KEYWORD,
+            NOT_KEYWORD,
+            IN_KEYWORD,
+            LIKE_KEYWORD,
+            ILIKE_KEYWORD,
+            BETWEEN_KEYWORD
+        ]
+    ));
+    return match keyword.as_str() {
+        OR_KEYWORD => ParseCommandSectionResult::Valid(idx_after_keyword, (keyword, OR_PRECEDENCE)),
+        AND_KEYWORD => {
+            ParseCommandSectionResult::Valid(idx_after_keyword, (keyword, AND_PRECEDENCE))
+        }
+        IS_KEYWORD => parse_is_operator(tokens, idx_after_keyword),
+        NOT_KEYWORD => {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_keyword);
+            let (idx_after, negated_keyword) = parse_section_from_section!(parse_one_of_keywords(
+                tokens,
+                idx_after_whitespace,
+                &[IN_KEYWORD, LIKE_KEYWORD, ILIKE_KEYWORD, BETWEEN_KEYWORD]
+            ));
+            ParseCommandSectionResult::Valid(
+                idx_after,
+                (format!("not {}", negated_keyword), PATTERN_PRECEDENCE),
+            )
+        }
+        _ => ParseCommandSectionResult::Valid(idx_after_keyword, (keyword, PATTERN_PRECEDENCE)),
+    };
+}
+
+// IS [NOT] [DISTINCT FROM], with the IS keyword already consumed
+fn parse_is_operator(
+    tokens: &Vec<Token>,
+    idx_after_is: usize,
+) -> ParseCommandSectionResult<(String, u8)> {
+    let mut idx = idx_after_is;
+    let mut operator = String::from(IS_KEYWORD);
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_not, _) =
+        parse_keyword(tokens, idx_after_whitespace, NOT_KEYWORD)
+    {
+        idx = idx_after_not;
+        operator.push_str(" not");
+    }
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_distinct_from, _) = parse_keywords(
+        tokens,
+        idx_after_whitespace,
+        &[DISTINCT_KEYWORD, FROM_KEYWORD],
+    ) {
+        idx = idx_after_distinct_from;
+        operator.push_str(" distinct from");
+    }
+    return ParseCommandSectionResult::Valid(idx, (operator, IS_PRECEDENCE));
+}
+
+// The bounds of [NOT] BETWEEN [SYMMETRIC] low AND high, with everything up
+// to and including the BETWEEN keyword already consumed
+fn parse_between(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    expression: Expression,
+    negated: bool,
+) -> ParseCommandSectionResult<Expression> {
+    let mut idx = start_idx;
+    let mut symmetric = false;
+    if let ParseCommandSectionResult::Valid(idx_after_keyword, keyword) =
+        parse_one_of_keywords(tokens, idx, &[SYMMETRIC_KEYWORD, ASYMMETRIC_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_keyword);
+        symmetric = keyword == SYMMETRIC_KEYWORD;
+    }
+    // The bounds can't contain boolean or comparison operators, so the AND
+    // separating them is never mistaken for a conjunction
+    let low: Expression;
+    (idx, low) = parse_section_from_section!(parse_expression_with_precedence(
+        tokens,
+        idx,
+        OTHER_OPERATOR_PRECEDENCE
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, AND_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let high: Expression;
+    (idx, high) = parse_section_from_section!(parse_expression_with_precedence(
+        tokens,
+        idx,
+        OTHER_OPERATOR_PRECEDENCE
+    ));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        Expression::Between(Between {
+            expression: Box::new(expression),
+            negated: negated,
+            symmetric: symmetric,
+            low: Box::new(low),
+            high: Box::new(high),
+        }),
+    );
+}
+
+// The right hand side of IN: either a subquery or a list of expressions
+fn parse_in_list(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Expression> {
+    if let ParseCommandSectionResult::Valid(idx_after, query) =
+        parse_parenthesized_query(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, Expression::Subquery(Box::new(query)));
+    }
+    let (idx_after, expressions) = parse_section_from_section!(parse_parenthesized_list(
+        tokens,
+        start_idx,
+        parse_expression
+    ));
+    return ParseCommandSectionResult::Valid(idx_after, Expression::Row(expressions));
+}
+
+// An operand followed by any number of ::type_name casts, which bind more
+// tightly than any operator
+fn parse_operand_with_casts(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Expression> {
+    let (mut idx, mut expression) = parse_section_from_section!(parse_operand(tokens, start_idx));
+    loop {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        let idx_after_cast = match parse_operator(tokens, idx_after_whitespace, "::") {
+            ParseCommandSectionResult::Valid(idx_after_cast, _) => idx_after_cast,
+            _ => break,
+        };
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_cast);
+        let (idx_after_type, type_name) =
+            parse_section_from_section!(parse_type_name(tokens, idx_after_whitespace));
+        idx = idx_after_type;
+        expression = Expression::Cast(Cast {
+            expression: Box::new(expression),
+            type_name: type_name,
+        });
+    }
+    return ParseCommandSectionResult::Valid(idx, expression);
+}
+
+fn parse_operand(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Expression> {
+    let token = match tokens.get(start_idx) {
+        Some(token) => token,
+        None => return ParseCommandSectionResult::EndOfInput,
+    };
+    let lowered = token.value.to_ascii_lowercase();
+    let idx_after_token = start_idx + 1;
+
+    if token.value == "(" {
+        return parse_parenthesized_operand(tokens, start_idx);
+    } else if token.value == "-" || token.value == "+" {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_token);
+        let (idx_after, operand) = parse_section_from_section!(parse_expression_with_precedence(
+            tokens,
+            idx_after_whitespace,
+            UNARY_SIGN_PRECEDENCE
+        ));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            Expression::UnaryOperation(UnaryOperation {
+                operator: token.value.clone(),
+                operand: Box::new(operand),
+            }),
+        );
+    } else if lowered == NOT_KEYWORD {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_token);
+        let (idx_after, operand) = parse_section_from_section!(parse_expression_with_precedence(
+            tokens,
+            idx_after_whitespace,
+            NOT_PRECEDENCE
+        ));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            Expression::UnaryOperation(UnaryOperation {
+                operator: lowered,
+                operand: Box::new(operand),
+            }),
+        );
+    } else if lowered == EXISTS_KEYWORD {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_token);
+        if let ParseCommandSectionResult::Valid(idx_after, query) =
+            parse_parenthesized_query(tokens, idx_after_whitespace)
+        {
+            return ParseCommandSectionResult::Valid(
+                idx_after,
+                Expression::Exists(Box::new(query)),
+            );
+        }
+    } else if lowered == CASE_KEYWORD {
+        return parse_case(tokens, start_idx);
+    } else if lowered == NULL_KEYWORD {
+        return ParseCommandSectionResult::Valid(
+            idx_after_token,
+            Expression::Literal(Literal::Null),
+        );
+    } else if lowered == TRUE_KEYWORD || lowered == FALSE_KEYWORD {
+        return ParseCommandSectionResult::Valid(
+            idx_after_token,
+            Expression::Literal(Literal::Boolean(lowered == TRUE_KEYWORD)),
+        );
+    } else if lowered == DEFAULT_KEYWORD {
+        return ParseCommandSectionResult::Valid(idx_after_token, Expression::Default);
+    } else if let SimpleParseResult::Valid(literal) = parse_literal_token_value(&token.value) {
+        return ParseCommandSectionResult::Valid(idx_after_token, Expression::Literal(literal));
+    } else if let ParseCommandSectionResult::Valid(idx_after, parameter) =
+        parse_parameter(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, Expression::Parameter(parameter));
+    } else if let ParseCommandSectionResult::Valid(idx_after, all_columns) =
+        parse_all_columns_selected_expression(tokens, start_idx)
+    {
+        // A bare * is only an operand as a function argument
+        if all_columns.table_name.is_some() {
+            return ParseCommandSectionResult::Valid(
+                idx_after,
+                Expression::AllColumns(all_columns),
+            );
+        }
+    }
+    return parse_column_or_function_call(tokens, start_idx);
+}
+
+// CASE [operand] WHEN condition THEN result [...] [ELSE result] END
+fn parse_case(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Expression> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, CASE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut operand: Option<Box<Expression>> = None;
+    if let ParseCommandSectionResult::Invalid = parse_keyword(tokens, idx, WHEN_KEYWORD) {
+        let value: Expression;
+        (idx, value) = parse_section_from_section!(parse_expression(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        operand = Some(Box::new(value));
+    }
+
+    let mut when_clauses: Vec<CaseWhen> = Vec::new();
+    while let ParseCommandSectionResult::Valid(idx_after_when, _) =
+        parse_keyword(tokens, idx, WHEN_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_when);
+        let condition: Expression;
+        (idx, condition) = parse_section_from_section!(parse_expression(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, THEN_KEYWORD));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        let result: Expression;
+        (idx, result) = parse_section_from_section!(parse_expression(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        when_clauses.push(CaseWhen {
+            condition: condition,
+            result: result,
+        });
+    }
+    if when_clauses.is_empty() {
+        return ParseCommandSectionResult::Invalid;
+    }
+
+    let mut else_result: Option<Box<Expression>> = None;
+    if let ParseCommandSectionResult::Valid(idx_after_else, _) =
+        parse_keyword(tokens, idx, ELSE_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_else);
+        let result: Expression;
+        (idx, result) = parse_section_from_section!(parse_expression(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        else_result = Some(Box::new(result));
+    }
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, END_KEYWORD));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        Expression::Case(Case {
+            operand: operand,
+            when_clauses: when_clauses,
+            else_result: else_result,
+        }),
+    );
+}
+
+// A subquery, a row constructor such as (1, 2), or just a parenthesized
+// expression, which is returned without the parentheses
+fn parse_parenthesized_operand(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Expression> {
+    let idx_after_parenthesis = idx_after_optional_whitespace(tokens, start_idx + 1);
+    if token_starts_query(tokens.get(idx_after_parenthesis)) {
+        let (idx_after, query) =
+            parse_section_from_section!(parse_parenthesized_query(tokens, start_idx));
+        return ParseCommandSectionResult::Valid(idx_after, Expression::Subquery(Box::new(query)));
+    }
+    let (idx_after, mut expressions) = parse_section_from_section!(parse_parenthesized_list(
+        tokens,
+        start_idx,
+        parse_expression
+    ));
+    if expressions.len() == 1 {
+        return ParseCommandSectionResult::Valid(idx_after, expressions.pop().unwrap());
+    }
+    return ParseCommandSectionResult::Valid(idx_after, Expression::Row(expressions));
+}
+
+fn parse_literal_token_value(token_value: &String) -> SimpleParseResult<Literal> {
+    if token_value.starts_with('\'') {
+        if token_value.len() < 2 || !token_value.ends_with('\'') {
+            return SimpleParseResult::Invalid;
+        }
+        let contents = &token_value[1..token_value.len() - 1];
+        return SimpleParseResult::Valid(Literal::String(contents.replace("''", "'")));
+    }
+    if let Some(contents) = dollar_quoted_contents(token_value) {
+        return SimpleParseResult::Valid(Literal::String(String::from(contents)));
+    }
+    if token_value.starts_with(|c: char| c.is_ascii_digit())
+        && token_value.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && token_value.matches('.').count() <= 1
+    {
+        return SimpleParseResult::Valid(Literal::Number(token_value.clone()));
+    }
+    return SimpleParseResult::Invalid;
+}
+
+// The contents of a $tag$...$tag$ string, which are taken literally
+fn dollar_quoted_contents(token_value: &str) -> Option<&str> {
+    let tag_len = token_value.get(1..)?.find('$')? + 2;
+    let tag = &token_value[..tag_len];
+    if token_value.len() < 2 * tag_len || !token_value.ends_with(tag) {
+        return None;
+    }
+    return Some(&token_value[tag_len..token_value.len() - tag_len]);
+}
+
+// A string constant, either quoted or dollar quoted, e.g. a function body
+pub fn parse_string_literal(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<String> {
+    return match tokens.get(start_idx) {
+        Some(token) => match parse_literal_token_value(&token.value) {
+            SimpleParseResult::Valid(Literal::String(value)) => {
+                ParseCommandSectionResult::Valid(start_idx + 1, value)
+            }
+            _ => ParseCommandSectionResult::Invalid,
+        },
+        None => ParseCommandSectionResult::EndOfInput,
+    };
+}
+
+// (expression), where the parentheses are required, e.g. the WHEN condition
+// of a trigger or the USING clause of a policy
+pub fn parse_parenthesized_expression(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Expression> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_open_parenthesis(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let expression;
+    (idx, expression) = parse_section_from_section!(parse_expression(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_close_parenthesis(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx, expression);
+}
+
+// An argument of a function call, which can also be a bare * as in count(*)
+fn parse_function_argument(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Expression> {
+    if let Some(token) = tokens.get(start_idx) {
+        if token.value == "*" {
+            return ParseCommandSectionResult::Valid(
+                start_idx + 1,
+                Expression::AllColumns(AllColumnsSelectedExpression {
+                    schema_name: None,
+                    table_name: None,
+                }),
+            );
+        }
+    }
+    return parse_expression(tokens, start_idx);
+}
+
+// function_name ([argument, ...]), e.g. for a function call in FROM
+pub fn parse_function_call(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<FunctionCall> {
+    let (idx_after_name, function_name) =
+        parse_section_from_section!(parse_qualified_name(tokens, start_idx));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_name);
+    let (idx_after_arguments, arguments) =
+        parse_section_from_section!(parse_optionally_empty_parenthesized_list(
+            tokens,
+            idx_after_whitespace,
+            parse_function_argument
+        ));
+    return ParseCommandSectionResult::Valid(
+        idx_after_arguments,
+        FunctionCall {
+            function_name: function_name,
+            arguments: arguments,
+        },
+    );
+}
+
+fn parse_column_or_function_call(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Expression> {
+    let (idx_after_name, separated_values) =
+        parse_section_from_section!(parse_dot_separated_value(tokens, start_idx, 2));
+    validate_separated_values_len(&separated_values, 3);
+
+    let mut identifiers = match parse_identifiers_from_dot_separated_values(separated_values) {
+        SimpleParseResult::Valid(identifiers) => identifiers,
+        SimpleParseResult::Invalid => return ParseCommandSectionResult::Invalid,
+    };
+
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_name);
+    if let ParseCommandSectionResult::Valid(_, _) =
+        parse_open_parenthesis(tokens, idx_after_whitespace)
+    {
+        if identifiers.len() > 2 {
+            return ParseCommandSectionResult::Invalid;
+        }
+        let (idx_after_arguments, arguments) =
+            parse_section_from_section!(parse_optionally_empty_parenthesized_list(
+                tokens,
+                idx_after_whitespace,
+                parse_function_argument
+            ));
+        let function_name = identifiers.pop().unwrap();
+        return ParseCommandSectionResult::Valid(
+            idx_after_arguments,
+            Expression::FunctionCall(FunctionCall {
+                function_name: QualifiedName {
+                    schema_name: identifiers.pop(),
+                    name: function_name,
+                },
+                arguments: arguments,
+            }),
+        );
+    }
+
+    let column_name = identifiers.pop().unwrap();
+    let table_name: Option<Identifier> = identifiers.pop();
+    let schema_name: Option<Identifier> = identifiers.pop();
+    return ParseCommandSectionResult::Valid(
+        idx_after_name,
+        Expression::Column(ColumnSelectedExpression {
+            schema_name: schema_name,
+            table_name: table_name,
+            column_name: column_name,
+        }),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tokenize_postgresql;
+    use crate::parser::ast::TypeName;
+
+    fn column(name: &str) -> Expression {
+        return Expression::Column(ColumnSelectedExpression {
+            schema_name: None,
+            table_name: None,
+            column_name: Identifier {
+                quoted: false,
+                value: String::from(name),
+            },
+        });
+    }
+
+    fn number(value: &str) -> Expression {
+        return Expression::Literal(Literal::Number(String::from(value)));
+    }
+
+    fn binary(left: Expression, operator: &str, right: Expression) -> Expression {
+        return Expression::BinaryOperation(BinaryOperation {
+            left: Box::new(left),
+            operator: String::from(operator),
+            right: Box::new(right),
+        });
+    }
+
+    fn parse(text: &str) -> ParseCommandSectionResult<Expression> {
+        return parse_expression(&tokenize_postgresql(text), 0);
+    }
+
+    #[test]
+    fn expression_precedence() {
+        assert_eq!(
+            parse("a + 2 * 3 = 7 or not b and c"),
+            ParseCommandSectionResult::Valid(
+                23,
+                binary(
+                    binary(
+                        binary(column("a"), "+", binary(number("2"), "*", number("3"))),
+                        "=",
+                        number("7")
+                    ),
+                    "or",
+                    binary(
+                        Expression::UnaryOperation(UnaryOperation {
+                            operator: String::from("not"),
+                            operand: Box::new(column("b"))
+                        }),
+                        "and",
+                        column("c")
+                    )
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn expression_left_associative() {
+        assert_eq!(
+            parse("a - b - c"),
+            ParseCommandSectionResult::Valid(
+                9,
+                binary(binary(column("a"), "-", column("b")), "-", column("c"))
+            )
+        );
+    }
+
+    #[test]
+    fn expression_parentheses() {
+        assert_eq!(
+            parse("(a - b) * -c"),
+            ParseCommandSectionResult::Valid(
+                12,
+                binary(
+                    binary(column("a"), "-", column("b")),
+                    "*",
+                    Expression::UnaryOperation(UnaryOperation {
+                        operator: String::from("-"),
+                        operand: Box::new(column("c"))
+                    })
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn expression_is_not_distinct_from() {
+        assert_eq!(
+            parse("a IS NOT DISTINCT FROM b IS NULL"),
+            ParseCommandSectionResult::Valid(
+                15,
+                binary(
+                    binary(column("a"), "is not distinct from", column("b")),
+                    "is",
+                    Expression::Literal(Literal::Null)
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn expression_between_binds_tighter_than_and() {
+        assert_eq!(
+            parse("a NOT BETWEEN SYMMETRIC 1 AND b + 1 AND c"),
+            ParseCommandSectionResult::Valid(
+                21,
+                binary(
+                    Expression::Between(Between {
+                        expression: Box::new(column("a")),
+                        negated: true,
+                        symmetric: true,
+                        low: Box::new(number("1")),
+                        high: Box::new(binary(column("b"), "+", number("1"))),
+                    }),
+                    "and",
+                    column("c")
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn expression_case() {
+        assert_eq!(
+            parse("CASE a WHEN 1 THEN 2 WHEN 3 THEN 4 ELSE 5 END"),
+            ParseCommandSectionResult::Valid(
+                25,
+                Expression::Case(Case {
+                    operand: Some(Box::new(column("a"))),
+                    when_clauses: vec![
+                        CaseWhen {
+                            condition: number("1"),
+                            result: number("2"),
+                        },
+                        CaseWhen {
+                            condition: number("3"),
+                            result: number("4"),
+                        },
+                    ],
+                    else_result: Some(Box::new(number("5"))),
+                })
+            )
+        );
+        assert_eq!(
+            parse("CASE WHEN a > 1 THEN a END"),
+            ParseCommandSectionResult::Valid(
+                15,
+                Expression::Case(Case {
+                    operand: None,
+                    when_clauses: vec![CaseWhen {
+                        condition: binary(column("a"), ">", number("1")),
+                        result: column("a"),
+                    }],
+                    else_result: None,
+                })
+            )
+        );
+    }
+
+    #[test]
+    fn expression_invalid_case_without_when() {
+        assert_eq!(
+            parse("CASE a ELSE 1 END"),
+            ParseCommandSectionResult::Invalid
+        );
+    }
+
+    #[test]
+    fn expression_all_columns() {
+        assert_eq!(
+            parse("old.* IS DISTINCT FROM new.*"),
+            ParseCommandSectionResult::Valid(
+                13,
+                binary(
+                    Expression::AllColumns(AllColumnsSelectedExpression {
+                        schema_name: None,
+                        table_name: Some(Identifier {
+                            quoted: false,
+                            value: String::from("old")
+                        }),
+                    }),
+                    "is distinct from",
+                    Expression::AllColumns(AllColumnsSelectedExpression {
+                        schema_name: None,
+                        table_name: Some(Identifier {
+                            quoted: false,
+                            value: String::from("new")
+                        }),
+                    })
+                )
+            )
+        );
+        assert_eq!(parse("*"), ParseCommandSectionResult::Invalid);
+    }
+
+    #[test]
+    fn expression_not_in_list() {
+        assert_eq!(
+            parse("a not in (1, 2)"),
+            ParseCommandSectionResult::Valid(
+                12,
+                binary(
+                    column("a"),
+                    "not in",
+                    Expression::Row(vec![number("1"), number("2")])
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn expression_function_call() {
+        assert_eq!(
+            parse("pg_catalog.lower(name, 'it''s')"),
+            ParseCommandSectionResult::Valid(
+                9,
+                Expression::FunctionCall(FunctionCall {
+                    function_name: QualifiedName {
+                        schema_name: Some(Identifier {
+                            quoted: false,
+                            value: String::from("pg_catalog")
+                        }),
+                        name: Identifier {
+                            quoted: false,
+                            value: String::from("lower")
+                        }
+                    },
+                    arguments: vec![
+                        column("name"),
+                        Expression::Literal(Literal::String(String::from("it's")))
+                    ]
+                })
+            )
+        );
+    }
+
+    #[test]
+    fn expression_function_call_with_star_argument() {
+        assert_eq!(
+            parse("count(*)"),
+            ParseCommandSectionResult::Valid(
+                4,
+                Expression::FunctionCall(FunctionCall {
+                    function_name: QualifiedName {
+                        schema_name: None,
+                        name: Identifier {
+                            quoted: false,
+                            value: String::from("count")
+                        }
+                    },
+                    arguments: vec![Expression::AllColumns(AllColumnsSelectedExpression {
+                        schema_name: None,
+                        table_name: None,
+                    })]
+                })
+            )
+        );
+    }
+
+    #[test]
+    fn expression_dollar_quoted_string() {
+        assert_eq!(
+            parse("$fn$it's $$ quoted$fn$"),
+            ParseCommandSectionResult::Valid(
+                1,
+                Expression::Literal(Literal::String(String::from("it's $$ quoted")))
+            )
+        );
+    }
+
+    #[test]
+    fn expression_does_not_consume_trailing_keyword() {
+        assert_eq!(
+            parse("a from t"),
+            ParseCommandSectionResult::Valid(1, column("a"))
+        );
+    }
+
+    #[test]
+    fn expression_invalid_dangling_operator() {
+        assert_eq!(parse("a +"), ParseCommandSectionResult::EndOfInput);
+        assert_eq!(parse("a + )"), ParseCommandSectionResult::Invalid);
+    }
+
+    #[test]
+    fn expression_cast_binds_tighter_than_operators() {
+        let cast = Expression::Cast(Cast {
+            expression: Box::new(column("b")),
+            type_name: TypeName {
+                name: QualifiedName {
+                    schema_name: None,
+                    name: Identifier {
+                        quoted: false,
+                        value: String::from("int"),
+                    },
+                },
+                modifiers: vec![],
+                array_dimensions: 0,
+            },
+        });
+        assert_eq!(
+            parse("a + b :: int"),
+            ParseCommandSectionResult::Valid(9, binary(column("a"), "+", cast))
+        );
+    }
+}
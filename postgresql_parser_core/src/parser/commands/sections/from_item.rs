@@ -1,39 +1,326 @@
 use crate::lexer::token::Token;
-use crate::parser::ast::FromItem;
-use crate::parser::ast::Identifier;
+use crate::parser::ast::{
+    FromFunction, FromItem, FunctionColumnDefinition, FunctionFromItem, Identifier,
+    RowsFromFunction, SubqueryFromItem, TableAlias, TableFromItem, TableSample,
+};
 use crate::parser::commands::parse_section::{
     parse_section_from_section, ParseCommandSectionResult,
 };
-use crate::parser::commands::sections::dot_separated_value::{
-    parse_dot_separated_value, validate_separated_values_len,
-};
-use crate::parser::commands::sections::identifier::{
-    parse_identifiers_from_dot_separated_values, SimpleParseResult,
+use crate::parser::commands::sections::alias::{parse_alias, parse_table_alias};
+use crate::parser::commands::sections::expression::{parse_expression, parse_function_call};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, AS_KEYWORD, FROM_KEYWORD, LATERAL_KEYWORD, ONLY_KEYWORD,
+    ORDINALITY_KEYWORD, REPEATABLE_KEYWORD, ROWS_KEYWORD, TABLESAMPLE_KEYWORD, WITH_KEYWORD,
 };
+use crate::parser::commands::sections::list::parse_parenthesized_list;
+use crate::parser::commands::sections::operator::parse_operator;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::query::parse_parenthesized_query;
+use crate::parser::commands::sections::type_name::parse_type_name;
+use crate::parser::utils::idx_after_optional_whitespace;
 
 pub fn parse_from_item(
     tokens: &Vec<Token>,
     start_idx: usize,
 ) -> ParseCommandSectionResult<FromItem> {
-    let (idx_after, separated_values) =
-        parse_section_from_section!(parse_dot_separated_value(tokens, start_idx, 1));
-    validate_separated_values_len(&separated_values, 2);
+    let mut idx = start_idx;
+
+    let mut lateral = false;
+    if let ParseCommandSectionResult::Valid(idx_after_lateral, _) =
+        parse_keyword(tokens, idx, LATERAL_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_lateral);
+        lateral = true;
+    }
+
+    if let ParseCommandSectionResult::Valid(idx_after, subquery) =
+        parse_subquery_from_item(tokens, idx, lateral)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, FromItem::Subquery(subquery));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, function) =
+        parse_function_from_item(tokens, idx, lateral)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, FromItem::Function(function));
+    }
+    // LATERAL only applies to subqueries and functions
+    if lateral {
+        return ParseCommandSectionResult::Invalid;
+    }
+    let (idx_after, table) = parse_section_from_section!(parse_table_from_item(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx_after, FromItem::Table(table));
+}
+
+// [ONLY] table_name [*] [alias] [TABLESAMPLE method (argument, ...) [REPEATABLE (seed)]]
+fn parse_table_from_item(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TableFromItem> {
+    let mut idx = start_idx;
+
+    let mut only = false;
+    if let ParseCommandSectionResult::Valid(idx_after_only, _) =
+        parse_keyword(tokens, idx, ONLY_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_only);
+        only = true;
+    }
+
+    let (idx_after_table_name, table_name) =
+        parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_table_name;
+
+    // table_name * explicitly includes inheriting tables, which is the default
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_star, _) =
+        parse_operator(tokens, idx_after_whitespace, "*")
+    {
+        if only {
+            return ParseCommandSectionResult::Invalid;
+        }
+        idx = idx_after_star;
+    }
+
+    let mut alias = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_alias, table_alias) =
+        parse_table_alias(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_alias;
+        alias = Some(table_alias);
+    }
+
+    let mut tablesample = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_tablesample, _) =
+        parse_keyword(tokens, idx_after_whitespace, TABLESAMPLE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_tablesample);
+        let (idx_after_sample, sample) =
+            parse_section_from_section!(parse_table_sample(tokens, idx_after_whitespace));
+        idx = idx_after_sample;
+        tablesample = Some(sample);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TableFromItem {
+            only: only,
+            schema_name: table_name.schema_name,
+            table_name: table_name.name,
+            alias: alias,
+            tablesample: tablesample,
+        },
+    );
+}
+
+// Everything after TABLESAMPLE
+fn parse_table_sample(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TableSample> {
+    let (mut idx, sampling_function) =
+        parse_section_from_section!(parse_function_call(tokens, start_idx));
+    if sampling_function.arguments.is_empty() {
+        return ParseCommandSectionResult::Invalid;
+    }
+
+    let mut repeatable_seed = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_repeatable, _) =
+        parse_keyword(tokens, idx_after_whitespace, REPEATABLE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_repeatable);
+        let (idx_after_seed, mut seed) = parse_section_from_section!(parse_parenthesized_list(
+            tokens,
+            idx_after_whitespace,
+            parse_expression
+        ));
+        if seed.len() != 1 {
+            return ParseCommandSectionResult::Invalid;
+        }
+        idx = idx_after_seed;
+        repeatable_seed = seed.pop();
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TableSample {
+            method: sampling_function.function_name,
+            arguments: sampling_function.arguments,
+            repeatable_seed: repeatable_seed,
+        },
+    );
+}
 
-    let identifiers = match parse_identifiers_from_dot_separated_values(separated_values) {
-        SimpleParseResult::Valid(identifiers) => identifiers,
-        SimpleParseResult::Invalid => return ParseCommandSectionResult::Invalid,
-    };
+// (query) [alias]. The LATERAL keyword has already been parsed.
+fn parse_subquery_from_item(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    lateral: bool,
+) -> ParseCommandSectionResult<SubqueryFromItem> {
+    let (mut idx, query) =
+        parse_section_from_section!(parse_parenthesized_query(tokens, start_idx));
 
-    let mut schema_name: Option<Identifier> = None;
-    if identifiers.len() == 2 {
-        schema_name = Some(identifiers.get(0).unwrap().clone());
+    let mut alias = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_alias, table_alias) =
+        parse_table_alias(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_alias;
+        alias = Some(table_alias);
     }
-    let table_name = identifiers.last().unwrap().clone();
+
     return ParseCommandSectionResult::Valid(
-        idx_after,
-        FromItem {
-            schema_name: schema_name,
-            table_name: table_name,
+        idx,
+        SubqueryFromItem {
+            lateral: lateral,
+            query: Box::new(query),
+            alias: alias,
         },
     );
 }
+
+// {function_call | ROWS FROM (...)} [WITH ORDINALITY] [alias]. The LATERAL
+// keyword has already been parsed.
+fn parse_function_from_item(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    lateral: bool,
+) -> ParseCommandSectionResult<FunctionFromItem> {
+    let mut idx = start_idx;
+    let function: FromFunction;
+    if let ParseCommandSectionResult::Valid(idx_after_rows_from, _) =
+        parse_keywords(tokens, idx, &[ROWS_KEYWORD, FROM_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_rows_from);
+        let (idx_after_functions, functions) = parse_section_from_section!(
+            parse_parenthesized_list(tokens, idx_after_whitespace, parse_rows_from_function)
+        );
+        idx = idx_after_functions;
+        function = FromFunction::RowsFrom(functions);
+    } else {
+        let (idx_after_call, function_call) =
+            parse_section_from_section!(parse_function_call(tokens, idx));
+        idx = idx_after_call;
+        function = FromFunction::Call(function_call);
+    }
+
+    let mut with_ordinality = false;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_ordinality, _) = parse_keywords(
+        tokens,
+        idx_after_whitespace,
+        &[WITH_KEYWORD, ORDINALITY_KEYWORD],
+    ) {
+        idx = idx_after_ordinality;
+        with_ordinality = true;
+    }
+
+    let mut alias = None;
+    let mut column_definitions = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_alias, (parsed_alias, definitions)) =
+        parse_function_alias(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_alias;
+        alias = parsed_alias;
+        column_definitions = definitions;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        FunctionFromItem {
+            lateral: lateral,
+            function: function,
+            with_ordinality: with_ordinality,
+            alias: alias,
+            column_definitions: column_definitions,
+        },
+    );
+}
+
+// function_call [AS (column_definition, ...)], inside ROWS FROM (...)
+fn parse_rows_from_function(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<RowsFromFunction> {
+    let (mut idx, function_call) =
+        parse_section_from_section!(parse_function_call(tokens, start_idx));
+
+    let mut column_definitions = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_as, _) =
+        parse_keyword(tokens, idx_after_whitespace, AS_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_as);
+        let (idx_after_definitions, definitions) =
+            parse_section_from_section!(parse_column_definitions(tokens, idx_after_whitespace));
+        idx = idx_after_definitions;
+        column_definitions = definitions;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        RowsFromFunction {
+            function_call: function_call,
+            column_definitions: column_definitions,
+        },
+    );
+}
+
+// The alias of a function, which is one of
+//   [AS] alias [(column_alias, ...)]
+//   [AS] alias (column_definition, ...)
+//   AS (column_definition, ...)
+fn parse_function_alias(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<(Option<TableAlias>, Vec<FunctionColumnDefinition>)> {
+    if let ParseCommandSectionResult::Valid(idx_after_as, _) =
+        parse_keyword(tokens, start_idx, AS_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_as);
+        if let ParseCommandSectionResult::Valid(idx_after, definitions) =
+            parse_column_definitions(tokens, idx_after_whitespace)
+        {
+            return ParseCommandSectionResult::Valid(idx_after, (None, definitions));
+        }
+    }
+
+    let (idx_after_alias, name) = parse_section_from_section!(parse_alias(tokens, start_idx));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_alias);
+    if let ParseCommandSectionResult::Valid(idx_after, definitions) =
+        parse_column_definitions(tokens, idx_after_whitespace)
+    {
+        let alias = TableAlias {
+            name: name,
+            column_names: Vec::new(),
+        };
+        return ParseCommandSectionResult::Valid(idx_after, (Some(alias), definitions));
+    }
+    let (idx_after, alias) = parse_section_from_section!(parse_table_alias(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, (Some(alias), Vec::new()));
+}
+
+// (column_name data_type, ...)
+pub fn parse_column_definitions(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<FunctionColumnDefinition>> {
+    return parse_parenthesized_list(tokens, start_idx, |tokens, idx| {
+        let (idx_after_name, name): (usize, Identifier) =
+            parse_section_from_section!(parse_identifier(tokens, idx));
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_name);
+        let (idx_after_type, type_name) =
+            parse_section_from_section!(parse_type_name(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after_type,
+            FunctionColumnDefinition {
+                name: name,
+                type_name: type_name,
+            },
+        );
+    });
+}
@@ -0,0 +1,176 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{FunctionParameter, FunctionParameterMode};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_one_of_keywords, DEFAULT_KEYWORD, INOUT_KEYWORD, IN_KEYWORD, OUT_KEYWORD,
+    VARIADIC_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_optionally_empty_parenthesized_list;
+use crate::parser::commands::sections::operator::parse_operator;
+use crate::parser::commands::sections::type_name::parse_type_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// ([parameter, ...]), the parameters of a function, as in
+// DROP FUNCTION f(int, text)
+pub fn parse_function_signature(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<FunctionParameter>> {
+    return parse_optionally_empty_parenthesized_list(tokens, start_idx, parse_function_parameter);
+}
+
+// [IN | OUT | INOUT | VARIADIC] [name] type [{DEFAULT | =} expression]
+pub fn parse_function_parameter(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<FunctionParameter> {
+    let (mut idx, mut parameter) =
+        parse_section_from_section!(parse_function_parameter_without_default(tokens, start_idx));
+
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    let idx_after_default = match parse_keyword(tokens, idx_after_whitespace, DEFAULT_KEYWORD) {
+        ParseCommandSectionResult::Valid(idx_after, _) => Some(idx_after),
+        _ => match parse_operator(tokens, idx_after_whitespace, "=") {
+            ParseCommandSectionResult::Valid(idx_after, _) => Some(idx_after),
+            _ => None,
+        },
+    };
+    if let Some(idx_after_default) = idx_after_default {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_default);
+        let (idx_after_expression, default) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        idx = idx_after_expression;
+        parameter.default = Some(default);
+    }
+
+    return ParseCommandSectionResult::Valid(idx, parameter);
+}
+
+fn parse_function_parameter_without_default(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<FunctionParameter> {
+    let mut idx = start_idx;
+    let mut mode = None;
+    if let ParseCommandSectionResult::Valid(idx_after_mode, keyword) = parse_one_of_keywords(
+        tokens,
+        idx,
+        &[IN_KEYWORD, OUT_KEYWORD, INOUT_KEYWORD, VARIADIC_KEYWORD],
+    ) {
+        idx = idx_after_optional_whitespace(tokens, idx_after_mode);
+        mode = match keyword.as_str() {
+            IN_KEYWORD => Some(FunctionParameterMode::In),
+            OUT_KEYWORD => Some(FunctionParameterMode::Out),
+            INOUT_KEYWORD => Some(FunctionParameterMode::InOut),
+            _ => Some(FunctionParameterMode::Variadic),
+        };
+    }
+
+    // Both the name and the type may be a single word, so the parameter is
+    // only named when reading a name first gets further than reading a type,
+    // as with a int but not with double precision
+    let (idx_after_type, type_name) = parse_section_from_section!(parse_type_name(tokens, idx));
+    if let ParseCommandSectionResult::Valid(idx_after_name, name) = parse_identifier(tokens, idx) {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_name);
+        let followed_by_default = matches!(
+            parse_keyword(tokens, idx_after_whitespace, DEFAULT_KEYWORD),
+            ParseCommandSectionResult::Valid(_, _)
+        ) || matches!(
+            parse_operator(tokens, idx_after_whitespace, "="),
+            ParseCommandSectionResult::Valid(_, _)
+        );
+        if let ParseCommandSectionResult::Valid(idx_after_named_type, named_type) =
+            parse_type_name(tokens, idx_after_whitespace)
+        {
+            if idx_after_named_type > idx_after_type && !followed_by_default {
+                return ParseCommandSectionResult::Valid(
+                    idx_after_named_type,
+                    FunctionParameter {
+                        mode: mode,
+                        name: Some(name),
+                        type_name: named_type,
+                        default: None,
+                    },
+                );
+            }
+        }
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx_after_type,
+        FunctionParameter {
+            mode: mode,
+            name: None,
+            type_name: type_name,
+            default: None,
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tokenize_postgresql;
+    use crate::parser::ast::{Expression, Literal};
+
+    fn parameter_names_and_types(input: &str) -> Vec<(Option<String>, String)> {
+        let tokens = tokenize_postgresql(input);
+        return match parse_function_signature(&tokens, 0) {
+            ParseCommandSectionResult::Valid(idx, parameters) => {
+                assert_eq!(idx, tokens.len());
+                parameters
+                    .into_iter()
+                    .map(|parameter| {
+                        return (
+                            parameter.name.map(|name| name.value),
+                            parameter.type_name.name.name.value,
+                        );
+                    })
+                    .collect()
+            }
+            result => panic!("Expected a valid signature, got {:?}", result),
+        };
+    }
+
+    #[test]
+    fn unnamed_and_named_parameters() {
+        assert_eq!(
+            parameter_names_and_types("(int, a text, double precision, b double precision)"),
+            vec![
+                (None, String::from("int")),
+                (Some(String::from("a")), String::from("text")),
+                (None, String::from("double precision")),
+                (Some(String::from("b")), String::from("double precision")),
+            ]
+        );
+    }
+
+    #[test]
+    fn parameter_defaults() {
+        let tokens = tokenize_postgresql("(a int DEFAULT 1, text = 'x', b text)");
+        let defaults: Vec<Option<Expression>> = match parse_function_signature(&tokens, 0) {
+            ParseCommandSectionResult::Valid(_, parameters) => parameters
+                .into_iter()
+                .map(|parameter| parameter.default)
+                .collect(),
+            result => panic!("Expected a valid signature, got {:?}", result),
+        };
+        assert_eq!(
+            defaults,
+            vec![
+                Some(Expression::Literal(Literal::Number(String::from("1")))),
+                Some(Expression::Literal(Literal::String(String::from("x")))),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_signature() {
+        assert_eq!(parameter_names_and_types("( )"), vec![]);
+    }
+}
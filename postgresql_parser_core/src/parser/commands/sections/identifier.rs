@@ -1,4 +1,6 @@
+use crate::lexer::token::Token;
 use crate::parser::ast::Identifier;
+use crate::parser::commands::parse_section::ParseCommandSectionResult;
 
 #[derive(Debug, PartialEq)]
 pub enum SimpleParseResult<TParsedData> {
@@ -19,6 +21,18 @@ pub fn parse_identifiers_from_dot_separated_values(
     return SimpleParseResult::Valid(identifiers);
 }
 
+pub fn parse_identifier(tokens: &Vec<Token>, idx: usize) -> ParseCommandSectionResult<Identifier> {
+    return match tokens.get(idx) {
+        Some(token) => match parse_identifier_token_value(&token.value) {
+            SimpleParseResult::Valid(identifier) => {
+                ParseCommandSectionResult::Valid(idx + 1, identifier)
+            }
+            SimpleParseResult::Invalid => ParseCommandSectionResult::Invalid,
+        },
+        None => ParseCommandSectionResult::EndOfInput,
+    };
+}
+
 pub fn parse_identifier_token_value(token_value: &String) -> SimpleParseResult<Identifier> {
     return match parse_quoted_identifier_token_value(token_value) {
         SimpleParseResult::Valid(identifier) => SimpleParseResult::Valid(identifier),
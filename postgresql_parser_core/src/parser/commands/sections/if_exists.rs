@@ -0,0 +1,19 @@
+use crate::lexer::token::Token;
+use crate::parser::commands::parse_section::ParseCommandSectionResult;
+use crate::parser::commands::sections::keywords::{
+    parse_keywords, EXISTS_KEYWORD, IF_KEYWORD, NOT_KEYWORD,
+};
+
+// IF EXISTS
+pub fn parse_if_exists(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<()> {
+    return parse_keywords(tokens, start_idx, &[IF_KEYWORD, EXISTS_KEYWORD]);
+}
+
+// IF NOT EXISTS
+pub fn parse_if_not_exists(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<()> {
+    return parse_keywords(
+        tokens,
+        start_idx,
+        &[IF_KEYWORD, NOT_KEYWORD, EXISTS_KEYWORD],
+    );
+}
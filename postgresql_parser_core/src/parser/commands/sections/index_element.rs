@@ -0,0 +1,140 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::IndexElement;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_one_of_keywords, ASC_KEYWORD, COLLATE_KEYWORD, DESC_KEYWORD,
+    NULLS_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::order_by::parse_sort_order;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// expression [COLLATE collation] [opclass] [ASC | DESC] [NULLS {FIRST | LAST}]
+pub fn parse_index_element(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<IndexElement> {
+    let (mut idx, expression) = parse_section_from_section!(parse_expression(tokens, start_idx));
+
+    let mut collation = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_collate, _) =
+        parse_keyword(tokens, idx_after_whitespace, COLLATE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_collate);
+        let (idx_after_collation, collation_name) =
+            parse_section_from_section!(parse_qualified_name(tokens, idx_after_whitespace));
+        idx = idx_after_collation;
+        collation = Some(collation_name);
+    }
+
+    // The sort order keywords, and the WITH following an element of an
+    // exclusion constraint, would otherwise be taken as an operator class
+    let mut operator_class = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if !matches!(
+        parse_one_of_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[ASC_KEYWORD, DESC_KEYWORD, NULLS_KEYWORD, WITH_KEYWORD]
+        ),
+        ParseCommandSectionResult::Valid(_, _)
+    ) {
+        if let ParseCommandSectionResult::Valid(idx_after_operator_class, operator_class_name) =
+            parse_qualified_name(tokens, idx_after_whitespace)
+        {
+            idx = idx_after_operator_class;
+            operator_class = Some(operator_class_name);
+        }
+    }
+
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_sort_order, (direction, nulls)) =
+        parse_section_from_section!(parse_sort_order(tokens, idx_after_whitespace));
+    if idx_after_sort_order != idx_after_whitespace {
+        idx = idx_after_sort_order;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        IndexElement {
+            expression: expression,
+            collation: collation,
+            operator_class: operator_class,
+            direction: direction,
+            nulls: nulls,
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tokenize_postgresql;
+    use crate::parser::ast::{
+        ColumnSelectedExpression, Expression, Identifier, NullsOrder, QualifiedName, SortDirection,
+    };
+
+    fn identifier(value: &str) -> Identifier {
+        return Identifier {
+            quoted: false,
+            value: String::from(value),
+        };
+    }
+
+    fn column(name: &str) -> Expression {
+        return Expression::Column(ColumnSelectedExpression {
+            schema_name: None,
+            table_name: None,
+            column_name: identifier(name),
+        });
+    }
+
+    #[test]
+    fn index_element_with_operator_class_and_sort_order() {
+        let tokens = tokenize_postgresql("name text_pattern_ops DESC NULLS LAST)");
+        assert_eq!(
+            parse_index_element(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                9,
+                IndexElement {
+                    expression: column("name"),
+                    collation: None,
+                    operator_class: Some(QualifiedName {
+                        schema_name: None,
+                        name: identifier("text_pattern_ops"),
+                    }),
+                    direction: Some(SortDirection::Descending),
+                    nulls: Some(NullsOrder::Last),
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn index_element_nulls_is_not_an_operator_class() {
+        let tokens = tokenize_postgresql("name COLLATE \"C\" NULLS FIRST)");
+        assert_eq!(
+            parse_index_element(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                9,
+                IndexElement {
+                    expression: column("name"),
+                    collation: Some(QualifiedName {
+                        schema_name: None,
+                        name: Identifier {
+                            quoted: true,
+                            value: String::from("C"),
+                        },
+                    }),
+                    operator_class: None,
+                    direction: None,
+                    nulls: Some(NullsOrder::First),
+                }
+            )
+        );
+    }
+}
@@ -1,16 +1,488 @@
 use crate::lexer::token::Token;
-use crate::parser::commands::parse_section::ParseCommandSectionResult;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
 use crate::parser::utils::empty_parsed_datum;
+use crate::parser::utils::idx_after_optional_whitespace;
 use crate::parser::utils::parse_simple_token;
 
 pub const SELECT_KEYWORD: &str = "select";
 pub const FROM_KEYWORD: &str = "from";
+pub const INSERT_KEYWORD: &str = "insert";
+pub const INTO_KEYWORD: &str = "into";
+pub const AS_KEYWORD: &str = "as";
+pub const OVERRIDING_KEYWORD: &str = "overriding";
+pub const SYSTEM_KEYWORD: &str = "system";
+pub const USER_KEYWORD: &str = "user";
+pub const VALUE_KEYWORD: &str = "value";
+pub const VALUES_KEYWORD: &str = "values";
+pub const DEFAULT_KEYWORD: &str = "default";
+pub const ON_KEYWORD: &str = "on";
+pub const CONFLICT_KEYWORD: &str = "conflict";
+pub const CONSTRAINT_KEYWORD: &str = "constraint";
+pub const DO_KEYWORD: &str = "do";
+pub const NOTHING_KEYWORD: &str = "nothing";
+pub const UPDATE_KEYWORD: &str = "update";
+pub const SET_KEYWORD: &str = "set";
+pub const WHERE_KEYWORD: &str = "where";
+pub const RETURNING_KEYWORD: &str = "returning";
+pub const AND_KEYWORD: &str = "and";
+pub const OR_KEYWORD: &str = "or";
+pub const NOT_KEYWORD: &str = "not";
+pub const IS_KEYWORD: &str = "is";
+pub const DISTINCT_KEYWORD: &str = "distinct";
+pub const IN_KEYWORD: &str = "in";
+pub const LIKE_KEYWORD: &str = "like";
+pub const ILIKE_KEYWORD: &str = "ilike";
+pub const EXISTS_KEYWORD: &str = "exists";
+pub const NULL_KEYWORD: &str = "null";
+pub const TRUE_KEYWORD: &str = "true";
+pub const FALSE_KEYWORD: &str = "false";
+pub const ONLY_KEYWORD: &str = "only";
+pub const CURRENT_KEYWORD: &str = "current";
+pub const OF_KEYWORD: &str = "of";
+pub const DELETE_KEYWORD: &str = "delete";
+pub const USING_KEYWORD: &str = "using";
+pub const MERGE_KEYWORD: &str = "merge";
+pub const WHEN_KEYWORD: &str = "when";
+pub const MATCHED_KEYWORD: &str = "matched";
+pub const BY_KEYWORD: &str = "by";
+pub const SOURCE_KEYWORD: &str = "source";
+pub const TARGET_KEYWORD: &str = "target";
+pub const THEN_KEYWORD: &str = "then";
+pub const UNION_KEYWORD: &str = "union";
+pub const INTERSECT_KEYWORD: &str = "intersect";
+pub const EXCEPT_KEYWORD: &str = "except";
+pub const ALL_KEYWORD: &str = "all";
+pub const ORDER_KEYWORD: &str = "order";
+pub const ASC_KEYWORD: &str = "asc";
+pub const DESC_KEYWORD: &str = "desc";
+pub const NULLS_KEYWORD: &str = "nulls";
+pub const FIRST_KEYWORD: &str = "first";
+pub const LAST_KEYWORD: &str = "last";
+pub const LIMIT_KEYWORD: &str = "limit";
+pub const OFFSET_KEYWORD: &str = "offset";
+pub const ROW_KEYWORD: &str = "row";
+pub const ROWS_KEYWORD: &str = "rows";
+pub const FOR_KEYWORD: &str = "for";
+pub const NO_KEYWORD: &str = "no";
+pub const KEY_KEYWORD: &str = "key";
+pub const SHARE_KEYWORD: &str = "share";
+pub const NOWAIT_KEYWORD: &str = "nowait";
+pub const SKIP_KEYWORD: &str = "skip";
+pub const LOCKED_KEYWORD: &str = "locked";
+pub const LATERAL_KEYWORD: &str = "lateral";
+pub const WITH_KEYWORD: &str = "with";
+pub const WITHOUT_KEYWORD: &str = "without";
+pub const ORDINALITY_KEYWORD: &str = "ordinality";
+pub const TABLESAMPLE_KEYWORD: &str = "tablesample";
+pub const REPEATABLE_KEYWORD: &str = "repeatable";
+pub const ARRAY_KEYWORD: &str = "array";
+pub const DOUBLE_KEYWORD: &str = "double";
+pub const PRECISION_KEYWORD: &str = "precision";
+pub const CHARACTER_KEYWORD: &str = "character";
+pub const CHAR_KEYWORD: &str = "char";
+pub const BIT_KEYWORD: &str = "bit";
+pub const VARYING_KEYWORD: &str = "varying";
+pub const TIME_KEYWORD: &str = "time";
+pub const TIMESTAMP_KEYWORD: &str = "timestamp";
+pub const ZONE_KEYWORD: &str = "zone";
+pub const CREATE_KEYWORD: &str = "create";
+pub const TABLE_KEYWORD: &str = "table";
+pub const TEMP_KEYWORD: &str = "temp";
+pub const TEMPORARY_KEYWORD: &str = "temporary";
+pub const UNLOGGED_KEYWORD: &str = "unlogged";
+pub const GLOBAL_KEYWORD: &str = "global";
+pub const LOCAL_KEYWORD: &str = "local";
+pub const IF_KEYWORD: &str = "if";
+pub const PRIMARY_KEYWORD: &str = "primary";
+pub const FOREIGN_KEYWORD: &str = "foreign";
+pub const REFERENCES_KEYWORD: &str = "references";
+pub const CHECK_KEYWORD: &str = "check";
+pub const UNIQUE_KEYWORD: &str = "unique";
+pub const INCLUDE_KEYWORD: &str = "include";
+pub const MATCH_KEYWORD: &str = "match";
+pub const FULL_KEYWORD: &str = "full";
+pub const PARTIAL_KEYWORD: &str = "partial";
+pub const SIMPLE_KEYWORD: &str = "simple";
+pub const CASCADE_KEYWORD: &str = "cascade";
+pub const RESTRICT_KEYWORD: &str = "restrict";
+pub const ACTION_KEYWORD: &str = "action";
+pub const DEFERRABLE_KEYWORD: &str = "deferrable";
+pub const INITIALLY_KEYWORD: &str = "initially";
+pub const DEFERRED_KEYWORD: &str = "deferred";
+pub const IMMEDIATE_KEYWORD: &str = "immediate";
+pub const INHERIT_KEYWORD: &str = "inherit";
+pub const INHERITS_KEYWORD: &str = "inherits";
+pub const GENERATED_KEYWORD: &str = "generated";
+pub const ALWAYS_KEYWORD: &str = "always";
+pub const IDENTITY_KEYWORD: &str = "identity";
+pub const STORED_KEYWORD: &str = "stored";
+pub const COLLATE_KEYWORD: &str = "collate";
+pub const PARTITION_KEYWORD: &str = "partition";
+pub const RANGE_KEYWORD: &str = "range";
+pub const LIST_KEYWORD: &str = "list";
+pub const HASH_KEYWORD: &str = "hash";
+pub const TO_KEYWORD: &str = "to";
+pub const MODULUS_KEYWORD: &str = "modulus";
+pub const REMAINDER_KEYWORD: &str = "remainder";
+pub const MINVALUE_KEYWORD: &str = "minvalue";
+pub const MAXVALUE_KEYWORD: &str = "maxvalue";
+pub const TABLESPACE_KEYWORD: &str = "tablespace";
+pub const INCLUDING_KEYWORD: &str = "including";
+pub const EXCLUDING_KEYWORD: &str = "excluding";
+pub const COMMENTS_KEYWORD: &str = "comments";
+pub const COMPRESSION_KEYWORD: &str = "compression";
+pub const CONSTRAINTS_KEYWORD: &str = "constraints";
+pub const DEFAULTS_KEYWORD: &str = "defaults";
+pub const INDEXES_KEYWORD: &str = "indexes";
+pub const STATISTICS_KEYWORD: &str = "statistics";
+pub const STORAGE_KEYWORD: &str = "storage";
+pub const INCREMENT_KEYWORD: &str = "increment";
+pub const START_KEYWORD: &str = "start";
+pub const CACHE_KEYWORD: &str = "cache";
+pub const CYCLE_KEYWORD: &str = "cycle";
+pub const OWNED_KEYWORD: &str = "owned";
+pub const NONE_KEYWORD: &str = "none";
+pub const SEQUENCE_KEYWORD: &str = "sequence";
+pub const NAME_KEYWORD: &str = "name";
+pub const ALTER_KEYWORD: &str = "alter";
+pub const ADD_KEYWORD: &str = "add";
+pub const COLUMN_KEYWORD: &str = "column";
+pub const DROP_KEYWORD: &str = "drop";
+pub const DATA_KEYWORD: &str = "data";
+pub const TYPE_KEYWORD: &str = "type";
+pub const VALIDATE_KEYWORD: &str = "validate";
+pub const RENAME_KEYWORD: &str = "rename";
+pub const SCHEMA_KEYWORD: &str = "schema";
+pub const ATTACH_KEYWORD: &str = "attach";
+pub const DETACH_KEYWORD: &str = "detach";
+pub const CONCURRENTLY_KEYWORD: &str = "concurrently";
+pub const FINALIZE_KEYWORD: &str = "finalize";
+pub const ENABLE_KEYWORD: &str = "enable";
+pub const DISABLE_KEYWORD: &str = "disable";
+pub const TRIGGER_KEYWORD: &str = "trigger";
+pub const REPLICA_KEYWORD: &str = "replica";
+pub const LEVEL_KEYWORD: &str = "level";
+pub const SECURITY_KEYWORD: &str = "security";
+pub const FORCE_KEYWORD: &str = "force";
+pub const OWNER_KEYWORD: &str = "owner";
+pub const CURRENT_ROLE_KEYWORD: &str = "current_role";
+pub const CURRENT_USER_KEYWORD: &str = "current_user";
+pub const SESSION_USER_KEYWORD: &str = "session_user";
+pub const PLAIN_KEYWORD: &str = "plain";
+pub const EXTERNAL_KEYWORD: &str = "external";
+pub const EXTENDED_KEYWORD: &str = "extended";
+pub const MAIN_KEYWORD: &str = "main";
+pub const VALID_KEYWORD: &str = "valid";
+pub const RESET_KEYWORD: &str = "reset";
+pub const VIEW_KEYWORD: &str = "view";
+pub const MATERIALIZED_KEYWORD: &str = "materialized";
+pub const INDEX_KEYWORD: &str = "index";
+pub const DOMAIN_KEYWORD: &str = "domain";
+pub const FUNCTION_KEYWORD: &str = "function";
+pub const PROCEDURE_KEYWORD: &str = "procedure";
+pub const ROUTINE_KEYWORD: &str = "routine";
+pub const AGGREGATE_KEYWORD: &str = "aggregate";
+pub const EVENT_KEYWORD: &str = "event";
+pub const POLICY_KEYWORD: &str = "policy";
+pub const RULE_KEYWORD: &str = "rule";
+pub const EXTENSION_KEYWORD: &str = "extension";
+pub const ROLE_KEYWORD: &str = "role";
+pub const GROUP_KEYWORD: &str = "group";
+pub const DATABASE_KEYWORD: &str = "database";
+pub const PUBLICATION_KEYWORD: &str = "publication";
+pub const SUBSCRIPTION_KEYWORD: &str = "subscription";
+pub const COLLATION_KEYWORD: &str = "collation";
+pub const OUT_KEYWORD: &str = "out";
+pub const INOUT_KEYWORD: &str = "inout";
+pub const VARIADIC_KEYWORD: &str = "variadic";
+pub const REPLACE_KEYWORD: &str = "replace";
+pub const RECURSIVE_KEYWORD: &str = "recursive";
+pub const CASCADED_KEYWORD: &str = "cascaded";
+pub const OPTION_KEYWORD: &str = "option";
+pub const REFRESH_KEYWORD: &str = "refresh";
+pub const RETURNS_KEYWORD: &str = "returns";
+pub const SETOF_KEYWORD: &str = "setof";
+pub const LANGUAGE_KEYWORD: &str = "language";
+pub const IMMUTABLE_KEYWORD: &str = "immutable";
+pub const STABLE_KEYWORD: &str = "stable";
+pub const VOLATILE_KEYWORD: &str = "volatile";
+pub const STRICT_KEYWORD: &str = "strict";
+pub const CALLED_KEYWORD: &str = "called";
+pub const INPUT_KEYWORD: &str = "input";
+pub const DEFINER_KEYWORD: &str = "definer";
+pub const INVOKER_KEYWORD: &str = "invoker";
+pub const LEAKPROOF_KEYWORD: &str = "leakproof";
+pub const PARALLEL_KEYWORD: &str = "parallel";
+pub const UNSAFE_KEYWORD: &str = "unsafe";
+pub const RESTRICTED_KEYWORD: &str = "restricted";
+pub const COST_KEYWORD: &str = "cost";
+pub const ATOMIC_KEYWORD: &str = "atomic";
+pub const BEGIN_KEYWORD: &str = "begin";
+pub const END_KEYWORD: &str = "end";
+pub const RETURN_KEYWORD: &str = "return";
+pub const SAFE_KEYWORD: &str = "safe";
+pub const DECLARE_KEYWORD: &str = "declare";
+pub const ELSIF_KEYWORD: &str = "elsif";
+pub const ELSEIF_KEYWORD: &str = "elseif";
+pub const LOOP_KEYWORD: &str = "loop";
+pub const WHILE_KEYWORD: &str = "while";
+pub const FOREACH_KEYWORD: &str = "foreach";
+pub const SLICE_KEYWORD: &str = "slice";
+pub const REVERSE_KEYWORD: &str = "reverse";
+pub const EXIT_KEYWORD: &str = "exit";
+pub const CONTINUE_KEYWORD: &str = "continue";
+pub const NEXT_KEYWORD: &str = "next";
+pub const QUERY_KEYWORD: &str = "query";
+pub const RAISE_KEYWORD: &str = "raise";
+pub const PERFORM_KEYWORD: &str = "perform";
+pub const EXECUTE_KEYWORD: &str = "execute";
+pub const GET_KEYWORD: &str = "get";
+pub const DIAGNOSTICS_KEYWORD: &str = "diagnostics";
+pub const STACKED_KEYWORD: &str = "stacked";
+pub const EXCEPTION_KEYWORD: &str = "exception";
+pub const CONSTANT_KEYWORD: &str = "constant";
+pub const ALIAS_KEYWORD: &str = "alias";
+pub const CURSOR_KEYWORD: &str = "cursor";
+pub const ROWTYPE_KEYWORD: &str = "rowtype";
+pub const SQLSTATE_KEYWORD: &str = "sqlstate";
+pub const DEBUG_KEYWORD: &str = "debug";
+pub const LOG_KEYWORD: &str = "log";
+pub const INFO_KEYWORD: &str = "info";
+pub const NOTICE_KEYWORD: &str = "notice";
+pub const WARNING_KEYWORD: &str = "warning";
+pub const ELSE_KEYWORD: &str = "else";
+pub const CASE_KEYWORD: &str = "case";
+pub const BEFORE_KEYWORD: &str = "before";
+pub const AFTER_KEYWORD: &str = "after";
+pub const INSTEAD_KEYWORD: &str = "instead";
+pub const TRUNCATE_KEYWORD: &str = "truncate";
+pub const REFERENCING_KEYWORD: &str = "referencing";
+pub const OLD_KEYWORD: &str = "old";
+pub const NEW_KEYWORD: &str = "new";
+pub const EACH_KEYWORD: &str = "each";
+pub const STATEMENT_KEYWORD: &str = "statement";
+pub const ENUM_KEYWORD: &str = "enum";
+pub const RESTART_KEYWORD: &str = "restart";
+pub const AUTHORIZATION_KEYWORD: &str = "authorization";
+pub const VERSION_KEYWORD: &str = "version";
+pub const LOCATION_KEYWORD: &str = "location";
+pub const CONNECTION_KEYWORD: &str = "connection";
+pub const GRANT_KEYWORD: &str = "grant";
+pub const REVOKE_KEYWORD: &str = "revoke";
+pub const PRIVILEGES_KEYWORD: &str = "privileges";
+pub const PUBLIC_KEYWORD: &str = "public";
+pub const GRANTED_KEYWORD: &str = "granted";
+pub const ADMIN_KEYWORD: &str = "admin";
+pub const USAGE_KEYWORD: &str = "usage";
+pub const CONNECT_KEYWORD: &str = "connect";
+pub const MAINTAIN_KEYWORD: &str = "maintain";
+pub const TABLES_KEYWORD: &str = "tables";
+pub const SEQUENCES_KEYWORD: &str = "sequences";
+pub const FUNCTIONS_KEYWORD: &str = "functions";
+pub const PROCEDURES_KEYWORD: &str = "procedures";
+pub const ROUTINES_KEYWORD: &str = "routines";
+pub const TYPES_KEYWORD: &str = "types";
+pub const SCHEMAS_KEYWORD: &str = "schemas";
+pub const SUPERUSER_KEYWORD: &str = "superuser";
+pub const NOSUPERUSER_KEYWORD: &str = "nosuperuser";
+pub const CREATEDB_KEYWORD: &str = "createdb";
+pub const NOCREATEDB_KEYWORD: &str = "nocreatedb";
+pub const CREATEROLE_KEYWORD: &str = "createrole";
+pub const NOCREATEROLE_KEYWORD: &str = "nocreaterole";
+pub const NOINHERIT_KEYWORD: &str = "noinherit";
+pub const LOGIN_KEYWORD: &str = "login";
+pub const NOLOGIN_KEYWORD: &str = "nologin";
+pub const REPLICATION_KEYWORD: &str = "replication";
+pub const NOREPLICATION_KEYWORD: &str = "noreplication";
+pub const BYPASSRLS_KEYWORD: &str = "bypassrls";
+pub const NOBYPASSRLS_KEYWORD: &str = "nobypassrls";
+pub const ENCRYPTED_KEYWORD: &str = "encrypted";
+pub const PASSWORD_KEYWORD: &str = "password";
+pub const UNTIL_KEYWORD: &str = "until";
+pub const PERMISSIVE_KEYWORD: &str = "permissive";
+pub const RESTRICTIVE_KEYWORD: &str = "restrictive";
+pub const TRANSACTION_KEYWORD: &str = "transaction";
+pub const WORK_KEYWORD: &str = "work";
+pub const COMMIT_KEYWORD: &str = "commit";
+pub const ROLLBACK_KEYWORD: &str = "rollback";
+pub const ABORT_KEYWORD: &str = "abort";
+pub const SAVEPOINT_KEYWORD: &str = "savepoint";
+pub const RELEASE_KEYWORD: &str = "release";
+pub const PREPARE_KEYWORD: &str = "prepare";
+pub const PREPARED_KEYWORD: &str = "prepared";
+pub const CHAIN_KEYWORD: &str = "chain";
+pub const ISOLATION_KEYWORD: &str = "isolation";
+pub const SERIALIZABLE_KEYWORD: &str = "serializable";
+pub const READ_KEYWORD: &str = "read";
+pub const COMMITTED_KEYWORD: &str = "committed";
+pub const UNCOMMITTED_KEYWORD: &str = "uncommitted";
+pub const WRITE_KEYWORD: &str = "write";
+pub const SESSION_KEYWORD: &str = "session";
+pub const CHARACTERISTICS_KEYWORD: &str = "characteristics";
+pub const SNAPSHOT_KEYWORD: &str = "snapshot";
+pub const SHOW_KEYWORD: &str = "show";
+pub const EXPLAIN_KEYWORD: &str = "explain";
+pub const ANALYZE_KEYWORD: &str = "analyze";
+pub const ANALYSE_KEYWORD: &str = "analyse";
+pub const VERBOSE_KEYWORD: &str = "verbose";
+pub const COSTS_KEYWORD: &str = "costs";
+pub const SETTINGS_KEYWORD: &str = "settings";
+pub const GENERIC_PLAN_KEYWORD: &str = "generic_plan";
+pub const BUFFERS_KEYWORD: &str = "buffers";
+pub const SERIALIZE_KEYWORD: &str = "serialize";
+pub const WAL_KEYWORD: &str = "wal";
+pub const TIMING_KEYWORD: &str = "timing";
+pub const SUMMARY_KEYWORD: &str = "summary";
+pub const MEMORY_KEYWORD: &str = "memory";
+pub const FORMAT_KEYWORD: &str = "format";
+pub const TEXT_KEYWORD: &str = "text";
+pub const XML_KEYWORD: &str = "xml";
+pub const JSON_KEYWORD: &str = "json";
+pub const YAML_KEYWORD: &str = "yaml";
+pub const BINARY_KEYWORD: &str = "binary";
+pub const OFF_KEYWORD: &str = "off";
+pub const COPY_KEYWORD: &str = "copy";
+pub const PROGRAM_KEYWORD: &str = "program";
+pub const STDIN_KEYWORD: &str = "stdin";
+pub const STDOUT_KEYWORD: &str = "stdout";
+pub const CSV_KEYWORD: &str = "csv";
+pub const DELIMITER_KEYWORD: &str = "delimiter";
+pub const HEADER_KEYWORD: &str = "header";
+pub const QUOTE_KEYWORD: &str = "quote";
+pub const ESCAPE_KEYWORD: &str = "escape";
+pub const FORCE_QUOTE_KEYWORD: &str = "force_quote";
+pub const FORCE_NOT_NULL_KEYWORD: &str = "force_not_null";
+pub const FORCE_NULL_KEYWORD: &str = "force_null";
+pub const ENCODING_KEYWORD: &str = "encoding";
+pub const ON_ERROR_KEYWORD: &str = "on_error";
+pub const FREEZE_KEYWORD: &str = "freeze";
+pub const REJECT_LIMIT_KEYWORD: &str = "reject_limit";
+pub const LOG_VERBOSITY_KEYWORD: &str = "log_verbosity";
+pub const BETWEEN_KEYWORD: &str = "between";
+pub const SYMMETRIC_KEYWORD: &str = "symmetric";
+pub const ASYMMETRIC_KEYWORD: &str = "asymmetric";
+pub const EXCLUDE_KEYWORD: &str = "exclude";
+
+// Keywords that can never be used as an unquoted column or table alias
+pub const RESERVED_KEYWORDS: [&str; 78] = [
+    "all",
+    "analyse",
+    "analyze",
+    "and",
+    "any",
+    "array",
+    "as",
+    "asc",
+    "asymmetric",
+    "both",
+    "case",
+    "cast",
+    "check",
+    "collate",
+    "column",
+    "constraint",
+    "create",
+    "current_catalog",
+    "current_date",
+    "current_role",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "default",
+    "deferrable",
+    "desc",
+    "distinct",
+    "do",
+    "else",
+    "end",
+    "except",
+    "false",
+    "fetch",
+    "for",
+    "foreign",
+    "from",
+    "grant",
+    "group",
+    "having",
+    "in",
+    "initially",
+    "intersect",
+    "into",
+    "lateral",
+    "leading",
+    "limit",
+    "localtime",
+    "localtimestamp",
+    "not",
+    "null",
+    "offset",
+    "on",
+    "only",
+    "or",
+    "order",
+    "placing",
+    "primary",
+    "references",
+    "returning",
+    "select",
+    "session_user",
+    "some",
+    "symmetric",
+    "system_user",
+    "table",
+    "then",
+    "to",
+    "trailing",
+    "true",
+    "union",
+    "unique",
+    "user",
+    "using",
+    "variadic",
+    "when",
+    "where",
+    "window",
+    "with",
+];
+
+// Keywords that can name a function or a type, but not a table or column
+pub const TYPE_FUNCTION_NAME_KEYWORDS: [&str; 23] = [
+    "authorization",
+    "binary",
+    "collation",
+    "concurrently",
+    "cross",
+    "current_schema",
+    "freeze",
+    "full",
+    "ilike",
+    "inner",
+    "is",
+    "isnull",
+    "join",
+    "left",
+    "like",
+    "natural",
+    "notnull",
+    "outer",
+    "overlaps",
+    "right",
+    "similar",
+    "tablesample",
+    "verbose",
+];
 
 pub fn parse_keyword_from(tokens: &Vec<Token>, idx: usize) -> ParseCommandSectionResult<()> {
     return parse_keyword(tokens, idx, FROM_KEYWORD);
 }
 
-fn parse_keyword(tokens: &Vec<Token>, idx: usize, keyword: &str) -> ParseCommandSectionResult<()> {
+pub fn parse_keyword(
+    tokens: &Vec<Token>,
+    idx: usize,
+    keyword: &str,
+) -> ParseCommandSectionResult<()> {
     return parse_simple_token(
         tokens,
         idx,
@@ -18,3 +490,76 @@ fn parse_keyword(tokens: &Vec<Token>, idx: usize, keyword: &str) -> ParseCommand
         empty_parsed_datum,
     );
 }
+
+// Parses a run of keywords such as ON CONFLICT or DEFAULT VALUES. Does not
+// consume whitespace before the first or after the last keyword.
+pub fn parse_keywords(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    keywords: &[&str],
+) -> ParseCommandSectionResult<()> {
+    let mut idx = start_idx;
+    for (keyword_idx, keyword) in keywords.iter().enumerate() {
+        if keyword_idx > 0 {
+            idx = idx_after_optional_whitespace(tokens, idx);
+        }
+        (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, keyword));
+    }
+    return ParseCommandSectionResult::Valid(idx, ());
+}
+
+// Parses whichever of the given keywords is next, returning it lowercased
+pub fn parse_one_of_keywords(
+    tokens: &Vec<Token>,
+    idx: usize,
+    keywords: &[&str],
+) -> ParseCommandSectionResult<String> {
+    return parse_simple_token(
+        tokens,
+        idx,
+        |token| return keywords.contains(&token.value.to_ascii_lowercase().as_str()),
+        |token| return token.value.to_ascii_lowercase(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::tokens_from_str_vector;
+
+    #[test]
+    fn keywords_sequence() {
+        assert_eq!(
+            parse_keywords(
+                &tokens_from_str_vector(vec!["ON", " ", "conflict", " "]),
+                0,
+                &[ON_KEYWORD, CONFLICT_KEYWORD]
+            ),
+            ParseCommandSectionResult::Valid(3, ())
+        );
+    }
+
+    #[test]
+    fn keywords_sequence_partial_match() {
+        assert_eq!(
+            parse_keywords(
+                &tokens_from_str_vector(vec!["on", " ", "constraint"]),
+                0,
+                &[ON_KEYWORD, CONFLICT_KEYWORD]
+            ),
+            ParseCommandSectionResult::Invalid
+        );
+    }
+
+    #[test]
+    fn one_of_keywords() {
+        assert_eq!(
+            parse_one_of_keywords(
+                &tokens_from_str_vector(vec!["User"]),
+                0,
+                &[SYSTEM_KEYWORD, USER_KEYWORD]
+            ),
+            ParseCommandSectionResult::Valid(1, String::from("user"))
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::Expression;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_one_of_keywords, ALL_KEYWORD, LIMIT_KEYWORD, OFFSET_KEYWORD, ROWS_KEYWORD,
+    ROW_KEYWORD,
+};
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// LIMIT {count | ALL}. LIMIT ALL is the same as no limit, so it is None.
+pub fn parse_limit_clause(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Option<Expression>> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, LIMIT_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after, _) = parse_keyword(tokens, idx, ALL_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, None);
+    }
+    let (idx_after, count) = parse_section_from_section!(parse_expression(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx_after, Some(count));
+}
+
+// OFFSET start [ROW | ROWS]
+pub fn parse_offset_clause(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Expression> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, OFFSET_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_start, offset) = parse_section_from_section!(parse_expression(tokens, idx));
+    idx = idx_after_start;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_rows, _) =
+        parse_one_of_keywords(tokens, idx_after_whitespace, &[ROW_KEYWORD, ROWS_KEYWORD])
+    {
+        idx = idx_after_rows;
+    }
+    return ParseCommandSectionResult::Valid(idx, offset);
+}
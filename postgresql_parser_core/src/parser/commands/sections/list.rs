@@ -0,0 +1,159 @@
+use crate::lexer::token::Token;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::comma::parse_comma;
+use crate::parser::commands::sections::parenthesis::{
+    parse_close_parenthesis, parse_open_parenthesis,
+};
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Parses one or more items separated by commas. Whitespace around the commas
+// is consumed, but not whitespace after the last item.
+pub fn parse_comma_separated_list<TParsedDatum, FParseItem>(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    parse_item: FParseItem,
+) -> ParseCommandSectionResult<Vec<TParsedDatum>>
+where
+    FParseItem: Fn(&Vec<Token>, usize) -> ParseCommandSectionResult<TParsedDatum>,
+{
+    let mut items: Vec<TParsedDatum> = Vec::new();
+    let mut idx = start_idx;
+
+    loop {
+        let (idx_after_item, item) = parse_section_from_section!(parse_item(tokens, idx));
+        items.push(item);
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_item);
+        match parse_comma(tokens, idx_after_whitespace) {
+            ParseCommandSectionResult::Valid(idx_after_comma, _) => {
+                idx = idx_after_optional_whitespace(tokens, idx_after_comma);
+            }
+            ParseCommandSectionResult::Invalid | ParseCommandSectionResult::EndOfInput => {
+                idx = idx_after_item;
+                break;
+            }
+        }
+    }
+    return ParseCommandSectionResult::Valid(idx, items);
+}
+
+// Parses ( item, item, ... ) with at least one item
+pub fn parse_parenthesized_list<TParsedDatum, FParseItem>(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    parse_item: FParseItem,
+) -> ParseCommandSectionResult<Vec<TParsedDatum>>
+where
+    FParseItem: Fn(&Vec<Token>, usize) -> ParseCommandSectionResult<TParsedDatum>,
+{
+    let mut idx = start_idx;
+    let items: Vec<TParsedDatum>;
+    (idx, _) = parse_section_from_section!(parse_open_parenthesis(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, items) = parse_section_from_section!(parse_comma_separated_list(tokens, idx, parse_item));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_close_parenthesis(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx, items);
+}
+
+// Like parse_parenthesized_list, but also accepts ()
+pub fn parse_optionally_empty_parenthesized_list<TParsedDatum, FParseItem>(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    parse_item: FParseItem,
+) -> ParseCommandSectionResult<Vec<TParsedDatum>>
+where
+    FParseItem: Fn(&Vec<Token>, usize) -> ParseCommandSectionResult<TParsedDatum>,
+{
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_open_parenthesis(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after, _) = parse_close_parenthesis(tokens, idx) {
+        return ParseCommandSectionResult::Valid(idx_after, Vec::new());
+    }
+    return parse_parenthesized_list(tokens, start_idx, parse_item);
+}
+
+// Parses zero or more items separated by whitespace, such as the options of
+// CREATE SEQUENCE. Whitespace after the last item is not consumed.
+pub fn parse_whitespace_separated_list<TParsedDatum, FParseItem>(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    parse_item: FParseItem,
+) -> ParseCommandSectionResult<Vec<TParsedDatum>>
+where
+    FParseItem: Fn(&Vec<Token>, usize) -> ParseCommandSectionResult<TParsedDatum>,
+{
+    let mut items: Vec<TParsedDatum> = Vec::new();
+    let mut idx = start_idx;
+    loop {
+        let idx_before_item = match items.len() {
+            0 => idx,
+            _ => idx_after_optional_whitespace(tokens, idx),
+        };
+        match parse_item(tokens, idx_before_item) {
+            ParseCommandSectionResult::Valid(idx_after_item, item) => {
+                items.push(item);
+                idx = idx_after_item;
+            }
+            ParseCommandSectionResult::Invalid | ParseCommandSectionResult::EndOfInput => break,
+        }
+    }
+    return ParseCommandSectionResult::Valid(idx, items);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ast::Identifier;
+    use crate::parser::commands::sections::identifier::parse_identifier;
+    use crate::test_utils::tokens_from_str_vector;
+
+    fn identifier(value: &str) -> Identifier {
+        return Identifier {
+            quoted: false,
+            value: String::from(value),
+        };
+    }
+
+    #[test]
+    fn parenthesized_list() {
+        assert_eq!(
+            parse_parenthesized_list(
+                &tokens_from_str_vector(vec!["(", " ", "a", ",", "b", " ", ")", " "]),
+                0,
+                parse_identifier
+            ),
+            ParseCommandSectionResult::Valid(7, vec![identifier("a"), identifier("b")])
+        );
+    }
+
+    #[test]
+    fn parenthesized_list_trailing_comma() {
+        assert_eq!(
+            parse_parenthesized_list(
+                &tokens_from_str_vector(vec!["(", "a", ",", ")"]),
+                0,
+                parse_identifier
+            ),
+            ParseCommandSectionResult::Invalid
+        );
+    }
+
+    #[test]
+    fn parenthesized_list_empty() {
+        assert_eq!(
+            parse_parenthesized_list(&tokens_from_str_vector(vec!["(", ")"]), 0, parse_identifier),
+            ParseCommandSectionResult::Invalid
+        );
+        assert_eq!(
+            parse_optionally_empty_parenthesized_list(
+                &tokens_from_str_vector(vec!["(", ")"]),
+                0,
+                parse_identifier
+            ),
+            ParseCommandSectionResult::Valid(2, vec![])
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{LockStrength, LockWaitPolicy, LockingClause};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, FOR_KEYWORD, KEY_KEYWORD, LOCKED_KEYWORD, NOWAIT_KEYWORD,
+    NO_KEYWORD, OF_KEYWORD, SHARE_KEYWORD, SKIP_KEYWORD, UPDATE_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// FOR {UPDATE | NO KEY UPDATE | SHARE | KEY SHARE} [OF table_name, ...]
+// [NOWAIT | SKIP LOCKED]
+pub fn parse_locking_clause(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<LockingClause> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, FOR_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_strength, strength) =
+        parse_section_from_section!(parse_lock_strength(tokens, idx));
+    idx = idx_after_strength;
+
+    let mut tables = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_of, _) =
+        parse_keyword(tokens, idx_after_whitespace, OF_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_of);
+        let (idx_after_tables, table_names) = parse_section_from_section!(
+            parse_comma_separated_list(tokens, idx_after_whitespace, parse_qualified_name)
+        );
+        idx = idx_after_tables;
+        tables = table_names;
+    }
+
+    let mut wait_policy = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_nowait, _) =
+        parse_keyword(tokens, idx_after_whitespace, NOWAIT_KEYWORD)
+    {
+        idx = idx_after_nowait;
+        wait_policy = Some(LockWaitPolicy::NoWait);
+    } else if let ParseCommandSectionResult::Valid(idx_after_skip_locked, _) = parse_keywords(
+        tokens,
+        idx_after_whitespace,
+        &[SKIP_KEYWORD, LOCKED_KEYWORD],
+    ) {
+        idx = idx_after_skip_locked;
+        wait_policy = Some(LockWaitPolicy::SkipLocked);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        LockingClause {
+            strength: strength,
+            tables: tables,
+            wait_policy: wait_policy,
+        },
+    );
+}
+
+fn parse_lock_strength(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<LockStrength> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, UPDATE_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, LockStrength::Update);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) = parse_keywords(
+        tokens,
+        start_idx,
+        &[NO_KEYWORD, KEY_KEYWORD, UPDATE_KEYWORD],
+    ) {
+        return ParseCommandSectionResult::Valid(idx_after, LockStrength::NoKeyUpdate);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, SHARE_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, LockStrength::Share);
+    }
+    let (idx_after, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        start_idx,
+        &[KEY_KEYWORD, SHARE_KEYWORD]
+    ));
+    return ParseCommandSectionResult::Valid(idx_after, LockStrength::KeyShare);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tokenize_postgresql;
+    use crate::parser::ast::{Identifier, QualifiedName};
+
+    #[test]
+    fn locking_clause_no_key_update_of_tables_skip_locked() {
+        let tokens = tokenize_postgresql("FOR NO KEY UPDATE OF job, public.worker SKIP LOCKED;");
+        assert_eq!(
+            parse_locking_clause(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                20,
+                LockingClause {
+                    strength: LockStrength::NoKeyUpdate,
+                    tables: vec![
+                        QualifiedName {
+                            schema_name: None,
+                            name: Identifier {
+                                quoted: false,
+                                value: String::from("job"),
+                            },
+                        },
+                        QualifiedName {
+                            schema_name: Some(Identifier {
+                                quoted: false,
+                                value: String::from("public"),
+                            }),
+                            name: Identifier {
+                                quoted: false,
+                                value: String::from("worker"),
+                            },
+                        },
+                    ],
+                    wait_policy: Some(LockWaitPolicy::SkipLocked),
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn locking_clause_key_share_nowait() {
+        let tokens = tokenize_postgresql("for key share nowait");
+        assert_eq!(
+            parse_locking_clause(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                7,
+                LockingClause {
+                    strength: LockStrength::KeyShare,
+                    tables: vec![],
+                    wait_policy: Some(LockWaitPolicy::NoWait),
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn locking_clause_invalid_strength() {
+        let tokens = tokenize_postgresql("for key update");
+        assert_eq!(
+            parse_locking_clause(&tokens, 0),
+            ParseCommandSectionResult::Invalid
+        );
+    }
+}
@@ -1,9 +1,40 @@
+pub mod alias;
+pub mod assignment;
+pub mod boolean;
+pub mod bracket;
+pub mod column_definition;
 pub mod comma;
+pub mod configuration_setting;
 pub mod dot;
 pub mod dot_separated_value;
+pub mod drop_behavior;
+pub mod expression;
 pub mod from_item;
+pub mod function_parameter;
 pub mod identifier;
+pub mod if_exists;
+pub mod index_element;
 pub mod keywords;
+pub mod limit;
+pub mod list;
+pub mod locking;
+pub mod operator;
+pub mod order_by;
+pub mod parameter;
+pub mod parenthesis;
+pub mod partition_bound;
+pub mod privileges;
+pub mod qualified_name;
+pub mod query;
+pub mod returning;
+pub mod role_specification;
 pub mod selected_expressions;
 pub mod semicolon;
+pub mod sequence_options;
+pub mod storage_parameters;
+pub mod table_constraint;
+pub mod target_table;
+pub mod type_name;
+pub mod where_clause;
 pub mod whitespace;
+pub mod with_data;
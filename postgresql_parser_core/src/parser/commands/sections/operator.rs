@@ -0,0 +1,28 @@
+use crate::lexer::char_is_operator;
+use crate::lexer::token::Token;
+use crate::parser::commands::parse_section::ParseCommandSectionResult;
+use crate::parser::utils::empty_parsed_datum;
+use crate::parser::utils::parse_simple_token;
+
+pub fn parse_operator(
+    tokens: &Vec<Token>,
+    idx: usize,
+    operator: &str,
+) -> ParseCommandSectionResult<()> {
+    return parse_simple_token(
+        tokens,
+        idx,
+        |token| return token.value == operator,
+        empty_parsed_datum,
+    );
+}
+
+// Any operator made up of operator characters, e.g. ||, ->>, @>
+pub fn parse_any_operator(tokens: &Vec<Token>, idx: usize) -> ParseCommandSectionResult<String> {
+    return parse_simple_token(
+        tokens,
+        idx,
+        |token| return token.value.chars().all(char_is_operator),
+        |token| return token.value.clone(),
+    );
+}
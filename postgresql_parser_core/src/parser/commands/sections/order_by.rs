@@ -0,0 +1,140 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{NullsOrder, SortDirection, SortExpression};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, ASC_KEYWORD, BY_KEYWORD, DESC_KEYWORD,
+    FIRST_KEYWORD, LAST_KEYWORD, NULLS_KEYWORD, ORDER_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// ORDER BY expression [ASC | DESC] [NULLS {FIRST | LAST}], ...
+pub fn parse_order_by_clause(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<SortExpression>> {
+    let mut idx = start_idx;
+    (idx, _) =
+        parse_section_from_section!(parse_keywords(tokens, idx, &[ORDER_KEYWORD, BY_KEYWORD]));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    return parse_comma_separated_list(tokens, idx, parse_sort_expression);
+}
+
+fn parse_sort_expression(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<SortExpression> {
+    let (mut idx, expression) = parse_section_from_section!(parse_expression(tokens, start_idx));
+
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_sort_order, (direction, nulls)) =
+        parse_section_from_section!(parse_sort_order(tokens, idx_after_whitespace));
+    if idx_after_sort_order != idx_after_whitespace {
+        idx = idx_after_sort_order;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        SortExpression {
+            expression: expression,
+            direction: direction,
+            nulls: nulls,
+        },
+    );
+}
+
+// [ASC | DESC] [NULLS {FIRST | LAST}], as used by ORDER BY and index
+// columns. Always valid, consuming nothing when neither is given.
+pub fn parse_sort_order(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<(Option<SortDirection>, Option<NullsOrder>)> {
+    let mut idx = start_idx;
+
+    let mut direction = None;
+    if let ParseCommandSectionResult::Valid(idx_after_direction, keyword) =
+        parse_one_of_keywords(tokens, idx, &[ASC_KEYWORD, DESC_KEYWORD])
+    {
+        idx = idx_after_direction;
+        direction = match keyword.as_str() {
+            ASC_KEYWORD => Some(SortDirection::Ascending),
+            _ => Some(SortDirection::Descending),
+        };
+    }
+
+    let idx_before_nulls = match direction {
+        Some(_) => idx_after_optional_whitespace(tokens, idx),
+        None => idx,
+    };
+    let mut nulls = None;
+    if let ParseCommandSectionResult::Valid(idx_after_nulls, _) =
+        parse_keyword(tokens, idx_before_nulls, NULLS_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_nulls);
+        let (idx_after_order, keyword) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[FIRST_KEYWORD, LAST_KEYWORD]
+        ));
+        idx = idx_after_order;
+        nulls = match keyword.as_str() {
+            FIRST_KEYWORD => Some(NullsOrder::First),
+            _ => Some(NullsOrder::Last),
+        };
+    }
+
+    return ParseCommandSectionResult::Valid(idx, (direction, nulls));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tokenize_postgresql;
+    use crate::parser::ast::{ColumnSelectedExpression, Expression, Identifier};
+
+    fn column(name: &str) -> Expression {
+        return Expression::Column(ColumnSelectedExpression {
+            schema_name: None,
+            table_name: None,
+            column_name: Identifier {
+                quoted: false,
+                value: String::from(name),
+            },
+        });
+    }
+
+    #[test]
+    fn order_by_with_direction_and_nulls() {
+        let tokens = tokenize_postgresql("ORDER BY a, b DESC NULLS LAST;");
+        assert_eq!(
+            parse_order_by_clause(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                14,
+                vec![
+                    SortExpression {
+                        expression: column("a"),
+                        direction: None,
+                        nulls: None,
+                    },
+                    SortExpression {
+                        expression: column("b"),
+                        direction: Some(SortDirection::Descending),
+                        nulls: Some(NullsOrder::Last),
+                    },
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn order_by_invalid_nulls() {
+        let tokens = tokenize_postgresql("order by a nulls;");
+        assert_eq!(
+            parse_order_by_clause(&tokens, 0),
+            ParseCommandSectionResult::Invalid
+        );
+    }
+}
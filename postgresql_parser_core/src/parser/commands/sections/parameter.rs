@@ -0,0 +1,148 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Parameter, ParameterKind};
+use crate::parser::commands::parse_section::ParseCommandSectionResult;
+use crate::parser::commands::sections::identifier::SimpleParseResult;
+
+pub fn parse_parameter(tokens: &Vec<Token>, idx: usize) -> ParseCommandSectionResult<Parameter> {
+    return match tokens.get(idx) {
+        Some(token) => match parse_parameter_token_value(&token.value) {
+            SimpleParseResult::Valid(kind) => ParseCommandSectionResult::Valid(
+                idx + 1,
+                Parameter {
+                    position: token.position.clone(),
+                    kind: kind,
+                },
+            ),
+            SimpleParseResult::Invalid => ParseCommandSectionResult::Invalid,
+        },
+        None => ParseCommandSectionResult::EndOfInput,
+    };
+}
+
+fn parse_parameter_token_value(token_value: &String) -> SimpleParseResult<ParameterKind> {
+    if let Some(number) = token_value.strip_prefix('$') {
+        return parse_positional_parameter_number(number);
+    }
+    if let Some(name) = token_value.strip_prefix(':') {
+        return parse_psql_variable_name(name);
+    }
+    return SimpleParseResult::Invalid;
+}
+
+fn parse_positional_parameter_number(number: &str) -> SimpleParseResult<ParameterKind> {
+    if number.len() == 0 || !number.chars().all(|c| c.is_ascii_digit()) {
+        return SimpleParseResult::Invalid;
+    }
+    return match number.parse::<usize>() {
+        Ok(0) | Err(_) => SimpleParseResult::Invalid,
+        Ok(number) => SimpleParseResult::Valid(ParameterKind::Positional(number)),
+    };
+}
+
+fn parse_psql_variable_name(name: &str) -> SimpleParseResult<ParameterKind> {
+    if let Some(quoted_name) = strip_surrounding(name, '\'') {
+        if is_psql_variable_name(quoted_name) {
+            return SimpleParseResult::Valid(ParameterKind::NamedAsLiteral(String::from(
+                quoted_name,
+            )));
+        }
+    } else if let Some(quoted_name) = strip_surrounding(name, '"') {
+        if is_psql_variable_name(quoted_name) {
+            return SimpleParseResult::Valid(ParameterKind::NamedAsIdentifier(String::from(
+                quoted_name,
+            )));
+        }
+    } else if is_psql_variable_name(name) {
+        return SimpleParseResult::Valid(ParameterKind::Named(String::from(name)));
+    }
+    return SimpleParseResult::Invalid;
+}
+
+fn strip_surrounding(value: &str, quote: char) -> Option<&str> {
+    return value.strip_prefix(quote)?.strip_suffix(quote);
+}
+
+fn is_psql_variable_name(name: &str) -> bool {
+    return name.len() > 0 && name.chars().all(|c| c == '_' || c.is_alphanumeric());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ast::TokenPosition;
+    use crate::test_utils::tokens_from_str_vector;
+
+    fn parameter_kind(token_value: &str) -> ParseCommandSectionResult<ParameterKind> {
+        return match parse_parameter(&tokens_from_str_vector(vec![token_value]), 0) {
+            ParseCommandSectionResult::Valid(idx, parameter) => {
+                ParseCommandSectionResult::Valid(idx, parameter.kind)
+            }
+            ParseCommandSectionResult::Invalid => ParseCommandSectionResult::Invalid,
+            ParseCommandSectionResult::EndOfInput => ParseCommandSectionResult::EndOfInput,
+        };
+    }
+
+    #[test]
+    fn parameter_positional() {
+        assert_eq!(
+            parse_parameter(&tokens_from_str_vector(vec![" ", "$12"]), 1),
+            ParseCommandSectionResult::Valid(
+                2,
+                Parameter {
+                    position: TokenPosition { line: 0, column: 1 },
+                    kind: ParameterKind::Positional(12)
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn parameter_named() {
+        assert_eq!(
+            parameter_kind(":user_id"),
+            ParseCommandSectionResult::Valid(1, ParameterKind::Named(String::from("user_id")))
+        );
+    }
+
+    #[test]
+    fn parameter_named_as_literal() {
+        assert_eq!(
+            parameter_kind(":'name'"),
+            ParseCommandSectionResult::Valid(
+                1,
+                ParameterKind::NamedAsLiteral(String::from("name"))
+            )
+        );
+    }
+
+    #[test]
+    fn parameter_named_as_identifier() {
+        assert_eq!(
+            parameter_kind(":\"name\""),
+            ParseCommandSectionResult::Valid(
+                1,
+                ParameterKind::NamedAsIdentifier(String::from("name"))
+            )
+        );
+    }
+
+    #[test]
+    fn parameter_invalid_zero() {
+        assert_eq!(parameter_kind("$0"), ParseCommandSectionResult::Invalid);
+    }
+
+    #[test]
+    fn parameter_invalid_non_numeric() {
+        assert_eq!(parameter_kind("$1a"), ParseCommandSectionResult::Invalid);
+    }
+
+    #[test]
+    fn parameter_invalid_empty_name() {
+        assert_eq!(parameter_kind(":''"), ParseCommandSectionResult::Invalid);
+    }
+
+    #[test]
+    fn parameter_invalid_identifier() {
+        assert_eq!(parameter_kind("foo"), ParseCommandSectionResult::Invalid);
+    }
+}
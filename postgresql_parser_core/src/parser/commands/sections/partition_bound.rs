@@ -0,0 +1,104 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{PartitionBound, PartitionRangeValue};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::comma::parse_comma;
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, DEFAULT_KEYWORD, FOR_KEYWORD,
+    FROM_KEYWORD, IN_KEYWORD, MAXVALUE_KEYWORD, MINVALUE_KEYWORD, MODULUS_KEYWORD,
+    REMAINDER_KEYWORD, TO_KEYWORD, VALUES_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_parenthesized_list;
+use crate::parser::commands::sections::parenthesis::{
+    parse_close_parenthesis, parse_open_parenthesis,
+};
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// FOR VALUES {IN (...) | FROM (...) TO (...) | WITH (MODULUS n, REMAINDER n)} or DEFAULT
+pub fn parse_partition_bound(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<PartitionBound> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, DEFAULT_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, PartitionBound::Default);
+    }
+    let mut idx = start_idx;
+    (idx, _) =
+        parse_section_from_section!(parse_keywords(tokens, idx, &[FOR_KEYWORD, VALUES_KEYWORD]));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_keyword, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        idx,
+        &[IN_KEYWORD, FROM_KEYWORD, WITH_KEYWORD]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx_after_keyword);
+
+    if keyword == IN_KEYWORD {
+        let (idx_after_values, values) =
+            parse_section_from_section!(parse_parenthesized_list(tokens, idx, parse_expression));
+        return ParseCommandSectionResult::Valid(idx_after_values, PartitionBound::In(values));
+    }
+
+    if keyword == FROM_KEYWORD {
+        let (idx_after_from, from) = parse_section_from_section!(parse_parenthesized_list(
+            tokens,
+            idx,
+            parse_partition_range_value
+        ));
+        idx = idx_after_optional_whitespace(tokens, idx_after_from);
+        (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TO_KEYWORD));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        let (idx_after_to, to) = parse_section_from_section!(parse_parenthesized_list(
+            tokens,
+            idx,
+            parse_partition_range_value
+        ));
+        return ParseCommandSectionResult::Valid(
+            idx_after_to,
+            PartitionBound::Range { from: from, to: to },
+        );
+    }
+
+    (idx, _) = parse_section_from_section!(parse_open_parenthesis(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, MODULUS_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_modulus, modulus) = parse_section_from_section!(parse_expression(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_modulus);
+    (idx, _) = parse_section_from_section!(parse_comma(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, REMAINDER_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_remainder, remainder) =
+        parse_section_from_section!(parse_expression(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_remainder);
+    (idx, _) = parse_section_from_section!(parse_close_parenthesis(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        PartitionBound::Hash {
+            modulus: modulus,
+            remainder: remainder,
+        },
+    );
+}
+
+fn parse_partition_range_value(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<PartitionRangeValue> {
+    if let ParseCommandSectionResult::Valid(idx_after, keyword) =
+        parse_one_of_keywords(tokens, start_idx, &[MINVALUE_KEYWORD, MAXVALUE_KEYWORD])
+    {
+        let value = match keyword.as_str() {
+            MINVALUE_KEYWORD => PartitionRangeValue::MinValue,
+            _ => PartitionRangeValue::MaxValue,
+        };
+        return ParseCommandSectionResult::Valid(idx_after, value);
+    }
+    let (idx_after, value) = parse_section_from_section!(parse_expression(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, PartitionRangeValue::Value(value));
+}
@@ -0,0 +1,373 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    Identifier, Privilege, PrivilegeKind, PrivilegeObject, PrivilegeObjectType, PrivilegeTarget,
+    Privileges, QualifiedName, RoleSpecification,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::function_parameter::parse_function_signature;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, ALL_KEYWORD, ALTER_KEYWORD, BY_KEYWORD,
+    CONNECT_KEYWORD, CREATE_KEYWORD, DATABASE_KEYWORD, DELETE_KEYWORD, DOMAIN_KEYWORD,
+    EXECUTE_KEYWORD, FUNCTIONS_KEYWORD, FUNCTION_KEYWORD, GRANTED_KEYWORD, INSERT_KEYWORD,
+    IN_KEYWORD, LANGUAGE_KEYWORD, MAINTAIN_KEYWORD, PRIVILEGES_KEYWORD, PROCEDURES_KEYWORD,
+    PROCEDURE_KEYWORD, REFERENCES_KEYWORD, ROUTINES_KEYWORD, ROUTINE_KEYWORD, SCHEMAS_KEYWORD,
+    SCHEMA_KEYWORD, SELECT_KEYWORD, SEQUENCES_KEYWORD, SEQUENCE_KEYWORD, SET_KEYWORD,
+    SYSTEM_KEYWORD, TABLESPACE_KEYWORD, TABLES_KEYWORD, TABLE_KEYWORD, TEMPORARY_KEYWORD,
+    TEMP_KEYWORD, TRIGGER_KEYWORD, TRUNCATE_KEYWORD, TYPES_KEYWORD, TYPE_KEYWORD, UPDATE_KEYWORD,
+    USAGE_KEYWORD,
+};
+use crate::parser::commands::sections::list::{
+    parse_comma_separated_list, parse_parenthesized_list,
+};
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::role_specification::{
+    parse_grantee, parse_role_specification,
+};
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// ALL [PRIVILEGES] [(column, ...)] or privilege [(column, ...)], ...
+pub fn parse_privileges(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Privileges> {
+    if let ParseCommandSectionResult::Valid(idx_after_all, _) =
+        parse_keyword(tokens, start_idx, ALL_KEYWORD)
+    {
+        let mut idx = idx_after_all;
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_privileges, _) =
+            parse_keyword(tokens, idx_after_whitespace, PRIVILEGES_KEYWORD)
+        {
+            idx = idx_after_privileges;
+        }
+        let (idx_after_columns, columns) =
+            parse_section_from_section!(parse_optional_columns(tokens, idx));
+        return ParseCommandSectionResult::Valid(idx_after_columns, Privileges::All(columns));
+    }
+    let (idx_after, privileges) = parse_section_from_section!(parse_comma_separated_list(
+        tokens,
+        start_idx,
+        parse_privilege
+    ));
+    return ParseCommandSectionResult::Valid(idx_after, Privileges::List(privileges));
+}
+
+fn parse_privilege(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Privilege> {
+    let idx;
+    let kind;
+    if let ParseCommandSectionResult::Valid(idx_after_alter_system, _) =
+        parse_keywords(tokens, start_idx, &[ALTER_KEYWORD, SYSTEM_KEYWORD])
+    {
+        idx = idx_after_alter_system;
+        kind = PrivilegeKind::AlterSystem;
+    } else {
+        let keyword;
+        (idx, keyword) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            start_idx,
+            &[
+                SELECT_KEYWORD,
+                INSERT_KEYWORD,
+                UPDATE_KEYWORD,
+                DELETE_KEYWORD,
+                TRUNCATE_KEYWORD,
+                REFERENCES_KEYWORD,
+                TRIGGER_KEYWORD,
+                MAINTAIN_KEYWORD,
+                CREATE_KEYWORD,
+                CONNECT_KEYWORD,
+                TEMPORARY_KEYWORD,
+                TEMP_KEYWORD,
+                EXECUTE_KEYWORD,
+                USAGE_KEYWORD,
+                SET_KEYWORD,
+            ]
+        ));
+        kind = match keyword.as_str() {
+            SELECT_KEYWORD => PrivilegeKind::Select,
+            INSERT_KEYWORD => PrivilegeKind::Insert,
+            UPDATE_KEYWORD => PrivilegeKind::Update,
+            DELETE_KEYWORD => PrivilegeKind::Delete,
+            TRUNCATE_KEYWORD => PrivilegeKind::Truncate,
+            REFERENCES_KEYWORD => PrivilegeKind::References,
+            TRIGGER_KEYWORD => PrivilegeKind::Trigger,
+            MAINTAIN_KEYWORD => PrivilegeKind::Maintain,
+            CREATE_KEYWORD => PrivilegeKind::Create,
+            CONNECT_KEYWORD => PrivilegeKind::Connect,
+            TEMPORARY_KEYWORD | TEMP_KEYWORD => PrivilegeKind::Temporary,
+            EXECUTE_KEYWORD => PrivilegeKind::Execute,
+            USAGE_KEYWORD => PrivilegeKind::Usage,
+            _ => PrivilegeKind::Set,
+        };
+    }
+    let (idx_after_columns, columns) =
+        parse_section_from_section!(parse_optional_columns(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after_columns,
+        Privilege {
+            kind: kind,
+            columns: columns,
+        },
+    );
+}
+
+// [(column, ...)], without consuming the whitespace before a missing list
+fn parse_optional_columns(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<Identifier>> {
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, start_idx);
+    if let ParseCommandSectionResult::Valid(idx_after_columns, columns) =
+        parse_parenthesized_list(tokens, idx_after_whitespace, parse_identifier)
+    {
+        return ParseCommandSectionResult::Valid(idx_after_columns, columns);
+    }
+    return ParseCommandSectionResult::Valid(start_idx, Vec::new());
+}
+
+// The objects after ON in GRANT and REVOKE, i.e. [object_type] object, ...
+// or ALL {TABLES | SEQUENCES | FUNCTIONS | PROCEDURES | ROUTINES} IN SCHEMA
+// schema, ...
+pub fn parse_privilege_target(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<PrivilegeTarget> {
+    if let ParseCommandSectionResult::Valid(idx_after_all, _) =
+        parse_keyword(tokens, start_idx, ALL_KEYWORD)
+    {
+        let mut idx = idx_after_optional_whitespace(tokens, idx_after_all);
+        let object_type;
+        (idx, object_type) = parse_section_from_section!(parse_plural_object_type(
+            tokens,
+            idx,
+            &[
+                TABLES_KEYWORD,
+                SEQUENCES_KEYWORD,
+                FUNCTIONS_KEYWORD,
+                PROCEDURES_KEYWORD,
+                ROUTINES_KEYWORD,
+            ]
+        ));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        (idx, _) =
+            parse_section_from_section!(parse_keywords(tokens, idx, &[IN_KEYWORD, SCHEMA_KEYWORD]));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        let schemas;
+        (idx, schemas) =
+            parse_section_from_section!(parse_comma_separated_list(tokens, idx, parse_identifier));
+        return ParseCommandSectionResult::Valid(
+            idx,
+            PrivilegeTarget::AllInSchema {
+                object_type: object_type,
+                schemas: schemas,
+            },
+        );
+    }
+
+    let mut idx = start_idx;
+    let mut object_type = PrivilegeObjectType::Table;
+    if let ParseCommandSectionResult::Valid(idx_after_object_type, keyword) = parse_one_of_keywords(
+        tokens,
+        idx,
+        &[
+            TABLE_KEYWORD,
+            SEQUENCE_KEYWORD,
+            DATABASE_KEYWORD,
+            DOMAIN_KEYWORD,
+            FUNCTION_KEYWORD,
+            PROCEDURE_KEYWORD,
+            ROUTINE_KEYWORD,
+            LANGUAGE_KEYWORD,
+            SCHEMA_KEYWORD,
+            TABLESPACE_KEYWORD,
+            TYPE_KEYWORD,
+        ],
+    ) {
+        idx = idx_after_optional_whitespace(tokens, idx_after_object_type);
+        object_type = match keyword.as_str() {
+            TABLE_KEYWORD => PrivilegeObjectType::Table,
+            SEQUENCE_KEYWORD => PrivilegeObjectType::Sequence,
+            DATABASE_KEYWORD => PrivilegeObjectType::Database,
+            DOMAIN_KEYWORD => PrivilegeObjectType::Domain,
+            FUNCTION_KEYWORD => PrivilegeObjectType::Function,
+            PROCEDURE_KEYWORD => PrivilegeObjectType::Procedure,
+            ROUTINE_KEYWORD => PrivilegeObjectType::Routine,
+            LANGUAGE_KEYWORD => PrivilegeObjectType::Language,
+            SCHEMA_KEYWORD => PrivilegeObjectType::Schema,
+            TABLESPACE_KEYWORD => PrivilegeObjectType::Tablespace,
+            _ => PrivilegeObjectType::Type,
+        };
+    }
+
+    let objects;
+    (idx, objects) = parse_section_from_section!(parse_comma_separated_list(
+        tokens,
+        idx,
+        match object_type {
+            PrivilegeObjectType::Function
+            | PrivilegeObjectType::Procedure
+            | PrivilegeObjectType::Routine => parse_routine_object,
+            PrivilegeObjectType::Database
+            | PrivilegeObjectType::Language
+            | PrivilegeObjectType::Schema
+            | PrivilegeObjectType::Tablespace => parse_unqualified_object,
+            _ => parse_qualified_object,
+        }
+    ));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        PrivilegeTarget::Objects {
+            object_type: object_type,
+            objects: objects,
+        },
+    );
+}
+
+// One of the given plural object type keywords, e.g. TABLES, as in
+// ALL TABLES IN SCHEMA or ALTER DEFAULT PRIVILEGES ... ON TABLES
+pub fn parse_plural_object_type(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    keywords: &[&str],
+) -> ParseCommandSectionResult<PrivilegeObjectType> {
+    let (idx_after, keyword) =
+        parse_section_from_section!(parse_one_of_keywords(tokens, start_idx, keywords));
+    let object_type = match keyword.as_str() {
+        TABLES_KEYWORD => PrivilegeObjectType::Table,
+        SEQUENCES_KEYWORD => PrivilegeObjectType::Sequence,
+        FUNCTIONS_KEYWORD => PrivilegeObjectType::Function,
+        PROCEDURES_KEYWORD => PrivilegeObjectType::Procedure,
+        ROUTINES_KEYWORD => PrivilegeObjectType::Routine,
+        TYPES_KEYWORD => PrivilegeObjectType::Type,
+        SCHEMAS_KEYWORD => PrivilegeObjectType::Schema,
+        _ => return ParseCommandSectionResult::Invalid,
+    };
+    return ParseCommandSectionResult::Valid(idx_after, object_type);
+}
+
+fn privilege_object(name: QualifiedName) -> PrivilegeObject {
+    return PrivilegeObject {
+        name: name,
+        parameters: None,
+    };
+}
+
+// [schema.]name
+fn parse_qualified_object(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<PrivilegeObject> {
+    let (idx_after, name) = parse_section_from_section!(parse_qualified_name(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, privilege_object(name));
+}
+
+// name, for objects such as schemas and databases that do not belong to a
+// schema
+fn parse_unqualified_object(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<PrivilegeObject> {
+    let (idx_after, name) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        privilege_object(QualifiedName {
+            schema_name: None,
+            name: name,
+        }),
+    );
+}
+
+// [schema.]name [([parameter, ...])]
+fn parse_routine_object(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<PrivilegeObject> {
+    let (mut idx, mut object) =
+        parse_section_from_section!(parse_qualified_object(tokens, start_idx));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_signature, parameters) =
+        parse_function_signature(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_signature;
+        object.parameters = Some(parameters);
+    }
+    return ParseCommandSectionResult::Valid(idx, object);
+}
+
+// grantee, ..., as in GRANT ... TO grantee, ...
+pub fn parse_grantees(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<RoleSpecification>> {
+    return parse_comma_separated_list(tokens, start_idx, parse_grantee);
+}
+
+// GRANTED BY role
+pub fn parse_granted_by(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<RoleSpecification> {
+    let (idx_after_granted_by, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        start_idx,
+        &[GRANTED_KEYWORD, BY_KEYWORD]
+    ));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_granted_by);
+    return parse_role_specification(tokens, idx_after_whitespace);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tokenize_postgresql;
+
+    fn identifier(value: &str) -> Identifier {
+        return Identifier {
+            quoted: false,
+            value: String::from(value),
+        };
+    }
+
+    #[test]
+    fn privileges_with_columns() {
+        let tokens = tokenize_postgresql("select, update (name, email), alter system on");
+        assert_eq!(
+            parse_privileges(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                16,
+                Privileges::List(vec![
+                    Privilege {
+                        kind: PrivilegeKind::Select,
+                        columns: vec![],
+                    },
+                    Privilege {
+                        kind: PrivilegeKind::Update,
+                        columns: vec![identifier("name"), identifier("email")],
+                    },
+                    Privilege {
+                        kind: PrivilegeKind::AlterSystem,
+                        columns: vec![],
+                    },
+                ])
+            )
+        );
+    }
+
+    #[test]
+    fn privilege_target_all_in_schema() {
+        let tokens = tokenize_postgresql("all tables in schema app, audit to");
+        assert_eq!(
+            parse_privilege_target(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                12,
+                PrivilegeTarget::AllInSchema {
+                    object_type: PrivilegeObjectType::Table,
+                    schemas: vec![identifier("app"), identifier("audit")],
+                }
+            )
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Identifier, QualifiedName};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::dot_separated_value::{
+    parse_dot_separated_value, validate_separated_values_len,
+};
+use crate::parser::commands::sections::identifier::{
+    parse_identifiers_from_dot_separated_values, SimpleParseResult,
+};
+
+pub fn parse_qualified_name(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<QualifiedName> {
+    let (idx_after, separated_values) =
+        parse_section_from_section!(parse_dot_separated_value(tokens, start_idx, 1));
+    validate_separated_values_len(&separated_values, 2);
+
+    let identifiers = match parse_identifiers_from_dot_separated_values(separated_values) {
+        SimpleParseResult::Valid(identifiers) => identifiers,
+        SimpleParseResult::Invalid => return ParseCommandSectionResult::Invalid,
+    };
+
+    let mut schema_name: Option<Identifier> = None;
+    if identifiers.len() == 2 {
+        schema_name = Some(identifiers.get(0).unwrap().clone());
+    }
+    let name = identifiers.last().unwrap().clone();
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        QualifiedName {
+            schema_name: schema_name,
+            name: name,
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::tokens_from_str_vector;
+
+    #[test]
+    fn qualified_name_with_schema() {
+        assert_eq!(
+            parse_qualified_name(
+                &tokens_from_str_vector(vec!["public", ".", "\"Teacher\""]),
+                0
+            ),
+            ParseCommandSectionResult::Valid(
+                3,
+                QualifiedName {
+                    schema_name: Some(Identifier {
+                        quoted: false,
+                        value: String::from("public")
+                    }),
+                    name: Identifier {
+                        quoted: true,
+                        value: String::from("Teacher")
+                    }
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn qualified_name_too_many_parts() {
+        assert_eq!(
+            parse_qualified_name(&tokens_from_str_vector(vec!["a", ".", "b", ".", "c"]), 0),
+            ParseCommandSectionResult::Valid(
+                3,
+                QualifiedName {
+                    schema_name: Some(Identifier {
+                        quoted: false,
+                        value: String::from("a")
+                    }),
+                    name: Identifier {
+                        quoted: false,
+                        value: String::from("b")
+                    }
+                }
+            )
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    Command, DataManipulationCommand, Expression, LockingClause, Query, SetOperation, SetOperator,
+    SortExpression,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_one_of_keywords, ALL_KEYWORD, DISTINCT_KEYWORD, EXCEPT_KEYWORD,
+    INTERSECT_KEYWORD, SELECT_KEYWORD, UNION_KEYWORD, VALUES_KEYWORD,
+};
+use crate::parser::commands::sections::limit::{parse_limit_clause, parse_offset_clause};
+use crate::parser::commands::sections::locking::parse_locking_clause;
+use crate::parser::commands::sections::order_by::parse_order_by_clause;
+use crate::parser::commands::sections::parenthesis::{
+    parse_close_parenthesis, parse_open_parenthesis,
+};
+use crate::parser::commands::select::parse_select;
+use crate::parser::commands::values::parse_values;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+const QUERY_KEYWORDS: [&str; 2] = [SELECT_KEYWORD, VALUES_KEYWORD];
+
+pub fn token_starts_query(token: Option<&Token>) -> bool {
+    return match token {
+        Some(token) => QUERY_KEYWORDS.contains(&token.value.to_ascii_lowercase().as_str()),
+        None => false,
+    };
+}
+
+// A query that can be embedded in another command, e.g. INSERT ... SELECT or
+// a subquery expression
+pub fn parse_query(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Query> {
+    let (idx_after_operand, first_operand) =
+        parse_section_from_section!(parse_query_operand(tokens, start_idx));
+    return parse_query_continuation(tokens, idx_after_operand, first_operand);
+}
+
+pub fn parse_parenthesized_query(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Query> {
+    let mut idx = start_idx;
+    let query: Query;
+    (idx, _) = parse_section_from_section!(parse_open_parenthesis(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, query) = parse_section_from_section!(parse_query(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_close_parenthesis(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx, query);
+}
+
+// Parses the set operations, ORDER BY, LIMIT, OFFSET and locking clauses that
+// can follow the first operand of a query
+pub fn parse_query_continuation(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    first_operand: Query,
+) -> ParseCommandSectionResult<Query> {
+    let (mut idx, mut query) =
+        parse_section_from_section!(parse_set_operations(tokens, start_idx, first_operand, 0));
+
+    let mut order_by: Vec<SortExpression> = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_order_by, sort_expressions) =
+        parse_order_by_clause(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_order_by;
+        order_by = sort_expressions;
+    }
+
+    // LIMIT, OFFSET and locking clauses can be given in any order
+    let mut limit: Option<Expression> = None;
+    let mut offset: Option<Expression> = None;
+    let mut locking: Vec<LockingClause> = Vec::new();
+    loop {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_limit, count) =
+            parse_limit_clause(tokens, idx_after_whitespace)
+        {
+            if limit.is_some() {
+                return ParseCommandSectionResult::Invalid;
+            }
+            idx = idx_after_limit;
+            limit = count;
+            continue;
+        }
+        if let ParseCommandSectionResult::Valid(idx_after_offset, start) =
+            parse_offset_clause(tokens, idx_after_whitespace)
+        {
+            if offset.is_some() {
+                return ParseCommandSectionResult::Invalid;
+            }
+            idx = idx_after_offset;
+            offset = Some(start);
+            continue;
+        }
+        if let ParseCommandSectionResult::Valid(idx_after_locking, locking_clause) =
+            parse_locking_clause(tokens, idx_after_whitespace)
+        {
+            idx = idx_after_locking;
+            locking.push(locking_clause);
+            continue;
+        }
+        break;
+    }
+
+    // Rows can only be locked by a plain SELECT, not by VALUES or the result
+    // of a set operation
+    if !locking.is_empty() {
+        match &mut query {
+            Query::Select(select_command) => select_command.locking.append(&mut locking),
+            Query::Values(_) | Query::SetOperation(_) => {
+                return ParseCommandSectionResult::Invalid;
+            }
+        }
+    }
+
+    // A parenthesized operand may already have its own clauses, e.g.
+    // (SELECT ... LIMIT 1) LIMIT 2, which PostgreSQL rejects
+    let (query_order_by, query_limit, query_offset) = match &mut query {
+        Query::Select(select_command) => (
+            &mut select_command.order_by,
+            &mut select_command.limit,
+            &mut select_command.offset,
+        ),
+        Query::Values(values_command) => (
+            &mut values_command.order_by,
+            &mut values_command.limit,
+            &mut values_command.offset,
+        ),
+        Query::SetOperation(set_operation) => (
+            &mut set_operation.order_by,
+            &mut set_operation.limit,
+            &mut set_operation.offset,
+        ),
+    };
+    if (!order_by.is_empty() && !query_order_by.is_empty())
+        || (limit.is_some() && query_limit.is_some())
+        || (offset.is_some() && query_offset.is_some())
+    {
+        return ParseCommandSectionResult::Invalid;
+    }
+    if !order_by.is_empty() {
+        *query_order_by = order_by;
+    }
+    if limit.is_some() {
+        *query_limit = limit;
+    }
+    if offset.is_some() {
+        *query_offset = offset;
+    }
+
+    return ParseCommandSectionResult::Valid(idx, query);
+}
+
+pub fn query_into_command(query: Query) -> Command {
+    return Command::DataManipulation(match query {
+        Query::Select(select_command) => DataManipulationCommand::Select(select_command),
+        Query::Values(values_command) => DataManipulationCommand::Values(values_command),
+        Query::SetOperation(set_operation) => DataManipulationCommand::SetOperation(set_operation),
+    });
+}
+
+// SELECT ..., VALUES ... or a parenthesized query
+fn parse_query_operand(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Query> {
+    if let ParseCommandSectionResult::Valid(idx_after_select, _) =
+        parse_keyword(tokens, start_idx, SELECT_KEYWORD)
+    {
+        let (idx_after, select_command) =
+            parse_section_from_section!(parse_select(tokens, idx_after_select));
+        return ParseCommandSectionResult::Valid(idx_after, Query::Select(select_command));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_values, _) =
+        parse_keyword(tokens, start_idx, VALUES_KEYWORD)
+    {
+        let (idx_after, values_command) =
+            parse_section_from_section!(parse_values(tokens, idx_after_values));
+        return ParseCommandSectionResult::Valid(idx_after, Query::Values(values_command));
+    }
+    return parse_parenthesized_query(tokens, start_idx);
+}
+
+// INTERSECT binds more tightly than UNION and EXCEPT
+fn set_operator_precedence(operator: &SetOperator) -> u8 {
+    return match operator {
+        SetOperator::Union | SetOperator::Except => 1,
+        SetOperator::Intersect => 2,
+    };
+}
+
+fn parse_set_operations(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    first_operand: Query,
+    min_precedence: u8,
+) -> ParseCommandSectionResult<Query> {
+    let mut idx = start_idx;
+    let mut left = first_operand;
+    loop {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        let (idx_after_operator, (operator, all)) =
+            match parse_set_operator(tokens, idx_after_whitespace) {
+                ParseCommandSectionResult::Valid(idx_after, (operator, all))
+                    if set_operator_precedence(&operator) >= min_precedence =>
+                {
+                    (idx_after, (operator, all))
+                }
+                _ => break,
+            };
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_operator);
+        let (idx_after_operand, operand) =
+            parse_section_from_section!(parse_query_operand(tokens, idx_after_whitespace));
+        let (idx_after_right, right) = parse_section_from_section!(parse_set_operations(
+            tokens,
+            idx_after_operand,
+            operand,
+            set_operator_precedence(&operator) + 1
+        ));
+        idx = idx_after_right;
+        left = Query::SetOperation(SetOperation {
+            left: Box::new(left),
+            operator: operator,
+            all: all,
+            right: Box::new(right),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        });
+    }
+    return ParseCommandSectionResult::Valid(idx, left);
+}
+
+// {UNION | INTERSECT | EXCEPT} [ALL | DISTINCT]
+fn parse_set_operator(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<(SetOperator, bool)> {
+    let (mut idx, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[UNION_KEYWORD, INTERSECT_KEYWORD, EXCEPT_KEYWORD]
+    ));
+    let operator = match keyword.as_str() {
+        UNION_KEYWORD => SetOperator::Union,
+        INTERSECT_KEYWORD => SetOperator::Intersect,
+        _ => SetOperator::Except,
+    };
+    let mut all = false;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_quantifier, quantifier) =
+        parse_one_of_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[ALL_KEYWORD, DISTINCT_KEYWORD],
+        )
+    {
+        idx = idx_after_quantifier;
+        all = quantifier == ALL_KEYWORD;
+    }
+    return ParseCommandSectionResult::Valid(idx, (operator, all));
+}
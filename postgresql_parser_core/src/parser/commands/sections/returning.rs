@@ -0,0 +1,48 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Identifier, OutputExpression, SelectedExpression};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::alias::parse_alias;
+use crate::parser::commands::sections::keywords::{parse_keyword, RETURNING_KEYWORD};
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::commands::sections::selected_expressions::parse_selected_expression;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// RETURNING output_expression [[AS] output_name], ...
+pub fn parse_returning_clause(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<OutputExpression>> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, RETURNING_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    return parse_comma_separated_list(tokens, idx, parse_output_expression);
+}
+
+fn parse_output_expression(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<OutputExpression> {
+    let (mut idx, expression) =
+        parse_section_from_section!(parse_selected_expression(tokens, start_idx));
+    let mut output_name: Option<Identifier> = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_name, name) =
+        parse_alias(tokens, idx_after_whitespace)
+    {
+        // * expands to several columns, so it can't be given a name
+        if let SelectedExpression::AllColumns(_) = expression {
+            return ParseCommandSectionResult::Invalid;
+        }
+        idx = idx_after_name;
+        output_name = Some(name);
+    }
+    return ParseCommandSectionResult::Valid(
+        idx,
+        OutputExpression {
+            expression: expression,
+            output_name: output_name,
+        },
+    );
+}
@@ -0,0 +1,55 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::RoleSpecification;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_one_of_keywords, CURRENT_ROLE_KEYWORD, CURRENT_USER_KEYWORD,
+    GROUP_KEYWORD, PUBLIC_KEYWORD, SESSION_USER_KEYWORD,
+};
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// role_name | CURRENT_ROLE | CURRENT_USER | SESSION_USER
+pub fn parse_role_specification(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<RoleSpecification> {
+    if let ParseCommandSectionResult::Valid(idx_after, keyword) = parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[
+            CURRENT_ROLE_KEYWORD,
+            CURRENT_USER_KEYWORD,
+            SESSION_USER_KEYWORD,
+        ],
+    ) {
+        let role = match keyword.as_str() {
+            CURRENT_ROLE_KEYWORD => RoleSpecification::CurrentRole,
+            CURRENT_USER_KEYWORD => RoleSpecification::CurrentUser,
+            _ => RoleSpecification::SessionUser,
+        };
+        return ParseCommandSectionResult::Valid(idx_after, role);
+    }
+    let (idx_after, name) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, RoleSpecification::Name(name));
+}
+
+// [GROUP] role_specification | PUBLIC, as in GRANT ... TO grantee
+pub fn parse_grantee(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<RoleSpecification> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, PUBLIC_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, RoleSpecification::Public);
+    }
+    let mut idx = start_idx;
+    if let ParseCommandSectionResult::Valid(idx_after_group, _) =
+        parse_keyword(tokens, idx, GROUP_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_group);
+    }
+    return parse_role_specification(tokens, idx);
+}
@@ -1,6 +1,6 @@
 use crate::lexer::token::Token;
 use crate::parser::ast::{
-    AllColumnsSelectedExpression, ColumnSelectedExpression, Identifier, SelectedExpression,
+    AllColumnsSelectedExpression, Expression, Identifier, SelectedExpression,
 };
 use crate::parser::commands::parse_section::{
     parse_section_from_section, ParseCommandSectionResult,
@@ -9,69 +9,66 @@ use crate::parser::commands::sections::comma::parse_comma;
 use crate::parser::commands::sections::dot_separated_value::{
     parse_dot_separated_value, validate_separated_values_len,
 };
+use crate::parser::commands::sections::expression::parse_expression;
 use crate::parser::commands::sections::identifier::{
     parse_identifier_token_value, SimpleParseResult,
 };
 use crate::parser::utils::idx_after_optional_whitespace;
 
-fn parse_selected_expression(
+pub fn parse_selected_expression(
     tokens: &Vec<Token>,
     start_idx: usize,
 ) -> ParseCommandSectionResult<SelectedExpression> {
+    if let ParseCommandSectionResult::Valid(idx_after, all_columns) =
+        parse_all_columns_selected_expression(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            SelectedExpression::AllColumns(all_columns),
+        );
+    }
+    let (idx_after, expression) = parse_section_from_section!(parse_expression(tokens, start_idx));
+    let selected_expression = match expression {
+        Expression::Column(column) => SelectedExpression::Column(column),
+        expression => SelectedExpression::Expression(expression),
+    };
+    return ParseCommandSectionResult::Valid(idx_after, selected_expression);
+}
+
+pub fn parse_all_columns_selected_expression(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<AllColumnsSelectedExpression> {
     let (idx_after, separated_values) =
         parse_section_from_section!(parse_dot_separated_value(tokens, start_idx, 2));
     validate_separated_values_len(&separated_values, 3);
 
-    let mut column_is_star = false;
+    if separated_values.last().unwrap() != "*" {
+        return ParseCommandSectionResult::Invalid;
+    }
 
     let mut identifiers: Vec<Identifier> = Vec::new();
-    for (idx, value) in separated_values.iter().enumerate() {
+    for value in separated_values.iter().take(separated_values.len() - 1) {
         match parse_identifier_token_value(&value) {
             SimpleParseResult::Valid(identifier) => identifiers.push(identifier),
-            SimpleParseResult::Invalid => {
-                if idx == separated_values.len() - 1 && value == "*" {
-                    column_is_star = true
-                } else {
-                    return ParseCommandSectionResult::Invalid;
-                }
-            }
+            SimpleParseResult::Invalid => return ParseCommandSectionResult::Invalid,
         }
     }
 
-    if column_is_star {
-        let mut schema_name: Option<Identifier> = None;
-        let mut table_name: Option<Identifier> = None;
-        if identifiers.len() == 2 {
-            schema_name = Some(identifiers.get(0).unwrap().clone());
-            table_name = Some(identifiers.last().unwrap().clone());
-        } else if identifiers.len() == 1 {
-            table_name = Some(identifiers.last().unwrap().clone());
-        }
-        return ParseCommandSectionResult::Valid(
-            idx_after,
-            SelectedExpression::AllColumns(AllColumnsSelectedExpression {
-                schema_name: schema_name,
-                table_name: table_name,
-            }),
-        );
-    }
-
     let mut schema_name: Option<Identifier> = None;
     let mut table_name: Option<Identifier> = None;
-    if identifiers.len() == 3 {
+    if identifiers.len() == 2 {
         schema_name = Some(identifiers.get(0).unwrap().clone());
-        table_name = Some(identifiers.get(1).unwrap().clone());
-    } else if separated_values.len() == 2 {
-        table_name = Some(identifiers.get(0).unwrap().clone())
-    };
-    let column = identifiers.last().unwrap().clone();
+        table_name = Some(identifiers.last().unwrap().clone());
+    } else if identifiers.len() == 1 {
+        table_name = Some(identifiers.last().unwrap().clone());
+    }
     return ParseCommandSectionResult::Valid(
         idx_after,
-        SelectedExpression::Column(ColumnSelectedExpression {
+        AllColumnsSelectedExpression {
             schema_name: schema_name,
             table_name: table_name,
-            column_name: column,
-        }),
+        },
     );
 }
 
@@ -103,7 +100,7 @@ pub fn parse_selected_expressions(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::parser::ast::{AllColumnsSelectedExpression, ColumnSelectedExpression};
+    use crate::parser::ast::ColumnSelectedExpression;
     use crate::test_utils::tokens_from_str_vector;
 
     #[test]
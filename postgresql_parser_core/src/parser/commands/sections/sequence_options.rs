@@ -0,0 +1,284 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{ColumnSelectedExpression, Expression, SequenceOption};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::dot_separated_value::{
+    parse_dot_separated_value, validate_separated_values_len,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::identifier::{
+    parse_identifiers_from_dot_separated_values, SimpleParseResult,
+};
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, AS_KEYWORD, BY_KEYWORD, CACHE_KEYWORD,
+    CYCLE_KEYWORD, INCREMENT_KEYWORD, MAXVALUE_KEYWORD, MINVALUE_KEYWORD, NAME_KEYWORD,
+    NONE_KEYWORD, NO_KEYWORD, OWNED_KEYWORD, RESTART_KEYWORD, SEQUENCE_KEYWORD, START_KEYWORD,
+    WITH_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_whitespace_separated_list;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::type_name::parse_type_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Zero or more sequence options separated by whitespace
+pub fn parse_sequence_options(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<SequenceOption>> {
+    return parse_whitespace_separated_list(tokens, start_idx, parse_sequence_option);
+}
+
+pub fn parse_sequence_option(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<SequenceOption> {
+    if let ParseCommandSectionResult::Valid(idx_after_as, _) =
+        parse_keyword(tokens, start_idx, AS_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_as);
+        let (idx_after, type_name) =
+            parse_section_from_section!(parse_type_name(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, SequenceOption::As(type_name));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_increment, _) =
+        parse_keyword(tokens, start_idx, INCREMENT_KEYWORD)
+    {
+        let (idx_after, increment) = parse_section_from_section!(parse_optional_keyword_and_value(
+            tokens,
+            idx_after_increment,
+            BY_KEYWORD
+        ));
+        return ParseCommandSectionResult::Valid(idx_after, SequenceOption::IncrementBy(increment));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_start, _) =
+        parse_keyword(tokens, start_idx, START_KEYWORD)
+    {
+        let (idx_after, start) = parse_section_from_section!(parse_optional_keyword_and_value(
+            tokens,
+            idx_after_start,
+            WITH_KEYWORD
+        ));
+        return ParseCommandSectionResult::Valid(idx_after, SequenceOption::StartWith(start));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_no, _) =
+        parse_keyword(tokens, start_idx, NO_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_no);
+        let (idx_after, keyword) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[MINVALUE_KEYWORD, MAXVALUE_KEYWORD, CYCLE_KEYWORD]
+        ));
+        let option = match keyword.as_str() {
+            MINVALUE_KEYWORD => SequenceOption::MinValue(None),
+            MAXVALUE_KEYWORD => SequenceOption::MaxValue(None),
+            _ => SequenceOption::Cycle(false),
+        };
+        return ParseCommandSectionResult::Valid(idx_after, option);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, CYCLE_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, SequenceOption::Cycle(true));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_keyword, keyword) = parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[MINVALUE_KEYWORD, MAXVALUE_KEYWORD, CACHE_KEYWORD],
+    ) {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_keyword);
+        let (idx_after, value) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        let option = match keyword.as_str() {
+            MINVALUE_KEYWORD => SequenceOption::MinValue(Some(value)),
+            MAXVALUE_KEYWORD => SequenceOption::MaxValue(Some(value)),
+            _ => SequenceOption::Cache(value),
+        };
+        return ParseCommandSectionResult::Valid(idx_after, option);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_restart, _) =
+        parse_keyword(tokens, start_idx, RESTART_KEYWORD)
+    {
+        let (idx_after, restart) =
+            parse_section_from_section!(parse_restart_value(tokens, idx_after_restart));
+        return ParseCommandSectionResult::Valid(idx_after, SequenceOption::Restart(restart));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_owned_by, _) =
+        parse_keywords(tokens, start_idx, &[OWNED_KEYWORD, BY_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_owned_by);
+        if let ParseCommandSectionResult::Valid(idx_after, _) =
+            parse_keyword(tokens, idx_after_whitespace, NONE_KEYWORD)
+        {
+            return ParseCommandSectionResult::Valid(idx_after, SequenceOption::OwnedBy(None));
+        }
+        let (idx_after, column) =
+            parse_section_from_section!(parse_owning_column(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, SequenceOption::OwnedBy(Some(column)));
+    }
+    let (idx_after_sequence_name, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        start_idx,
+        &[SEQUENCE_KEYWORD, NAME_KEYWORD]
+    ));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_sequence_name);
+    let (idx_after, sequence_name) =
+        parse_section_from_section!(parse_qualified_name(tokens, idx_after_whitespace));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        SequenceOption::SequenceName(sequence_name),
+    );
+}
+
+// [keyword] value, as the BY in INCREMENT [BY] 1
+fn parse_optional_keyword_and_value(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    keyword: &str,
+) -> ParseCommandSectionResult<Expression> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    if let ParseCommandSectionResult::Valid(idx_after_keyword, _) =
+        parse_keyword(tokens, idx, keyword)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_keyword);
+    }
+    return parse_expression(tokens, idx);
+}
+
+// [[WITH] value] after RESTART. Without WITH, a following option is not
+// taken for the value, as in RESTART CACHE 10.
+fn parse_restart_value(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Option<Expression>> {
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, start_idx);
+    if let ParseCommandSectionResult::Valid(idx_after_with, _) =
+        parse_keyword(tokens, idx_after_whitespace, WITH_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_with);
+        let (idx_after, value) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, Some(value));
+    }
+    if let ParseCommandSectionResult::Valid(_, _) =
+        parse_sequence_option(tokens, idx_after_whitespace)
+    {
+        return ParseCommandSectionResult::Valid(start_idx, None);
+    }
+    return match parse_expression(tokens, idx_after_whitespace) {
+        ParseCommandSectionResult::Valid(idx_after, value) => {
+            ParseCommandSectionResult::Valid(idx_after, Some(value))
+        }
+        _ => ParseCommandSectionResult::Valid(start_idx, None),
+    };
+}
+
+// [schema.]table.column
+fn parse_owning_column(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ColumnSelectedExpression> {
+    let (idx_after, separated_values) =
+        parse_section_from_section!(parse_dot_separated_value(tokens, start_idx, 2));
+    validate_separated_values_len(&separated_values, 3);
+    if separated_values.len() < 2 {
+        return ParseCommandSectionResult::Invalid;
+    }
+    let mut identifiers = match parse_identifiers_from_dot_separated_values(separated_values) {
+        SimpleParseResult::Valid(identifiers) => identifiers,
+        SimpleParseResult::Invalid => return ParseCommandSectionResult::Invalid,
+    };
+    let column_name = identifiers.pop().unwrap();
+    let table_name = identifiers.pop();
+    let schema_name = identifiers.pop();
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        ColumnSelectedExpression {
+            schema_name: schema_name,
+            table_name: table_name,
+            column_name: column_name,
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tokenize_postgresql;
+    use crate::parser::ast::{Identifier, Literal, UnaryOperation};
+
+    fn number(value: &str) -> Expression {
+        return Expression::Literal(Literal::Number(String::from(value)));
+    }
+
+    #[test]
+    fn sequence_options() {
+        let tokens = tokenize_postgresql(
+            "INCREMENT BY 2 START 10 NO MINVALUE MAXVALUE 100 CACHE 5 NO CYCLE OWNED BY t.id",
+        );
+        assert_eq!(
+            parse_sequence_options(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                33,
+                vec![
+                    SequenceOption::IncrementBy(number("2")),
+                    SequenceOption::StartWith(number("10")),
+                    SequenceOption::MinValue(None),
+                    SequenceOption::MaxValue(Some(number("100"))),
+                    SequenceOption::Cache(number("5")),
+                    SequenceOption::Cycle(false),
+                    SequenceOption::OwnedBy(Some(ColumnSelectedExpression {
+                        schema_name: None,
+                        table_name: Some(Identifier {
+                            quoted: false,
+                            value: String::from("t"),
+                        }),
+                        column_name: Identifier {
+                            quoted: false,
+                            value: String::from("id"),
+                        },
+                    })),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn sequence_options_stop_at_unknown_option() {
+        let tokens = tokenize_postgresql("start with -1 increment -1 primary key");
+        assert_eq!(
+            parse_sequence_options(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                11,
+                vec![
+                    SequenceOption::StartWith(Expression::UnaryOperation(UnaryOperation {
+                        operator: String::from("-"),
+                        operand: Box::new(number("1")),
+                    })),
+                    SequenceOption::IncrementBy(Expression::UnaryOperation(UnaryOperation {
+                        operator: String::from("-"),
+                        operand: Box::new(number("1")),
+                    })),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn sequence_options_restart() {
+        let tokens = tokenize_postgresql("restart cache 10 restart with 5 restart 7");
+        assert_eq!(
+            parse_sequence_options(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                15,
+                vec![
+                    SequenceOption::Restart(None),
+                    SequenceOption::Cache(number("10")),
+                    SequenceOption::Restart(Some(number("5"))),
+                    SequenceOption::Restart(Some(number("7"))),
+                ]
+            )
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::StorageParameter;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::list::parse_parenthesized_list;
+use crate::parser::commands::sections::operator::parse_operator;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// (name [= value], ...), as in WITH (fillfactor = 70)
+pub fn parse_storage_parameters(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<StorageParameter>> {
+    return parse_parenthesized_list(tokens, start_idx, parse_storage_parameter);
+}
+
+// (name, ...), as in RESET (fillfactor)
+pub fn parse_storage_parameter_names(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<StorageParameter>> {
+    let (idx_after, names) = parse_section_from_section!(parse_parenthesized_list(
+        tokens,
+        start_idx,
+        parse_qualified_name
+    ));
+    let parameters = names
+        .into_iter()
+        .map(|name| {
+            return StorageParameter {
+                namespace: name.schema_name,
+                name: name.name,
+                value: None,
+            };
+        })
+        .collect();
+    return ParseCommandSectionResult::Valid(idx_after, parameters);
+}
+
+fn parse_storage_parameter(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<StorageParameter> {
+    // A parameter may be namespaced, as in toast.autovacuum_enabled
+    let (mut idx, name) = parse_section_from_section!(parse_qualified_name(tokens, start_idx));
+
+    let mut value = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_equals, _) =
+        parse_operator(tokens, idx_after_whitespace, "=")
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_equals);
+        let (idx_after_value, parsed_value) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        idx = idx_after_value;
+        value = Some(parsed_value);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        StorageParameter {
+            namespace: name.schema_name,
+            name: name.name,
+            value: value,
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tokenize_postgresql;
+    use crate::parser::ast::{Expression, Identifier, Literal};
+
+    fn identifier(value: &str) -> Identifier {
+        return Identifier {
+            quoted: false,
+            value: String::from(value),
+        };
+    }
+
+    #[test]
+    fn storage_parameters() {
+        let tokens = tokenize_postgresql(
+            "(fillfactor = 70, toast.autovacuum_enabled=false, security_barrier)",
+        );
+        assert_eq!(
+            parse_storage_parameters(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                17,
+                vec![
+                    StorageParameter {
+                        namespace: None,
+                        name: identifier("fillfactor"),
+                        value: Some(Expression::Literal(Literal::Number(String::from("70")))),
+                    },
+                    StorageParameter {
+                        namespace: Some(identifier("toast")),
+                        name: identifier("autovacuum_enabled"),
+                        value: Some(Expression::Literal(Literal::Boolean(false))),
+                    },
+                    StorageParameter {
+                        namespace: None,
+                        name: identifier("security_barrier"),
+                        value: None,
+                    },
+                ]
+            )
+        );
+    }
+}
@@ -0,0 +1,584 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    ExclusionElement, Expression, ForeignKeyMatch, ForeignKeyReference, Identifier,
+    ReferentialAction, TableConstraint, TableConstraintKind, TableConstraintUsingIndex,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::index_element::parse_index_element;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, ACTION_KEYWORD, CASCADE_KEYWORD,
+    CHECK_KEYWORD, CONSTRAINT_KEYWORD, DEFAULT_KEYWORD, DEFERRABLE_KEYWORD, DEFERRED_KEYWORD,
+    DELETE_KEYWORD, DISTINCT_KEYWORD, EXCLUDE_KEYWORD, FOREIGN_KEYWORD, FULL_KEYWORD,
+    IMMEDIATE_KEYWORD, INCLUDE_KEYWORD, INDEX_KEYWORD, INHERIT_KEYWORD, INITIALLY_KEYWORD,
+    KEY_KEYWORD, MATCH_KEYWORD, NOT_KEYWORD, NO_KEYWORD, NULLS_KEYWORD, NULL_KEYWORD, ON_KEYWORD,
+    PARTIAL_KEYWORD, PRIMARY_KEYWORD, REFERENCES_KEYWORD, RESTRICT_KEYWORD, SET_KEYWORD,
+    SIMPLE_KEYWORD, UNIQUE_KEYWORD, UPDATE_KEYWORD, USING_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_parenthesized_list;
+use crate::parser::commands::sections::operator::parse_any_operator;
+use crate::parser::commands::sections::parenthesis::{
+    parse_close_parenthesis, parse_open_parenthesis,
+};
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::where_clause::parse_where_clause;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// [CONSTRAINT name] {CHECK | UNIQUE | PRIMARY KEY | EXCLUDE | FOREIGN KEY} ...
+// [[NOT] DEFERRABLE] [INITIALLY {DEFERRED | IMMEDIATE}]
+pub fn parse_table_constraint(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TableConstraint> {
+    let mut idx = start_idx;
+
+    let mut name = None;
+    if let ParseCommandSectionResult::Valid(idx_after_name, constraint_name) =
+        parse_constraint_name(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_name);
+        name = Some(constraint_name);
+    }
+
+    let (idx_after_kind, kind) =
+        parse_section_from_section!(parse_table_constraint_kind(tokens, idx));
+    idx = idx_after_kind;
+
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_characteristics, (deferrable, initially_deferred)) = parse_section_from_section!(
+        parse_constraint_characteristics(tokens, idx_after_whitespace)
+    );
+    if idx_after_characteristics != idx_after_whitespace {
+        idx = idx_after_characteristics;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TableConstraint {
+            name: name,
+            kind: kind,
+            deferrable: deferrable,
+            initially_deferred: initially_deferred,
+        },
+    );
+}
+
+// [CONSTRAINT name] {UNIQUE | PRIMARY KEY} USING INDEX index_name
+// [[NOT] DEFERRABLE] [INITIALLY {DEFERRED | IMMEDIATE}]
+pub fn parse_table_constraint_using_index(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TableConstraintUsingIndex> {
+    let mut idx = start_idx;
+
+    let mut name = None;
+    if let ParseCommandSectionResult::Valid(idx_after_name, constraint_name) =
+        parse_constraint_name(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_name);
+        name = Some(constraint_name);
+    }
+
+    let primary_key;
+    if let ParseCommandSectionResult::Valid(idx_after_primary_key, _) =
+        parse_keywords(tokens, idx, &[PRIMARY_KEYWORD, KEY_KEYWORD])
+    {
+        idx = idx_after_primary_key;
+        primary_key = true;
+    } else {
+        (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, UNIQUE_KEYWORD));
+        primary_key = false;
+    }
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) =
+        parse_section_from_section!(parse_keywords(tokens, idx, &[USING_KEYWORD, INDEX_KEYWORD]));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let index_name;
+    (idx, index_name) = parse_section_from_section!(parse_identifier(tokens, idx));
+
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_characteristics, (deferrable, initially_deferred)) = parse_section_from_section!(
+        parse_constraint_characteristics(tokens, idx_after_whitespace)
+    );
+    if idx_after_characteristics != idx_after_whitespace {
+        idx = idx_after_characteristics;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TableConstraintUsingIndex {
+            name: name,
+            primary_key: primary_key,
+            index_name: index_name,
+            deferrable: deferrable,
+            initially_deferred: initially_deferred,
+        },
+    );
+}
+
+// CONSTRAINT name
+pub fn parse_constraint_name(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Identifier> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, CONSTRAINT_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    return parse_identifier(tokens, idx);
+}
+
+fn parse_table_constraint_kind(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TableConstraintKind> {
+    if let ParseCommandSectionResult::Valid(idx_after_check, (expression, no_inherit)) =
+        parse_check(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after_check,
+            TableConstraintKind::Check {
+                expression: expression,
+                no_inherit: no_inherit,
+            },
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_unique, nulls_not_distinct) =
+        parse_unique(tokens, start_idx)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_unique);
+        let (idx_after, (columns, include)) =
+            parse_section_from_section!(parse_index_columns(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            TableConstraintKind::Unique {
+                nulls_not_distinct: nulls_not_distinct,
+                columns: columns,
+                include: include,
+            },
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_primary_key, _) =
+        parse_keywords(tokens, start_idx, &[PRIMARY_KEYWORD, KEY_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_primary_key);
+        let (idx_after, (columns, include)) =
+            parse_section_from_section!(parse_index_columns(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            TableConstraintKind::PrimaryKey {
+                columns: columns,
+                include: include,
+            },
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_exclude, _) =
+        parse_keyword(tokens, start_idx, EXCLUDE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_exclude);
+        return parse_exclusion_constraint(tokens, idx_after_whitespace);
+    }
+    let mut idx = start_idx;
+    (idx, _) =
+        parse_section_from_section!(parse_keywords(tokens, idx, &[FOREIGN_KEYWORD, KEY_KEYWORD]));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_columns, columns) =
+        parse_section_from_section!(parse_parenthesized_list(tokens, idx, parse_identifier));
+    idx = idx_after_optional_whitespace(tokens, idx_after_columns);
+    let (idx_after, reference) =
+        parse_section_from_section!(parse_foreign_key_reference(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        TableConstraintKind::ForeignKey {
+            columns: columns,
+            reference: reference,
+        },
+    );
+}
+
+// [USING method] (element WITH operator, ...) [INCLUDE (column, ...)]
+// [WHERE (predicate)], with the EXCLUDE keyword already consumed
+fn parse_exclusion_constraint(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TableConstraintKind> {
+    let mut idx = start_idx;
+
+    let mut method = None;
+    if let ParseCommandSectionResult::Valid(idx_after_using, _) =
+        parse_keyword(tokens, idx, USING_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_using);
+        let (idx_after_method, method_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx_after_method);
+        method = Some(method_name);
+    }
+
+    let elements;
+    (idx, elements) = parse_section_from_section!(parse_parenthesized_list(
+        tokens,
+        idx,
+        parse_exclusion_element
+    ));
+
+    let mut include = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_include, _) =
+        parse_keyword(tokens, idx_after_whitespace, INCLUDE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_include);
+        let (idx_after_columns, columns) = parse_section_from_section!(parse_parenthesized_list(
+            tokens,
+            idx_after_whitespace,
+            parse_identifier
+        ));
+        idx = idx_after_columns;
+        include = columns;
+    }
+
+    let mut where_clause = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_where, predicate) =
+        parse_where_clause(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_where;
+        where_clause = Some(predicate);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TableConstraintKind::Exclude {
+            method: method,
+            elements: elements,
+            include: include,
+            where_clause: where_clause,
+        },
+    );
+}
+
+// index_element WITH operator
+fn parse_exclusion_element(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ExclusionElement> {
+    let mut idx = start_idx;
+    let element;
+    (idx, element) = parse_section_from_section!(parse_index_element(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, WITH_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let operator;
+    (idx, operator) = parse_section_from_section!(parse_any_operator(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        ExclusionElement {
+            element: element,
+            operator: operator,
+        },
+    );
+}
+
+// CHECK (expression) [NO INHERIT]
+pub fn parse_check(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<(Expression, bool)> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, CHECK_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_open_parenthesis(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_expression, expression) =
+        parse_section_from_section!(parse_expression(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx_after_expression);
+    (idx, _) = parse_section_from_section!(parse_close_parenthesis(tokens, idx));
+
+    let mut no_inherit = false;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_no_inherit, _) =
+        parse_keywords(tokens, idx_after_whitespace, &[NO_KEYWORD, INHERIT_KEYWORD])
+    {
+        idx = idx_after_no_inherit;
+        no_inherit = true;
+    }
+    return ParseCommandSectionResult::Valid(idx, (expression, no_inherit));
+}
+
+// UNIQUE [NULLS [NOT] DISTINCT], returning whether NULLS NOT DISTINCT was given
+pub fn parse_unique(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<bool> {
+    let (mut idx, _) =
+        parse_section_from_section!(parse_keyword(tokens, start_idx, UNIQUE_KEYWORD));
+    let mut nulls_not_distinct = false;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_nulls, _) =
+        parse_keyword(tokens, idx_after_whitespace, NULLS_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_nulls);
+        if let ParseCommandSectionResult::Valid(idx_after, _) = parse_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[NOT_KEYWORD, DISTINCT_KEYWORD],
+        ) {
+            idx = idx_after;
+            nulls_not_distinct = true;
+        } else {
+            (idx, _) = parse_section_from_section!(parse_keyword(
+                tokens,
+                idx_after_whitespace,
+                DISTINCT_KEYWORD
+            ));
+        }
+    }
+    return ParseCommandSectionResult::Valid(idx, nulls_not_distinct);
+}
+
+// (column, ...) [INCLUDE (column, ...)]
+fn parse_index_columns(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<(Vec<Identifier>, Vec<Identifier>)> {
+    let (mut idx, columns) = parse_section_from_section!(parse_parenthesized_list(
+        tokens,
+        start_idx,
+        parse_identifier
+    ));
+    let mut include = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_include, _) =
+        parse_keyword(tokens, idx_after_whitespace, INCLUDE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_include);
+        let (idx_after_columns, included_columns) = parse_section_from_section!(
+            parse_parenthesized_list(tokens, idx_after_whitespace, parse_identifier)
+        );
+        idx = idx_after_columns;
+        include = included_columns;
+    }
+    return ParseCommandSectionResult::Valid(idx, (columns, include));
+}
+
+// REFERENCES table [(column, ...)] [MATCH {FULL | PARTIAL | SIMPLE}]
+// [ON DELETE action] [ON UPDATE action]
+pub fn parse_foreign_key_reference(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ForeignKeyReference> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, REFERENCES_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_table, table_name) =
+        parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_table;
+
+    let mut columns = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_columns, referenced_columns) =
+        parse_parenthesized_list(tokens, idx_after_whitespace, parse_identifier)
+    {
+        idx = idx_after_columns;
+        columns = referenced_columns;
+    }
+
+    let mut match_type = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_match, _) =
+        parse_keyword(tokens, idx_after_whitespace, MATCH_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_match);
+        let (idx_after_type, keyword) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[FULL_KEYWORD, PARTIAL_KEYWORD, SIMPLE_KEYWORD]
+        ));
+        idx = idx_after_type;
+        match_type = match keyword.as_str() {
+            FULL_KEYWORD => Some(ForeignKeyMatch::Full),
+            PARTIAL_KEYWORD => Some(ForeignKeyMatch::Partial),
+            _ => Some(ForeignKeyMatch::Simple),
+        };
+    }
+
+    // ON DELETE and ON UPDATE can be given in either order
+    let mut on_delete = None;
+    let mut on_update = None;
+    loop {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        let (idx_after_event, event) = match parse_keyword(tokens, idx_after_whitespace, ON_KEYWORD)
+        {
+            ParseCommandSectionResult::Valid(idx_after_on, _) => {
+                let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_on);
+                parse_section_from_section!(parse_one_of_keywords(
+                    tokens,
+                    idx_after_whitespace,
+                    &[DELETE_KEYWORD, UPDATE_KEYWORD]
+                ))
+            }
+            _ => break,
+        };
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_event);
+        let (idx_after_action, action) =
+            parse_section_from_section!(parse_referential_action(tokens, idx_after_whitespace));
+        let previous_action = match event.as_str() {
+            DELETE_KEYWORD => on_delete.replace(action),
+            _ => on_update.replace(action),
+        };
+        if previous_action.is_some() {
+            return ParseCommandSectionResult::Invalid;
+        }
+        idx = idx_after_action;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        ForeignKeyReference {
+            table_name: table_name,
+            columns: columns,
+            match_type: match_type,
+            on_delete: on_delete,
+            on_update: on_update,
+        },
+    );
+}
+
+fn parse_referential_action(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ReferentialAction> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, start_idx, &[NO_KEYWORD, ACTION_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(idx_after, ReferentialAction::NoAction);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, RESTRICT_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, ReferentialAction::Restrict);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, CASCADE_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, ReferentialAction::Cascade);
+    }
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, SET_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let (idx_after_keyword, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        idx,
+        &[NULL_KEYWORD, DEFAULT_KEYWORD]
+    ));
+    idx = idx_after_keyword;
+    let mut columns = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_columns, action_columns) =
+        parse_parenthesized_list(tokens, idx_after_whitespace, parse_identifier)
+    {
+        idx = idx_after_columns;
+        columns = action_columns;
+    }
+    let action = match keyword.as_str() {
+        NULL_KEYWORD => ReferentialAction::SetNull(columns),
+        _ => ReferentialAction::SetDefault(columns),
+    };
+    return ParseCommandSectionResult::Valid(idx, action);
+}
+
+// [[NOT] DEFERRABLE] [INITIALLY {DEFERRED | IMMEDIATE}], in either order.
+// Always valid, returning (deferrable, initially_deferred).
+pub fn parse_constraint_characteristics(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<(Option<bool>, Option<bool>)> {
+    let mut idx = start_idx;
+    let mut deferrable = None;
+    let mut initially_deferred = None;
+    loop {
+        let idx_before_characteristic = match deferrable.is_some() || initially_deferred.is_some() {
+            true => idx_after_optional_whitespace(tokens, idx),
+            false => idx,
+        };
+        if deferrable.is_none() {
+            if let ParseCommandSectionResult::Valid(idx_after, _) =
+                parse_keyword(tokens, idx_before_characteristic, DEFERRABLE_KEYWORD)
+            {
+                idx = idx_after;
+                deferrable = Some(true);
+                continue;
+            }
+            if let ParseCommandSectionResult::Valid(idx_after, _) = parse_keywords(
+                tokens,
+                idx_before_characteristic,
+                &[NOT_KEYWORD, DEFERRABLE_KEYWORD],
+            ) {
+                idx = idx_after;
+                deferrable = Some(false);
+                continue;
+            }
+        }
+        if initially_deferred.is_none() {
+            if let ParseCommandSectionResult::Valid(idx_after_initially, _) =
+                parse_keyword(tokens, idx_before_characteristic, INITIALLY_KEYWORD)
+            {
+                let idx_after_whitespace =
+                    idx_after_optional_whitespace(tokens, idx_after_initially);
+                let (idx_after, keyword) = parse_section_from_section!(parse_one_of_keywords(
+                    tokens,
+                    idx_after_whitespace,
+                    &[DEFERRED_KEYWORD, IMMEDIATE_KEYWORD]
+                ));
+                idx = idx_after;
+                initially_deferred = Some(keyword == DEFERRED_KEYWORD);
+                continue;
+            }
+        }
+        break;
+    }
+    return ParseCommandSectionResult::Valid(idx, (deferrable, initially_deferred));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tokenize_postgresql;
+
+    #[test]
+    fn constraint_characteristics_in_any_order() {
+        let tokens = tokenize_postgresql("initially immediate not deferrable,");
+        assert_eq!(
+            parse_constraint_characteristics(&tokens, 0),
+            ParseCommandSectionResult::Valid(7, (Some(false), Some(false)))
+        );
+    }
+
+    #[test]
+    fn constraint_characteristics_absent() {
+        let tokens = tokenize_postgresql(", b int");
+        assert_eq!(
+            parse_constraint_characteristics(&tokens, 0),
+            ParseCommandSectionResult::Valid(0, (None, None))
+        );
+    }
+
+    #[test]
+    fn primary_key_with_include() {
+        let tokens = tokenize_postgresql("constraint pk primary key (a, b) include (c)");
+        match parse_table_constraint(&tokens, 0) {
+            ParseCommandSectionResult::Valid(idx, constraint) => {
+                assert_eq!(idx, tokens.len());
+                assert_eq!(
+                    constraint.name.map(|name| name.value),
+                    Some(String::from("pk"))
+                );
+                assert!(matches!(
+                    constraint.kind,
+                    TableConstraintKind::PrimaryKey { columns, include }
+                        if columns.len() == 2 && include.len() == 1
+                ));
+            }
+            result => panic!("Expected a valid constraint, got {:?}", result),
+        }
+    }
+}
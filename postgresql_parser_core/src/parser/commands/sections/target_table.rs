@@ -0,0 +1,59 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::TargetTable;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::alias::parse_alias;
+use crate::parser::commands::sections::keywords::{parse_keyword, ONLY_KEYWORD};
+use crate::parser::commands::sections::operator::parse_operator;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// [ONLY] table_name [*] [[AS] alias]
+pub fn parse_target_table(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TargetTable> {
+    let mut idx = start_idx;
+
+    let mut only = false;
+    if let ParseCommandSectionResult::Valid(idx_after_only, _) =
+        parse_keyword(tokens, idx, ONLY_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_only);
+        only = true;
+    }
+
+    let (idx_after_table_name, table_name) =
+        parse_section_from_section!(parse_qualified_name(tokens, idx));
+    idx = idx_after_table_name;
+
+    // table_name * explicitly includes inheriting tables, which is the default
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_star, _) =
+        parse_operator(tokens, idx_after_whitespace, "*")
+    {
+        if only {
+            return ParseCommandSectionResult::Invalid;
+        }
+        idx = idx_after_star;
+    }
+
+    let mut alias = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_alias, parsed_alias) =
+        parse_alias(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_alias;
+        alias = Some(parsed_alias);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TargetTable {
+            only: only,
+            table_name: table_name,
+            alias: alias,
+        },
+    );
+}
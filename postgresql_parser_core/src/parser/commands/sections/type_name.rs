@@ -0,0 +1,209 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Identifier, QualifiedName, TypeName};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::bracket::{parse_close_bracket, parse_open_bracket};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, ARRAY_KEYWORD, BIT_KEYWORD,
+    CHARACTER_KEYWORD, CHAR_KEYWORD, DOUBLE_KEYWORD, PRECISION_KEYWORD, TIMESTAMP_KEYWORD,
+    TIME_KEYWORD, VARYING_KEYWORD, WITHOUT_KEYWORD, WITH_KEYWORD, ZONE_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_parenthesized_list;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// Built-in types whose names are made of several keywords
+const MULTI_WORD_TYPE_NAMES: [&[&str]; 4] = [
+    &[DOUBLE_KEYWORD, PRECISION_KEYWORD],
+    &[CHARACTER_KEYWORD, VARYING_KEYWORD],
+    &[CHAR_KEYWORD, VARYING_KEYWORD],
+    &[BIT_KEYWORD, VARYING_KEYWORD],
+];
+
+// type_name [(modifier, ...)] [WITH | WITHOUT TIME ZONE] [[] ... | ARRAY [n]]
+pub fn parse_type_name(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TypeName> {
+    let (mut idx, mut name) = parse_section_from_section!(parse_base_type_name(tokens, start_idx));
+
+    let mut modifiers = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_modifiers, parsed_modifiers) =
+        parse_parenthesized_list(tokens, idx_after_whitespace, parse_expression)
+    {
+        idx = idx_after_modifiers;
+        modifiers = parsed_modifiers;
+    }
+
+    // The time zone comes after the precision, as in timestamp(3) with time zone
+    if name.schema_name.is_none() && !name.name.quoted {
+        let lowered = name.name.value.to_ascii_lowercase();
+        if lowered == TIME_KEYWORD || lowered == TIMESTAMP_KEYWORD {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+            if let ParseCommandSectionResult::Valid(idx_after_time_zone, with_or_without) =
+                parse_time_zone(tokens, idx_after_whitespace)
+            {
+                idx = idx_after_time_zone;
+                name.name.value = format!("{} {} time zone", lowered, with_or_without);
+            }
+        }
+    }
+
+    let mut array_dimensions = 0;
+    loop {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_bounds, _) =
+            parse_array_bounds(tokens, idx_after_whitespace)
+        {
+            idx = idx_after_bounds;
+            array_dimensions += 1;
+            continue;
+        }
+        // type ARRAY [n] declares a single dimension, and cannot be repeated
+        if array_dimensions == 0 {
+            if let ParseCommandSectionResult::Valid(idx_after_array, _) =
+                parse_keyword(tokens, idx_after_whitespace, ARRAY_KEYWORD)
+            {
+                idx = idx_after_array;
+                let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+                if let ParseCommandSectionResult::Valid(idx_after_bounds, _) =
+                    parse_array_bounds(tokens, idx_after_whitespace)
+                {
+                    idx = idx_after_bounds;
+                }
+                array_dimensions = 1;
+            }
+        }
+        break;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TypeName {
+            name: name,
+            modifiers: modifiers,
+            array_dimensions: array_dimensions,
+        },
+    );
+}
+
+fn parse_base_type_name(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<QualifiedName> {
+    for keywords in MULTI_WORD_TYPE_NAMES {
+        if let ParseCommandSectionResult::Valid(idx_after, _) =
+            parse_keywords(tokens, start_idx, keywords)
+        {
+            return ParseCommandSectionResult::Valid(
+                idx_after,
+                QualifiedName {
+                    schema_name: None,
+                    name: Identifier {
+                        quoted: false,
+                        value: keywords.join(" "),
+                    },
+                },
+            );
+        }
+    }
+    return parse_qualified_name(tokens, start_idx);
+}
+
+// {WITH | WITHOUT} TIME ZONE, returning "with" or "without"
+fn parse_time_zone(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<String> {
+    let (idx_after_with, with_or_without) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[WITH_KEYWORD, WITHOUT_KEYWORD]
+    ));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_with);
+    let (idx_after, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        idx_after_whitespace,
+        &[TIME_KEYWORD, ZONE_KEYWORD]
+    ));
+    return ParseCommandSectionResult::Valid(idx_after, with_or_without);
+}
+
+// [] or [n]. PostgreSQL accepts but ignores the declared size.
+fn parse_array_bounds(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<()> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_open_bracket(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_size, _) = parse_expression(tokens, idx) {
+        idx = idx_after_optional_whitespace(tokens, idx_after_size);
+    }
+    return parse_close_bracket(tokens, idx);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tokenize_postgresql;
+    use crate::parser::ast::{Expression, Literal};
+
+    fn type_name(name: &str, modifiers: Vec<&str>, array_dimensions: usize) -> TypeName {
+        return TypeName {
+            name: QualifiedName {
+                schema_name: None,
+                name: Identifier {
+                    quoted: false,
+                    value: String::from(name),
+                },
+            },
+            modifiers: modifiers
+                .into_iter()
+                .map(|modifier| Expression::Literal(Literal::Number(String::from(modifier))))
+                .collect(),
+            array_dimensions: array_dimensions,
+        };
+    }
+
+    #[test]
+    fn type_name_with_modifiers() {
+        let tokens = tokenize_postgresql("numeric(10, 2)");
+        assert_eq!(
+            parse_type_name(&tokens, 0),
+            ParseCommandSectionResult::Valid(7, type_name("numeric", vec!["10", "2"], 0))
+        );
+    }
+
+    #[test]
+    fn type_name_multiple_words() {
+        let tokens = tokenize_postgresql("DOUBLE PRECISION,");
+        assert_eq!(
+            parse_type_name(&tokens, 0),
+            ParseCommandSectionResult::Valid(3, type_name("double precision", vec![], 0))
+        );
+    }
+
+    #[test]
+    fn type_name_timestamp_with_time_zone() {
+        let tokens = tokenize_postgresql("timestamp(3) with time zone");
+        assert_eq!(
+            parse_type_name(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                10,
+                type_name("timestamp with time zone", vec!["3"], 0)
+            )
+        );
+    }
+
+    #[test]
+    fn type_name_arrays() {
+        let tokens = tokenize_postgresql("text[][3]");
+        assert_eq!(
+            parse_type_name(&tokens, 0),
+            ParseCommandSectionResult::Valid(6, type_name("text", vec![], 2))
+        );
+        let tokens = tokenize_postgresql("int array[4]");
+        assert_eq!(
+            parse_type_name(&tokens, 0),
+            ParseCommandSectionResult::Valid(6, type_name("int", vec![], 1))
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Expression, WhereClause};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, CURRENT_KEYWORD, OF_KEYWORD, WHERE_KEYWORD,
+};
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// WHERE condition
+pub fn parse_where_clause(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Expression> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, WHERE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    return parse_expression(tokens, idx);
+}
+
+// The WHERE of an UPDATE or DELETE, which can also be WHERE CURRENT OF cursor
+pub fn parse_where_or_current_of_clause(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<WhereClause> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, WHERE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_current_of, _) =
+        parse_keywords(tokens, idx, &[CURRENT_KEYWORD, OF_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_current_of);
+        let (idx_after, cursor_name) =
+            parse_section_from_section!(parse_identifier(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, WhereClause::CurrentOf(cursor_name));
+    }
+    let (idx_after, condition) = parse_section_from_section!(parse_expression(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx_after, WhereClause::Condition(condition));
+}
@@ -0,0 +1,26 @@
+use crate::lexer::token::Token;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, DATA_KEYWORD, NO_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::utils::idx_after_optional_whitespace;
+
+// WITH [NO] DATA, returning whether the data should be populated
+pub fn parse_with_data(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<bool> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, WITH_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut with_data = true;
+    if let ParseCommandSectionResult::Valid(idx_after_no, _) =
+        parse_keyword(tokens, idx, NO_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_no);
+        with_data = false;
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, DATA_KEYWORD));
+    return ParseCommandSectionResult::Valid(idx, with_data);
+}
@@ -1,32 +1,82 @@
 use crate::lexer::token::Token;
 use crate::parser::ast::SelectCommand;
-use crate::parser::ast::{Command, DataManipulationCommand, FromItem, SelectedExpression};
-use crate::parser::commands::parse_section::parse_section;
+use crate::parser::ast::{Expression, FromItem, Query, SelectedExpression};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
 use crate::parser::commands::sections::from_item::parse_from_item;
 use crate::parser::commands::sections::keywords::parse_keyword_from;
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::commands::sections::query::{parse_query_continuation, query_into_command};
 use crate::parser::commands::sections::selected_expressions::parse_selected_expressions;
-use crate::parser::commands::sections::semicolon::parse_semicolon;
+use crate::parser::commands::sections::where_clause::parse_where_clause;
 use crate::parser::commands::sections::whitespace::parse_whitespace;
 use crate::parser::parse_command_result::ParseCommandResult;
-use crate::parser::utils::idx_after_optional_whitespace;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
 
 pub fn parse_select_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_select_query, query_into_command);
+}
+
+// A query starting with SELECT, which may be followed by set operations,
+// ORDER BY, LIMIT, OFFSET and locking clauses
+fn parse_select_query(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Query> {
+    let (idx_after_select, select_command) =
+        parse_section_from_section!(parse_select(tokens, start_idx));
+    return parse_query_continuation(tokens, idx_after_select, Query::Select(select_command));
+}
+
+// Parses everything after the SELECT keyword, up to but not including
+// ORDER BY, LIMIT, OFFSET and locking clauses, which are parsed with the rest
+// of the query
+pub fn parse_select(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<SelectCommand> {
     let mut idx = start_idx;
-    let from_item: FromItem;
     let selected_expressions: Vec<SelectedExpression>;
-    (idx, _) = parse_section!(parse_whitespace, tokens, idx);
-    (idx, selected_expressions) = parse_section!(parse_selected_expressions, tokens, idx);
-    (idx, _) = parse_section!(parse_whitespace, tokens, idx);
-    (idx, _) = parse_section!(parse_keyword_from, tokens, idx);
-    (idx, _) = parse_section!(parse_whitespace, tokens, idx);
-    (idx, from_item) = parse_section!(parse_from_item, tokens, idx);
-    idx = idx_after_optional_whitespace(tokens, idx);
-    (idx, _) = parse_section!(parse_semicolon, tokens, idx);
-    return ParseCommandResult::Valid(
-        Command::DataManipulation(DataManipulationCommand::Select(SelectCommand {
-            from_item: from_item,
-            selected_expressions: selected_expressions,
-        })),
+    (idx, _) = parse_section_from_section!(parse_whitespace(tokens, idx));
+    (idx, selected_expressions) =
+        parse_section_from_section!(parse_selected_expressions(tokens, idx));
+
+    let mut from_items: Vec<FromItem> = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_from, items) =
+        parse_from_clause(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_from;
+        from_items = items;
+    }
+
+    let mut where_clause: Option<Expression> = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_where, condition) =
+        parse_where_clause(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_where;
+        where_clause = Some(condition);
+    }
+    return ParseCommandSectionResult::Valid(
         idx,
+        SelectCommand {
+            from_items: from_items,
+            where_clause: where_clause,
+            selected_expressions: selected_expressions,
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+            locking: Vec::new(),
+        },
     );
 }
+
+// FROM from_item, ...
+fn parse_from_clause(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<FromItem>> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword_from(tokens, idx));
+    (idx, _) = parse_section_from_section!(parse_whitespace(tokens, idx));
+    return parse_comma_separated_list(tokens, idx, parse_from_item);
+}
@@ -0,0 +1,238 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    Command, ConfigurationValue, IdentifierOrString, SetAction, SetCommand, SetScope,
+    TransactionControlCommand, UtilityCommand,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::configuration_setting::parse_configuration_setting;
+use crate::parser::commands::sections::expression::{parse_expression, parse_string_literal};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, ALL_KEYWORD, AS_KEYWORD,
+    AUTHORIZATION_KEYWORD, CHARACTERISTICS_KEYWORD, CONSTRAINTS_KEYWORD, DEFAULT_KEYWORD,
+    DEFERRED_KEYWORD, IMMEDIATE_KEYWORD, LOCAL_KEYWORD, NONE_KEYWORD, ROLE_KEYWORD,
+    SESSION_KEYWORD, SNAPSHOT_KEYWORD, TIME_KEYWORD, TRANSACTION_KEYWORD, ZONE_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::transaction::parse_transaction_modes;
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_set_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_set, |set_command| {
+        return set_command;
+    });
+}
+
+// Parses everything after the SET keyword. SET TRANSACTION and SET
+// CONSTRAINTS are transaction control commands, everything else changes a
+// session setting.
+fn parse_set(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Command> {
+    let idx = idx_after_optional_whitespace(tokens, start_idx);
+    if let ParseCommandSectionResult::Valid(idx_after, command) = parse_set_transaction(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, Command::TransactionControl(command));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, command) = parse_set_constraints(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, Command::TransactionControl(command));
+    }
+    let (idx_after, set_command) = parse_section_from_section!(parse_set_session(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        Command::Utility(UtilityCommand::Set(set_command)),
+    );
+}
+
+// [SESSION | LOCAL] action
+fn parse_set_session(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<SetCommand> {
+    if let ParseCommandSectionResult::Valid(idx_after_scope, keyword) =
+        parse_one_of_keywords(tokens, start_idx, &[SESSION_KEYWORD, LOCAL_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_scope);
+        // Otherwise SESSION is the start of SESSION AUTHORIZATION
+        if let ParseCommandSectionResult::Valid(idx_after, action) =
+            parse_set_action(tokens, idx_after_whitespace)
+        {
+            let scope = match keyword.as_str() {
+                SESSION_KEYWORD => SetScope::Session,
+                _ => SetScope::Local,
+            };
+            return ParseCommandSectionResult::Valid(
+                idx_after,
+                SetCommand {
+                    scope: Some(scope),
+                    action: action,
+                },
+            );
+        }
+    }
+    let (idx_after, action) = parse_section_from_section!(parse_set_action(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        SetCommand {
+            scope: None,
+            action: action,
+        },
+    );
+}
+
+fn parse_set_action(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<SetAction> {
+    // Tried first, as ROLE is also the name of a parameter, e.g. SET role TO name
+    if let ParseCommandSectionResult::Valid(idx_after, setting) =
+        parse_configuration_setting(tokens, start_idx)
+    {
+        // FROM CURRENT is only allowed in the SET option of a function
+        if setting.value == ConfigurationValue::FromCurrent {
+            return ParseCommandSectionResult::Invalid;
+        }
+        return ParseCommandSectionResult::Valid(idx_after, SetAction::Configuration(setting));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_time_zone, _) =
+        parse_keywords(tokens, start_idx, &[TIME_KEYWORD, ZONE_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_time_zone);
+        if let ParseCommandSectionResult::Valid(idx_after, _) = parse_one_of_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[LOCAL_KEYWORD, DEFAULT_KEYWORD],
+        ) {
+            return ParseCommandSectionResult::Valid(idx_after, SetAction::TimeZone(None));
+        }
+        let (idx_after, time_zone) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, SetAction::TimeZone(Some(time_zone)));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_role, _) =
+        parse_keyword(tokens, start_idx, ROLE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_role);
+        if let ParseCommandSectionResult::Valid(idx_after, _) =
+            parse_keyword(tokens, idx_after_whitespace, NONE_KEYWORD)
+        {
+            return ParseCommandSectionResult::Valid(idx_after, SetAction::Role(None));
+        }
+        let (idx_after, role) =
+            parse_section_from_section!(parse_identifier_or_string(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, SetAction::Role(Some(role)));
+    }
+
+    let (mut idx, _) = parse_section_from_section!(parse_keywords(
+        tokens,
+        start_idx,
+        &[SESSION_KEYWORD, AUTHORIZATION_KEYWORD]
+    ));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, idx, DEFAULT_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, SetAction::SessionAuthorization(None));
+    }
+    let user;
+    (idx, user) = parse_section_from_section!(parse_identifier_or_string(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx, SetAction::SessionAuthorization(Some(user)));
+}
+
+// A role name, or the same name as a string literal
+fn parse_identifier_or_string(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<IdentifierOrString> {
+    if let ParseCommandSectionResult::Valid(idx_after, value) =
+        parse_string_literal(tokens, start_idx)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, IdentifierOrString::String(value));
+    }
+    let (idx_after, identifier) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after, IdentifierOrString::Identifier(identifier));
+}
+
+// TRANSACTION SNAPSHOT snapshot_id, TRANSACTION mode, ... or
+// SESSION CHARACTERISTICS AS TRANSACTION mode, ...
+fn parse_set_transaction(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TransactionControlCommand> {
+    let mut idx = start_idx;
+    let mut session = false;
+    if let ParseCommandSectionResult::Valid(idx_after_session, _) = parse_keywords(
+        tokens,
+        idx,
+        &[SESSION_KEYWORD, CHARACTERISTICS_KEYWORD, AS_KEYWORD],
+    ) {
+        idx = idx_after_optional_whitespace(tokens, idx_after_session);
+        session = true;
+    }
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TRANSACTION_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    if !session {
+        if let ParseCommandSectionResult::Valid(idx_after_snapshot, _) =
+            parse_keyword(tokens, idx, SNAPSHOT_KEYWORD)
+        {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_snapshot);
+            let (idx_after, snapshot_id) =
+                parse_section_from_section!(parse_string_literal(tokens, idx_after_whitespace));
+            return ParseCommandSectionResult::Valid(
+                idx_after,
+                TransactionControlCommand::SetTransactionSnapshot(snapshot_id),
+            );
+        }
+    }
+
+    let modes;
+    (idx, modes) = parse_section_from_section!(parse_transaction_modes(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TransactionControlCommand::SetTransaction {
+            session: session,
+            modes: modes,
+        },
+    );
+}
+
+// CONSTRAINTS {ALL | name, ...} {DEFERRED | IMMEDIATE}
+fn parse_set_constraints(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TransactionControlCommand> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, CONSTRAINTS_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut constraints = None;
+    if let ParseCommandSectionResult::Valid(idx_after_all, _) =
+        parse_keyword(tokens, idx, ALL_KEYWORD)
+    {
+        idx = idx_after_all;
+    } else {
+        let names;
+        (idx, names) = parse_section_from_section!(parse_comma_separated_list(
+            tokens,
+            idx,
+            parse_qualified_name
+        ));
+        constraints = Some(names);
+    }
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let keyword;
+    (idx, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        idx,
+        &[DEFERRED_KEYWORD, IMMEDIATE_KEYWORD]
+    ));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TransactionControlCommand::SetConstraints {
+            constraints: constraints,
+            deferred: keyword == DEFERRED_KEYWORD,
+        },
+    );
+}
@@ -0,0 +1,21 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Command, ConfigurationParameter, UtilityCommand};
+use crate::parser::commands::parse_section::ParseCommandSectionResult;
+use crate::parser::commands::sections::configuration_setting::parse_configuration_parameter;
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_show_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_show, |parameter| {
+        return Command::Utility(UtilityCommand::Show(parameter));
+    });
+}
+
+// Parses everything after the SHOW keyword
+fn parse_show(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ConfigurationParameter> {
+    let idx = idx_after_optional_whitespace(tokens, start_idx);
+    return parse_configuration_parameter(tokens, idx);
+}
@@ -0,0 +1,387 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{
+    Command, Identifier, IsolationLevel, TransactionControlCommand, TransactionMode,
+};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::comma::parse_comma;
+use crate::parser::commands::sections::expression::parse_string_literal;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, AND_KEYWORD, CHAIN_KEYWORD,
+    COMMITTED_KEYWORD, DEFERRABLE_KEYWORD, ISOLATION_KEYWORD, LEVEL_KEYWORD, NOT_KEYWORD,
+    NO_KEYWORD, ONLY_KEYWORD, PREPARED_KEYWORD, READ_KEYWORD, REPEATABLE_KEYWORD,
+    SAVEPOINT_KEYWORD, SERIALIZABLE_KEYWORD, TO_KEYWORD, TRANSACTION_KEYWORD, UNCOMMITTED_KEYWORD,
+    WORK_KEYWORD, WRITE_KEYWORD,
+};
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+fn into_transaction_control_command(command: TransactionControlCommand) -> Command {
+    return Command::TransactionControl(command);
+}
+
+pub fn parse_begin_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(
+        tokens,
+        start_idx,
+        parse_begin,
+        into_transaction_control_command,
+    );
+}
+
+pub fn parse_start_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(
+        tokens,
+        start_idx,
+        parse_start,
+        into_transaction_control_command,
+    );
+}
+
+// Handles both COMMIT and its alias END
+pub fn parse_commit_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(
+        tokens,
+        start_idx,
+        parse_commit,
+        into_transaction_control_command,
+    );
+}
+
+// Handles both ROLLBACK and its alias ABORT
+pub fn parse_rollback_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(
+        tokens,
+        start_idx,
+        parse_rollback,
+        into_transaction_control_command,
+    );
+}
+
+pub fn parse_savepoint_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(
+        tokens,
+        start_idx,
+        parse_savepoint,
+        into_transaction_control_command,
+    );
+}
+
+pub fn parse_release_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(
+        tokens,
+        start_idx,
+        parse_release,
+        into_transaction_control_command,
+    );
+}
+
+pub fn parse_prepare_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(
+        tokens,
+        start_idx,
+        parse_prepare,
+        into_transaction_control_command,
+    );
+}
+
+// Parses everything after the BEGIN keyword
+fn parse_begin(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TransactionControlCommand> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_one_of_keywords(tokens, idx, &[WORK_KEYWORD, TRANSACTION_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after);
+    }
+    return parse_optional_transaction_modes(tokens, idx);
+}
+
+// Parses everything after the START keyword
+fn parse_start(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TransactionControlCommand> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TRANSACTION_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    return parse_optional_transaction_modes(tokens, idx);
+}
+
+// [mode, ...] of BEGIN and START TRANSACTION, where idx is either at the first
+// mode or at the end of the command
+fn parse_optional_transaction_modes(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TransactionControlCommand> {
+    return match parse_transaction_modes(tokens, start_idx) {
+        ParseCommandSectionResult::Valid(idx_after, modes) => {
+            ParseCommandSectionResult::Valid(idx_after, TransactionControlCommand::Begin(modes))
+        }
+        _ => ParseCommandSectionResult::Valid(start_idx, TransactionControlCommand::Begin(vec![])),
+    };
+}
+
+// Parses everything after the COMMIT or END keyword
+fn parse_commit(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TransactionControlCommand> {
+    let idx = idx_after_optional_whitespace(tokens, start_idx);
+    if let ParseCommandSectionResult::Valid(idx_after, transaction_id) =
+        parse_prepared_transaction_id(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            TransactionControlCommand::CommitPrepared(transaction_id),
+        );
+    }
+    let (idx_after, and_chain) =
+        parse_section_from_section!(parse_transaction_end(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        TransactionControlCommand::Commit {
+            and_chain: and_chain,
+        },
+    );
+}
+
+// Parses everything after the ROLLBACK or ABORT keyword
+fn parse_rollback(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TransactionControlCommand> {
+    let idx = idx_after_optional_whitespace(tokens, start_idx);
+    if let ParseCommandSectionResult::Valid(idx_after, transaction_id) =
+        parse_prepared_transaction_id(tokens, idx)
+    {
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            TransactionControlCommand::RollbackPrepared(transaction_id),
+        );
+    }
+
+    let mut idx_before_to = idx;
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_one_of_keywords(tokens, idx, &[WORK_KEYWORD, TRANSACTION_KEYWORD])
+    {
+        idx_before_to = idx_after_optional_whitespace(tokens, idx_after);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_to, _) =
+        parse_keyword(tokens, idx_before_to, TO_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_to);
+        let (idx_after, name) =
+            parse_section_from_section!(parse_savepoint_name(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            TransactionControlCommand::RollbackToSavepoint(name),
+        );
+    }
+
+    let (idx_after, and_chain) =
+        parse_section_from_section!(parse_transaction_end(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        TransactionControlCommand::Rollback {
+            and_chain: and_chain,
+        },
+    );
+}
+
+// [WORK | TRANSACTION] [AND [NO] CHAIN] of COMMIT and ROLLBACK, returning
+// whether AND CHAIN was given
+fn parse_transaction_end(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<bool> {
+    let mut idx = start_idx;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after, _) = parse_one_of_keywords(
+        tokens,
+        idx_after_whitespace,
+        &[WORK_KEYWORD, TRANSACTION_KEYWORD],
+    ) {
+        idx = idx_after;
+    }
+
+    let mut and_chain = false;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_and, _) =
+        parse_keyword(tokens, idx_after_whitespace, AND_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_and);
+        and_chain = true;
+        if let ParseCommandSectionResult::Valid(idx_after_no, _) =
+            parse_keyword(tokens, idx, NO_KEYWORD)
+        {
+            idx = idx_after_optional_whitespace(tokens, idx_after_no);
+            and_chain = false;
+        }
+        (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, CHAIN_KEYWORD));
+    }
+    return ParseCommandSectionResult::Valid(idx, and_chain);
+}
+
+// PREPARED transaction_id, as in COMMIT PREPARED and ROLLBACK PREPARED
+fn parse_prepared_transaction_id(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<String> {
+    let (idx_after_prepared, _) =
+        parse_section_from_section!(parse_keyword(tokens, start_idx, PREPARED_KEYWORD));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_prepared);
+    return parse_string_literal(tokens, idx_after_whitespace);
+}
+
+// [SAVEPOINT] name
+fn parse_savepoint_name(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Identifier> {
+    let mut idx = start_idx;
+    if let ParseCommandSectionResult::Valid(idx_after_savepoint, _) =
+        parse_keyword(tokens, idx, SAVEPOINT_KEYWORD)
+    {
+        // A savepoint may itself be named savepoint
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_savepoint);
+        if let ParseCommandSectionResult::Valid(_, _) =
+            parse_identifier(tokens, idx_after_whitespace)
+        {
+            idx = idx_after_whitespace;
+        }
+    }
+    return parse_identifier(tokens, idx);
+}
+
+// Parses everything after the SAVEPOINT keyword
+fn parse_savepoint(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TransactionControlCommand> {
+    let idx = idx_after_optional_whitespace(tokens, start_idx);
+    let (idx_after, name) = parse_section_from_section!(parse_identifier(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx_after, TransactionControlCommand::Savepoint(name));
+}
+
+// Parses everything after the RELEASE keyword
+fn parse_release(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TransactionControlCommand> {
+    let idx = idx_after_optional_whitespace(tokens, start_idx);
+    let (idx_after, name) = parse_section_from_section!(parse_savepoint_name(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        TransactionControlCommand::ReleaseSavepoint(name),
+    );
+}
+
+// Parses everything after the PREPARE keyword of a PREPARE TRANSACTION command
+fn parse_prepare(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TransactionControlCommand> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, TRANSACTION_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let transaction_id;
+    (idx, transaction_id) = parse_section_from_section!(parse_string_literal(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        TransactionControlCommand::PrepareTransaction(transaction_id),
+    );
+}
+
+// mode [[,] mode ...], as in BEGIN and SET TRANSACTION
+pub fn parse_transaction_modes(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<TransactionMode>> {
+    let (mut idx, mode) = parse_section_from_section!(parse_transaction_mode(tokens, start_idx));
+    let mut modes = vec![mode];
+    loop {
+        let mut idx_before_mode = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(idx_after_comma, _) =
+            parse_comma(tokens, idx_before_mode)
+        {
+            idx_before_mode = idx_after_optional_whitespace(tokens, idx_after_comma);
+        }
+        match parse_transaction_mode(tokens, idx_before_mode) {
+            ParseCommandSectionResult::Valid(idx_after_mode, mode) => {
+                idx = idx_after_mode;
+                modes.push(mode);
+            }
+            _ => break,
+        }
+    }
+    return ParseCommandSectionResult::Valid(idx, modes);
+}
+
+// ISOLATION LEVEL level | READ {ONLY | WRITE} | [NOT] DEFERRABLE
+fn parse_transaction_mode(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<TransactionMode> {
+    if let ParseCommandSectionResult::Valid(idx_after_isolation_level, _) =
+        parse_keywords(tokens, start_idx, &[ISOLATION_KEYWORD, LEVEL_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_isolation_level);
+        let (idx_after, level) =
+            parse_section_from_section!(parse_isolation_level(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(idx_after, TransactionMode::IsolationLevel(level));
+    }
+    if let ParseCommandSectionResult::Valid(idx_after_read, _) =
+        parse_keyword(tokens, start_idx, READ_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_read);
+        let (idx_after, keyword) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            idx_after_whitespace,
+            &[ONLY_KEYWORD, WRITE_KEYWORD]
+        ));
+        return ParseCommandSectionResult::Valid(
+            idx_after,
+            TransactionMode::ReadOnly(keyword == ONLY_KEYWORD),
+        );
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, start_idx, &[NOT_KEYWORD, DEFERRABLE_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(idx_after, TransactionMode::Deferrable(false));
+    }
+    let (idx_after, _) =
+        parse_section_from_section!(parse_keyword(tokens, start_idx, DEFERRABLE_KEYWORD));
+    return ParseCommandSectionResult::Valid(idx_after, TransactionMode::Deferrable(true));
+}
+
+// SERIALIZABLE | REPEATABLE READ | READ COMMITTED | READ UNCOMMITTED
+fn parse_isolation_level(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<IsolationLevel> {
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keyword(tokens, start_idx, SERIALIZABLE_KEYWORD)
+    {
+        return ParseCommandSectionResult::Valid(idx_after, IsolationLevel::Serializable);
+    }
+    if let ParseCommandSectionResult::Valid(idx_after, _) =
+        parse_keywords(tokens, start_idx, &[REPEATABLE_KEYWORD, READ_KEYWORD])
+    {
+        return ParseCommandSectionResult::Valid(idx_after, IsolationLevel::RepeatableRead);
+    }
+    let (mut idx, _) = parse_section_from_section!(parse_keyword(tokens, start_idx, READ_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let keyword;
+    (idx, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        idx,
+        &[COMMITTED_KEYWORD, UNCOMMITTED_KEYWORD]
+    ));
+    let level = match keyword.as_str() {
+        COMMITTED_KEYWORD => IsolationLevel::ReadCommitted,
+        _ => IsolationLevel::ReadUncommitted,
+    };
+    return ParseCommandSectionResult::Valid(idx, level);
+}
@@ -0,0 +1,49 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Query, ValuesCommand};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::list::{
+    parse_comma_separated_list, parse_parenthesized_list,
+};
+use crate::parser::commands::sections::query::{parse_query_continuation, query_into_command};
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_terminated_command};
+
+pub fn parse_values_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+    return parse_terminated_command(tokens, start_idx, parse_values_query, query_into_command);
+}
+
+// A query starting with VALUES, which may be followed by set operations,
+// ORDER BY, LIMIT and OFFSET
+fn parse_values_query(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Query> {
+    let (idx_after_values, values_command) =
+        parse_section_from_section!(parse_values(tokens, start_idx));
+    return parse_query_continuation(tokens, idx_after_values, Query::Values(values_command));
+}
+
+// Parses the rows after the VALUES keyword, which must all have the same
+// number of expressions
+pub fn parse_values(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ValuesCommand> {
+    let idx = idx_after_optional_whitespace(tokens, start_idx);
+    let (idx_after, rows) =
+        parse_section_from_section!(parse_comma_separated_list(tokens, idx, |tokens, idx| {
+            parse_parenthesized_list(tokens, idx, parse_expression)
+        }));
+    if rows.iter().any(|row| row.len() != rows[0].len()) {
+        return ParseCommandSectionResult::Invalid;
+    }
+    return ParseCommandSectionResult::Valid(
+        idx_after,
+        ValuesCommand {
+            rows: rows,
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        },
+    );
+}
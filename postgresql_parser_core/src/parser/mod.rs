@@ -1,6 +1,7 @@
 use crate::lexer::token::Token;
 pub mod ast;
 use ast::PostgresqlAbstractSyntaxTree;
+pub mod parameters;
 pub mod parse_command_result;
 use parse_command_result::ParseCommandResult;
 use std::collections::HashMap;
@@ -42,11 +43,119 @@ lazy_static! {
             String::from(keywords::SELECT_KEYWORD),
             commands::select::parse_select_command,
         );
+        parsers.insert(
+            String::from(keywords::VALUES_KEYWORD),
+            commands::values::parse_values_command,
+        );
+        parsers.insert(
+            String::from("("),
+            commands::parenthesized_query::parse_parenthesized_query_command,
+        );
+        parsers.insert(
+            String::from(keywords::INSERT_KEYWORD),
+            commands::insert::parse_insert_command,
+        );
+        parsers.insert(
+            String::from(keywords::UPDATE_KEYWORD),
+            commands::update::parse_update_command,
+        );
+        parsers.insert(
+            String::from(keywords::DELETE_KEYWORD),
+            commands::delete::parse_delete_command,
+        );
+        parsers.insert(
+            String::from(keywords::MERGE_KEYWORD),
+            commands::merge::parse_merge_command,
+        );
+        parsers.insert(
+            String::from(keywords::CREATE_KEYWORD),
+            commands::create::parse_create_command,
+        );
+        parsers.insert(
+            String::from(keywords::ALTER_KEYWORD),
+            commands::alter::parse_alter_command,
+        );
+        parsers.insert(
+            String::from(keywords::DROP_KEYWORD),
+            commands::drop::parse_drop_command,
+        );
+        parsers.insert(
+            String::from(keywords::REFRESH_KEYWORD),
+            commands::refresh::parse_refresh_command,
+        );
+        parsers.insert(
+            String::from(keywords::DO_KEYWORD),
+            commands::do_block::parse_do_command,
+        );
+        parsers.insert(
+            String::from(keywords::GRANT_KEYWORD),
+            commands::grant::parse_grant_command,
+        );
+        parsers.insert(
+            String::from(keywords::REVOKE_KEYWORD),
+            commands::revoke::parse_revoke_command,
+        );
+        parsers.insert(
+            String::from(keywords::BEGIN_KEYWORD),
+            commands::transaction::parse_begin_command,
+        );
+        parsers.insert(
+            String::from(keywords::START_KEYWORD),
+            commands::transaction::parse_start_command,
+        );
+        parsers.insert(
+            String::from(keywords::COMMIT_KEYWORD),
+            commands::transaction::parse_commit_command,
+        );
+        parsers.insert(
+            String::from(keywords::END_KEYWORD),
+            commands::transaction::parse_commit_command,
+        );
+        parsers.insert(
+            String::from(keywords::ROLLBACK_KEYWORD),
+            commands::transaction::parse_rollback_command,
+        );
+        parsers.insert(
+            String::from(keywords::ABORT_KEYWORD),
+            commands::transaction::parse_rollback_command,
+        );
+        parsers.insert(
+            String::from(keywords::SAVEPOINT_KEYWORD),
+            commands::transaction::parse_savepoint_command,
+        );
+        parsers.insert(
+            String::from(keywords::RELEASE_KEYWORD),
+            commands::transaction::parse_release_command,
+        );
+        parsers.insert(
+            String::from(keywords::PREPARE_KEYWORD),
+            commands::transaction::parse_prepare_command,
+        );
+        parsers.insert(
+            String::from(keywords::SET_KEYWORD),
+            commands::set::parse_set_command,
+        );
+        parsers.insert(
+            String::from(keywords::RESET_KEYWORD),
+            commands::reset::parse_reset_command,
+        );
+        parsers.insert(
+            String::from(keywords::SHOW_KEYWORD),
+            commands::show::parse_show_command,
+        );
+        parsers.insert(
+            String::from(keywords::EXPLAIN_KEYWORD),
+            commands::explain::parse_explain_command,
+        );
+        parsers.insert(
+            String::from(keywords::COPY_KEYWORD),
+            commands::copy::parse_copy_command,
+        );
         return parsers;
     };
 }
 
-fn parse_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
+pub fn parse_command(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandResult {
     let idx_after_whitespace = idx_after_optional_whitespace(tokens, start_idx);
     return match tokens.get(idx_after_whitespace) {
         Some(token) => {
@@ -0,0 +1,802 @@
+use crate::parser::ast::{
+    AlterColumnAction, AlterDatabaseAction, AlterPolicyAction, AlterRoleAction, AlterTableAction,
+    AlterTablespaceAction, Assignment, ColumnConstraintKind, ColumnDefinition, Command,
+    ConfigurationSetting, ConfigurationValue, ConflictAction, ConflictTarget, CopyOption,
+    CopyRelation, CreateFunctionCommand, CreateTableCommand, DataControlCommand,
+    DataDefinitionCommand, DataManipulationCommand, DatabaseOption, DeleteCommand,
+    DomainConstraintKind, Expression, FromFunction, FromItem, FunctionBody, FunctionOption,
+    FunctionParameter, IndexElement, InsertCommand, InsertSource, MergeAction, MergeCommand,
+    MergeSource, OutputExpression, Parameter, PartitionBound, PartitionRangeValue, PrivilegeTarget,
+    Query, RoleOption, SelectCommand, SelectedExpression, SequenceOption, SetAction, SetOperation,
+    SortExpression, StorageParameter, TableConstraint, TableConstraintKind, TableElement,
+    TypeDefinition, TypeOptionValue, UpdateCommand, UtilityCommand, ValuesCommand, WhereClause,
+};
+
+pub fn collect_command_parameters<'a>(command: &'a Command, parameters: &mut Vec<&'a Parameter>) {
+    match command {
+        Command::Empty(_) => {}
+        Command::DataManipulation(data_manipulation_command) => {
+            collect_data_manipulation_parameters(data_manipulation_command, parameters)
+        }
+        Command::DataDefinition(data_definition_command) => {
+            collect_data_definition_parameters(data_definition_command, parameters)
+        }
+        Command::DataControl(data_control_command) => {
+            collect_data_control_parameters(data_control_command, parameters)
+        }
+        // Transaction modes, savepoints and transaction ids are never
+        // expressions
+        Command::TransactionControl(_) => {}
+        Command::Utility(utility_command) => {
+            collect_utility_parameters(utility_command, parameters)
+        }
+    }
+}
+
+fn collect_data_manipulation_parameters<'a>(
+    command: &'a DataManipulationCommand,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    match command {
+        DataManipulationCommand::Select(select_command) => {
+            collect_select_parameters(select_command, parameters)
+        }
+        DataManipulationCommand::Values(values_command) => {
+            collect_values_parameters(values_command, parameters)
+        }
+        DataManipulationCommand::SetOperation(set_operation) => {
+            collect_set_operation_parameters(set_operation, parameters)
+        }
+        DataManipulationCommand::Insert(insert_command) => {
+            collect_insert_parameters(insert_command, parameters)
+        }
+        DataManipulationCommand::Update(update_command) => {
+            collect_update_parameters(update_command, parameters)
+        }
+        DataManipulationCommand::Delete(delete_command) => {
+            collect_delete_parameters(delete_command, parameters)
+        }
+        DataManipulationCommand::Merge(merge_command) => {
+            collect_merge_parameters(merge_command, parameters)
+        }
+    }
+}
+
+fn collect_query_parameters<'a>(query: &'a Query, parameters: &mut Vec<&'a Parameter>) {
+    match query {
+        Query::Select(select_command) => collect_select_parameters(select_command, parameters),
+        Query::Values(values_command) => collect_values_parameters(values_command, parameters),
+        Query::SetOperation(set_operation) => {
+            collect_set_operation_parameters(set_operation, parameters)
+        }
+    }
+}
+
+fn collect_select_parameters<'a>(
+    select_command: &'a SelectCommand,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    collect_selected_expressions_parameters(&select_command.selected_expressions, parameters);
+    collect_from_items_parameters(&select_command.from_items, parameters);
+    collect_optional_expression_parameters(&select_command.where_clause, parameters);
+    collect_sort_and_limit_parameters(
+        &select_command.order_by,
+        &select_command.limit,
+        &select_command.offset,
+        parameters,
+    );
+}
+
+fn collect_values_parameters<'a>(
+    values_command: &'a ValuesCommand,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for row in &values_command.rows {
+        collect_expressions_parameters(row, parameters);
+    }
+    collect_sort_and_limit_parameters(
+        &values_command.order_by,
+        &values_command.limit,
+        &values_command.offset,
+        parameters,
+    );
+}
+
+fn collect_set_operation_parameters<'a>(
+    set_operation: &'a SetOperation,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    collect_query_parameters(&set_operation.left, parameters);
+    collect_query_parameters(&set_operation.right, parameters);
+    collect_sort_and_limit_parameters(
+        &set_operation.order_by,
+        &set_operation.limit,
+        &set_operation.offset,
+        parameters,
+    );
+}
+
+fn collect_sort_and_limit_parameters<'a>(
+    order_by: &'a Vec<SortExpression>,
+    limit: &'a Option<Expression>,
+    offset: &'a Option<Expression>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for sort_expression in order_by {
+        collect_expression_parameters(&sort_expression.expression, parameters);
+    }
+    collect_optional_expression_parameters(limit, parameters);
+    collect_optional_expression_parameters(offset, parameters);
+}
+
+fn collect_from_items_parameters<'a>(
+    from_items: &'a Vec<FromItem>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for from_item in from_items {
+        collect_from_item_parameters(from_item, parameters);
+    }
+}
+
+fn collect_from_item_parameters<'a>(from_item: &'a FromItem, parameters: &mut Vec<&'a Parameter>) {
+    match from_item {
+        FromItem::Table(table) => {
+            if let Some(tablesample) = &table.tablesample {
+                collect_expressions_parameters(&tablesample.arguments, parameters);
+                collect_optional_expression_parameters(&tablesample.repeatable_seed, parameters);
+            }
+        }
+        FromItem::Subquery(subquery) => collect_query_parameters(&subquery.query, parameters),
+        FromItem::Function(function) => match &function.function {
+            FromFunction::Call(function_call) => {
+                collect_expressions_parameters(&function_call.arguments, parameters)
+            }
+            FromFunction::RowsFrom(functions) => {
+                for rows_from_function in functions {
+                    collect_expressions_parameters(
+                        &rows_from_function.function_call.arguments,
+                        parameters,
+                    );
+                }
+            }
+        },
+    }
+}
+
+fn collect_insert_parameters<'a>(
+    insert_command: &'a InsertCommand,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    match &insert_command.source {
+        InsertSource::Values(rows) => {
+            for row in rows {
+                collect_expressions_parameters(row, parameters);
+            }
+        }
+        InsertSource::Query(query) => collect_query_parameters(query, parameters),
+        InsertSource::DefaultValues => {}
+    }
+    if let Some(on_conflict) = &insert_command.on_conflict {
+        if let Some(ConflictTarget::IndexExpressions {
+            expressions,
+            index_predicate,
+        }) = &on_conflict.target
+        {
+            collect_expressions_parameters(expressions, parameters);
+            collect_optional_expression_parameters(index_predicate, parameters);
+        }
+        if let ConflictAction::DoUpdate {
+            assignments,
+            where_clause,
+        } = &on_conflict.action
+        {
+            collect_assignments_parameters(assignments, parameters);
+            collect_optional_expression_parameters(where_clause, parameters);
+        }
+    }
+    collect_returning_parameters(&insert_command.returning, parameters);
+}
+
+fn collect_update_parameters<'a>(
+    update_command: &'a UpdateCommand,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    collect_assignments_parameters(&update_command.assignments, parameters);
+    collect_from_items_parameters(&update_command.from_items, parameters);
+    collect_where_clause_parameters(&update_command.where_clause, parameters);
+    collect_returning_parameters(&update_command.returning, parameters);
+}
+
+fn collect_delete_parameters<'a>(
+    delete_command: &'a DeleteCommand,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    collect_from_items_parameters(&delete_command.using_items, parameters);
+    collect_where_clause_parameters(&delete_command.where_clause, parameters);
+    collect_returning_parameters(&delete_command.returning, parameters);
+}
+
+fn collect_merge_parameters<'a>(
+    merge_command: &'a MergeCommand,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    if let MergeSource::Query { query, .. } = &merge_command.source {
+        collect_query_parameters(query, parameters);
+    }
+    collect_expression_parameters(&merge_command.join_condition, parameters);
+    for when_clause in &merge_command.when_clauses {
+        collect_optional_expression_parameters(&when_clause.condition, parameters);
+        match &when_clause.action {
+            MergeAction::Update(assignments) => {
+                collect_assignments_parameters(assignments, parameters)
+            }
+            MergeAction::Insert(merge_insert) => {
+                if let Some(values) = &merge_insert.values {
+                    collect_expressions_parameters(values, parameters);
+                }
+            }
+            MergeAction::Delete | MergeAction::DoNothing => {}
+        }
+    }
+    collect_returning_parameters(&merge_command.returning, parameters);
+}
+
+fn collect_data_definition_parameters<'a>(
+    command: &'a DataDefinitionCommand,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    match command {
+        DataDefinitionCommand::CreateTable(create_table_command) => {
+            collect_create_table_parameters(create_table_command, parameters)
+        }
+        DataDefinitionCommand::AlterTable(alter_table_command) => {
+            for action in &alter_table_command.actions {
+                collect_alter_table_action_parameters(action, parameters);
+            }
+        }
+        DataDefinitionCommand::CreateIndex(create_index_command) => {
+            collect_index_elements_parameters(&create_index_command.elements, parameters);
+            collect_storage_parameters_parameters(
+                &create_index_command.storage_parameters,
+                parameters,
+            );
+            collect_optional_expression_parameters(&create_index_command.where_clause, parameters);
+        }
+        DataDefinitionCommand::CreateView(create_view_command) => {
+            collect_storage_parameters_parameters(&create_view_command.options, parameters);
+            collect_query_parameters(&create_view_command.query, parameters);
+        }
+        DataDefinitionCommand::CreateMaterializedView(create_materialized_view_command) => {
+            collect_storage_parameters_parameters(
+                &create_materialized_view_command.storage_parameters,
+                parameters,
+            );
+            collect_query_parameters(&create_materialized_view_command.query, parameters);
+        }
+        DataDefinitionCommand::CreateFunction(create_function_command) => {
+            collect_create_function_parameters(create_function_command, parameters)
+        }
+        DataDefinitionCommand::CreateTrigger(create_trigger_command) => {
+            collect_optional_expression_parameters(&create_trigger_command.condition, parameters);
+            collect_expressions_parameters(&create_trigger_command.arguments, parameters);
+        }
+        DataDefinitionCommand::CreateType(create_type_command) => {
+            match &create_type_command.definition {
+                Some(TypeDefinition::Range(options)) | Some(TypeDefinition::Base(options)) => {
+                    for option in options {
+                        if let Some(TypeOptionValue::Expression(expression)) = &option.value {
+                            collect_expression_parameters(expression, parameters);
+                        }
+                    }
+                }
+                Some(TypeDefinition::Composite(_)) | Some(TypeDefinition::Enum(_)) | None => {}
+            }
+        }
+        DataDefinitionCommand::CreateDomain(create_domain_command) => {
+            collect_optional_expression_parameters(&create_domain_command.default, parameters);
+            for constraint in &create_domain_command.constraints {
+                if let DomainConstraintKind::Check(expression) = &constraint.kind {
+                    collect_expression_parameters(expression, parameters);
+                }
+            }
+        }
+        DataDefinitionCommand::CreateSequence(create_sequence_command) => {
+            collect_sequence_options_parameters(&create_sequence_command.options, parameters)
+        }
+        DataDefinitionCommand::AlterSequence(alter_sequence_command) => {
+            collect_sequence_options_parameters(&alter_sequence_command.options, parameters)
+        }
+        DataDefinitionCommand::CreateSchema(create_schema_command) => {
+            for element in &create_schema_command.elements {
+                collect_command_parameters(element, parameters);
+            }
+        }
+        DataDefinitionCommand::CreateDatabase(create_database_command) => {
+            collect_database_options_parameters(&create_database_command.options, parameters)
+        }
+        DataDefinitionCommand::AlterDatabase(alter_database_command) => {
+            match &alter_database_command.action {
+                AlterDatabaseAction::Options(options) => {
+                    collect_database_options_parameters(options, parameters)
+                }
+                AlterDatabaseAction::Set(setting) => {
+                    collect_configuration_setting_parameters(setting, parameters)
+                }
+                AlterDatabaseAction::RenameTo(_)
+                | AlterDatabaseAction::OwnerTo(_)
+                | AlterDatabaseAction::SetTablespace(_)
+                | AlterDatabaseAction::Reset(_) => {}
+            }
+        }
+        DataDefinitionCommand::CreateTablespace(create_tablespace_command) => {
+            collect_storage_parameters_parameters(&create_tablespace_command.options, parameters)
+        }
+        DataDefinitionCommand::AlterTablespace(alter_tablespace_command) => {
+            match &alter_tablespace_command.action {
+                AlterTablespaceAction::SetOptions(options)
+                | AlterTablespaceAction::ResetOptions(options) => {
+                    collect_storage_parameters_parameters(options, parameters)
+                }
+                AlterTablespaceAction::RenameTo(_) | AlterTablespaceAction::OwnerTo(_) => {}
+            }
+        }
+        DataDefinitionCommand::CreateRole(create_role_command) => {
+            collect_role_options_parameters(&create_role_command.options, parameters)
+        }
+        DataDefinitionCommand::AlterRole(alter_role_command) => match &alter_role_command.action {
+            AlterRoleAction::Options(options) => {
+                collect_role_options_parameters(options, parameters)
+            }
+            AlterRoleAction::Set { setting, .. } => {
+                collect_configuration_setting_parameters(setting, parameters)
+            }
+            AlterRoleAction::RenameTo(_) | AlterRoleAction::Reset { .. } => {}
+        },
+        DataDefinitionCommand::CreatePolicy(create_policy_command) => {
+            collect_optional_expression_parameters(&create_policy_command.using, parameters);
+            collect_optional_expression_parameters(&create_policy_command.with_check, parameters);
+        }
+        DataDefinitionCommand::AlterPolicy(alter_policy_command) => {
+            if let AlterPolicyAction::Update {
+                using, with_check, ..
+            } = &alter_policy_command.action
+            {
+                collect_optional_expression_parameters(using, parameters);
+                collect_optional_expression_parameters(with_check, parameters);
+            }
+        }
+        DataDefinitionCommand::Drop(drop_command) => {
+            for object in &drop_command.objects {
+                collect_signature_parameters(&object.parameters, parameters);
+            }
+        }
+        // Names, labels, versions and event filters are never expressions
+        DataDefinitionCommand::RefreshMaterializedView(_)
+        | DataDefinitionCommand::CreateEventTrigger(_)
+        | DataDefinitionCommand::AlterType(_)
+        | DataDefinitionCommand::CreateExtension(_)
+        | DataDefinitionCommand::AlterExtension(_)
+        | DataDefinitionCommand::AlterDefaultPrivileges(_) => {}
+    }
+}
+
+fn collect_create_table_parameters<'a>(
+    create_table_command: &'a CreateTableCommand,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for element in &create_table_command.elements {
+        match element {
+            TableElement::Column(column) => {
+                collect_column_definition_parameters(column, parameters)
+            }
+            TableElement::Constraint(constraint) => {
+                collect_table_constraint_parameters(constraint, parameters)
+            }
+            TableElement::Like(_) => {}
+        }
+    }
+    if let Some(partition_of) = &create_table_command.partition_of {
+        collect_partition_bound_parameters(&partition_of.bound, parameters);
+    }
+    if let Some(partition_by) = &create_table_command.partition_by {
+        for key in &partition_by.keys {
+            collect_expression_parameters(&key.expression, parameters);
+        }
+    }
+    collect_storage_parameters_parameters(&create_table_command.storage_parameters, parameters);
+}
+
+fn collect_alter_table_action_parameters<'a>(
+    action: &'a AlterTableAction,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    match action {
+        AlterTableAction::AddColumn { column, .. } => {
+            collect_column_definition_parameters(column, parameters)
+        }
+        AlterTableAction::AlterColumn { action, .. } => match action {
+            AlterColumnAction::SetDataType { using, .. } => {
+                collect_optional_expression_parameters(using, parameters)
+            }
+            AlterColumnAction::SetDefault(expression)
+            | AlterColumnAction::SetStatistics(expression) => {
+                collect_expression_parameters(expression, parameters)
+            }
+            AlterColumnAction::AddIdentity {
+                sequence_options, ..
+            } => collect_sequence_options_parameters(sequence_options, parameters),
+            AlterColumnAction::DropDefault
+            | AlterColumnAction::SetNotNull
+            | AlterColumnAction::DropNotNull
+            | AlterColumnAction::DropIdentity { .. }
+            | AlterColumnAction::SetStorage(_) => {}
+        },
+        AlterTableAction::AddConstraint { constraint, .. } => {
+            collect_table_constraint_parameters(constraint, parameters)
+        }
+        AlterTableAction::AttachPartition { bound, .. } => {
+            collect_partition_bound_parameters(bound, parameters)
+        }
+        AlterTableAction::SetStorageParameters(storage_parameters)
+        | AlterTableAction::ResetStorageParameters(storage_parameters) => {
+            collect_storage_parameters_parameters(storage_parameters, parameters)
+        }
+        AlterTableAction::AddConstraintUsingIndex(_)
+        | AlterTableAction::DropColumn { .. }
+        | AlterTableAction::DropConstraint { .. }
+        | AlterTableAction::ValidateConstraint(_)
+        | AlterTableAction::RenameConstraint { .. }
+        | AlterTableAction::RenameColumn { .. }
+        | AlterTableAction::RenameTo(_)
+        | AlterTableAction::SetSchema(_)
+        | AlterTableAction::DetachPartition { .. }
+        | AlterTableAction::SetTriggerState { .. }
+        | AlterTableAction::RowLevelSecurity(_)
+        | AlterTableAction::OwnerTo(_) => {}
+    }
+}
+
+fn collect_column_definition_parameters<'a>(
+    column: &'a ColumnDefinition,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for constraint in &column.constraints {
+        match &constraint.kind {
+            ColumnConstraintKind::Default(expression)
+            | ColumnConstraintKind::Check { expression, .. }
+            | ColumnConstraintKind::Generated(expression) => {
+                collect_expression_parameters(expression, parameters)
+            }
+            ColumnConstraintKind::Identity {
+                sequence_options, ..
+            } => collect_sequence_options_parameters(sequence_options, parameters),
+            ColumnConstraintKind::NotNull
+            | ColumnConstraintKind::Null
+            | ColumnConstraintKind::Unique { .. }
+            | ColumnConstraintKind::PrimaryKey
+            | ColumnConstraintKind::References(_) => {}
+        }
+    }
+}
+
+fn collect_table_constraint_parameters<'a>(
+    constraint: &'a TableConstraint,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    match &constraint.kind {
+        TableConstraintKind::Check { expression, .. } => {
+            collect_expression_parameters(expression, parameters)
+        }
+        TableConstraintKind::Exclude {
+            elements,
+            where_clause,
+            ..
+        } => {
+            for element in elements {
+                collect_expression_parameters(&element.element.expression, parameters);
+            }
+            collect_optional_expression_parameters(where_clause, parameters);
+        }
+        TableConstraintKind::Unique { .. }
+        | TableConstraintKind::PrimaryKey { .. }
+        | TableConstraintKind::ForeignKey { .. } => {}
+    }
+}
+
+fn collect_partition_bound_parameters<'a>(
+    bound: &'a PartitionBound,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    match bound {
+        PartitionBound::In(expressions) => collect_expressions_parameters(expressions, parameters),
+        PartitionBound::Range { from, to } => {
+            for value in from.iter().chain(to) {
+                if let PartitionRangeValue::Value(expression) = value {
+                    collect_expression_parameters(expression, parameters);
+                }
+            }
+        }
+        PartitionBound::Hash { modulus, remainder } => {
+            collect_expression_parameters(modulus, parameters);
+            collect_expression_parameters(remainder, parameters);
+        }
+        PartitionBound::Default => {}
+    }
+}
+
+fn collect_index_elements_parameters<'a>(
+    elements: &'a Vec<IndexElement>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for element in elements {
+        collect_expression_parameters(&element.expression, parameters);
+    }
+}
+
+fn collect_storage_parameters_parameters<'a>(
+    storage_parameters: &'a Vec<StorageParameter>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for storage_parameter in storage_parameters {
+        collect_optional_expression_parameters(&storage_parameter.value, parameters);
+    }
+}
+
+fn collect_sequence_options_parameters<'a>(
+    options: &'a Vec<SequenceOption>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for option in options {
+        match option {
+            SequenceOption::IncrementBy(expression)
+            | SequenceOption::StartWith(expression)
+            | SequenceOption::Cache(expression) => {
+                collect_expression_parameters(expression, parameters)
+            }
+            SequenceOption::MinValue(expression)
+            | SequenceOption::MaxValue(expression)
+            | SequenceOption::Restart(expression) => {
+                collect_optional_expression_parameters(expression, parameters)
+            }
+            SequenceOption::As(_)
+            | SequenceOption::Cycle(_)
+            | SequenceOption::OwnedBy(_)
+            | SequenceOption::SequenceName(_) => {}
+        }
+    }
+}
+
+fn collect_database_options_parameters<'a>(
+    options: &'a Vec<DatabaseOption>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for option in options {
+        collect_optional_expression_parameters(&option.value, parameters);
+    }
+}
+
+fn collect_role_options_parameters<'a>(
+    options: &'a Vec<RoleOption>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for option in options {
+        if let RoleOption::ConnectionLimit(expression) = option {
+            collect_expression_parameters(expression, parameters);
+        }
+    }
+}
+
+fn collect_create_function_parameters<'a>(
+    create_function_command: &'a CreateFunctionCommand,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for function_parameter in &create_function_command.parameters {
+        collect_optional_expression_parameters(&function_parameter.default, parameters);
+    }
+    for option in &create_function_command.options {
+        match option {
+            FunctionOption::Cost(expression) | FunctionOption::Rows(expression) => {
+                collect_expression_parameters(expression, parameters)
+            }
+            FunctionOption::Set(setting) => {
+                collect_configuration_setting_parameters(setting, parameters)
+            }
+            FunctionOption::Language(_)
+            | FunctionOption::Volatility(_)
+            | FunctionOption::Strict(_)
+            | FunctionOption::SecurityDefiner(_)
+            | FunctionOption::Leakproof(_)
+            | FunctionOption::Parallel(_) => {}
+        }
+    }
+    match &create_function_command.body {
+        Some(FunctionBody::Return(expression)) => {
+            collect_expression_parameters(expression, parameters)
+        }
+        Some(FunctionBody::Atomic(commands)) => {
+            for command in commands {
+                collect_command_parameters(command, parameters);
+            }
+        }
+        Some(FunctionBody::Definition(_)) | Some(FunctionBody::ObjectFile { .. }) | None => {}
+    }
+}
+
+// The parameter defaults of a function signature, as in
+// DROP FUNCTION f(integer DEFAULT 1)
+fn collect_signature_parameters<'a>(
+    signature: &'a Option<Vec<FunctionParameter>>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    if let Some(function_parameters) = signature {
+        for function_parameter in function_parameters {
+            collect_optional_expression_parameters(&function_parameter.default, parameters);
+        }
+    }
+}
+
+fn collect_data_control_parameters<'a>(
+    command: &'a DataControlCommand,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    let target = match command {
+        DataControlCommand::Grant(grant_command) => &grant_command.target,
+        DataControlCommand::Revoke(revoke_command) => &revoke_command.target,
+        DataControlCommand::GrantRole(_) | DataControlCommand::RevokeRole(_) => return,
+    };
+    if let PrivilegeTarget::Objects { objects, .. } = target {
+        for object in objects {
+            collect_signature_parameters(&object.parameters, parameters);
+        }
+    }
+}
+
+fn collect_utility_parameters<'a>(
+    command: &'a UtilityCommand,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    match command {
+        UtilityCommand::Set(set_command) => match &set_command.action {
+            SetAction::Configuration(setting) => {
+                collect_configuration_setting_parameters(setting, parameters)
+            }
+            SetAction::TimeZone(time_zone) => {
+                collect_optional_expression_parameters(time_zone, parameters)
+            }
+            SetAction::Role(_) | SetAction::SessionAuthorization(_) => {}
+        },
+        // The statement of an EXPLAIN keeps its parameters, e.g. for
+        // EXPLAIN (GENERIC_PLAN)
+        UtilityCommand::Explain(explain_command) => {
+            collect_command_parameters(&explain_command.statement, parameters)
+        }
+        UtilityCommand::Copy(copy_command) => {
+            if let CopyRelation::Query(query) = &copy_command.relation {
+                collect_query_parameters(query, parameters);
+            }
+            for option in &copy_command.options {
+                if let CopyOption::RejectLimit(expression) = option {
+                    collect_expression_parameters(expression, parameters);
+                }
+            }
+        }
+        // The body of a DO is in its own language, and RESET and SHOW only
+        // name a configuration parameter
+        UtilityCommand::Do(_) | UtilityCommand::Reset(_) | UtilityCommand::Show(_) => {}
+    }
+}
+
+fn collect_configuration_setting_parameters<'a>(
+    setting: &'a ConfigurationSetting,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    if let ConfigurationValue::Values(values) = &setting.value {
+        collect_expressions_parameters(values, parameters);
+    }
+}
+
+fn collect_where_clause_parameters<'a>(
+    where_clause: &'a Option<WhereClause>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    if let Some(WhereClause::Condition(condition)) = where_clause {
+        collect_expression_parameters(condition, parameters);
+    }
+}
+
+fn collect_assignments_parameters<'a>(
+    assignments: &'a Vec<Assignment>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for assignment in assignments {
+        collect_expression_parameters(&assignment.value, parameters);
+    }
+}
+
+fn collect_returning_parameters<'a>(
+    returning: &'a Vec<OutputExpression>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for output_expression in returning {
+        if let SelectedExpression::Expression(expression) = &output_expression.expression {
+            collect_expression_parameters(expression, parameters);
+        }
+    }
+}
+
+fn collect_selected_expressions_parameters<'a>(
+    selected_expressions: &'a Vec<SelectedExpression>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for selected_expression in selected_expressions {
+        match selected_expression {
+            SelectedExpression::AllColumns(_) | SelectedExpression::Column(_) => {}
+            SelectedExpression::Expression(expression) => {
+                collect_expression_parameters(expression, parameters)
+            }
+        }
+    }
+}
+
+fn collect_optional_expression_parameters<'a>(
+    expression: &'a Option<Expression>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    if let Some(expression) = expression {
+        collect_expression_parameters(expression, parameters);
+    }
+}
+
+fn collect_expressions_parameters<'a>(
+    expressions: &'a Vec<Expression>,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    for expression in expressions {
+        collect_expression_parameters(expression, parameters);
+    }
+}
+
+pub fn collect_expression_parameters<'a>(
+    expression: &'a Expression,
+    parameters: &mut Vec<&'a Parameter>,
+) {
+    match expression {
+        Expression::Parameter(parameter) => parameters.push(parameter),
+        Expression::Column(_)
+        | Expression::Literal(_)
+        | Expression::Default
+        | Expression::AllColumns(_) => {}
+        Expression::FunctionCall(function_call) => {
+            collect_expressions_parameters(&function_call.arguments, parameters)
+        }
+        Expression::UnaryOperation(operation) => {
+            collect_expression_parameters(&operation.operand, parameters)
+        }
+        Expression::BinaryOperation(operation) => {
+            collect_expression_parameters(&operation.left, parameters);
+            collect_expression_parameters(&operation.right, parameters);
+        }
+        Expression::Row(expressions) => collect_expressions_parameters(expressions, parameters),
+        Expression::Cast(cast) => collect_expression_parameters(&cast.expression, parameters),
+        Expression::Subquery(query) | Expression::Exists(query) => {
+            collect_query_parameters(query, parameters)
+        }
+        Expression::Between(between) => {
+            collect_expression_parameters(&between.expression, parameters);
+            collect_expression_parameters(&between.low, parameters);
+            collect_expression_parameters(&between.high, parameters);
+        }
+        Expression::Case(case) => {
+            if let Some(operand) = &case.operand {
+                collect_expression_parameters(operand, parameters);
+            }
+            for when_clause in &case.when_clauses {
+                collect_expression_parameters(&when_clause.condition, parameters);
+                collect_expression_parameters(&when_clause.result, parameters);
+            }
+            if let Some(else_result) = &case.else_result {
+                collect_expression_parameters(else_result, parameters);
+            }
+        }
+    }
+}
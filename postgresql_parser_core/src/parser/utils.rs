@@ -1,15 +1,49 @@
-use crate::lexer::char_is_whitespace;
 use crate::lexer::token::Token;
-use crate::parser::commands::parse_section::ParseCommandSectionResult;
+use crate::lexer::token_value_is_whitespace;
+use crate::parser::ast::Command;
+use crate::parser::commands::parse_section::{parse_section, ParseCommandSectionResult};
+use crate::parser::commands::sections::keywords::{
+    parse_keywords, ATOMIC_KEYWORD, BEGIN_KEYWORD, CASE_KEYWORD, END_KEYWORD,
+};
+use crate::parser::commands::sections::semicolon::parse_semicolon;
+use crate::parser::parse_command_result::ParseCommandResult;
 
+// Skips past the semicolon ending the command. The semicolons between the
+// statements of a BEGIN ATOMIC ... END function body don't end the command.
 pub fn skip_invalid_command(tokens: &Vec<Token>, start_idx: usize) -> usize {
     let mut idx = start_idx;
     while !token_is_semicolon(tokens.get(idx)) && option_is_some(tokens.get(idx)) {
-        idx += 1;
+        if let ParseCommandSectionResult::Valid(idx_after_begin_atomic, _) =
+            parse_keywords(tokens, idx, &[BEGIN_KEYWORD, ATOMIC_KEYWORD])
+        {
+            idx = idx_after_atomic_body(tokens, idx_after_begin_atomic);
+        } else {
+            idx += 1;
+        }
     }
     return idx + 1;
 }
 
+// The index after the END closing a BEGIN ATOMIC body, counting the END of
+// any CASE expressions in its statements
+fn idx_after_atomic_body(tokens: &Vec<Token>, start_idx: usize) -> usize {
+    let mut idx = start_idx;
+    let mut depth = 1;
+    while let Some(token) = tokens.get(idx) {
+        idx += 1;
+        let lowered = token.value.to_ascii_lowercase();
+        if lowered == CASE_KEYWORD {
+            depth += 1;
+        } else if lowered == END_KEYWORD {
+            depth -= 1;
+            if depth == 0 {
+                break;
+            }
+        }
+    }
+    return idx;
+}
+
 fn token_is_semicolon(token: Option<&Token>) -> bool {
     return token_is_str(token, ";");
 }
@@ -51,11 +85,10 @@ pub fn idx_after_optional_whitespace(tokens: &Vec<Token>, start_idx: usize) -> u
 }
 
 pub fn token_is_whitespace(token: &Token) -> bool {
-    let first_char = token.value.chars().next();
-    return match first_char {
-        Some(character) => char_is_whitespace(character),
-        None => panic!("Received a token with no first character: {:?}", token),
-    };
+    if token.value.is_empty() {
+        panic!("Received a token with no first character: {:?}", token);
+    }
+    return token_value_is_whitespace(&token.value);
 }
 
 pub fn parse_simple_token<TParsedDatum, FTestFunction>(
@@ -79,6 +112,21 @@ where
     };
 }
 
+// Parses a command body followed by the semicolon that ends the command
+pub fn parse_terminated_command<TParsedCommand>(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    parse_command_body: fn(&Vec<Token>, usize) -> ParseCommandSectionResult<TParsedCommand>,
+    into_command: fn(TParsedCommand) -> Command,
+) -> ParseCommandResult {
+    let mut idx = start_idx;
+    let parsed_command: TParsedCommand;
+    (idx, parsed_command) = parse_section!(parse_command_body, tokens, idx);
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section!(parse_semicolon, tokens, idx);
+    return ParseCommandResult::Valid(into_command(parsed_command), idx);
+}
+
 pub fn empty_parsed_datum(_: &Token) -> () {
     return ();
 }
@@ -0,0 +1,240 @@
+use crate::parser::ast::{
+    Command, Expression, FunctionColumnDefinition, Identifier, QualifiedName, TypeName,
+};
+
+// [<<label>>] [DECLARE declaration ...] BEGIN statement ...
+//     [EXCEPTION WHEN condition THEN statement ... ...] END [label]
+#[derive(Debug, PartialEq)]
+pub struct Block {
+    pub label: Option<Identifier>,
+    pub declarations: Vec<Declaration>,
+    pub statements: Vec<Statement>,
+    pub exception_handlers: Vec<ExceptionHandler>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Declaration {
+    Variable(VariableDeclaration),
+    // name ALIAS FOR parameter, where the parameter is e.g. $1
+    Alias {
+        name: Identifier,
+        target: Expression,
+    },
+    Cursor(CursorDeclaration),
+}
+
+// name [CONSTANT] type [COLLATE collation] [NOT NULL]
+//     [{DEFAULT | := | =} expression]
+#[derive(Debug, PartialEq)]
+pub struct VariableDeclaration {
+    pub name: Identifier,
+    pub constant: bool,
+    pub data_type: DataType,
+    pub collation: Option<QualifiedName>,
+    pub not_null: bool,
+    pub default: Option<Expression>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DataType {
+    Type(TypeName),
+    // The type of a variable or table column, as in users.email%TYPE
+    TypeOf(QualifiedName),
+    // The row type of a table, as in users%ROWTYPE
+    RowTypeOf(QualifiedName),
+}
+
+// name CURSOR [(argument type, ...)] {FOR | IS} query
+#[derive(Debug, PartialEq)]
+pub struct CursorDeclaration {
+    pub name: Identifier,
+    pub arguments: Vec<FunctionColumnDefinition>,
+    pub query: EmbeddedSql,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Statement {
+    Block(Block),
+    // target {:= | =} expression
+    Assignment {
+        target: Expression,
+        value: Expression,
+    },
+    If(IfStatement),
+    Case(CaseStatement),
+    Loop(LoopStatement),
+    // EXIT [label] [WHEN condition]
+    Exit {
+        label: Option<Identifier>,
+        condition: Option<Expression>,
+    },
+    // CONTINUE [label] [WHEN condition]
+    Continue {
+        label: Option<Identifier>,
+        condition: Option<Expression>,
+    },
+    Return(Option<Expression>),
+    ReturnNext(Option<Expression>),
+    ReturnQuery(EmbeddedSql),
+    ReturnQueryExecute(ExecuteStatement),
+    Raise(RaiseStatement),
+    // PERFORM query, which is run as the query with SELECT in place of
+    // PERFORM and its result discarded
+    Perform(EmbeddedSql),
+    Execute(ExecuteStatement),
+    GetDiagnostics(GetDiagnosticsStatement),
+    Null,
+    Sql(EmbeddedSql),
+}
+
+// A SQL command run from PL/pgSQL, which may store its result with INTO
+#[derive(Debug, PartialEq)]
+pub struct EmbeddedSql {
+    // The command as written, including any INTO clause
+    pub text: String,
+    // The command without its INTO clause, when the core parser accepts it
+    pub command: Option<Command>,
+    pub into: Option<IntoClause>,
+}
+
+// INTO [STRICT] target, ...
+#[derive(Debug, PartialEq)]
+pub struct IntoClause {
+    pub strict: bool,
+    pub targets: Vec<Expression>,
+}
+
+// IF condition THEN statement ... [ELSIF condition THEN statement ... ...]
+//     [ELSE statement ...] END IF
+#[derive(Debug, PartialEq)]
+pub struct IfStatement {
+    pub branches: Vec<ConditionalBranch>,
+    pub else_statements: Option<Vec<Statement>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ConditionalBranch {
+    pub condition: Expression,
+    pub statements: Vec<Statement>,
+}
+
+// CASE [expression] WHEN value, ... THEN statement ... ...
+//     [ELSE statement ...] END CASE
+#[derive(Debug, PartialEq)]
+pub struct CaseStatement {
+    pub expression: Option<Expression>,
+    pub branches: Vec<CaseBranch>,
+    pub else_statements: Option<Vec<Statement>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CaseBranch {
+    // The values compared with the CASE expression, or a single condition
+    // when there is none
+    pub values: Vec<Expression>,
+    pub statements: Vec<Statement>,
+}
+
+// [<<label>>] [WHILE condition | FOR ... | FOREACH ...] LOOP statement ...
+//     END LOOP [label]
+#[derive(Debug, PartialEq)]
+pub struct LoopStatement {
+    pub label: Option<Identifier>,
+    pub kind: LoopKind,
+    pub statements: Vec<Statement>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LoopKind {
+    Loop,
+    While(Expression),
+    // FOR name IN [REVERSE] lower .. upper [BY step]
+    IntegerRange {
+        variable: Identifier,
+        reverse: bool,
+        lower: Expression,
+        upper: Expression,
+        step: Option<Expression>,
+    },
+    // FOR target, ... IN query
+    Query {
+        targets: Vec<Identifier>,
+        query: EmbeddedSql,
+    },
+    // FOR target, ... IN EXECUTE command [USING expression, ...]
+    Execute {
+        targets: Vec<Identifier>,
+        execute: ExecuteStatement,
+    },
+    // FOREACH target, ... [SLICE dimensions] IN ARRAY expression
+    ForEach {
+        targets: Vec<Identifier>,
+        slice: Option<Expression>,
+        array: Expression,
+    },
+}
+
+// EXECUTE command [INTO [STRICT] target, ...] [USING expression, ...]
+#[derive(Debug, PartialEq)]
+pub struct ExecuteStatement {
+    pub command: Expression,
+    pub into: Option<IntoClause>,
+    pub using: Vec<Expression>,
+}
+
+// RAISE [level] {'format' [, expression ...] | condition | SQLSTATE 'code'}
+//     [USING option = expression, ...], or just RAISE to re-raise the
+//     current exception
+#[derive(Debug, PartialEq)]
+pub struct RaiseStatement {
+    pub level: Option<RaiseLevel>,
+    pub message: Option<String>,
+    pub parameters: Vec<Expression>,
+    pub condition: Option<ExceptionCondition>,
+    pub options: Vec<RaiseOption>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RaiseLevel {
+    Debug,
+    Log,
+    Info,
+    Notice,
+    Warning,
+    Exception,
+}
+
+// One of the USING options of RAISE, e.g. MESSAGE, DETAIL, HINT or ERRCODE
+#[derive(Debug, PartialEq)]
+pub struct RaiseOption {
+    pub name: Identifier,
+    pub value: Expression,
+}
+
+// GET [CURRENT | STACKED] DIAGNOSTICS target {= | :=} item, ...
+#[derive(Debug, PartialEq)]
+pub struct GetDiagnosticsStatement {
+    pub stacked: bool,
+    pub items: Vec<DiagnosticsItem>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DiagnosticsItem {
+    pub target: Identifier,
+    // e.g. ROW_COUNT, or MESSAGE_TEXT in an exception handler
+    pub item: Identifier,
+}
+
+// WHEN condition [OR condition ...] THEN statement ...
+#[derive(Debug, PartialEq)]
+pub struct ExceptionHandler {
+    pub conditions: Vec<ExceptionCondition>,
+    pub statements: Vec<Statement>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ExceptionCondition {
+    // A condition name such as unique_violation, or OTHERS
+    Name(Identifier),
+    SqlState(String),
+}
@@ -0,0 +1,295 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::Identifier;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::{parse_expression, parse_string_literal};
+use crate::parser::commands::sections::from_item::parse_column_definitions;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, ALIAS_KEYWORD, BEGIN_KEYWORD,
+    COLLATE_KEYWORD, CONSTANT_KEYWORD, CURSOR_KEYWORD, DECLARE_KEYWORD, DEFAULT_KEYWORD,
+    END_KEYWORD, EXCEPTION_KEYWORD, FOR_KEYWORD, IS_KEYWORD, NOT_KEYWORD, NULL_KEYWORD, OR_KEYWORD,
+    ROWTYPE_KEYWORD, SQLSTATE_KEYWORD, THEN_KEYWORD, TYPE_KEYWORD, WHEN_KEYWORD,
+};
+use crate::parser::commands::sections::operator::parse_operator;
+use crate::parser::commands::sections::qualified_name::parse_qualified_name;
+use crate::parser::commands::sections::semicolon::parse_semicolon;
+use crate::parser::commands::sections::type_name::parse_type_name;
+use crate::parser::utils::idx_after_optional_whitespace;
+use crate::plpgsql::ast::{
+    Block, CursorDeclaration, DataType, Declaration, ExceptionCondition, ExceptionHandler,
+    VariableDeclaration,
+};
+use crate::plpgsql::embedded_sql::parse_embedded_sql;
+use crate::plpgsql::statement::{parse_assignment_operator, parse_statements};
+
+// Parses a block from its DECLARE or BEGIN, the label having already been
+// read by the caller
+pub fn parse_block(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    label: Option<Identifier>,
+) -> ParseCommandSectionResult<Block> {
+    let mut idx = start_idx;
+
+    let mut declarations = Vec::new();
+    if let ParseCommandSectionResult::Valid(idx_after_declare, _) =
+        parse_keyword(tokens, idx, DECLARE_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_declare);
+        while let ParseCommandSectionResult::Invalid = parse_keyword(tokens, idx, BEGIN_KEYWORD) {
+            let (idx_after_declaration, declaration) =
+                parse_section_from_section!(parse_declaration(tokens, idx));
+            idx = idx_after_optional_whitespace(tokens, idx_after_declaration);
+            declarations.push(declaration);
+        }
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, BEGIN_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let statements;
+    (idx, statements) = parse_section_from_section!(parse_statements(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut exception_handlers = Vec::new();
+    if let ParseCommandSectionResult::Valid(idx_after_exception, _) =
+        parse_keyword(tokens, idx, EXCEPTION_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_exception);
+        while let ParseCommandSectionResult::Valid(_, _) = parse_keyword(tokens, idx, WHEN_KEYWORD)
+        {
+            let (idx_after_handler, handler) =
+                parse_section_from_section!(parse_exception_handler(tokens, idx));
+            idx = idx_after_optional_whitespace(tokens, idx_after_handler);
+            exception_handlers.push(handler);
+        }
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, END_KEYWORD));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_label, _) =
+        parse_identifier(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_label;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        Block {
+            label: label,
+            declarations: declarations,
+            statements: statements,
+            exception_handlers: exception_handlers,
+        },
+    );
+}
+
+// A variable, alias or cursor declaration, including its semicolon
+fn parse_declaration(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Declaration> {
+    let (mut idx, name) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let declaration;
+    if let ParseCommandSectionResult::Valid(idx_after_alias, _) =
+        parse_keywords(tokens, idx, &[ALIAS_KEYWORD, FOR_KEYWORD])
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_alias);
+        let (idx_after_target, target) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        idx = idx_after_target;
+        declaration = Declaration::Alias {
+            name: name,
+            target: target,
+        };
+    } else if let ParseCommandSectionResult::Valid(idx_after_cursor, _) =
+        parse_keyword(tokens, idx, CURSOR_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_cursor);
+        let mut arguments = Vec::new();
+        if let ParseCommandSectionResult::Valid(idx_after_arguments, cursor_arguments) =
+            parse_column_definitions(tokens, idx)
+        {
+            idx = idx_after_optional_whitespace(tokens, idx_after_arguments);
+            arguments = cursor_arguments;
+        }
+        (idx, _) = parse_section_from_section!(parse_one_of_keywords(
+            tokens,
+            idx,
+            &[FOR_KEYWORD, IS_KEYWORD]
+        ));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        let query;
+        (idx, query) =
+            parse_section_from_section!(parse_embedded_sql(tokens, idx, |_| false, None));
+        declaration = Declaration::Cursor(CursorDeclaration {
+            name: name,
+            arguments: arguments,
+            query: query,
+        });
+    } else {
+        let variable;
+        (idx, variable) =
+            parse_section_from_section!(parse_variable_declaration(tokens, idx, name));
+        declaration = Declaration::Variable(variable);
+    }
+
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_semicolon(tokens, idx));
+    return ParseCommandSectionResult::Valid(idx, declaration);
+}
+
+// Everything after the name of a variable declaration
+fn parse_variable_declaration(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    name: Identifier,
+) -> ParseCommandSectionResult<VariableDeclaration> {
+    let mut idx = start_idx;
+
+    let mut constant = false;
+    if let ParseCommandSectionResult::Valid(idx_after_constant, _) =
+        parse_keyword(tokens, idx, CONSTANT_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_constant);
+        constant = true;
+    }
+
+    let data_type;
+    (idx, data_type) = parse_section_from_section!(parse_data_type(tokens, idx));
+
+    let mut collation = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_collate, _) =
+        parse_keyword(tokens, idx_after_whitespace, COLLATE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_collate);
+        let (idx_after_collation, collation_name) =
+            parse_section_from_section!(parse_qualified_name(tokens, idx_after_whitespace));
+        idx = idx_after_collation;
+        collation = Some(collation_name);
+    }
+
+    let mut not_null = false;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_not_null, _) =
+        parse_keywords(tokens, idx_after_whitespace, &[NOT_KEYWORD, NULL_KEYWORD])
+    {
+        idx = idx_after_not_null;
+        not_null = true;
+    }
+
+    let mut default = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    let idx_after_default = match parse_keyword(tokens, idx_after_whitespace, DEFAULT_KEYWORD) {
+        ParseCommandSectionResult::Valid(idx_after, _) => Some(idx_after),
+        _ => match parse_assignment_operator(tokens, idx_after_whitespace) {
+            ParseCommandSectionResult::Valid(idx_after, _) => Some(idx_after),
+            _ => None,
+        },
+    };
+    if let Some(idx_after_default) = idx_after_default {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_default);
+        let (idx_after_expression, expression) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        idx = idx_after_expression;
+        default = Some(expression);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        VariableDeclaration {
+            name: name,
+            constant: constant,
+            data_type: data_type,
+            collation: collation,
+            not_null: not_null,
+            default: default,
+        },
+    );
+}
+
+// A type name, or name%TYPE or table%ROWTYPE
+fn parse_data_type(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<DataType> {
+    if let ParseCommandSectionResult::Valid(idx_after_name, name) =
+        parse_qualified_name(tokens, start_idx)
+    {
+        if let ParseCommandSectionResult::Valid(idx_after_percent, _) =
+            parse_operator(tokens, idx_after_name, "%")
+        {
+            let (idx_after_keyword, keyword) = parse_section_from_section!(parse_one_of_keywords(
+                tokens,
+                idx_after_percent,
+                &[TYPE_KEYWORD, ROWTYPE_KEYWORD]
+            ));
+            return match keyword.as_str() {
+                TYPE_KEYWORD => {
+                    ParseCommandSectionResult::Valid(idx_after_keyword, DataType::TypeOf(name))
+                }
+                _ => ParseCommandSectionResult::Valid(idx_after_keyword, DataType::RowTypeOf(name)),
+            };
+        }
+    }
+    let (idx_after_type, type_name) =
+        parse_section_from_section!(parse_type_name(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after_type, DataType::Type(type_name));
+}
+
+// WHEN condition [OR condition ...] THEN statement ...
+fn parse_exception_handler(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ExceptionHandler> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, WHEN_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut conditions = Vec::new();
+    loop {
+        let (idx_after_condition, condition) =
+            parse_section_from_section!(parse_exception_condition(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx_after_condition);
+        conditions.push(condition);
+        match parse_keyword(tokens, idx, OR_KEYWORD) {
+            ParseCommandSectionResult::Valid(idx_after_or, _) => {
+                idx = idx_after_optional_whitespace(tokens, idx_after_or);
+            }
+            _ => break,
+        }
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, THEN_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let statements;
+    (idx, statements) = parse_section_from_section!(parse_statements(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        ExceptionHandler {
+            conditions: conditions,
+            statements: statements,
+        },
+    );
+}
+
+// condition_name or SQLSTATE 'code'
+pub fn parse_exception_condition(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ExceptionCondition> {
+    if let ParseCommandSectionResult::Valid(idx_after_sqlstate, _) =
+        parse_keyword(tokens, start_idx, SQLSTATE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_sqlstate);
+        let (idx_after_code, code) =
+            parse_section_from_section!(parse_string_literal(tokens, idx_after_whitespace));
+        return ParseCommandSectionResult::Valid(
+            idx_after_code,
+            ExceptionCondition::SqlState(code),
+        );
+    }
+    let (idx_after_name, name) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+    return ParseCommandSectionResult::Valid(idx_after_name, ExceptionCondition::Name(name));
+}
@@ -0,0 +1,449 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Expression, Identifier, Literal};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_keywords, parse_one_of_keywords, ARRAY_KEYWORD, BY_KEYWORD, CASE_KEYWORD,
+    CONTINUE_KEYWORD, ELSEIF_KEYWORD, ELSE_KEYWORD, ELSIF_KEYWORD, END_KEYWORD, EXECUTE_KEYWORD,
+    EXIT_KEYWORD, FOREACH_KEYWORD, FOR_KEYWORD, IF_KEYWORD, IN_KEYWORD, LOOP_KEYWORD,
+    REVERSE_KEYWORD, SLICE_KEYWORD, THEN_KEYWORD, WHEN_KEYWORD, WHILE_KEYWORD, WITH_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::commands::sections::operator::parse_operator;
+use crate::parser::commands::sections::query::token_starts_query;
+use crate::parser::utils::{idx_after_optional_whitespace, parse_simple_token};
+use crate::plpgsql::ast::{
+    CaseBranch, CaseStatement, ConditionalBranch, IfStatement, LoopKind, LoopStatement, Statement,
+};
+use crate::plpgsql::embedded_sql::parse_embedded_sql;
+use crate::plpgsql::statement::{parse_execute, parse_statements};
+
+// IF condition THEN statement ... [ELSIF condition THEN statement ... ...]
+//     [ELSE statement ...] END IF
+pub fn parse_if(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<IfStatement> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, IF_KEYWORD));
+
+    let mut branches = Vec::new();
+    loop {
+        let (idx_after_branch, branch) =
+            parse_section_from_section!(parse_conditional_branch(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx_after_branch);
+        branches.push(branch);
+        match parse_one_of_keywords(tokens, idx, &[ELSIF_KEYWORD, ELSEIF_KEYWORD]) {
+            ParseCommandSectionResult::Valid(idx_after_elsif, _) => idx = idx_after_elsif,
+            _ => break,
+        }
+    }
+
+    let mut else_statements = None;
+    if let ParseCommandSectionResult::Valid(idx_after_else, _) =
+        parse_keyword(tokens, idx, ELSE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_else);
+        let (idx_after_statements, statements) =
+            parse_section_from_section!(parse_statements(tokens, idx_after_whitespace));
+        idx = idx_after_optional_whitespace(tokens, idx_after_statements);
+        else_statements = Some(statements);
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keywords(tokens, idx, &[END_KEYWORD, IF_KEYWORD]));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        IfStatement {
+            branches: branches,
+            else_statements: else_statements,
+        },
+    );
+}
+
+// condition THEN statement ..., following an IF or ELSIF
+fn parse_conditional_branch(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ConditionalBranch> {
+    let mut idx = idx_after_optional_whitespace(tokens, start_idx);
+    let condition;
+    (idx, condition) = parse_section_from_section!(parse_expression(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, THEN_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let statements;
+    (idx, statements) = parse_section_from_section!(parse_statements(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        ConditionalBranch {
+            condition: condition,
+            statements: statements,
+        },
+    );
+}
+
+// CASE [expression] WHEN value, ... THEN statement ... ...
+//     [ELSE statement ...] END CASE
+pub fn parse_case(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<CaseStatement> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, CASE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut expression = None;
+    if let ParseCommandSectionResult::Invalid = parse_keyword(tokens, idx, WHEN_KEYWORD) {
+        let (idx_after_expression, case_expression) =
+            parse_section_from_section!(parse_expression(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx_after_expression);
+        expression = Some(case_expression);
+    }
+
+    let mut branches = Vec::new();
+    while let ParseCommandSectionResult::Valid(idx_after_when, _) =
+        parse_keyword(tokens, idx, WHEN_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_when);
+        let values;
+        (idx, values) =
+            parse_section_from_section!(parse_comma_separated_list(tokens, idx, parse_expression));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, THEN_KEYWORD));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        let statements;
+        (idx, statements) = parse_section_from_section!(parse_statements(tokens, idx));
+        idx = idx_after_optional_whitespace(tokens, idx);
+        branches.push(CaseBranch {
+            values: values,
+            statements: statements,
+        });
+    }
+    if branches.is_empty() {
+        return ParseCommandSectionResult::Invalid;
+    }
+
+    let mut else_statements = None;
+    if let ParseCommandSectionResult::Valid(idx_after_else, _) =
+        parse_keyword(tokens, idx, ELSE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_else);
+        let (idx_after_statements, statements) =
+            parse_section_from_section!(parse_statements(tokens, idx_after_whitespace));
+        idx = idx_after_optional_whitespace(tokens, idx_after_statements);
+        else_statements = Some(statements);
+    }
+
+    (idx, _) =
+        parse_section_from_section!(parse_keywords(tokens, idx, &[END_KEYWORD, CASE_KEYWORD]));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        CaseStatement {
+            expression: expression,
+            branches: branches,
+            else_statements: else_statements,
+        },
+    );
+}
+
+// [WHILE condition | FOR ... | FOREACH ...] LOOP statement ... END LOOP
+//     [label], the label having already been read by the caller
+pub fn parse_loop(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    label: Option<Identifier>,
+) -> ParseCommandSectionResult<LoopStatement> {
+    let mut idx = start_idx;
+    let kind;
+    (idx, kind) = parse_section_from_section!(parse_loop_kind(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, LOOP_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let statements;
+    (idx, statements) = parse_section_from_section!(parse_statements(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) =
+        parse_section_from_section!(parse_keywords(tokens, idx, &[END_KEYWORD, LOOP_KEYWORD]));
+
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_label, _) =
+        parse_identifier(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_label;
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        LoopStatement {
+            label: label,
+            kind: kind,
+            statements: statements,
+        },
+    );
+}
+
+// Everything before the LOOP keyword
+fn parse_loop_kind(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<LoopKind> {
+    let (idx_after_keyword, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[LOOP_KEYWORD, WHILE_KEYWORD, FOR_KEYWORD, FOREACH_KEYWORD]
+    ));
+    let idx = idx_after_optional_whitespace(tokens, idx_after_keyword);
+    return match keyword.as_str() {
+        LOOP_KEYWORD => ParseCommandSectionResult::Valid(start_idx, LoopKind::Loop),
+        WHILE_KEYWORD => {
+            let (idx_after_condition, condition) =
+                parse_section_from_section!(parse_expression(tokens, idx));
+            ParseCommandSectionResult::Valid(idx_after_condition, LoopKind::While(condition))
+        }
+        FOR_KEYWORD => parse_for(tokens, idx),
+        _ => parse_foreach(tokens, idx),
+    };
+}
+
+// target, ... IN {[REVERSE] lower .. upper [BY step] | query |
+//     EXECUTE command [USING expression, ...]}
+fn parse_for(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<LoopKind> {
+    let mut idx = start_idx;
+    let targets;
+    (idx, targets) =
+        parse_section_from_section!(parse_comma_separated_list(tokens, idx, parse_identifier));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, IN_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    if let ParseCommandSectionResult::Valid(_, _) = parse_keyword(tokens, idx, EXECUTE_KEYWORD) {
+        let (idx_after_execute, execute) = parse_section_from_section!(parse_execute(tokens, idx));
+        return ParseCommandSectionResult::Valid(
+            idx_after_execute,
+            LoopKind::Execute {
+                targets: targets,
+                execute: execute,
+            },
+        );
+    }
+
+    let starts_query = token_starts_query(tokens.get(idx))
+        || matches!(
+            parse_keyword(tokens, idx, WITH_KEYWORD),
+            ParseCommandSectionResult::Valid(_, _)
+        );
+    if !starts_query && targets.len() == 1 {
+        if let ParseCommandSectionResult::Valid(idx_after_range, range) =
+            parse_integer_range(tokens, idx, &targets[0])
+        {
+            return ParseCommandSectionResult::Valid(idx_after_range, range);
+        }
+    }
+
+    // The query runs up to the LOOP keyword, and may be parenthesized
+    let (idx_after_query, query) = parse_section_from_section!(parse_embedded_sql(
+        tokens,
+        idx,
+        |token| token.value.eq_ignore_ascii_case(LOOP_KEYWORD),
+        None
+    ));
+    return ParseCommandSectionResult::Valid(
+        idx_after_query,
+        LoopKind::Query {
+            targets: targets,
+            query: query,
+        },
+    );
+}
+
+// [REVERSE] lower .. upper [BY step]
+fn parse_integer_range(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    variable: &Identifier,
+) -> ParseCommandSectionResult<LoopKind> {
+    let mut idx = start_idx;
+    let mut reverse = false;
+    if let ParseCommandSectionResult::Valid(idx_after_reverse, _) =
+        parse_keyword(tokens, idx, REVERSE_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_reverse);
+        reverse = true;
+    }
+
+    let lower;
+    (idx, lower) = parse_section_from_section!(parse_expression(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_operator(tokens, idx, ".."));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let upper;
+    (idx, upper) = parse_section_from_section!(parse_expression(tokens, idx));
+
+    let mut step = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_by, _) =
+        parse_keyword(tokens, idx_after_whitespace, BY_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_by);
+        let (idx_after_step, step_expression) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        idx = idx_after_step;
+        step = Some(step_expression);
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        LoopKind::IntegerRange {
+            variable: variable.clone(),
+            reverse: reverse,
+            lower: lower,
+            upper: upper,
+            step: step,
+        },
+    );
+}
+
+// target, ... [SLICE dimensions] IN ARRAY expression
+fn parse_foreach(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<LoopKind> {
+    let mut idx = start_idx;
+    let targets;
+    (idx, targets) =
+        parse_section_from_section!(parse_comma_separated_list(tokens, idx, parse_identifier));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut slice = None;
+    if let ParseCommandSectionResult::Valid(idx_after_slice, _) =
+        parse_keyword(tokens, idx, SLICE_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_slice);
+        // The dimensions are an integer constant, which is read on its own
+        // as an expression would take the IN that follows as an operator
+        let (idx_after_dimensions, dimensions) = parse_section_from_section!(parse_simple_token(
+            tokens,
+            idx_after_whitespace,
+            |token| return token.value.chars().all(|c| c.is_ascii_digit()),
+            |token| return Expression::Literal(Literal::Number(token.value.clone()))
+        ));
+        idx = idx_after_optional_whitespace(tokens, idx_after_dimensions);
+        slice = Some(dimensions);
+    }
+
+    (idx, _) =
+        parse_section_from_section!(parse_keywords(tokens, idx, &[IN_KEYWORD, ARRAY_KEYWORD]));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let array;
+    (idx, array) = parse_section_from_section!(parse_expression(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        LoopKind::ForEach {
+            targets: targets,
+            slice: slice,
+            array: array,
+        },
+    );
+}
+
+// EXIT or CONTINUE [label] [WHEN condition]
+pub fn parse_exit(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Statement> {
+    let (mut idx, keyword) = parse_section_from_section!(parse_one_of_keywords(
+        tokens,
+        start_idx,
+        &[EXIT_KEYWORD, CONTINUE_KEYWORD]
+    ));
+
+    let mut label = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Invalid =
+        parse_keyword(tokens, idx_after_whitespace, WHEN_KEYWORD)
+    {
+        if let ParseCommandSectionResult::Valid(idx_after_label, loop_label) =
+            parse_identifier(tokens, idx_after_whitespace)
+        {
+            idx = idx_after_label;
+            label = Some(loop_label);
+        }
+    }
+
+    let mut condition = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_when, _) =
+        parse_keyword(tokens, idx_after_whitespace, WHEN_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_when);
+        let (idx_after_condition, exit_condition) =
+            parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+        idx = idx_after_condition;
+        condition = Some(exit_condition);
+    }
+
+    return match keyword.as_str() {
+        EXIT_KEYWORD => ParseCommandSectionResult::Valid(
+            idx,
+            Statement::Exit {
+                label: label,
+                condition: condition,
+            },
+        ),
+        _ => ParseCommandSectionResult::Valid(
+            idx,
+            Statement::Continue {
+                label: label,
+                condition: condition,
+            },
+        ),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tokenize_postgresql;
+
+    fn identifier(value: &str) -> Identifier {
+        return Identifier {
+            quoted: false,
+            value: String::from(value),
+        };
+    }
+
+    fn number(value: &str) -> Expression {
+        return Expression::Literal(Literal::Number(String::from(value)));
+    }
+
+    #[test]
+    fn loop_over_integer_range() {
+        let tokens = tokenize_postgresql("FOR i IN REVERSE 10..1 BY 2 LOOP END LOOP;");
+        assert_eq!(
+            parse_loop(&tokens, 0, None),
+            ParseCommandSectionResult::Valid(
+                21,
+                LoopStatement {
+                    label: None,
+                    kind: LoopKind::IntegerRange {
+                        variable: identifier("i"),
+                        reverse: true,
+                        lower: number("10"),
+                        upper: number("1"),
+                        step: Some(number("2")),
+                    },
+                    statements: vec![],
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn loop_over_parenthesized_query() {
+        let tokens = tokenize_postgresql("FOR a, b IN (select a, b from t) LOOP END LOOP;");
+        match parse_loop(&tokens, 0, None) {
+            ParseCommandSectionResult::Valid(idx, loop_statement) => {
+                assert_eq!(idx, 27);
+                match loop_statement.kind {
+                    LoopKind::Query { targets, query } => {
+                        assert_eq!(targets, vec![identifier("a"), identifier("b")]);
+                        assert_eq!(query.text, "(select a, b from t)");
+                    }
+                    kind => panic!("expected a query loop, got {:?}", kind),
+                }
+            }
+            result => panic!("expected a loop, got {:?}", result),
+        }
+    }
+}
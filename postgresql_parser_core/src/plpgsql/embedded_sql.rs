@@ -0,0 +1,148 @@
+use crate::lexer::token::Token;
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::expression::parse_expression;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, INSERT_KEYWORD, INTO_KEYWORD, MERGE_KEYWORD, STRICT_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::parse_command;
+use crate::parser::parse_command_result::ParseCommandResult;
+use crate::parser::utils::{idx_after_optional_whitespace, token_is_whitespace};
+use crate::plpgsql::ast::{EmbeddedSql, IntoClause};
+
+// Reads a SQL command up to, but not including, the first token outside of
+// parentheses for which ends_command is true, e.g. the semicolon ending the
+// statement or the LOOP of a FOR loop. The command is parsed with the core
+// parser after taking out its INTO clause, and with its first word replaced
+// by first_word when given, as for PERFORM.
+pub fn parse_embedded_sql(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+    ends_command: fn(&Token) -> bool,
+    first_word: Option<&str>,
+) -> ParseCommandSectionResult<EmbeddedSql> {
+    let mut end_idx = start_idx;
+    let mut depth = 0;
+    loop {
+        let token = match tokens.get(end_idx) {
+            Some(token) => token,
+            None => return ParseCommandSectionResult::EndOfInput,
+        };
+        if depth == 0 && (token.value == ";" || ends_command(token)) {
+            break;
+        }
+        if token.value == "(" {
+            depth += 1;
+        } else if token.value == ")" && depth > 0 {
+            depth -= 1;
+        }
+        end_idx += 1;
+    }
+    // Whitespace before the end is not part of the command
+    while end_idx > start_idx && token_is_whitespace(&tokens[end_idx - 1]) {
+        end_idx -= 1;
+    }
+    if end_idx == start_idx {
+        return ParseCommandSectionResult::Invalid;
+    }
+
+    let text = tokens[start_idx..end_idx]
+        .iter()
+        .map(|token| token.value.as_str())
+        .collect::<String>();
+
+    let mut command_tokens: Vec<Token> = tokens[start_idx..end_idx].to_vec();
+    let mut into = None;
+    if let Some(into_idx) = find_into_clause(tokens, start_idx, end_idx) {
+        let (idx_after_into, into_clause) =
+            parse_section_from_section!(parse_into_clause(tokens, into_idx));
+        if idx_after_into > end_idx {
+            return ParseCommandSectionResult::Invalid;
+        }
+        // Whitespace on both sides of the clause would otherwise be kept
+        let mut idx_before_into = into_idx;
+        while idx_before_into > start_idx && token_is_whitespace(&tokens[idx_before_into - 1]) {
+            idx_before_into -= 1;
+        }
+        command_tokens = tokens[start_idx..idx_before_into].to_vec();
+        command_tokens.extend_from_slice(&tokens[idx_after_into..end_idx]);
+        into = Some(into_clause);
+    }
+    if let Some(first_word) = first_word {
+        command_tokens[0].value = String::from(first_word);
+    }
+    let last_position = command_tokens.last().unwrap().position.clone();
+    command_tokens.push(Token::new(last_position, ';'));
+
+    let command = match parse_command(&command_tokens, 0) {
+        ParseCommandResult::Valid(command, idx_after) if idx_after == command_tokens.len() => {
+            Some(command)
+        }
+        _ => None,
+    };
+
+    return ParseCommandSectionResult::Valid(
+        end_idx,
+        EmbeddedSql {
+            text: text,
+            command: command,
+            into: into,
+        },
+    );
+}
+
+// The first INTO outside of parentheses that is not the INTO of
+// INSERT INTO or MERGE INTO
+fn find_into_clause(tokens: &Vec<Token>, start_idx: usize, end_idx: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut previous_word: Option<String> = None;
+    for (idx, token) in tokens.iter().enumerate().take(end_idx).skip(start_idx) {
+        if token_is_whitespace(token) {
+            continue;
+        }
+        let lowered = token.value.to_ascii_lowercase();
+        if token.value == "(" {
+            depth += 1;
+        } else if token.value == ")" && depth > 0 {
+            depth -= 1;
+        } else if depth == 0
+            && lowered == INTO_KEYWORD
+            && previous_word.as_deref() != Some(INSERT_KEYWORD)
+            && previous_word.as_deref() != Some(MERGE_KEYWORD)
+        {
+            return Some(idx);
+        }
+        previous_word = Some(lowered);
+    }
+    return None;
+}
+
+// INTO [STRICT] target, ...
+pub fn parse_into_clause(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<IntoClause> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, INTO_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut strict = false;
+    if let ParseCommandSectionResult::Valid(idx_after_strict, _) =
+        parse_keyword(tokens, idx, STRICT_KEYWORD)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_strict);
+        strict = true;
+    }
+
+    let (idx_after_targets, targets) =
+        parse_section_from_section!(parse_comma_separated_list(tokens, idx, parse_expression));
+    return ParseCommandSectionResult::Valid(
+        idx_after_targets,
+        IntoClause {
+            strict: strict,
+            targets: targets,
+        },
+    );
+}
@@ -0,0 +1,45 @@
+pub mod ast;
+mod block;
+mod control_flow;
+mod embedded_sql;
+mod statement;
+
+use crate::lexer::tokenize_postgresql;
+use crate::parser::commands::parse_section::ParseCommandSectionResult;
+use crate::parser::commands::sections::semicolon::parse_semicolon;
+use crate::parser::utils::idx_after_optional_whitespace;
+use ast::Block;
+use block::parse_block;
+use statement::parse_label;
+
+// Parses the body of a PL/pgSQL function or DO block, i.e. a single block
+// optionally followed by a semicolon
+pub fn parse_plpgsql(text: &str) -> Option<Block> {
+    let tokens = tokenize_postgresql(text);
+    let mut idx = idx_after_optional_whitespace(&tokens, 0);
+
+    let mut label = None;
+    if let ParseCommandSectionResult::Valid(idx_after_label, block_label) =
+        parse_label(&tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(&tokens, idx_after_label);
+        label = Some(block_label);
+    }
+
+    let block = match parse_block(&tokens, idx, label) {
+        ParseCommandSectionResult::Valid(idx_after_block, block) => {
+            idx = idx_after_optional_whitespace(&tokens, idx_after_block);
+            block
+        }
+        _ => return None,
+    };
+    if let ParseCommandSectionResult::Valid(idx_after_semicolon, _) = parse_semicolon(&tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(&tokens, idx_after_semicolon);
+    }
+
+    return match idx == tokens.len() {
+        true => Some(block),
+        false => None,
+    };
+}
@@ -0,0 +1,550 @@
+use crate::lexer::token::Token;
+use crate::parser::ast::{Expression, Identifier};
+use crate::parser::commands::parse_section::{
+    parse_section_from_section, ParseCommandSectionResult,
+};
+use crate::parser::commands::sections::comma::parse_comma;
+use crate::parser::commands::sections::expression::{parse_expression, parse_string_literal};
+use crate::parser::commands::sections::identifier::parse_identifier;
+use crate::parser::commands::sections::keywords::{
+    parse_keyword, parse_one_of_keywords, BEGIN_KEYWORD, CASE_KEYWORD, CONTINUE_KEYWORD,
+    CURRENT_KEYWORD, DEBUG_KEYWORD, DECLARE_KEYWORD, DIAGNOSTICS_KEYWORD, ELSEIF_KEYWORD,
+    ELSE_KEYWORD, ELSIF_KEYWORD, END_KEYWORD, EXCEPTION_KEYWORD, EXECUTE_KEYWORD, EXIT_KEYWORD,
+    FOREACH_KEYWORD, FOR_KEYWORD, GET_KEYWORD, IF_KEYWORD, INFO_KEYWORD, LOG_KEYWORD, LOOP_KEYWORD,
+    NEXT_KEYWORD, NOTICE_KEYWORD, NULL_KEYWORD, PERFORM_KEYWORD, QUERY_KEYWORD, RAISE_KEYWORD,
+    RETURN_KEYWORD, SELECT_KEYWORD, STACKED_KEYWORD, USING_KEYWORD, WARNING_KEYWORD, WHEN_KEYWORD,
+    WHILE_KEYWORD,
+};
+use crate::parser::commands::sections::list::parse_comma_separated_list;
+use crate::parser::commands::sections::operator::parse_operator;
+use crate::parser::commands::sections::semicolon::parse_semicolon;
+use crate::parser::utils::idx_after_optional_whitespace;
+use crate::plpgsql::ast::{
+    DiagnosticsItem, ExecuteStatement, GetDiagnosticsStatement, RaiseLevel, RaiseOption,
+    RaiseStatement, Statement,
+};
+use crate::plpgsql::block::{parse_block, parse_exception_condition};
+use crate::plpgsql::control_flow::{parse_case, parse_exit, parse_if, parse_loop};
+use crate::plpgsql::embedded_sql::{parse_embedded_sql, parse_into_clause};
+
+// The keywords that end a list of statements, e.g. the END of a block or
+// the ELSE of an IF
+const STATEMENT_LIST_END_KEYWORDS: [&str; 6] = [
+    END_KEYWORD,
+    ELSE_KEYWORD,
+    ELSIF_KEYWORD,
+    ELSEIF_KEYWORD,
+    WHEN_KEYWORD,
+    EXCEPTION_KEYWORD,
+];
+
+// Statements, each ending with a semicolon, up to one of the keywords that
+// end the enclosing block or branch
+pub fn parse_statements(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Vec<Statement>> {
+    let mut idx = start_idx;
+    let mut statements = Vec::new();
+    loop {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let ParseCommandSectionResult::Valid(_, _) =
+            parse_one_of_keywords(tokens, idx_after_whitespace, &STATEMENT_LIST_END_KEYWORDS)
+        {
+            return ParseCommandSectionResult::Valid(idx, statements);
+        }
+        let (idx_after_statement, statement) =
+            parse_section_from_section!(parse_statement(tokens, idx_after_whitespace));
+        idx = idx_after_statement;
+        statements.push(statement);
+    }
+}
+
+// A statement, including its semicolon
+fn parse_statement(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Statement> {
+    let (idx_after_statement, statement) =
+        parse_section_from_section!(parse_statement_before_semicolon(tokens, start_idx));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_statement);
+    let (idx_after_semicolon, _) =
+        parse_section_from_section!(parse_semicolon(tokens, idx_after_whitespace));
+    return ParseCommandSectionResult::Valid(idx_after_semicolon, statement);
+}
+
+fn parse_statement_before_semicolon(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Statement> {
+    let mut idx = start_idx;
+    let mut label = None;
+    if let ParseCommandSectionResult::Valid(idx_after_label, block_label) = parse_label(tokens, idx)
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_label);
+        label = Some(block_label);
+    }
+
+    if let ParseCommandSectionResult::Valid(_, _) =
+        parse_one_of_keywords(tokens, idx, &[DECLARE_KEYWORD, BEGIN_KEYWORD])
+    {
+        let (idx_after, block) = parse_section_from_section!(parse_block(tokens, idx, label));
+        return ParseCommandSectionResult::Valid(idx_after, Statement::Block(block));
+    }
+    if let ParseCommandSectionResult::Valid(_, _) = parse_one_of_keywords(
+        tokens,
+        idx,
+        &[LOOP_KEYWORD, WHILE_KEYWORD, FOR_KEYWORD, FOREACH_KEYWORD],
+    ) {
+        let (idx_after, loop_statement) =
+            parse_section_from_section!(parse_loop(tokens, idx, label));
+        return ParseCommandSectionResult::Valid(idx_after, Statement::Loop(loop_statement));
+    }
+    // Only blocks and loops can be labeled
+    if label.is_some() {
+        return ParseCommandSectionResult::Invalid;
+    }
+
+    let keyword = match parse_one_of_keywords(
+        tokens,
+        idx,
+        &[
+            IF_KEYWORD,
+            CASE_KEYWORD,
+            EXIT_KEYWORD,
+            CONTINUE_KEYWORD,
+            RETURN_KEYWORD,
+            RAISE_KEYWORD,
+            PERFORM_KEYWORD,
+            EXECUTE_KEYWORD,
+            GET_KEYWORD,
+            NULL_KEYWORD,
+        ],
+    ) {
+        ParseCommandSectionResult::Valid(_, keyword) => keyword,
+        _ => return parse_assignment_or_sql(tokens, idx),
+    };
+    return match keyword.as_str() {
+        IF_KEYWORD => {
+            let (idx_after, if_statement) = parse_section_from_section!(parse_if(tokens, idx));
+            ParseCommandSectionResult::Valid(idx_after, Statement::If(if_statement))
+        }
+        CASE_KEYWORD => {
+            let (idx_after, case_statement) = parse_section_from_section!(parse_case(tokens, idx));
+            ParseCommandSectionResult::Valid(idx_after, Statement::Case(case_statement))
+        }
+        EXIT_KEYWORD | CONTINUE_KEYWORD => parse_exit(tokens, idx),
+        RETURN_KEYWORD => parse_return(tokens, idx),
+        RAISE_KEYWORD => {
+            let (idx_after, raise) = parse_section_from_section!(parse_raise(tokens, idx));
+            ParseCommandSectionResult::Valid(idx_after, Statement::Raise(raise))
+        }
+        PERFORM_KEYWORD => {
+            let (idx_after, sql) = parse_section_from_section!(parse_embedded_sql(
+                tokens,
+                idx,
+                |_| false,
+                Some(SELECT_KEYWORD)
+            ));
+            ParseCommandSectionResult::Valid(idx_after, Statement::Perform(sql))
+        }
+        EXECUTE_KEYWORD => {
+            let (idx_after, execute) = parse_section_from_section!(parse_execute(tokens, idx));
+            ParseCommandSectionResult::Valid(idx_after, Statement::Execute(execute))
+        }
+        GET_KEYWORD => {
+            let (idx_after, get_diagnostics) =
+                parse_section_from_section!(parse_get_diagnostics(tokens, idx));
+            ParseCommandSectionResult::Valid(idx_after, Statement::GetDiagnostics(get_diagnostics))
+        }
+        _ => ParseCommandSectionResult::Valid(idx + 1, Statement::Null),
+    };
+}
+
+// <<label>>
+pub fn parse_label(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Identifier> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_operator(tokens, idx, "<<"));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let label;
+    (idx, label) = parse_section_from_section!(parse_identifier(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_operator(tokens, idx, ">>"));
+    return ParseCommandSectionResult::Valid(idx, label);
+}
+
+// := or =
+pub fn parse_assignment_operator(tokens: &Vec<Token>, idx: usize) -> ParseCommandSectionResult<()> {
+    return match parse_operator(tokens, idx, ":=") {
+        ParseCommandSectionResult::Valid(idx_after, _) => {
+            ParseCommandSectionResult::Valid(idx_after, ())
+        }
+        _ => parse_operator(tokens, idx, "="),
+    };
+}
+
+// Any statement not starting with a PL/pgSQL keyword is an assignment or a
+// SQL command
+fn parse_assignment_or_sql(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<Statement> {
+    if let ParseCommandSectionResult::Valid(idx_after_target, target) =
+        parse_expression(tokens, start_idx)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_target);
+        if let ParseCommandSectionResult::Valid(idx_after_operator, _) =
+            parse_operator(tokens, idx_after_whitespace, ":=")
+        {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_operator);
+            let (idx_after_value, value) =
+                parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+            return ParseCommandSectionResult::Valid(
+                idx_after_value,
+                Statement::Assignment {
+                    target: target,
+                    value: value,
+                },
+            );
+        }
+        // An assignment written with = reads as a comparison, which could
+        // not otherwise be a statement of its own
+        if let Expression::BinaryOperation(operation) = target {
+            if operation.operator == "="
+                && matches!(
+                    parse_semicolon(tokens, idx_after_whitespace),
+                    ParseCommandSectionResult::Valid(_, _)
+                )
+            {
+                return ParseCommandSectionResult::Valid(
+                    idx_after_target,
+                    Statement::Assignment {
+                        target: *operation.left,
+                        value: *operation.right,
+                    },
+                );
+            }
+        }
+    }
+    let (idx_after, sql) =
+        parse_section_from_section!(parse_embedded_sql(tokens, start_idx, |_| false, None));
+    return ParseCommandSectionResult::Valid(idx_after, Statement::Sql(sql));
+}
+
+// RETURN [expression], RETURN NEXT [expression], RETURN QUERY query or
+// RETURN QUERY EXECUTE command [USING expression, ...]
+fn parse_return(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<Statement> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, RETURN_KEYWORD));
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+
+    if let ParseCommandSectionResult::Valid(idx_after_query, _) =
+        parse_keyword(tokens, idx_after_whitespace, QUERY_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_query);
+        if let ParseCommandSectionResult::Valid(_, _) =
+            parse_keyword(tokens, idx_after_whitespace, EXECUTE_KEYWORD)
+        {
+            let (idx_after, execute) =
+                parse_section_from_section!(parse_execute(tokens, idx_after_whitespace));
+            return ParseCommandSectionResult::Valid(
+                idx_after,
+                Statement::ReturnQueryExecute(execute),
+            );
+        }
+        let (idx_after, sql) = parse_section_from_section!(parse_embedded_sql(
+            tokens,
+            idx_after_whitespace,
+            |_| false,
+            None
+        ));
+        return ParseCommandSectionResult::Valid(idx_after, Statement::ReturnQuery(sql));
+    }
+
+    let mut next = false;
+    let mut idx_before_expression = idx_after_whitespace;
+    if let ParseCommandSectionResult::Valid(idx_after_next, _) =
+        parse_keyword(tokens, idx_after_whitespace, NEXT_KEYWORD)
+    {
+        idx = idx_after_next;
+        idx_before_expression = idx_after_optional_whitespace(tokens, idx_after_next);
+        next = true;
+    }
+
+    let mut expression = None;
+    if let ParseCommandSectionResult::Valid(idx_after_expression, value) =
+        parse_expression(tokens, idx_before_expression)
+    {
+        idx = idx_after_expression;
+        expression = Some(value);
+    }
+    return match next {
+        true => ParseCommandSectionResult::Valid(idx, Statement::ReturnNext(expression)),
+        false => ParseCommandSectionResult::Valid(idx, Statement::Return(expression)),
+    };
+}
+
+// EXECUTE command [INTO [STRICT] target, ...] [USING expression, ...], where
+// INTO and USING may come in either order
+pub fn parse_execute(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<ExecuteStatement> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, EXECUTE_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let command;
+    (idx, command) = parse_section_from_section!(parse_expression(tokens, idx));
+
+    let mut into = None;
+    let mut using = Vec::new();
+    loop {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+        if let (None, ParseCommandSectionResult::Valid(idx_after_into, into_clause)) =
+            (&into, parse_into_clause(tokens, idx_after_whitespace))
+        {
+            idx = idx_after_into;
+            into = Some(into_clause);
+        } else if let (true, ParseCommandSectionResult::Valid(idx_after_using, _)) = (
+            using.is_empty(),
+            parse_keyword(tokens, idx_after_whitespace, USING_KEYWORD),
+        ) {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_using);
+            (idx, using) = parse_section_from_section!(parse_comma_separated_list(
+                tokens,
+                idx_after_whitespace,
+                parse_expression
+            ));
+        } else {
+            break;
+        }
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        ExecuteStatement {
+            command: command,
+            into: into,
+            using: using,
+        },
+    );
+}
+
+fn parse_raise(tokens: &Vec<Token>, start_idx: usize) -> ParseCommandSectionResult<RaiseStatement> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, RAISE_KEYWORD));
+
+    let mut level = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_level, keyword) = parse_one_of_keywords(
+        tokens,
+        idx_after_whitespace,
+        &[
+            DEBUG_KEYWORD,
+            LOG_KEYWORD,
+            INFO_KEYWORD,
+            NOTICE_KEYWORD,
+            WARNING_KEYWORD,
+            EXCEPTION_KEYWORD,
+        ],
+    ) {
+        idx = idx_after_level;
+        level = Some(match keyword.as_str() {
+            DEBUG_KEYWORD => RaiseLevel::Debug,
+            LOG_KEYWORD => RaiseLevel::Log,
+            INFO_KEYWORD => RaiseLevel::Info,
+            NOTICE_KEYWORD => RaiseLevel::Notice,
+            WARNING_KEYWORD => RaiseLevel::Warning,
+            _ => RaiseLevel::Exception,
+        });
+    }
+
+    let mut message = None;
+    let mut parameters = Vec::new();
+    let mut condition = None;
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_message, format) =
+        parse_string_literal(tokens, idx_after_whitespace)
+    {
+        idx = idx_after_message;
+        message = Some(format);
+        loop {
+            let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+            match parse_comma(tokens, idx_after_whitespace) {
+                ParseCommandSectionResult::Valid(idx_after_comma, _) => {
+                    let idx_after_whitespace =
+                        idx_after_optional_whitespace(tokens, idx_after_comma);
+                    let (idx_after_parameter, parameter) =
+                        parse_section_from_section!(parse_expression(tokens, idx_after_whitespace));
+                    idx = idx_after_parameter;
+                    parameters.push(parameter);
+                }
+                _ => break,
+            }
+        }
+    } else if let ParseCommandSectionResult::Invalid =
+        parse_one_of_keywords(tokens, idx_after_whitespace, &[USING_KEYWORD])
+    {
+        if let ParseCommandSectionResult::Valid(idx_after_condition, raised_condition) =
+            parse_exception_condition(tokens, idx_after_whitespace)
+        {
+            idx = idx_after_condition;
+            condition = Some(raised_condition);
+        }
+    }
+
+    let mut options = Vec::new();
+    let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx);
+    if let ParseCommandSectionResult::Valid(idx_after_using, _) =
+        parse_keyword(tokens, idx_after_whitespace, USING_KEYWORD)
+    {
+        let idx_after_whitespace = idx_after_optional_whitespace(tokens, idx_after_using);
+        (idx, options) = parse_section_from_section!(parse_comma_separated_list(
+            tokens,
+            idx_after_whitespace,
+            parse_raise_option
+        ));
+    }
+
+    return ParseCommandSectionResult::Valid(
+        idx,
+        RaiseStatement {
+            level: level,
+            message: message,
+            parameters: parameters,
+            condition: condition,
+            options: options,
+        },
+    );
+}
+
+// option = expression
+fn parse_raise_option(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<RaiseOption> {
+    let (mut idx, name) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_assignment_operator(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let value;
+    (idx, value) = parse_section_from_section!(parse_expression(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        RaiseOption {
+            name: name,
+            value: value,
+        },
+    );
+}
+
+fn parse_get_diagnostics(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<GetDiagnosticsStatement> {
+    let mut idx = start_idx;
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, GET_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+
+    let mut stacked = false;
+    if let ParseCommandSectionResult::Valid(idx_after_scope, keyword) =
+        parse_one_of_keywords(tokens, idx, &[CURRENT_KEYWORD, STACKED_KEYWORD])
+    {
+        idx = idx_after_optional_whitespace(tokens, idx_after_scope);
+        stacked = keyword == STACKED_KEYWORD;
+    }
+
+    (idx, _) = parse_section_from_section!(parse_keyword(tokens, idx, DIAGNOSTICS_KEYWORD));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let items;
+    (idx, items) = parse_section_from_section!(parse_comma_separated_list(
+        tokens,
+        idx,
+        parse_diagnostics_item
+    ));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        GetDiagnosticsStatement {
+            stacked: stacked,
+            items: items,
+        },
+    );
+}
+
+// target {= | :=} item
+fn parse_diagnostics_item(
+    tokens: &Vec<Token>,
+    start_idx: usize,
+) -> ParseCommandSectionResult<DiagnosticsItem> {
+    let (mut idx, target) = parse_section_from_section!(parse_identifier(tokens, start_idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    (idx, _) = parse_section_from_section!(parse_assignment_operator(tokens, idx));
+    idx = idx_after_optional_whitespace(tokens, idx);
+    let item;
+    (idx, item) = parse_section_from_section!(parse_identifier(tokens, idx));
+    return ParseCommandSectionResult::Valid(
+        idx,
+        DiagnosticsItem {
+            target: target,
+            item: item,
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tokenize_postgresql;
+    use crate::parser::ast::{BinaryOperation, ColumnSelectedExpression, Literal};
+    use crate::plpgsql::ast::IntoClause;
+
+    fn identifier(value: &str) -> Identifier {
+        return Identifier {
+            quoted: false,
+            value: String::from(value),
+        };
+    }
+
+    fn column(name: &str) -> Expression {
+        return Expression::Column(ColumnSelectedExpression {
+            schema_name: None,
+            table_name: None,
+            column_name: identifier(name),
+        });
+    }
+
+    #[test]
+    fn statement_assignment_with_equals() {
+        let tokens = tokenize_postgresql("total = total + 1; END");
+        assert_eq!(
+            parse_statements(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                10,
+                vec![Statement::Assignment {
+                    target: column("total"),
+                    value: Expression::BinaryOperation(BinaryOperation {
+                        left: Box::new(column("total")),
+                        operator: String::from("+"),
+                        right: Box::new(Expression::Literal(Literal::Number(String::from("1")))),
+                    }),
+                }]
+            )
+        );
+    }
+
+    #[test]
+    fn statement_execute_with_using_before_into() {
+        let tokens = tokenize_postgresql("EXECUTE command USING a, b INTO result;");
+        assert_eq!(
+            parse_statement(&tokens, 0),
+            ParseCommandSectionResult::Valid(
+                15,
+                Statement::Execute(ExecuteStatement {
+                    command: column("command"),
+                    into: Some(IntoClause {
+                        strict: false,
+                        targets: vec![column("result")],
+                    }),
+                    using: vec![column("a"), column("b")],
+                })
+            )
+        );
+    }
+}
@@ -1,9 +1,50 @@
 use postgresql_parser_core::ast::{
-    AllColumnsSelectedExpression, ColumnSelectedExpression, Command, DataManipulationCommand,
-    EmptyCommand, FromItem, Identifier, PostgresqlAbstractSyntaxTree, SelectCommand,
-    SelectedExpression,
+    AllColumnsSelectedExpression, AlterColumnAction, AlterDatabaseAction, AlterDatabaseCommand,
+    AlterDefaultPrivilegesCommand, AlterExtensionAction, AlterExtensionCommand, AlterPolicyAction,
+    AlterPolicyCommand, AlterRoleAction, AlterRoleCommand, AlterSequenceCommand, AlterTableAction,
+    AlterTableCommand, AlterTablespaceAction, AlterTablespaceCommand, AlterTypeAction,
+    AlterTypeCommand, Assignment, Between, BinaryOperation, Case, CaseWhen, Cast, ColumnConstraint,
+    ColumnConstraintKind, ColumnDefinition, ColumnSelectedExpression, ColumnStorage, Command,
+    CompositeTypeAttribute, ConfigurationParameter, ConfigurationSetting, ConfigurationValue,
+    ConflictAction, ConflictTarget, CopyCommand, CopyDirection, CopyFormat, CopyHeader, CopyOption,
+    CopyRelation, CopyTarget, CreateDatabaseCommand, CreateDomainCommand,
+    CreateEventTriggerCommand, CreateExtensionCommand, CreateFunctionCommand, CreateIndexCommand,
+    CreateMaterializedViewCommand, CreatePolicyCommand, CreateRoleCommand, CreateSchemaCommand,
+    CreateSequenceCommand, CreateTableCommand, CreateTablespaceCommand, CreateTriggerCommand,
+    CreateTypeCommand, CreateViewCommand, DataControlCommand, DataDefinitionCommand,
+    DataManipulationCommand, DatabaseOption, DefaultPrivilegesAction, DeleteCommand,
+    DetachPartitionMode, DoCommand, DomainConstraint, DomainConstraintKind, DropBehavior,
+    DropCommand, DropObject, DropObjectType, EmptyCommand, EnumValuePosition, EventTriggerFilter,
+    ExclusionElement, ExplainCommand, ExplainFormat, ExplainOption, ExplainSerialize, Expression,
+    ForeignKeyMatch, ForeignKeyReference, FromFunction, FromItem, FunctionBody, FunctionCall,
+    FunctionColumnDefinition, FunctionFromItem, FunctionOption, FunctionParallelSafety,
+    FunctionParameter, FunctionParameterMode, FunctionReturnType, FunctionVolatility, GrantCommand,
+    GrantRoleCommand, Identifier, IdentifierOrString, IndexElement, InsertCommand,
+    InsertOverriding, InsertSource, IsolationLevel, Literal, LockStrength, LockWaitPolicy,
+    LockingClause, MergeAction, MergeCommand, MergeInsert, MergeMatchKind, MergeSource,
+    MergeWhenClause, NullsOrder, OnConflictClause, OutputExpression, Parameter, ParameterKind,
+    PartitionBound, PartitionBy, PartitionKey, PartitionOf, PartitionRangeValue, PartitionStrategy,
+    PolicyCommand, PolicyKind, PostgresqlAbstractSyntaxTree, Privilege, PrivilegeKind,
+    PrivilegeObject, PrivilegeObjectType, PrivilegeTarget, Privileges, QualifiedName, Query,
+    ReferentialAction, RefreshMaterializedViewCommand, RevokeCommand, RevokeRoleCommand,
+    RoleGrantOption, RoleGrantOptionKind, RoleKind, RoleOption, RoleSpecification, RoutineKind,
+    RowLevelSecurityAction, SelectCommand, SelectedExpression, SequenceOption, SetAction,
+    SetCommand, SetOperation, SetOperator, SetScope, SortDirection, SortExpression,
+    StorageParameter, SubqueryFromItem, TableAlias, TableConstraint, TableConstraintKind,
+    TableConstraintUsingIndex, TableElement, TableFromItem, TableLikeClause, TableLikeOption,
+    TableLikeProperty, TablePersistence, TableSample, TargetTable, TokenPosition,
+    TransactionControlCommand, TransactionMode, TransitionRelationKind, TriggerEvent, TriggerLevel,
+    TriggerSelection, TriggerState, TriggerTiming, TriggerTransitionRelation, TypeDefinition,
+    TypeName, TypeOption, TypeOptionValue, UpdateCommand, UtilityCommand, ValuesCommand,
+    ViewCheckOption, WhereClause,
 };
 use postgresql_parser_core::parse_postgresql;
+use postgresql_parser_core::plpgsql::ast::{
+    Block, ConditionalBranch, DataType, Declaration, EmbeddedSql, ExceptionCondition,
+    ExceptionHandler, IfStatement, IntoClause, LoopKind, LoopStatement, RaiseLevel, RaiseStatement,
+    Statement, VariableDeclaration,
+};
+use postgresql_parser_core::plpgsql::parse_plpgsql;
 
 fn test_parse(input_string: &str, expected_commands: Vec<Command>) {
     let parsed_tree = parse_postgresql(input_string);
@@ -13,6 +54,96 @@ fn test_parse(input_string: &str, expected_commands: Vec<Command>) {
     );
 }
 
+fn identifier(value: &str) -> Identifier {
+    return Identifier {
+        quoted: false,
+        value: String::from(value),
+    };
+}
+
+fn table_name(name: &str) -> QualifiedName {
+    return QualifiedName {
+        schema_name: None,
+        name: identifier(name),
+    };
+}
+
+fn column(name: &str) -> Expression {
+    return Expression::Column(ColumnSelectedExpression {
+        schema_name: None,
+        table_name: None,
+        column_name: identifier(name),
+    });
+}
+
+fn qualified_column(table: &str, name: &str) -> Expression {
+    return Expression::Column(ColumnSelectedExpression {
+        schema_name: None,
+        table_name: Some(identifier(table)),
+        column_name: identifier(name),
+    });
+}
+
+fn number(value: &str) -> Expression {
+    return Expression::Literal(Literal::Number(String::from(value)));
+}
+
+fn type_name(name: &str) -> TypeName {
+    return TypeName {
+        name: table_name(name),
+        modifiers: vec![],
+        array_dimensions: 0,
+    };
+}
+
+fn select_all_from(table: &str) -> Query {
+    return Query::Select(SelectCommand {
+        selected_expressions: vec![SelectedExpression::AllColumns(
+            AllColumnsSelectedExpression {
+                schema_name: None,
+                table_name: None,
+            },
+        )],
+        from_items: vec![FromItem::Table(TableFromItem {
+            only: false,
+            schema_name: None,
+            table_name: identifier(table),
+            alias: None,
+            tablesample: None,
+        })],
+        where_clause: None,
+        order_by: vec![],
+        limit: None,
+        offset: None,
+        locking: vec![],
+    });
+}
+
+fn parse_single_command(input_string: &str) -> Command {
+    let mut parsed_tree = parse_postgresql(input_string);
+    assert_eq!(parsed_tree.commands.len(), 1, "{:?}", parsed_tree);
+    return parsed_tree.commands.pop().unwrap();
+}
+
+// Returns what the pattern binds from a parsed value, and fails the test with
+// the value when it has a different shape
+macro_rules! extract {
+    ($value:expr, $pattern:pat => $result:expr) => {
+        match $value {
+            $pattern => $result,
+            value => panic!("Expected {}, got {:?}", stringify!($pattern), value),
+        }
+    };
+}
+
+fn parameter_kinds(input_string: &str) -> Vec<ParameterKind> {
+    return parse_postgresql(input_string)
+        .parameters()
+        .into_iter()
+        .map(|parameter| parameter.kind.clone())
+        .collect();
+}
+
 #[test]
 fn empty_command() {
     test_parse(";", vec![Command::Empty(EmptyCommand {})]);
@@ -34,19 +165,27 @@ fn basic_select_command() {
         "select * from foobar;",
         vec![Command::DataManipulation(DataManipulationCommand::Select(
             SelectCommand {
-                from_item: FromItem {
+                from_items: vec![FromItem::Table(TableFromItem {
+                    only: false,
                     schema_name: None,
                     table_name: Identifier {
                         quoted: false,
                         value: String::from("foobar"),
                     },
-                },
+                    alias: None,
+                    tablesample: None,
+                })],
                 selected_expressions: vec![SelectedExpression::AllColumns(
                     AllColumnsSelectedExpression {
                         schema_name: None,
                         table_name: None,
                     },
                 )],
+                where_clause: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+                locking: vec![],
             },
         ))],
     );
@@ -58,19 +197,27 @@ fn select_command_extra_spaces() {
         "select *  from  \nfoobar ; ",
         vec![Command::DataManipulation(DataManipulationCommand::Select(
             SelectCommand {
-                from_item: FromItem {
+                from_items: vec![FromItem::Table(TableFromItem {
+                    only: false,
                     schema_name: None,
                     table_name: Identifier {
                         quoted: false,
                         value: String::from("foobar"),
                     },
-                },
+                    alias: None,
+                    tablesample: None,
+                })],
                 selected_expressions: vec![SelectedExpression::AllColumns(
                     AllColumnsSelectedExpression {
                         schema_name: None,
                         table_name: None,
                     },
                 )],
+                where_clause: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+                locking: vec![],
             },
         ))],
     );
@@ -82,7 +229,8 @@ fn select_command_table_schema() {
         "select * from foo.bar;",
         vec![Command::DataManipulation(DataManipulationCommand::Select(
             SelectCommand {
-                from_item: FromItem {
+                from_items: vec![FromItem::Table(TableFromItem {
+                    only: false,
                     schema_name: Some(Identifier {
                         quoted: false,
                         value: String::from("foo"),
@@ -91,13 +239,20 @@ fn select_command_table_schema() {
                         quoted: false,
                         value: String::from("bar"),
                     },
-                },
+                    alias: None,
+                    tablesample: None,
+                })],
                 selected_expressions: vec![SelectedExpression::AllColumns(
                     AllColumnsSelectedExpression {
                         schema_name: None,
                         table_name: None,
                     },
                 )],
+                where_clause: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+                locking: vec![],
             },
         ))],
     );
@@ -109,7 +264,8 @@ fn select_multi_complex_columns() {
         "select firstname,public.\"1-teacher两\".lastname , teacher.* from \"public\".teacher;",
         vec![Command::DataManipulation(DataManipulationCommand::Select(
             SelectCommand {
-                from_item: FromItem {
+                from_items: vec![FromItem::Table(TableFromItem {
+                    only: false,
                     schema_name: Some(Identifier {
                         quoted: true,
                         value: String::from("public"),
@@ -118,7 +274,9 @@ fn select_multi_complex_columns() {
                         quoted: false,
                         value: String::from("teacher"),
                     },
-                },
+                    alias: None,
+                    tablesample: None,
+                })],
                 selected_expressions: vec![
                     SelectedExpression::Column(ColumnSelectedExpression {
                         schema_name: None,
@@ -150,6 +308,11 @@ fn select_multi_complex_columns() {
                         }),
                     }),
                 ],
+                where_clause: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+                locking: vec![],
             },
         ))],
     );
@@ -161,13 +324,16 @@ fn todo_prevent_reserved_word_unquoted_identifiers() {
         "select from from from;",
         vec![Command::DataManipulation(DataManipulationCommand::Select(
             SelectCommand {
-                from_item: FromItem {
+                from_items: vec![FromItem::Table(TableFromItem {
+                    only: false,
                     schema_name: None,
                     table_name: Identifier {
                         quoted: false,
                         value: String::from("from"),
                     },
-                },
+                    alias: None,
+                    tablesample: None,
+                })],
                 selected_expressions: vec![SelectedExpression::Column(ColumnSelectedExpression {
                     schema_name: None,
                     table_name: None,
@@ -176,6 +342,11 @@ fn todo_prevent_reserved_word_unquoted_identifiers() {
                         value: String::from("from"),
                     },
                 })],
+                where_clause: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+                locking: vec![],
             },
         ))],
     );
@@ -225,3 +396,4254 @@ fn select_invalid_unquoted_identifier_in_from_item() {
 fn select_invalid_unclosed_quoted_identifier() {
     test_parse("select * from \"foo;", vec![]);
 }
+
+#[test]
+fn select_parameters() {
+    test_parse(
+        "select $1, :'name' from foo;",
+        vec![Command::DataManipulation(DataManipulationCommand::Select(
+            SelectCommand {
+                from_items: vec![FromItem::Table(TableFromItem {
+                    only: false,
+                    schema_name: None,
+                    table_name: Identifier {
+                        quoted: false,
+                        value: String::from("foo"),
+                    },
+                    alias: None,
+                    tablesample: None,
+                })],
+                selected_expressions: vec![
+                    SelectedExpression::Expression(Expression::Parameter(Parameter {
+                        position: TokenPosition { line: 0, column: 7 },
+                        kind: ParameterKind::Positional(1),
+                    })),
+                    SelectedExpression::Expression(Expression::Parameter(Parameter {
+                        position: TokenPosition {
+                            line: 0,
+                            column: 11,
+                        },
+                        kind: ParameterKind::NamedAsLiteral(String::from("name")),
+                    })),
+                ],
+                where_clause: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+                locking: vec![],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn tree_parameters() {
+    let parsed_tree = parse_postgresql("select :a from foo;\nselect\t$2, :\"b\" from bar;");
+    assert_eq!(
+        parsed_tree.parameters(),
+        vec![
+            &Parameter {
+                position: TokenPosition { line: 0, column: 7 },
+                kind: ParameterKind::Named(String::from("a")),
+            },
+            &Parameter {
+                position: TokenPosition { line: 1, column: 7 },
+                kind: ParameterKind::Positional(2),
+            },
+            &Parameter {
+                position: TokenPosition {
+                    line: 1,
+                    column: 11
+                },
+                kind: ParameterKind::NamedAsIdentifier(String::from("b")),
+            },
+        ]
+    );
+}
+
+#[test]
+fn select_invalid_parameter() {
+    test_parse("select $0 from foo;", vec![]);
+}
+
+#[test]
+fn insert_values() {
+    test_parse(
+        "insert into public.teacher (id, name) values (1, 'Ann'), (2, default);",
+        vec![Command::DataManipulation(DataManipulationCommand::Insert(
+            InsertCommand {
+                table_name: QualifiedName {
+                    schema_name: Some(identifier("public")),
+                    name: identifier("teacher"),
+                },
+                alias: None,
+                column_names: vec![identifier("id"), identifier("name")],
+                overriding: None,
+                source: InsertSource::Values(vec![
+                    vec![
+                        number("1"),
+                        Expression::Literal(Literal::String(String::from("Ann"))),
+                    ],
+                    vec![number("2"), Expression::Default],
+                ]),
+                on_conflict: None,
+                returning: vec![],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn insert_default_values() {
+    test_parse(
+        "INSERT INTO teacher AS t OVERRIDING USER VALUE DEFAULT VALUES;",
+        vec![Command::DataManipulation(DataManipulationCommand::Insert(
+            InsertCommand {
+                table_name: table_name("teacher"),
+                alias: Some(identifier("t")),
+                column_names: vec![],
+                overriding: Some(InsertOverriding::UserValue),
+                source: InsertSource::DefaultValues,
+                on_conflict: None,
+                returning: vec![],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn insert_returning_output_names() {
+    test_parse(
+        "INSERT INTO t VALUES (1) RETURNING id AS new_id, now() created_at;",
+        vec![Command::DataManipulation(DataManipulationCommand::Insert(
+            InsertCommand {
+                table_name: table_name("t"),
+                alias: None,
+                column_names: vec![],
+                overriding: None,
+                source: InsertSource::Values(vec![vec![number("1")]]),
+                on_conflict: None,
+                returning: vec![
+                    OutputExpression {
+                        expression: SelectedExpression::Column(ColumnSelectedExpression {
+                            schema_name: None,
+                            table_name: None,
+                            column_name: identifier("id"),
+                        }),
+                        output_name: Some(identifier("new_id")),
+                    },
+                    OutputExpression {
+                        expression: SelectedExpression::Expression(Expression::FunctionCall(
+                            FunctionCall {
+                                function_name: table_name("now"),
+                                arguments: vec![],
+                            },
+                        )),
+                        output_name: Some(identifier("created_at")),
+                    },
+                ],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn insert_invalid_named_returning_star() {
+    test_parse(
+        "INSERT INTO t VALUES (1) RETURNING * AS everything;",
+        vec![],
+    );
+}
+
+#[test]
+fn insert_select() {
+    test_parse(
+        "insert into teacher(name) select name from applicant;",
+        vec![Command::DataManipulation(DataManipulationCommand::Insert(
+            InsertCommand {
+                table_name: table_name("teacher"),
+                alias: None,
+                column_names: vec![identifier("name")],
+                overriding: None,
+                source: InsertSource::Query(Query::Select(SelectCommand {
+                    selected_expressions: vec![SelectedExpression::Column(
+                        ColumnSelectedExpression {
+                            schema_name: None,
+                            table_name: None,
+                            column_name: identifier("name"),
+                        },
+                    )],
+                    from_items: vec![FromItem::Table(TableFromItem {
+                        only: false,
+                        schema_name: None,
+                        table_name: identifier("applicant"),
+                        alias: None,
+                        tablesample: None,
+                    })],
+                    where_clause: None,
+                    order_by: vec![],
+                    limit: None,
+                    offset: None,
+                    locking: vec![],
+                })),
+                on_conflict: None,
+                returning: vec![],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn insert_on_conflict_on_constraint_do_nothing() {
+    let command = parse_single_command(
+        "insert into teacher values (1) on conflict on constraint teacher_pkey do nothing;",
+    );
+    let insert_command = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::Insert(insert_command)) => insert_command
+    );
+    assert_eq!(
+        insert_command.on_conflict,
+        Some(OnConflictClause {
+            target: Some(ConflictTarget::Constraint(identifier("teacher_pkey"))),
+            action: ConflictAction::DoNothing,
+        })
+    );
+}
+
+#[test]
+fn insert_on_conflict_do_update_returning() {
+    let command = parse_single_command(
+        "insert into teacher (id, name) values ($1, $2)
+        on conflict (id) where active do update set name = excluded.name, (a, b) = (1, 2)
+        where teacher.name <> excluded.name
+        returning id, *;",
+    );
+    let insert_command = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::Insert(insert_command)) => insert_command
+    );
+    assert_eq!(
+        insert_command.on_conflict,
+        Some(OnConflictClause {
+            target: Some(ConflictTarget::IndexExpressions {
+                expressions: vec![column("id")],
+                index_predicate: Some(column("active")),
+            }),
+            action: ConflictAction::DoUpdate {
+                assignments: vec![
+                    Assignment {
+                        column_names: vec![identifier("name")],
+                        value: Expression::Column(ColumnSelectedExpression {
+                            schema_name: None,
+                            table_name: Some(identifier("excluded")),
+                            column_name: identifier("name"),
+                        }),
+                    },
+                    Assignment {
+                        column_names: vec![identifier("a"), identifier("b")],
+                        value: Expression::Row(vec![number("1"), number("2")]),
+                    },
+                ],
+                where_clause: Some(Expression::BinaryOperation(BinaryOperation {
+                    left: Box::new(Expression::Column(ColumnSelectedExpression {
+                        schema_name: None,
+                        table_name: Some(identifier("teacher")),
+                        column_name: identifier("name"),
+                    })),
+                    operator: String::from("<>"),
+                    right: Box::new(Expression::Column(ColumnSelectedExpression {
+                        schema_name: None,
+                        table_name: Some(identifier("excluded")),
+                        column_name: identifier("name"),
+                    })),
+                })),
+            },
+        })
+    );
+    assert_eq!(
+        insert_command.returning,
+        vec![
+            OutputExpression {
+                expression: SelectedExpression::Column(ColumnSelectedExpression {
+                    schema_name: None,
+                    table_name: None,
+                    column_name: identifier("id"),
+                }),
+                output_name: None,
+            },
+            OutputExpression {
+                expression: SelectedExpression::AllColumns(AllColumnsSelectedExpression {
+                    schema_name: None,
+                    table_name: None,
+                }),
+                output_name: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn insert_parameters() {
+    let parsed_tree = parse_postgresql(
+        "insert into t (a) values (:a) on conflict (a) do update set a = $1 returning $2;",
+    );
+    assert_eq!(
+        parsed_tree
+            .parameters()
+            .into_iter()
+            .map(|parameter| parameter.kind.clone())
+            .collect::<Vec<ParameterKind>>(),
+        vec![
+            ParameterKind::Named(String::from("a")),
+            ParameterKind::Positional(1),
+            ParameterKind::Positional(2),
+        ]
+    );
+}
+
+#[test]
+fn insert_invalid_missing_into() {
+    test_parse("insert teacher values (1);", vec![]);
+}
+
+#[test]
+fn insert_invalid_empty_values() {
+    test_parse("insert into teacher values ();", vec![]);
+}
+
+#[test]
+fn insert_invalid_missing_conflict_action() {
+    test_parse("insert into teacher values (1) on conflict (id);", vec![]);
+}
+
+#[test]
+fn update_command() {
+    test_parse(
+        "update only public.teacher t set name = 'Ann', (a, b) = (select a, b from c) from d where t.id = d.id returning t.*;",
+        vec![Command::DataManipulation(DataManipulationCommand::Update(
+            UpdateCommand {
+                target: TargetTable {
+                    only: true,
+                    table_name: QualifiedName {
+                        schema_name: Some(identifier("public")),
+                        name: identifier("teacher"),
+                    },
+                    alias: Some(identifier("t")),
+                },
+                assignments: vec![
+                    Assignment {
+                        column_names: vec![identifier("name")],
+                        value: Expression::Literal(Literal::String(String::from("Ann"))),
+                    },
+                    Assignment {
+                        column_names: vec![identifier("a"), identifier("b")],
+                        value: Expression::Subquery(Box::new(Query::Select(SelectCommand {
+                            selected_expressions: vec![
+                                SelectedExpression::Column(ColumnSelectedExpression {
+                                    schema_name: None,
+                                    table_name: None,
+                                    column_name: identifier("a"),
+                                }),
+                                SelectedExpression::Column(ColumnSelectedExpression {
+                                    schema_name: None,
+                                    table_name: None,
+                                    column_name: identifier("b"),
+                                }),
+                            ],
+                            from_items: vec![FromItem::Table(TableFromItem {
+                                only: false,
+                                schema_name: None,
+                                table_name: identifier("c"),
+                                alias: None,
+                                tablesample: None,
+                            })],
+                            where_clause: None,
+                            order_by: vec![],
+                            limit: None,
+                            offset: None,
+                            locking: vec![],
+                        }))),
+                    },
+                ],
+                from_items: vec![FromItem::Table(TableFromItem {
+                    only: false,
+                    schema_name: None,
+                    table_name: identifier("d"),
+                    alias: None,
+                    tablesample: None,
+                })],
+                where_clause: Some(WhereClause::Condition(Expression::BinaryOperation(
+                    BinaryOperation {
+                        left: Box::new(Expression::Column(ColumnSelectedExpression {
+                            schema_name: None,
+                            table_name: Some(identifier("t")),
+                            column_name: identifier("id"),
+                        })),
+                        operator: String::from("="),
+                        right: Box::new(Expression::Column(ColumnSelectedExpression {
+                            schema_name: None,
+                            table_name: Some(identifier("d")),
+                            column_name: identifier("id"),
+                        })),
+                    },
+                ))),
+                returning: vec![OutputExpression {
+                    expression: SelectedExpression::AllColumns(AllColumnsSelectedExpression {
+                        schema_name: None,
+                        table_name: Some(identifier("t")),
+                    }),
+                    output_name: None,
+                }],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn update_where_current_of() {
+    test_parse(
+        "UPDATE teacher * AS t SET active = DEFAULT WHERE CURRENT OF teacher_cursor;",
+        vec![Command::DataManipulation(DataManipulationCommand::Update(
+            UpdateCommand {
+                target: TargetTable {
+                    only: false,
+                    table_name: table_name("teacher"),
+                    alias: Some(identifier("t")),
+                },
+                assignments: vec![Assignment {
+                    column_names: vec![identifier("active")],
+                    value: Expression::Default,
+                }],
+                from_items: vec![],
+                where_clause: Some(WhereClause::CurrentOf(identifier("teacher_cursor"))),
+                returning: vec![],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn update_parameters() {
+    let parsed_tree = parse_postgresql("update t set a = $2 where b = $1 returning $3;");
+    assert_eq!(
+        parsed_tree
+            .parameters()
+            .into_iter()
+            .map(|parameter| parameter.kind.clone())
+            .collect::<Vec<ParameterKind>>(),
+        vec![
+            ParameterKind::Positional(2),
+            ParameterKind::Positional(1),
+            ParameterKind::Positional(3),
+        ]
+    );
+}
+
+#[test]
+fn update_invalid_missing_set() {
+    test_parse("update teacher name = 'Ann';", vec![]);
+}
+
+#[test]
+fn update_invalid_only_with_star() {
+    test_parse("update only teacher * set name = 'Ann';", vec![]);
+}
+
+#[test]
+fn delete_command() {
+    test_parse(
+        "delete from only public.teacher as t using school, district where t.school_id = $1 returning t.id;",
+        vec![Command::DataManipulation(DataManipulationCommand::Delete(
+            DeleteCommand {
+                target: TargetTable {
+                    only: true,
+                    table_name: QualifiedName {
+                        schema_name: Some(identifier("public")),
+                        name: identifier("teacher"),
+                    },
+                    alias: Some(identifier("t")),
+                },
+                using_items: vec![
+                    FromItem::Table(TableFromItem {
+                        only: false,
+                        schema_name: None,
+                        table_name: identifier("school"),
+                        alias: None,
+                        tablesample: None,
+                    }),
+                    FromItem::Table(TableFromItem {
+                        only: false,
+                        schema_name: None,
+                        table_name: identifier("district"),
+                        alias: None,
+                        tablesample: None,
+                    }),
+                ],
+                where_clause: Some(WhereClause::Condition(Expression::BinaryOperation(
+                    BinaryOperation {
+                        left: Box::new(Expression::Column(ColumnSelectedExpression {
+                            schema_name: None,
+                            table_name: Some(identifier("t")),
+                            column_name: identifier("school_id"),
+                        })),
+                        operator: String::from("="),
+                        right: Box::new(Expression::Parameter(Parameter {
+                            position: TokenPosition { line: 0, column: 80 },
+                            kind: ParameterKind::Positional(1),
+                        })),
+                    },
+                ))),
+                returning: vec![OutputExpression {
+                    expression: SelectedExpression::Column(ColumnSelectedExpression {
+                        schema_name: None,
+                        table_name: Some(identifier("t")),
+                        column_name: identifier("id"),
+                    }),
+                    output_name: None,
+                }],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn delete_without_where_clause() {
+    test_parse(
+        "DELETE FROM teacher;",
+        vec![Command::DataManipulation(DataManipulationCommand::Delete(
+            DeleteCommand {
+                target: TargetTable {
+                    only: false,
+                    table_name: table_name("teacher"),
+                    alias: None,
+                },
+                using_items: vec![],
+                where_clause: None,
+                returning: vec![],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn delete_where_between() {
+    test_parse(
+        "DELETE FROM t WHERE a BETWEEN 1 AND 2;",
+        vec![Command::DataManipulation(DataManipulationCommand::Delete(
+            DeleteCommand {
+                target: TargetTable {
+                    only: false,
+                    table_name: table_name("t"),
+                    alias: None,
+                },
+                using_items: vec![],
+                where_clause: Some(WhereClause::Condition(Expression::Between(Between {
+                    expression: Box::new(column("a")),
+                    negated: false,
+                    symmetric: false,
+                    low: Box::new(number("1")),
+                    high: Box::new(number("2")),
+                }))),
+                returning: vec![],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn delete_where_current_of() {
+    let command = parse_single_command("delete from teacher where current of c;");
+    let delete_command = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::Delete(delete_command)) => delete_command
+    );
+    assert_eq!(
+        delete_command.where_clause,
+        Some(WhereClause::CurrentOf(identifier("c")))
+    );
+}
+
+#[test]
+fn delete_invalid_missing_from() {
+    test_parse("delete teacher where id = 1;", vec![]);
+}
+
+#[test]
+fn delete_invalid_empty_where() {
+    test_parse("delete from teacher where;", vec![]);
+}
+
+#[test]
+fn merge_command() {
+    test_parse(
+        "merge into teacher t using new_teacher n on t.id = n.id when matched and n.deleted then delete when matched then update set name = n.name when not matched then insert (id, name) values (n.id, n.name);",
+        vec![Command::DataManipulation(DataManipulationCommand::Merge(
+            MergeCommand {
+                target: TargetTable {
+                    only: false,
+                    table_name: table_name("teacher"),
+                    alias: Some(identifier("t")),
+                },
+                source: MergeSource::Table(TargetTable {
+                    only: false,
+                    table_name: table_name("new_teacher"),
+                    alias: Some(identifier("n")),
+                }),
+                join_condition: Expression::BinaryOperation(BinaryOperation {
+                    left: Box::new(qualified_column("t", "id")),
+                    operator: String::from("="),
+                    right: Box::new(qualified_column("n", "id")),
+                }),
+                when_clauses: vec![
+                    MergeWhenClause {
+                        match_kind: MergeMatchKind::Matched,
+                        condition: Some(qualified_column("n", "deleted")),
+                        action: MergeAction::Delete,
+                    },
+                    MergeWhenClause {
+                        match_kind: MergeMatchKind::Matched,
+                        condition: None,
+                        action: MergeAction::Update(vec![Assignment {
+                            column_names: vec![identifier("name")],
+                            value: qualified_column("n", "name"),
+                        }]),
+                    },
+                    MergeWhenClause {
+                        match_kind: MergeMatchKind::NotMatchedByTarget,
+                        condition: None,
+                        action: MergeAction::Insert(MergeInsert {
+                            column_names: vec![identifier("id"), identifier("name")],
+                            overriding: None,
+                            values: Some(vec![
+                                qualified_column("n", "id"),
+                                qualified_column("n", "name"),
+                            ]),
+                        }),
+                    },
+                ],
+                returning: vec![],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn merge_not_matched_by_source_and_returning() {
+    let command = parse_single_command(
+        "MERGE INTO teacher USING (SELECT id FROM new_teacher) AS n ON teacher.id = n.id
+        WHEN NOT MATCHED BY SOURCE THEN DELETE
+        WHEN NOT MATCHED BY TARGET THEN INSERT DEFAULT VALUES
+        RETURNING *;",
+    );
+    let merge_command = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::Merge(merge_command)) => merge_command
+    );
+    assert!(matches!(
+        merge_command.source,
+        MergeSource::Query { alias: Some(_), .. }
+    ));
+    assert_eq!(
+        merge_command
+            .when_clauses
+            .iter()
+            .map(|when_clause| &when_clause.match_kind)
+            .collect::<Vec<_>>(),
+        vec![
+            &MergeMatchKind::NotMatchedBySource,
+            &MergeMatchKind::NotMatchedByTarget
+        ]
+    );
+    assert_eq!(
+        merge_command.when_clauses[1].action,
+        MergeAction::Insert(MergeInsert {
+            column_names: vec![],
+            overriding: None,
+            values: None,
+        })
+    );
+    assert_eq!(merge_command.returning.len(), 1);
+}
+
+#[test]
+fn merge_query_source_with_column_aliases() {
+    let command = parse_single_command(
+        "merge into t using (values (1)) s(id) on t.id = s.id when not matched then insert default values;",
+    );
+    let (query, alias) = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::Merge(MergeCommand {
+            source: MergeSource::Query {
+                query,
+                alias: Some(alias),
+            },
+            ..
+        })) => (query, alias)
+    );
+    assert!(matches!(query, Query::Values(ValuesCommand { .. })));
+    assert_eq!(
+        alias,
+        TableAlias {
+            name: identifier("s"),
+            column_names: vec![identifier("id")],
+        }
+    );
+}
+
+#[test]
+fn merge_parameters() {
+    let parsed_tree = parse_postgresql(
+        "merge into t using s on t.id = $1 when matched and s.x > $2 then update set x = $3 when not matched then insert values ($4);",
+    );
+    assert_eq!(
+        parsed_tree
+            .parameters()
+            .into_iter()
+            .map(|parameter| parameter.kind.clone())
+            .collect::<Vec<ParameterKind>>(),
+        vec![
+            ParameterKind::Positional(1),
+            ParameterKind::Positional(2),
+            ParameterKind::Positional(3),
+            ParameterKind::Positional(4),
+        ]
+    );
+}
+
+#[test]
+fn merge_invalid_insert_when_matched() {
+    test_parse(
+        "merge into t using s on t.id = s.id when matched then insert values (1);",
+        vec![],
+    );
+}
+
+#[test]
+fn merge_invalid_delete_when_not_matched() {
+    test_parse(
+        "merge into t using s on t.id = s.id when not matched then delete;",
+        vec![],
+    );
+}
+
+#[test]
+fn merge_invalid_without_when_clause() {
+    test_parse("merge into t using s on t.id = s.id;", vec![]);
+}
+
+#[test]
+fn values_command() {
+    test_parse(
+        "VALUES (1, 'a'), (2, 'b') ORDER BY 1 DESC NULLS FIRST LIMIT 1;",
+        vec![Command::DataManipulation(DataManipulationCommand::Values(
+            ValuesCommand {
+                rows: vec![
+                    vec![
+                        number("1"),
+                        Expression::Literal(Literal::String(String::from("a"))),
+                    ],
+                    vec![
+                        number("2"),
+                        Expression::Literal(Literal::String(String::from("b"))),
+                    ],
+                ],
+                order_by: vec![SortExpression {
+                    expression: number("1"),
+                    direction: Some(SortDirection::Descending),
+                    nulls: Some(NullsOrder::First),
+                }],
+                limit: Some(number("1")),
+                offset: None,
+            },
+        ))],
+    );
+}
+
+#[test]
+fn select_from_values_with_column_aliases() {
+    let command =
+        parse_single_command("select id from (values (1, 'a'), (2, 'b')) as v(id, name);");
+    let select_command = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::Select(select_command)) => select_command
+    );
+    let (query, alias) = extract!(
+        select_command.from_items.into_iter().next().unwrap(),
+        FromItem::Subquery(SubqueryFromItem {
+            query,
+            alias: Some(alias),
+            ..
+        }) => (query, alias)
+    );
+    assert!(matches!(*query, Query::Values(ValuesCommand { .. })));
+    assert_eq!(
+        alias,
+        TableAlias {
+            name: identifier("v"),
+            column_names: vec![identifier("id"), identifier("name")],
+        }
+    );
+}
+
+#[test]
+fn set_operation_precedence_and_ordering() {
+    test_parse(
+        "values (1) union all values (2) intersect values (3) order by 1 limit 2 offset 1;",
+        vec![Command::DataManipulation(
+            DataManipulationCommand::SetOperation(SetOperation {
+                left: Box::new(Query::Values(ValuesCommand {
+                    rows: vec![vec![number("1")]],
+                    order_by: vec![],
+                    limit: None,
+                    offset: None,
+                })),
+                operator: SetOperator::Union,
+                all: true,
+                right: Box::new(Query::SetOperation(SetOperation {
+                    left: Box::new(Query::Values(ValuesCommand {
+                        rows: vec![vec![number("2")]],
+                        order_by: vec![],
+                        limit: None,
+                        offset: None,
+                    })),
+                    operator: SetOperator::Intersect,
+                    all: false,
+                    right: Box::new(Query::Values(ValuesCommand {
+                        rows: vec![vec![number("3")]],
+                        order_by: vec![],
+                        limit: None,
+                        offset: None,
+                    })),
+                    order_by: vec![],
+                    limit: None,
+                    offset: None,
+                })),
+                order_by: vec![SortExpression {
+                    expression: number("1"),
+                    direction: None,
+                    nulls: None,
+                }],
+                limit: Some(number("2")),
+                offset: Some(number("1")),
+            }),
+        )],
+    );
+}
+
+#[test]
+fn parenthesized_query_at_statement_start() {
+    test_parse(
+        "(SELECT * FROM t) UNION (SELECT * FROM u) LIMIT 1;",
+        vec![Command::DataManipulation(
+            DataManipulationCommand::SetOperation(SetOperation {
+                left: Box::new(select_all_from("t")),
+                operator: SetOperator::Union,
+                all: false,
+                right: Box::new(select_all_from("u")),
+                order_by: vec![],
+                limit: Some(number("1")),
+                offset: None,
+            }),
+        )],
+    );
+}
+
+#[test]
+fn parenthesized_query_invalid_unclosed() {
+    test_parse("(SELECT * FROM t UNION SELECT * FROM u;", vec![]);
+}
+
+#[test]
+fn select_union_values() {
+    let command = parse_single_command("select a from t except select b from u union values (1);");
+    let set_operation = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::SetOperation(set_operation)) =>
+            set_operation
+    );
+    assert_eq!(set_operation.operator, SetOperator::Union);
+    assert!(matches!(*set_operation.right, Query::Values(_)));
+    assert!(matches!(
+        *set_operation.left,
+        Query::SetOperation(SetOperation {
+            operator: SetOperator::Except,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn values_parameters() {
+    let parsed_tree = parse_postgresql("select a from (values ($1)) v union values ($2) limit $3;");
+    assert_eq!(
+        parsed_tree
+            .parameters()
+            .into_iter()
+            .map(|parameter| parameter.kind.clone())
+            .collect::<Vec<ParameterKind>>(),
+        vec![
+            ParameterKind::Positional(1),
+            ParameterKind::Positional(2),
+            ParameterKind::Positional(3),
+        ]
+    );
+}
+
+#[test]
+fn values_invalid_rows_of_different_lengths() {
+    test_parse("values (1, 'a'), (2);", vec![]);
+}
+
+#[test]
+fn select_invalid_duplicate_limit() {
+    test_parse("select a from t limit 1 limit 2;", vec![]);
+}
+
+#[test]
+fn select_for_update_skip_locked() {
+    test_parse(
+        "select id from job order by id limit 1 for update skip locked;",
+        vec![Command::DataManipulation(DataManipulationCommand::Select(
+            SelectCommand {
+                selected_expressions: vec![SelectedExpression::Column(ColumnSelectedExpression {
+                    schema_name: None,
+                    table_name: None,
+                    column_name: identifier("id"),
+                })],
+                from_items: vec![FromItem::Table(TableFromItem {
+                    only: false,
+                    schema_name: None,
+                    table_name: identifier("job"),
+                    alias: None,
+                    tablesample: None,
+                })],
+                where_clause: None,
+                order_by: vec![SortExpression {
+                    expression: column("id"),
+                    direction: None,
+                    nulls: None,
+                }],
+                limit: Some(number("1")),
+                offset: None,
+                locking: vec![LockingClause {
+                    strength: LockStrength::Update,
+                    tables: vec![],
+                    wait_policy: Some(LockWaitPolicy::SkipLocked),
+                }],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn select_where_for_update_skip_locked() {
+    test_parse(
+        "SELECT id FROM jobs WHERE status = 'new' ORDER BY id LIMIT 1 FOR UPDATE SKIP LOCKED;",
+        vec![Command::DataManipulation(DataManipulationCommand::Select(
+            SelectCommand {
+                selected_expressions: vec![SelectedExpression::Column(ColumnSelectedExpression {
+                    schema_name: None,
+                    table_name: None,
+                    column_name: identifier("id"),
+                })],
+                from_items: vec![FromItem::Table(TableFromItem {
+                    only: false,
+                    schema_name: None,
+                    table_name: identifier("jobs"),
+                    alias: None,
+                    tablesample: None,
+                })],
+                where_clause: Some(Expression::BinaryOperation(BinaryOperation {
+                    left: Box::new(column("status")),
+                    operator: String::from("="),
+                    right: Box::new(Expression::Literal(Literal::String(String::from("new")))),
+                })),
+                order_by: vec![SortExpression {
+                    expression: column("id"),
+                    direction: None,
+                    nulls: None,
+                }],
+                limit: Some(number("1")),
+                offset: None,
+                locking: vec![LockingClause {
+                    strength: LockStrength::Update,
+                    tables: vec![],
+                    wait_policy: Some(LockWaitPolicy::SkipLocked),
+                }],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn select_without_from() {
+    test_parse(
+        "SELECT pg_catalog.set_config('search_path', '', false);",
+        vec![Command::DataManipulation(DataManipulationCommand::Select(
+            SelectCommand {
+                selected_expressions: vec![SelectedExpression::Expression(
+                    Expression::FunctionCall(FunctionCall {
+                        function_name: QualifiedName {
+                            schema_name: Some(identifier("pg_catalog")),
+                            name: identifier("set_config"),
+                        },
+                        arguments: vec![
+                            Expression::Literal(Literal::String(String::from("search_path"))),
+                            Expression::Literal(Literal::String(String::from(""))),
+                            Expression::Literal(Literal::Boolean(false)),
+                        ],
+                    }),
+                )],
+                from_items: vec![],
+                where_clause: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+                locking: vec![],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn select_count_star() {
+    test_parse(
+        "SELECT count(*), CASE WHEN a > 0 THEN 'positive' ELSE 'other' END FROM t;",
+        vec![Command::DataManipulation(DataManipulationCommand::Select(
+            SelectCommand {
+                selected_expressions: vec![
+                    SelectedExpression::Expression(Expression::FunctionCall(FunctionCall {
+                        function_name: table_name("count"),
+                        arguments: vec![Expression::AllColumns(AllColumnsSelectedExpression {
+                            schema_name: None,
+                            table_name: None,
+                        })],
+                    })),
+                    SelectedExpression::Expression(Expression::Case(Case {
+                        operand: None,
+                        when_clauses: vec![CaseWhen {
+                            condition: Expression::BinaryOperation(BinaryOperation {
+                                left: Box::new(column("a")),
+                                operator: String::from(">"),
+                                right: Box::new(number("0")),
+                            }),
+                            result: Expression::Literal(Literal::String(String::from("positive"))),
+                        }],
+                        else_result: Some(Box::new(Expression::Literal(Literal::String(
+                            String::from("other"),
+                        )))),
+                    })),
+                ],
+                from_items: vec![FromItem::Table(TableFromItem {
+                    only: false,
+                    schema_name: None,
+                    table_name: identifier("t"),
+                    alias: None,
+                    tablesample: None,
+                })],
+                where_clause: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+                locking: vec![],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn select_multiple_locking_clauses() {
+    let command = parse_single_command(
+        "SELECT * FROM job FOR NO KEY UPDATE OF job NOWAIT FOR KEY SHARE OF worker LIMIT 5;",
+    );
+    let select_command = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::Select(select_command)) => select_command
+    );
+    assert_eq!(
+        select_command.locking,
+        vec![
+            LockingClause {
+                strength: LockStrength::NoKeyUpdate,
+                tables: vec![table_name("job")],
+                wait_policy: Some(LockWaitPolicy::NoWait),
+            },
+            LockingClause {
+                strength: LockStrength::KeyShare,
+                tables: vec![table_name("worker")],
+                wait_policy: None,
+            },
+        ]
+    );
+    assert_eq!(select_command.limit, Some(number("5")));
+}
+
+#[test]
+fn select_for_share_in_subquery() {
+    let command = parse_single_command("select * from (select * from job for share) j;");
+    let select_command = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::Select(select_command)) => select_command
+    );
+    let query = extract!(
+        select_command.from_items.into_iter().next().unwrap(),
+        FromItem::Subquery(SubqueryFromItem { query, .. }) => query
+    );
+    let subquery = extract!(*query, Query::Select(subquery) => subquery);
+    assert_eq!(subquery.locking.len(), 1);
+    assert_eq!(subquery.locking[0].strength, LockStrength::Share);
+}
+
+#[test]
+fn locking_invalid_with_set_operation() {
+    test_parse("select a from t union select b from u for update;", vec![]);
+}
+
+#[test]
+fn locking_invalid_with_values() {
+    test_parse("values (1) for update;", vec![]);
+}
+
+#[test]
+fn locking_invalid_missing_strength() {
+    test_parse("select * from job for skip locked;", vec![]);
+}
+
+#[test]
+fn from_lateral_function_with_ordinality() {
+    let command = parse_single_command(
+        "select * from series s, lateral generate_series(1, s.n) with ordinality as g(i, ord);",
+    );
+    let select_command = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::Select(select_command)) => select_command
+    );
+    assert_eq!(
+        select_command.from_items,
+        vec![
+            FromItem::Table(TableFromItem {
+                only: false,
+                schema_name: None,
+                table_name: identifier("series"),
+                alias: Some(TableAlias {
+                    name: identifier("s"),
+                    column_names: vec![],
+                }),
+                tablesample: None,
+            }),
+            FromItem::Function(FunctionFromItem {
+                lateral: true,
+                function: FromFunction::Call(FunctionCall {
+                    function_name: table_name("generate_series"),
+                    arguments: vec![number("1"), qualified_column("s", "n")],
+                }),
+                with_ordinality: true,
+                alias: Some(TableAlias {
+                    name: identifier("g"),
+                    column_names: vec![identifier("i"), identifier("ord")],
+                }),
+                column_definitions: vec![],
+            }),
+        ]
+    );
+}
+
+#[test]
+fn from_function_with_column_definition_list() {
+    let command = parse_single_command("select * from json_to_record($1) as (a int, b text);");
+    let select_command = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::Select(select_command)) => select_command
+    );
+    assert_eq!(
+        select_command.from_items,
+        vec![FromItem::Function(FunctionFromItem {
+            lateral: false,
+            function: FromFunction::Call(FunctionCall {
+                function_name: table_name("json_to_record"),
+                arguments: vec![Expression::Parameter(Parameter {
+                    position: TokenPosition {
+                        line: 0,
+                        column: 29
+                    },
+                    kind: ParameterKind::Positional(1),
+                })],
+            }),
+            with_ordinality: false,
+            alias: None,
+            column_definitions: vec![
+                FunctionColumnDefinition {
+                    name: identifier("a"),
+                    type_name: type_name("int"),
+                },
+                FunctionColumnDefinition {
+                    name: identifier("b"),
+                    type_name: type_name("text"),
+                },
+            ],
+        })]
+    );
+}
+
+#[test]
+fn from_rows_from() {
+    let command = parse_single_command(
+        "SELECT * FROM ROWS FROM (unnest($1), json_to_record($2) AS (id bigint, tags text[])) AS r;",
+    );
+    let select_command = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::Select(select_command)) => select_command
+    );
+    assert_eq!(select_command.from_items.len(), 1);
+    let (functions, alias) = extract!(
+        select_command.from_items.into_iter().next().unwrap(),
+        FromItem::Function(FunctionFromItem {
+            function: FromFunction::RowsFrom(functions),
+            alias: Some(alias),
+            ..
+        }) => (functions, alias)
+    );
+    assert_eq!(functions.len(), 2);
+    assert_eq!(functions[0].column_definitions, vec![]);
+    assert_eq!(
+        functions[1].column_definitions,
+        vec![
+            FunctionColumnDefinition {
+                name: identifier("id"),
+                type_name: type_name("bigint"),
+            },
+            FunctionColumnDefinition {
+                name: identifier("tags"),
+                type_name: TypeName {
+                    name: table_name("text"),
+                    modifiers: vec![],
+                    array_dimensions: 1,
+                },
+            },
+        ]
+    );
+    assert_eq!(alias.name, identifier("r"));
+}
+
+#[test]
+fn from_only_table_with_tablesample() {
+    let command = parse_single_command(
+        "select * from only measurement m tablesample bernoulli (10) repeatable (42);",
+    );
+    let select_command = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::Select(select_command)) => select_command
+    );
+    assert_eq!(
+        select_command.from_items,
+        vec![FromItem::Table(TableFromItem {
+            only: true,
+            schema_name: None,
+            table_name: identifier("measurement"),
+            alias: Some(TableAlias {
+                name: identifier("m"),
+                column_names: vec![],
+            }),
+            tablesample: Some(TableSample {
+                method: table_name("bernoulli"),
+                arguments: vec![number("10")],
+                repeatable_seed: Some(number("42")),
+            }),
+        })]
+    );
+}
+
+#[test]
+fn from_table_with_descendants_and_lateral_subquery() {
+    let command =
+        parse_single_command("select * from measurement *, lateral (select a from b) as s;");
+    let select_command = extract!(
+        command,
+        Command::DataManipulation(DataManipulationCommand::Select(select_command)) => select_command
+    );
+    assert!(matches!(
+        select_command.from_items[0],
+        FromItem::Table(TableFromItem { only: false, .. })
+    ));
+    assert!(matches!(
+        select_command.from_items[1],
+        FromItem::Subquery(SubqueryFromItem { lateral: true, .. })
+    ));
+}
+
+#[test]
+fn from_invalid_lateral_table() {
+    test_parse("select * from lateral measurement;", vec![]);
+}
+
+#[test]
+fn from_invalid_only_with_star() {
+    test_parse("select * from only measurement *;", vec![]);
+}
+
+#[test]
+fn from_invalid_tablesample_without_arguments() {
+    test_parse("select * from measurement tablesample system ();", vec![]);
+}
+
+#[test]
+fn create_table() {
+    test_parse(
+        "CREATE TEMP TABLE IF NOT EXISTS app.users (
+            id bigint PRIMARY KEY,
+            email varchar(255) COLLATE \"C\" NOT NULL UNIQUE,
+            active boolean DEFAULT true,
+            CONSTRAINT email_check CHECK (email <> '')
+        );",
+        vec![Command::DataDefinition(DataDefinitionCommand::CreateTable(
+            CreateTableCommand {
+                persistence: Some(TablePersistence::Temporary),
+                if_not_exists: true,
+                table_name: QualifiedName {
+                    schema_name: Some(identifier("app")),
+                    name: identifier("users"),
+                },
+                elements: vec![
+                    TableElement::Column(ColumnDefinition {
+                        name: identifier("id"),
+                        type_name: Some(type_name("bigint")),
+                        collation: None,
+                        constraints: vec![ColumnConstraint {
+                            name: None,
+                            kind: ColumnConstraintKind::PrimaryKey,
+                            deferrable: None,
+                            initially_deferred: None,
+                        }],
+                    }),
+                    TableElement::Column(ColumnDefinition {
+                        name: identifier("email"),
+                        type_name: Some(TypeName {
+                            name: table_name("varchar"),
+                            modifiers: vec![number("255")],
+                            array_dimensions: 0,
+                        }),
+                        collation: Some(QualifiedName {
+                            schema_name: None,
+                            name: Identifier {
+                                quoted: true,
+                                value: String::from("C"),
+                            },
+                        }),
+                        constraints: vec![
+                            ColumnConstraint {
+                                name: None,
+                                kind: ColumnConstraintKind::NotNull,
+                                deferrable: None,
+                                initially_deferred: None,
+                            },
+                            ColumnConstraint {
+                                name: None,
+                                kind: ColumnConstraintKind::Unique {
+                                    nulls_not_distinct: false,
+                                },
+                                deferrable: None,
+                                initially_deferred: None,
+                            },
+                        ],
+                    }),
+                    TableElement::Column(ColumnDefinition {
+                        name: identifier("active"),
+                        type_name: Some(type_name("boolean")),
+                        collation: None,
+                        constraints: vec![ColumnConstraint {
+                            name: None,
+                            kind: ColumnConstraintKind::Default(Expression::Literal(
+                                Literal::Boolean(true),
+                            )),
+                            deferrable: None,
+                            initially_deferred: None,
+                        }],
+                    }),
+                    TableElement::Constraint(TableConstraint {
+                        name: Some(identifier("email_check")),
+                        kind: TableConstraintKind::Check {
+                            expression: Expression::BinaryOperation(BinaryOperation {
+                                left: Box::new(column("email")),
+                                operator: String::from("<>"),
+                                right: Box::new(Expression::Literal(Literal::String(
+                                    String::from(""),
+                                ))),
+                            }),
+                            no_inherit: false,
+                        },
+                        deferrable: None,
+                        initially_deferred: None,
+                    }),
+                ],
+                inherits: vec![],
+                partition_of: None,
+                partition_by: None,
+                storage_parameters: vec![],
+                tablespace: None,
+            },
+        ))],
+    );
+}
+
+#[test]
+fn create_table_identity_and_generated_columns() {
+    let command = parse_single_command(
+        "create table orders (
+            id int generated by default as identity (start with 100 increment by 10),
+            net numeric,
+            gross numeric generated always as (net * 2) stored
+        );",
+    );
+    let create_table_command = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateTable(create_table_command)) =>
+            create_table_command
+    );
+    let constraints: Vec<&ColumnConstraintKind> = create_table_command
+        .elements
+        .iter()
+        .filter_map(|element| match element {
+            TableElement::Column(column_definition) => Some(column_definition),
+            _ => None,
+        })
+        .flat_map(|column_definition| column_definition.constraints.iter())
+        .map(|constraint| &constraint.kind)
+        .collect();
+    assert_eq!(
+        constraints,
+        vec![
+            &ColumnConstraintKind::Identity {
+                always: false,
+                sequence_options: vec![
+                    SequenceOption::StartWith(number("100")),
+                    SequenceOption::IncrementBy(number("10")),
+                ],
+            },
+            &ColumnConstraintKind::Generated(Expression::BinaryOperation(BinaryOperation {
+                left: Box::new(column("net")),
+                operator: String::from("*"),
+                right: Box::new(number("2")),
+            })),
+        ]
+    );
+}
+
+#[test]
+fn create_table_foreign_keys() {
+    let command = parse_single_command(
+        "create table items (
+            order_id int references orders on delete cascade,
+            product_id int,
+            constraint product_fk foreign key (product_id) references products (id)
+                match full on update set null (product_id) deferrable initially deferred
+        );",
+    );
+    let create_table_command = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateTable(create_table_command)) =>
+            create_table_command
+    );
+    assert_eq!(
+        create_table_command.elements[0],
+        TableElement::Column(ColumnDefinition {
+            name: identifier("order_id"),
+            type_name: Some(type_name("int")),
+            collation: None,
+            constraints: vec![ColumnConstraint {
+                name: None,
+                kind: ColumnConstraintKind::References(ForeignKeyReference {
+                    table_name: table_name("orders"),
+                    columns: vec![],
+                    match_type: None,
+                    on_delete: Some(ReferentialAction::Cascade),
+                    on_update: None,
+                }),
+                deferrable: None,
+                initially_deferred: None
+            }],
+        })
+    );
+    assert_eq!(
+        create_table_command.elements[2],
+        TableElement::Constraint(TableConstraint {
+            name: Some(identifier("product_fk")),
+            kind: TableConstraintKind::ForeignKey {
+                columns: vec![identifier("product_id")],
+                reference: ForeignKeyReference {
+                    table_name: table_name("products"),
+                    columns: vec![identifier("id")],
+                    match_type: Some(ForeignKeyMatch::Full),
+                    on_delete: None,
+                    on_update: Some(ReferentialAction::SetNull(vec![identifier("product_id")])),
+                },
+            },
+            deferrable: Some(true),
+            initially_deferred: Some(true),
+        })
+    );
+}
+
+#[test]
+fn create_table_like_inherits_with_tablespace() {
+    let command = parse_single_command(
+        "create unlogged table audit (like events including defaults excluding indexes)
+            inherits (base_audit) with (fillfactor = 70) tablespace fast_disk;",
+    );
+    let create_table_command = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateTable(create_table_command)) =>
+            create_table_command
+    );
+    assert_eq!(
+        create_table_command.persistence,
+        Some(TablePersistence::Unlogged)
+    );
+    assert_eq!(
+        create_table_command.elements,
+        vec![TableElement::Like(TableLikeClause {
+            source_table: table_name("events"),
+            options: vec![
+                TableLikeOption {
+                    including: true,
+                    property: TableLikeProperty::Defaults,
+                },
+                TableLikeOption {
+                    including: false,
+                    property: TableLikeProperty::Indexes,
+                },
+            ],
+        })]
+    );
+    assert_eq!(
+        create_table_command.inherits,
+        vec![table_name("base_audit")]
+    );
+    assert_eq!(
+        create_table_command.storage_parameters,
+        vec![StorageParameter {
+            namespace: None,
+            name: identifier("fillfactor"),
+            value: Some(number("70")),
+        }]
+    );
+    assert_eq!(
+        create_table_command.tablespace,
+        Some(identifier("fast_disk"))
+    );
+}
+
+#[test]
+fn create_table_partitions() {
+    let command = parse_single_command(
+        "create table measurement (logdate date) partition by range (logdate);",
+    );
+    let partitioned = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateTable(partitioned)) => partitioned
+    );
+    assert_eq!(
+        partitioned.partition_by,
+        Some(PartitionBy {
+            strategy: PartitionStrategy::Range,
+            keys: vec![PartitionKey {
+                expression: column("logdate"),
+                collation: None,
+                operator_class: None,
+            }],
+        })
+    );
+
+    let command = parse_single_command(
+        "create table measurement_y2024 partition of measurement (logdate not null)
+            for values from ('2024-01-01') to (maxvalue);",
+    );
+    let partition = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateTable(partition)) => partition
+    );
+    assert_eq!(
+        partition.partition_of,
+        Some(PartitionOf {
+            parent_table: table_name("measurement"),
+            bound: PartitionBound::Range {
+                from: vec![PartitionRangeValue::Value(Expression::Literal(
+                    Literal::String(String::from("2024-01-01"))
+                ))],
+                to: vec![PartitionRangeValue::MaxValue],
+            },
+        })
+    );
+    assert_eq!(
+        partition.elements,
+        vec![TableElement::Column(ColumnDefinition {
+            name: identifier("logdate"),
+            type_name: None,
+            collation: None,
+            constraints: vec![ColumnConstraint {
+                name: None,
+                kind: ColumnConstraintKind::NotNull,
+                deferrable: None,
+                initially_deferred: None
+            }],
+        })]
+    );
+
+    let command = parse_single_command(
+        "create table p partition of h for values with (modulus 4, remainder 1);",
+    );
+    let partition = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateTable(partition)) => partition
+    );
+    assert_eq!(
+        partition
+            .partition_of
+            .map(|partition_of| partition_of.bound),
+        Some(PartitionBound::Hash {
+            modulus: number("4"),
+            remainder: number("1"),
+        })
+    );
+    let command = parse_single_command("create table p partition of l default;");
+    let partition = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateTable(partition)) => partition
+    );
+    assert_eq!(
+        partition
+            .partition_of
+            .map(|partition_of| partition_of.bound),
+        Some(PartitionBound::Default)
+    );
+}
+
+#[test]
+fn create_table_exclusion_constraint() {
+    test_parse(
+        "CREATE TABLE booking (
+            room int,
+            during tsrange,
+            CONSTRAINT no_overlap EXCLUDE USING gist (room WITH =, during WITH &&) INCLUDE (room) WHERE (room > 0)
+        );",
+        vec![Command::DataDefinition(DataDefinitionCommand::CreateTable(
+            CreateTableCommand {
+                persistence: None,
+                if_not_exists: false,
+                table_name: table_name("booking"),
+                elements: vec![
+                    TableElement::Column(ColumnDefinition {
+                        name: identifier("room"),
+                        type_name: Some(type_name("int")),
+                        collation: None,
+                        constraints: vec![],
+                    }),
+                    TableElement::Column(ColumnDefinition {
+                        name: identifier("during"),
+                        type_name: Some(type_name("tsrange")),
+                        collation: None,
+                        constraints: vec![],
+                    }),
+                    TableElement::Constraint(TableConstraint {
+                        name: Some(identifier("no_overlap")),
+                        kind: TableConstraintKind::Exclude {
+                            method: Some(identifier("gist")),
+                            elements: vec![
+                                ExclusionElement {
+                                    element: IndexElement {
+                                        expression: column("room"),
+                                        collation: None,
+                                        operator_class: None,
+                                        direction: None,
+                                        nulls: None,
+                                    },
+                                    operator: String::from("="),
+                                },
+                                ExclusionElement {
+                                    element: IndexElement {
+                                        expression: column("during"),
+                                        collation: None,
+                                        operator_class: None,
+                                        direction: None,
+                                        nulls: None,
+                                    },
+                                    operator: String::from("&&"),
+                                },
+                            ],
+                            include: vec![identifier("room")],
+                            where_clause: Some(Expression::BinaryOperation(BinaryOperation {
+                                left: Box::new(column("room")),
+                                operator: String::from(">"),
+                                right: Box::new(number("0")),
+                            })),
+                        },
+                        deferrable: None,
+                        initially_deferred: None,
+                    }),
+                ],
+                inherits: vec![],
+                partition_of: None,
+                partition_by: None,
+                storage_parameters: vec![],
+                tablespace: None,
+            },
+        ))],
+    );
+}
+
+#[test]
+fn create_table_invalid_exclusion_element_without_operator() {
+    test_parse("CREATE TABLE r (a int, EXCLUDE USING gist (a));", vec![]);
+}
+
+#[test]
+fn create_table_invalid_trailing_comma() {
+    test_parse("create table t (a int,);", vec![]);
+}
+
+#[test]
+fn create_table_invalid_column_without_type() {
+    test_parse("create table t (a);", vec![]);
+}
+
+#[test]
+fn create_table_invalid_generated_by_default_column() {
+    test_parse(
+        "create table t (a int generated by default as (1) stored);",
+        vec![],
+    );
+}
+
+#[test]
+fn alter_table_add_and_drop_columns() {
+    test_parse(
+        "ALTER TABLE IF EXISTS ONLY app.users
+            ADD COLUMN IF NOT EXISTS age int NOT NULL,
+            DROP COLUMN legacy CASCADE,
+            DROP IF EXISTS other;",
+        vec![Command::DataDefinition(DataDefinitionCommand::AlterTable(
+            AlterTableCommand {
+                if_exists: true,
+                only: true,
+                table_name: QualifiedName {
+                    schema_name: Some(identifier("app")),
+                    name: identifier("users"),
+                },
+                actions: vec![
+                    AlterTableAction::AddColumn {
+                        if_not_exists: true,
+                        column: ColumnDefinition {
+                            name: identifier("age"),
+                            type_name: Some(type_name("int")),
+                            collation: None,
+                            constraints: vec![ColumnConstraint {
+                                name: None,
+                                kind: ColumnConstraintKind::NotNull,
+                                deferrable: None,
+                                initially_deferred: None,
+                            }],
+                        },
+                    },
+                    AlterTableAction::DropColumn {
+                        if_exists: false,
+                        name: identifier("legacy"),
+                        behavior: Some(DropBehavior::Cascade),
+                    },
+                    AlterTableAction::DropColumn {
+                        if_exists: true,
+                        name: identifier("other"),
+                        behavior: None,
+                    },
+                ],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn alter_table_alter_columns() {
+    test_parse(
+        "alter table users
+                alter column age type bigint using age::bigint,
+                alter name set default 'anonymous',
+                alter name drop not null,
+                alter id add generated always as identity,
+                alter id drop identity if exists,
+                alter bio set storage external,
+                alter bio set statistics 500;",
+        vec![Command::DataDefinition(DataDefinitionCommand::AlterTable(
+            AlterTableCommand {
+                if_exists: false,
+                only: false,
+                table_name: table_name("users"),
+                actions: vec![
+                    AlterTableAction::AlterColumn {
+                        name: identifier("age"),
+                        action: AlterColumnAction::SetDataType {
+                            type_name: type_name("bigint"),
+                            collation: None,
+                            using: Some(Expression::Cast(Cast {
+                                expression: Box::new(column("age")),
+                                type_name: type_name("bigint"),
+                            })),
+                        },
+                    },
+                    AlterTableAction::AlterColumn {
+                        name: identifier("name"),
+                        action: AlterColumnAction::SetDefault(Expression::Literal(
+                            Literal::String(String::from("anonymous")),
+                        )),
+                    },
+                    AlterTableAction::AlterColumn {
+                        name: identifier("name"),
+                        action: AlterColumnAction::DropNotNull,
+                    },
+                    AlterTableAction::AlterColumn {
+                        name: identifier("id"),
+                        action: AlterColumnAction::AddIdentity {
+                            always: true,
+                            sequence_options: vec![],
+                        },
+                    },
+                    AlterTableAction::AlterColumn {
+                        name: identifier("id"),
+                        action: AlterColumnAction::DropIdentity { if_exists: true },
+                    },
+                    AlterTableAction::AlterColumn {
+                        name: identifier("bio"),
+                        action: AlterColumnAction::SetStorage(ColumnStorage::External),
+                    },
+                    AlterTableAction::AlterColumn {
+                        name: identifier("bio"),
+                        action: AlterColumnAction::SetStatistics(number("500")),
+                    },
+                ],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn alter_table_constraints() {
+    let command = parse_single_command(
+        "alter table orders
+            add constraint orders_user_fk foreign key (user_id) references users not valid,
+            validate constraint orders_user_fk,
+            rename constraint orders_user_fk to orders_owner_fk,
+            drop constraint if exists old_check restrict;",
+    );
+    let actions = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::AlterTable(alter_table_command)) =>
+            alter_table_command.actions
+    );
+    assert!(matches!(
+        &actions[0],
+        AlterTableAction::AddConstraint {
+            constraint: TableConstraint {
+                kind: TableConstraintKind::ForeignKey { .. },
+                ..
+            },
+            not_valid: true,
+        }
+    ));
+    assert_eq!(
+        actions[1..],
+        [
+            AlterTableAction::ValidateConstraint(identifier("orders_user_fk")),
+            AlterTableAction::RenameConstraint {
+                name: identifier("orders_user_fk"),
+                new_name: identifier("orders_owner_fk"),
+            },
+            AlterTableAction::DropConstraint {
+                if_exists: true,
+                name: identifier("old_check"),
+                behavior: Some(DropBehavior::Restrict),
+            },
+        ]
+    );
+}
+
+#[test]
+fn alter_table_add_constraints_using_index() {
+    test_parse(
+        "alter table t add constraint pk primary key using index idx, add unique using index t_email_idx deferrable initially deferred;",
+        vec![Command::DataDefinition(DataDefinitionCommand::AlterTable(
+            AlterTableCommand {
+                if_exists: false,
+                only: false,
+                table_name: table_name("t"),
+                actions: vec![
+                    AlterTableAction::AddConstraintUsingIndex(TableConstraintUsingIndex {
+                        name: Some(identifier("pk")),
+                        primary_key: true,
+                        index_name: identifier("idx"),
+                        deferrable: None,
+                        initially_deferred: None,
+                    }),
+                    AlterTableAction::AddConstraintUsingIndex(TableConstraintUsingIndex {
+                        name: None,
+                        primary_key: false,
+                        index_name: identifier("t_email_idx"),
+                        deferrable: Some(true),
+                        initially_deferred: Some(true),
+                    }),
+                ],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn create_table_invalid_constraint_using_index() {
+    test_parse(
+        "create table t (id int, constraint pk primary key using index idx);",
+        vec![],
+    );
+}
+
+#[test]
+fn alter_table_renames_and_ownership() {
+    test_parse(
+        "alter table users rename to people;",
+        vec![Command::DataDefinition(DataDefinitionCommand::AlterTable(
+            AlterTableCommand {
+                if_exists: false,
+                only: false,
+                table_name: table_name("users"),
+                actions: vec![AlterTableAction::RenameTo(identifier("people"))],
+            },
+        ))],
+    );
+    test_parse(
+        "alter table users rename column name to full_name;",
+        vec![Command::DataDefinition(DataDefinitionCommand::AlterTable(
+            AlterTableCommand {
+                if_exists: false,
+                only: false,
+                table_name: table_name("users"),
+                actions: vec![AlterTableAction::RenameColumn {
+                    name: identifier("name"),
+                    new_name: identifier("full_name"),
+                }],
+            },
+        ))],
+    );
+    test_parse(
+        "alter table users set schema archive;",
+        vec![Command::DataDefinition(DataDefinitionCommand::AlterTable(
+            AlterTableCommand {
+                if_exists: false,
+                only: false,
+                table_name: table_name("users"),
+                actions: vec![AlterTableAction::SetSchema(identifier("archive"))],
+            },
+        ))],
+    );
+    test_parse(
+        "alter table users owner to current_user, set (fillfactor = 50), reset (autovacuum_enabled);",
+        vec![Command::DataDefinition(DataDefinitionCommand::AlterTable(
+            AlterTableCommand {
+                if_exists: false,
+                only: false,
+                table_name: table_name("users"),
+                actions: vec![
+            AlterTableAction::OwnerTo(RoleSpecification::CurrentUser),
+            AlterTableAction::SetStorageParameters(vec![StorageParameter {
+                namespace: None,
+                name: identifier("fillfactor"),
+                value: Some(number("50")),
+            }]),
+            AlterTableAction::ResetStorageParameters(vec![StorageParameter {
+                namespace: None,
+                name: identifier("autovacuum_enabled"),
+                value: None,
+            }]),
+        ],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn alter_table_partitions_triggers_and_row_level_security() {
+    test_parse(
+        "alter table measurement
+                attach partition measurement_y2024 default,
+                detach partition measurement_y2023 concurrently,
+                disable trigger all,
+                enable replica trigger audit,
+                enable row level security,
+                no force row level security;",
+        vec![Command::DataDefinition(DataDefinitionCommand::AlterTable(
+            AlterTableCommand {
+                if_exists: false,
+                only: false,
+                table_name: table_name("measurement"),
+                actions: vec![
+                    AlterTableAction::AttachPartition {
+                        partition: table_name("measurement_y2024"),
+                        bound: PartitionBound::Default,
+                    },
+                    AlterTableAction::DetachPartition {
+                        partition: table_name("measurement_y2023"),
+                        mode: Some(DetachPartitionMode::Concurrently),
+                    },
+                    AlterTableAction::SetTriggerState {
+                        state: TriggerState::Disabled,
+                        trigger: TriggerSelection::All,
+                    },
+                    AlterTableAction::SetTriggerState {
+                        state: TriggerState::EnabledReplica,
+                        trigger: TriggerSelection::Name(identifier("audit")),
+                    },
+                    AlterTableAction::RowLevelSecurity(RowLevelSecurityAction::Enable),
+                    AlterTableAction::RowLevelSecurity(RowLevelSecurityAction::NoForce),
+                ],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn alter_table_invalid_without_actions() {
+    test_parse("alter table users;", vec![]);
+}
+
+#[test]
+fn alter_table_invalid_add_generated_stored_column_expression() {
+    test_parse(
+        "alter table users alter id add generated always as (1) stored;",
+        vec![],
+    );
+}
+
+#[test]
+fn drop_tables() {
+    test_parse(
+        "DROP TABLE IF EXISTS users, app.orders CASCADE;",
+        vec![Command::DataDefinition(DataDefinitionCommand::Drop(
+            DropCommand {
+                object_type: DropObjectType::Table,
+                concurrently: false,
+                if_exists: true,
+                objects: vec![
+                    DropObject {
+                        name: table_name("users"),
+                        parameters: None,
+                        table_name: None,
+                    },
+                    DropObject {
+                        name: QualifiedName {
+                            schema_name: Some(identifier("app")),
+                            name: identifier("orders"),
+                        },
+                        parameters: None,
+                        table_name: None,
+                    },
+                ],
+                behavior: Some(DropBehavior::Cascade),
+                force: false,
+            },
+        ))],
+    );
+}
+
+#[test]
+fn drop_index_concurrently_and_materialized_view() {
+    test_parse(
+        "drop index concurrently users_email_idx; drop materialized view totals restrict;",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::Drop(DropCommand {
+                object_type: DropObjectType::Index,
+                concurrently: true,
+                if_exists: false,
+                objects: vec![DropObject {
+                    name: table_name("users_email_idx"),
+                    parameters: None,
+                    table_name: None,
+                }],
+                behavior: None,
+                force: false,
+            })),
+            Command::DataDefinition(DataDefinitionCommand::Drop(DropCommand {
+                object_type: DropObjectType::MaterializedView,
+                concurrently: false,
+                if_exists: false,
+                objects: vec![DropObject {
+                    name: table_name("totals"),
+                    parameters: None,
+                    table_name: None,
+                }],
+                behavior: Some(DropBehavior::Restrict),
+                force: false,
+            })),
+        ],
+    );
+}
+
+#[test]
+fn drop_functions_with_signatures() {
+    test_parse(
+        "drop function if exists add(int, b int), cleanup;",
+        vec![Command::DataDefinition(DataDefinitionCommand::Drop(
+            DropCommand {
+                object_type: DropObjectType::Function,
+                concurrently: false,
+                if_exists: true,
+                objects: vec![
+                    DropObject {
+                        name: table_name("add"),
+                        parameters: Some(vec![
+                            FunctionParameter {
+                                mode: None,
+                                name: None,
+                                type_name: type_name("int"),
+                                default: None,
+                            },
+                            FunctionParameter {
+                                mode: None,
+                                name: Some(identifier("b")),
+                                type_name: type_name("int"),
+                                default: None,
+                            },
+                        ]),
+                        table_name: None,
+                    },
+                    DropObject {
+                        name: table_name("cleanup"),
+                        parameters: None,
+                        table_name: None,
+                    },
+                ],
+                behavior: None,
+                force: false,
+            },
+        ))],
+    );
+}
+
+#[test]
+fn drop_trigger_on_table() {
+    test_parse(
+        "drop trigger audit_users on users;",
+        vec![Command::DataDefinition(DataDefinitionCommand::Drop(
+            DropCommand {
+                object_type: DropObjectType::Trigger,
+                concurrently: false,
+                if_exists: false,
+                objects: vec![DropObject {
+                    name: table_name("audit_users"),
+                    parameters: None,
+                    table_name: Some(table_name("users")),
+                }],
+                behavior: None,
+                force: false,
+            },
+        ))],
+    );
+}
+
+#[test]
+fn drop_roles_and_extensions() {
+    let drop_command = extract!(
+        parse_single_command("drop extension if exists pgcrypto, hstore;"),
+        Command::DataDefinition(DataDefinitionCommand::Drop(drop_command)) => drop_command
+    );
+    assert_eq!(drop_command.object_type, DropObjectType::Extension);
+    assert_eq!(drop_command.objects.len(), 2);
+    let drop_command = extract!(
+        parse_single_command("drop role reader;"),
+        Command::DataDefinition(DataDefinitionCommand::Drop(drop_command)) => drop_command
+    );
+    assert_eq!(drop_command.object_type, DropObjectType::Role);
+}
+
+#[test]
+fn drop_invalid_concurrently_table() {
+    test_parse("drop table concurrently users;", vec![]);
+}
+
+#[test]
+fn drop_invalid_qualified_schema() {
+    test_parse("drop schema app.reports;", vec![]);
+}
+
+#[test]
+fn drop_invalid_trigger_without_table() {
+    test_parse("drop trigger audit_users;", vec![]);
+}
+
+#[test]
+fn create_partial_expression_index() {
+    test_parse(
+        "CREATE INDEX users_lower_email ON users (lower(email) text_pattern_ops, created_at DESC NULLS LAST) WHERE deleted_at IS NULL;",
+        vec![Command::DataDefinition(DataDefinitionCommand::CreateIndex(
+            CreateIndexCommand {
+                unique: false,
+                concurrently: false,
+                if_not_exists: false,
+                name: Some(identifier("users_lower_email")),
+                only: false,
+                table_name: table_name("users"),
+                method: None,
+                elements: vec![
+                    IndexElement {
+                        expression: Expression::FunctionCall(FunctionCall {
+                            function_name: table_name("lower"),
+                            arguments: vec![column("email")],
+                        }),
+                        collation: None,
+                        operator_class: Some(table_name("text_pattern_ops")),
+                        direction: None,
+                        nulls: None,
+                    },
+                    IndexElement {
+                        expression: column("created_at"),
+                        collation: None,
+                        operator_class: None,
+                        direction: Some(SortDirection::Descending),
+                        nulls: Some(NullsOrder::Last),
+                    },
+                ],
+                include: vec![],
+                nulls_not_distinct: false,
+                storage_parameters: vec![],
+                tablespace: None,
+                where_clause: Some(Expression::BinaryOperation(BinaryOperation {
+                    left: Box::new(column("deleted_at")),
+                    operator: String::from("is"),
+                    right: Box::new(Expression::Literal(Literal::Null)),
+                })),
+            },
+        ))],
+    );
+}
+
+#[test]
+fn create_unique_index_concurrently_with_options() {
+    test_parse(
+        "create unique index concurrently if not exists on only app.orders using btree (customer_id, (total * 2)) include (status) nulls not distinct with (fillfactor = 70) tablespace fast;",
+        vec![Command::DataDefinition(DataDefinitionCommand::CreateIndex(
+            CreateIndexCommand {
+                unique: true,
+                concurrently: true,
+                if_not_exists: true,
+                name: None,
+                only: true,
+                table_name: QualifiedName {
+                    schema_name: Some(identifier("app")),
+                    name: identifier("orders"),
+                },
+                method: Some(identifier("btree")),
+                elements: vec![
+                    IndexElement {
+                        expression: column("customer_id"),
+                        collation: None,
+                        operator_class: None,
+                        direction: None,
+                        nulls: None,
+                    },
+                    IndexElement {
+                        expression: Expression::BinaryOperation(BinaryOperation {
+                            left: Box::new(column("total")),
+                            operator: String::from("*"),
+                            right: Box::new(number("2")),
+                        }),
+                        collation: None,
+                        operator_class: None,
+                        direction: None,
+                        nulls: None,
+                    },
+                ],
+                include: vec![identifier("status")],
+                nulls_not_distinct: true,
+                storage_parameters: vec![StorageParameter {
+                    namespace: None,
+                    name: identifier("fillfactor"),
+                    value: Some(number("70")),
+                }],
+                tablespace: Some(identifier("fast")),
+                where_clause: None,
+            },
+        ))],
+    );
+}
+
+#[test]
+fn create_index_invalid_without_columns() {
+    test_parse("create index users_email on users;", vec![]);
+}
+
+#[test]
+fn create_index_invalid_empty_predicate() {
+    test_parse("create index on users (email) where;", vec![]);
+}
+
+#[test]
+fn create_or_replace_view_with_check_option() {
+    test_parse(
+        "CREATE OR REPLACE TEMP VIEW active_users (id, name) WITH (security_barrier = true) AS SELECT * FROM users WITH LOCAL CHECK OPTION;",
+        vec![Command::DataDefinition(DataDefinitionCommand::CreateView(
+            CreateViewCommand {
+                or_replace: true,
+                temporary: true,
+                recursive: false,
+                name: table_name("active_users"),
+                columns: vec![identifier("id"), identifier("name")],
+                options: vec![StorageParameter {
+                    namespace: None,
+                    name: identifier("security_barrier"),
+                    value: Some(Expression::Literal(Literal::Boolean(true))),
+                }],
+                query: select_all_from("users"),
+                check_option: Some(ViewCheckOption::Local),
+            },
+        ))],
+    );
+}
+
+#[test]
+fn create_view_default_check_option() {
+    let command = parse_single_command(
+        "create recursive view tree (id) as select * from nodes with check option;",
+    );
+    let create_view_command = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateView(create_view_command)) =>
+            create_view_command
+    );
+    assert!(create_view_command.recursive);
+    assert_eq!(
+        create_view_command.check_option,
+        Some(ViewCheckOption::Cascaded)
+    );
+}
+
+#[test]
+fn create_materialized_view_with_no_data() {
+    test_parse(
+        "create materialized view if not exists app.order_totals using heap with (fillfactor = 50) tablespace fast as select * from orders with no data;",
+        vec![Command::DataDefinition(
+            DataDefinitionCommand::CreateMaterializedView(CreateMaterializedViewCommand {
+                if_not_exists: true,
+                name: QualifiedName {
+                    schema_name: Some(identifier("app")),
+                    name: identifier("order_totals"),
+                },
+                columns: vec![],
+                method: Some(identifier("heap")),
+                storage_parameters: vec![StorageParameter {
+                    namespace: None,
+                    name: identifier("fillfactor"),
+                    value: Some(number("50")),
+                }],
+                tablespace: Some(identifier("fast")),
+                query: select_all_from("orders"),
+                with_data: false,
+            }),
+        )],
+    );
+}
+
+#[test]
+fn refresh_materialized_view() {
+    test_parse(
+        "REFRESH MATERIALIZED VIEW CONCURRENTLY order_totals WITH DATA; refresh materialized view order_totals with no data;",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::RefreshMaterializedView(
+                RefreshMaterializedViewCommand {
+                    concurrently: true,
+                    name: table_name("order_totals"),
+                    with_data: true,
+                },
+            )),
+            Command::DataDefinition(DataDefinitionCommand::RefreshMaterializedView(
+                RefreshMaterializedViewCommand {
+                    concurrently: false,
+                    name: table_name("order_totals"),
+                    with_data: false,
+                },
+            )),
+        ],
+    );
+}
+
+#[test]
+fn create_view_invalid_without_query() {
+    test_parse("create view active_users;", vec![]);
+}
+
+#[test]
+fn refresh_invalid_plain_view() {
+    test_parse("refresh view active_users;", vec![]);
+}
+
+#[test]
+fn create_function_with_options_and_dollar_quoted_body() {
+    test_parse(
+        "CREATE OR REPLACE FUNCTION app.add(a integer, b integer DEFAULT 1) RETURNS integer LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE SET search_path = pg_catalog, public AS $$ select a + b $$;",
+        vec![Command::DataDefinition(DataDefinitionCommand::CreateFunction(
+            CreateFunctionCommand {
+                or_replace: true,
+                kind: RoutineKind::Function,
+                name: QualifiedName {
+                    schema_name: Some(identifier("app")),
+                    name: identifier("add"),
+                },
+                parameters: vec![
+                    FunctionParameter {
+                        mode: None,
+                        name: Some(identifier("a")),
+                        type_name: type_name("integer"),
+                        default: None,
+                    },
+                    FunctionParameter {
+                        mode: None,
+                        name: Some(identifier("b")),
+                        type_name: type_name("integer"),
+                        default: Some(number("1")),
+                    },
+                ],
+                returns: Some(FunctionReturnType::Type(type_name("integer"))),
+                options: vec![
+                    FunctionOption::Language(identifier("sql")),
+                    FunctionOption::Volatility(FunctionVolatility::Immutable),
+                    FunctionOption::Strict(true),
+                    FunctionOption::Parallel(FunctionParallelSafety::Safe),
+                    FunctionOption::Set(ConfigurationSetting {
+                        name: table_name("search_path"),
+                        value: ConfigurationValue::Values(vec![
+                            column("pg_catalog"),
+                            column("public"),
+                        ]),
+                    }),
+                ],
+                body: Some(FunctionBody::Definition(String::from(" select a + b "))),
+            },
+        ))],
+    );
+}
+
+#[test]
+fn create_function_returns_table_and_setof() {
+    let command = parse_single_command(
+        "create function active_users(out id int, inout name text) returns setof record language plpgsql security definer cost 100 rows 10 as 'begin end';",
+    );
+    let command = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateFunction(command)) => command
+    );
+    assert_eq!(command.parameters[0].mode, Some(FunctionParameterMode::Out));
+    assert_eq!(
+        command.returns,
+        Some(FunctionReturnType::SetOf(type_name("record")))
+    );
+    assert_eq!(
+        command.options,
+        vec![
+            FunctionOption::Language(identifier("plpgsql")),
+            FunctionOption::SecurityDefiner(true),
+            FunctionOption::Cost(number("100")),
+            FunctionOption::Rows(number("10")),
+        ]
+    );
+
+    let command = parse_single_command(
+        "create function user_names() returns table (id int, name text) as 'select id, name from users' language sql;",
+    );
+    let command = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateFunction(command)) => command
+    );
+    assert_eq!(
+        command.returns,
+        Some(FunctionReturnType::Table(vec![
+            FunctionColumnDefinition {
+                name: identifier("id"),
+                type_name: type_name("int"),
+            },
+            FunctionColumnDefinition {
+                name: identifier("name"),
+                type_name: type_name("text"),
+            },
+        ]))
+    );
+}
+
+#[test]
+fn create_function_sql_body_is_parsed_recursively() {
+    let command = parse_single_command(
+        "create function all_user_names() returns setof text language sql stable as $body$ select name from users $body$;",
+    );
+    let command = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateFunction(command)) => command
+    );
+    let body = command.parse_sql_body().unwrap();
+    assert_eq!(body.commands.len(), 1);
+    assert!(matches!(
+        body.commands[0],
+        Command::DataManipulation(DataManipulationCommand::Select(_))
+    ));
+
+    let command = parse_single_command(
+        "create function f() returns int language sql as $$ select 1 -- trailing\n$$;",
+    );
+    let command = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateFunction(command)) => command
+    );
+    assert_eq!(
+        command.parse_sql_body().unwrap(),
+        parse_postgresql("select 1;")
+    );
+
+    let command = parse_single_command(
+        "create function all_user_names() returns setof text language plpgsql as $$ begin end $$;",
+    );
+    let command = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateFunction(command)) => command
+    );
+    assert_eq!(command.parse_sql_body(), None);
+}
+
+#[test]
+fn create_function_sql_standard_bodies() {
+    let command = parse_single_command(
+        "create function increment(a int) returns int language sql immutable return a + 1;",
+    );
+    let command = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateFunction(command)) => command
+    );
+    assert_eq!(
+        command.body,
+        Some(FunctionBody::Return(Expression::BinaryOperation(
+            BinaryOperation {
+                left: Box::new(column("a")),
+                operator: String::from("+"),
+                right: Box::new(number("1")),
+            }
+        )))
+    );
+
+    let command = parse_single_command(
+        "create procedure archive() language sql begin atomic delete from users where deleted; delete from orders where deleted; end;",
+    );
+    let command = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateFunction(command)) => command
+    );
+    assert_eq!(command.kind, RoutineKind::Procedure);
+    let commands = extract!(command.body, Some(FunctionBody::Atomic(commands)) => commands);
+    assert_eq!(commands.len(), 2);
+}
+
+#[test]
+fn create_function_object_file_body() {
+    let command = parse_single_command(
+        "create function add_one(integer) returns integer as 'funcs', 'add_one' language c strict;",
+    );
+    let command = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateFunction(command)) => command
+    );
+    assert_eq!(
+        command.body,
+        Some(FunctionBody::ObjectFile {
+            object_file: String::from("funcs"),
+            link_symbol: String::from("add_one"),
+        })
+    );
+}
+
+#[test]
+fn create_function_invalid_missing_language() {
+    test_parse(
+        "create function f() returns int language as 'select 1';",
+        vec![],
+    );
+}
+
+#[test]
+fn create_function_invalid_unterminated_atomic_body() {
+    test_parse(
+        "create function f() returns int begin atomic select 1 from t;",
+        vec![],
+    );
+}
+
+#[test]
+fn create_procedure_invalid_atomic_statement_skips_whole_body() {
+    test_parse(
+        "CREATE PROCEDURE p() LANGUAGE sql BEGIN ATOMIC
+            UPDATE u SET a = CASE WHEN a > 0 THEN 1 END;
+            DELETE FROM t WHERE;
+            UPDATE u SET a = 1;
+        END;
+        COMMIT;",
+        vec![Command::TransactionControl(
+            TransactionControlCommand::Commit { and_chain: false },
+        )],
+    );
+}
+
+#[test]
+fn plpgsql_function_body() {
+    let command = parse_single_command(
+        "create function total_price(order_id integer) returns numeric language plpgsql as $$
+        declare
+            total numeric := 0;
+            line order_lines%rowtype;
+        begin
+            for line in select * from order_lines loop
+                total := total + line.price;
+            end loop;
+            if total > 100 then
+                raise notice 'large order %', order_id;
+            end if;
+            return total;
+        exception
+            when division_by_zero or sqlstate '22012' then
+                return null;
+        end;
+        $$;",
+    );
+    let command = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateFunction(command)) => command
+    );
+    let block = command.parse_plpgsql_body().unwrap();
+    assert_eq!(
+        block.declarations,
+        vec![
+            Declaration::Variable(VariableDeclaration {
+                name: identifier("total"),
+                constant: false,
+                data_type: DataType::Type(type_name("numeric")),
+                collation: None,
+                not_null: false,
+                default: Some(number("0")),
+            }),
+            Declaration::Variable(VariableDeclaration {
+                name: identifier("line"),
+                constant: false,
+                data_type: DataType::RowTypeOf(table_name("order_lines")),
+                collation: None,
+                not_null: false,
+                default: None,
+            }),
+        ]
+    );
+    assert_eq!(block.statements.len(), 3);
+    let (targets, query, statements) = extract!(
+        &block.statements[0],
+        Statement::Loop(LoopStatement {
+            label: None,
+            kind: LoopKind::Query { targets, query },
+            statements,
+        }) => (targets, query, statements)
+    );
+    assert_eq!(targets, &vec![identifier("line")]);
+    assert_eq!(query.text, "select * from order_lines");
+    assert!(query.command.is_some());
+    assert_eq!(
+        statements,
+        &vec![Statement::Assignment {
+            target: column("total"),
+            value: Expression::BinaryOperation(BinaryOperation {
+                left: Box::new(column("total")),
+                operator: String::from("+"),
+                right: Box::new(qualified_column("line", "price")),
+            }),
+        }]
+    );
+    assert_eq!(
+        block.statements[1],
+        Statement::If(IfStatement {
+            branches: vec![ConditionalBranch {
+                condition: Expression::BinaryOperation(BinaryOperation {
+                    left: Box::new(column("total")),
+                    operator: String::from(">"),
+                    right: Box::new(number("100")),
+                }),
+                statements: vec![Statement::Raise(RaiseStatement {
+                    level: Some(RaiseLevel::Notice),
+                    message: Some(String::from("large order %")),
+                    parameters: vec![column("order_id")],
+                    condition: None,
+                    options: vec![],
+                })],
+            }],
+            else_statements: None,
+        })
+    );
+    assert_eq!(
+        block.statements[2],
+        Statement::Return(Some(column("total")))
+    );
+    assert_eq!(
+        block.exception_handlers,
+        vec![ExceptionHandler {
+            conditions: vec![
+                ExceptionCondition::Name(identifier("division_by_zero")),
+                ExceptionCondition::SqlState(String::from("22012")),
+            ],
+            statements: vec![Statement::Return(Some(Expression::Literal(Literal::Null)))],
+        }]
+    );
+}
+
+#[test]
+fn plpgsql_select_into() {
+    let block = parse_plpgsql(
+        "begin select name into strict user_name from users where id = user_id; perform id from users; end",
+    )
+    .unwrap();
+    let (text, into) = extract!(
+        &block.statements[..],
+        [Statement::Sql(EmbeddedSql {
+            text,
+            command: Some(_),
+            into: Some(into),
+        }), Statement::Perform(EmbeddedSql {
+            command: Some(_), ..
+        })] => (text, into)
+    );
+    assert_eq!(
+        text,
+        "select name into strict user_name from users where id = user_id"
+    );
+    assert_eq!(
+        into,
+        &IntoClause {
+            strict: true,
+            targets: vec![column("user_name")],
+        }
+    );
+}
+
+#[test]
+fn plpgsql_invalid_unterminated_if() {
+    assert_eq!(parse_plpgsql("begin if done then return; end;"), None);
+}
+
+#[test]
+fn do_block() {
+    test_parse(
+        "DO LANGUAGE plpgsql $$ BEGIN NULL; END $$;",
+        vec![Command::Utility(UtilityCommand::Do(DoCommand {
+            language: Some(identifier("plpgsql")),
+            code: String::from(" BEGIN NULL; END "),
+        }))],
+    );
+
+    let command =
+        parse_single_command("do $$ <<main>> begin return; end main $$ language plpgsql;");
+    let do_command =
+        extract!(command, Command::Utility(UtilityCommand::Do(do_command)) => do_command);
+    assert_eq!(
+        do_command.parse_plpgsql_body(),
+        Some(Block {
+            label: Some(identifier("main")),
+            declarations: vec![],
+            statements: vec![Statement::Return(None)],
+            exception_handlers: vec![],
+        })
+    );
+}
+
+#[test]
+fn create_trigger() {
+    test_parse(
+        "CREATE OR REPLACE TRIGGER audit_users AFTER INSERT OR UPDATE OF name, email OR DELETE ON public.users
+            REFERENCING NEW TABLE AS inserted OLD TABLE deleted
+            FOR EACH ROW WHEN (new.name <> old.name) EXECUTE FUNCTION audit.log_change('users', 1);",
+        vec![Command::DataDefinition(DataDefinitionCommand::CreateTrigger(
+            CreateTriggerCommand {
+                or_replace: true,
+                constraint: false,
+                name: identifier("audit_users"),
+                timing: TriggerTiming::After,
+                events: vec![
+                    TriggerEvent::Insert,
+                    TriggerEvent::Update(vec![identifier("name"), identifier("email")]),
+                    TriggerEvent::Delete,
+                ],
+                table_name: QualifiedName {
+                    schema_name: Some(identifier("public")),
+                    name: identifier("users"),
+                },
+                referenced_table: None,
+                deferrable: None,
+                initially_deferred: None,
+                transition_relations: vec![
+                    TriggerTransitionRelation {
+                        kind: TransitionRelationKind::New,
+                        name: identifier("inserted"),
+                    },
+                    TriggerTransitionRelation {
+                        kind: TransitionRelationKind::Old,
+                        name: identifier("deleted"),
+                    },
+                ],
+                level: TriggerLevel::Row,
+                condition: Some(Expression::BinaryOperation(BinaryOperation {
+                    left: Box::new(qualified_column("new", "name")),
+                    operator: String::from("<>"),
+                    right: Box::new(qualified_column("old", "name")),
+                })),
+                function_name: QualifiedName {
+                    schema_name: Some(identifier("audit")),
+                    name: identifier("log_change"),
+                },
+                arguments: vec![
+                    Expression::Literal(Literal::String(String::from("users"))),
+                    number("1"),
+                ],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn create_trigger_when_row_changed() {
+    test_parse(
+        "CREATE TRIGGER touch BEFORE UPDATE ON t FOR EACH ROW WHEN (OLD.* IS DISTINCT FROM NEW.*) EXECUTE FUNCTION touch();",
+        vec![Command::DataDefinition(DataDefinitionCommand::CreateTrigger(
+            CreateTriggerCommand {
+                or_replace: false,
+                constraint: false,
+                name: identifier("touch"),
+                timing: TriggerTiming::Before,
+                events: vec![TriggerEvent::Update(vec![])],
+                table_name: table_name("t"),
+                referenced_table: None,
+                deferrable: None,
+                initially_deferred: None,
+                transition_relations: vec![],
+                level: TriggerLevel::Row,
+                condition: Some(Expression::BinaryOperation(BinaryOperation {
+                    left: Box::new(Expression::AllColumns(AllColumnsSelectedExpression {
+                        schema_name: None,
+                        table_name: Some(identifier("OLD")),
+                    })),
+                    operator: String::from("is distinct from"),
+                    right: Box::new(Expression::AllColumns(AllColumnsSelectedExpression {
+                        schema_name: None,
+                        table_name: Some(identifier("NEW")),
+                    })),
+                })),
+                function_name: table_name("touch"),
+                arguments: vec![],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn create_constraint_trigger() {
+    test_parse(
+        "create constraint trigger check_balance after update on accounts from ledger deferrable initially deferred for each row execute procedure check_balance();",
+        vec![Command::DataDefinition(DataDefinitionCommand::CreateTrigger(
+            CreateTriggerCommand {
+                or_replace: false,
+                constraint: true,
+                name: identifier("check_balance"),
+                timing: TriggerTiming::After,
+                events: vec![TriggerEvent::Update(vec![])],
+                table_name: table_name("accounts"),
+                referenced_table: Some(table_name("ledger")),
+                deferrable: Some(true),
+                initially_deferred: Some(true),
+                transition_relations: vec![],
+                level: TriggerLevel::Row,
+                condition: None,
+                function_name: table_name("check_balance"),
+                arguments: vec![],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn create_trigger_instead_of_with_default_level() {
+    let command = parse_single_command(
+        "create trigger view_insert instead of insert on user_view execute function insert_user();",
+    );
+    let trigger = extract!(
+        command,
+        Command::DataDefinition(DataDefinitionCommand::CreateTrigger(trigger)) => trigger
+    );
+    assert_eq!(trigger.timing, TriggerTiming::InsteadOf);
+    assert_eq!(trigger.level, TriggerLevel::Statement);
+}
+
+#[test]
+fn create_event_trigger() {
+    test_parse(
+        "CREATE EVENT TRIGGER log_ddl ON ddl_command_end WHEN tag IN ('CREATE TABLE', 'DROP TABLE') EXECUTE FUNCTION log_ddl();",
+        vec![Command::DataDefinition(
+            DataDefinitionCommand::CreateEventTrigger(CreateEventTriggerCommand {
+                name: identifier("log_ddl"),
+                event: identifier("ddl_command_end"),
+                filters: vec![EventTriggerFilter {
+                    variable: identifier("tag"),
+                    values: vec![String::from("CREATE TABLE"), String::from("DROP TABLE")],
+                }],
+                function_name: table_name("log_ddl"),
+            }),
+        )],
+    );
+}
+
+#[test]
+fn create_trigger_invalid_missing_timing() {
+    test_parse(
+        "create trigger t insert on users execute function f();",
+        vec![],
+    );
+}
+
+#[test]
+fn create_enum_and_composite_types() {
+    test_parse(
+        "CREATE TYPE mood AS ENUM ('sad', 'ok', 'happy'); create type app.address as (street text collate \"C\", zip varchar(10));",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::CreateType(CreateTypeCommand {
+                name: table_name("mood"),
+                definition: Some(TypeDefinition::Enum(vec![
+                    String::from("sad"),
+                    String::from("ok"),
+                    String::from("happy"),
+                ])),
+            })),
+            Command::DataDefinition(DataDefinitionCommand::CreateType(CreateTypeCommand {
+                name: QualifiedName {
+                    schema_name: Some(identifier("app")),
+                    name: identifier("address"),
+                },
+                definition: Some(TypeDefinition::Composite(vec![
+                    CompositeTypeAttribute {
+                        name: identifier("street"),
+                        type_name: type_name("text"),
+                        collation: Some(QualifiedName {
+                            schema_name: None,
+                            name: Identifier {
+                                quoted: true,
+                                value: String::from("C"),
+                            },
+                        }),
+                    },
+                    CompositeTypeAttribute {
+                        name: identifier("zip"),
+                        type_name: TypeName {
+                            name: table_name("varchar"),
+                            modifiers: vec![number("10")],
+                            array_dimensions: 0,
+                        },
+                        collation: None,
+                    },
+                ])),
+            })),
+        ],
+    );
+}
+
+#[test]
+fn create_range_base_and_shell_types() {
+    test_parse(
+        "create type floatrange as range (subtype = float8, subtype_diff = float8mi); create type box3d; create type box3d (input = box3d_in, output = box3d_out, internallength = 48);",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::CreateType(CreateTypeCommand {
+                name: table_name("floatrange"),
+                definition: Some(TypeDefinition::Range(vec![
+                    TypeOption {
+                        name: identifier("subtype"),
+                        value: Some(TypeOptionValue::Type(type_name("float8"))),
+                    },
+                    TypeOption {
+                        name: identifier("subtype_diff"),
+                        value: Some(TypeOptionValue::Expression(column("float8mi"))),
+                    },
+                ])),
+            })),
+            Command::DataDefinition(DataDefinitionCommand::CreateType(CreateTypeCommand {
+                name: table_name("box3d"),
+                definition: None,
+            })),
+            Command::DataDefinition(DataDefinitionCommand::CreateType(CreateTypeCommand {
+                name: table_name("box3d"),
+                definition: Some(TypeDefinition::Base(vec![
+                    TypeOption {
+                        name: identifier("input"),
+                        value: Some(TypeOptionValue::Expression(column("box3d_in"))),
+                    },
+                    TypeOption {
+                        name: identifier("output"),
+                        value: Some(TypeOptionValue::Expression(column("box3d_out"))),
+                    },
+                    TypeOption {
+                        name: identifier("internallength"),
+                        value: Some(TypeOptionValue::Expression(number("48"))),
+                    },
+                ])),
+            })),
+        ],
+    );
+}
+
+#[test]
+fn create_types_with_type_valued_options() {
+    test_parse(
+        "create type r as range (subtype = double precision);
+        create type tsr as range (subtype = timestamp with time zone, multirange_type_name = app.tsmultirange);
+        create type vr as range (subtype = varchar(10));
+        create type ir as range (subtype = pg_catalog.int4, subtype_opclass = int4_ops);
+        create type int4vec (input = int4vec_in, output = int4vec_out, element = int4, passedbyvalue);",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::CreateType(CreateTypeCommand {
+                name: table_name("r"),
+                definition: Some(TypeDefinition::Range(vec![TypeOption {
+                    name: identifier("subtype"),
+                    value: Some(TypeOptionValue::Type(type_name("double precision"))),
+                }])),
+            })),
+            Command::DataDefinition(DataDefinitionCommand::CreateType(CreateTypeCommand {
+                name: table_name("tsr"),
+                definition: Some(TypeDefinition::Range(vec![
+                    TypeOption {
+                        name: identifier("subtype"),
+                        value: Some(TypeOptionValue::Type(type_name(
+                            "timestamp with time zone",
+                        ))),
+                    },
+                    TypeOption {
+                        name: identifier("multirange_type_name"),
+                        value: Some(TypeOptionValue::Type(TypeName {
+                            name: QualifiedName {
+                                schema_name: Some(identifier("app")),
+                                name: identifier("tsmultirange"),
+                            },
+                            modifiers: vec![],
+                            array_dimensions: 0,
+                        })),
+                    },
+                ])),
+            })),
+            Command::DataDefinition(DataDefinitionCommand::CreateType(CreateTypeCommand {
+                name: table_name("vr"),
+                definition: Some(TypeDefinition::Range(vec![TypeOption {
+                    name: identifier("subtype"),
+                    value: Some(TypeOptionValue::Type(TypeName {
+                        name: table_name("varchar"),
+                        modifiers: vec![number("10")],
+                        array_dimensions: 0,
+                    })),
+                }])),
+            })),
+            Command::DataDefinition(DataDefinitionCommand::CreateType(CreateTypeCommand {
+                name: table_name("ir"),
+                definition: Some(TypeDefinition::Range(vec![
+                    TypeOption {
+                        name: identifier("subtype"),
+                        value: Some(TypeOptionValue::Type(TypeName {
+                            name: QualifiedName {
+                                schema_name: Some(identifier("pg_catalog")),
+                                name: identifier("int4"),
+                            },
+                            modifiers: vec![],
+                            array_dimensions: 0,
+                        })),
+                    },
+                    TypeOption {
+                        name: identifier("subtype_opclass"),
+                        value: Some(TypeOptionValue::Expression(column("int4_ops"))),
+                    },
+                ])),
+            })),
+            Command::DataDefinition(DataDefinitionCommand::CreateType(CreateTypeCommand {
+                name: table_name("int4vec"),
+                definition: Some(TypeDefinition::Base(vec![
+                    TypeOption {
+                        name: identifier("input"),
+                        value: Some(TypeOptionValue::Expression(column("int4vec_in"))),
+                    },
+                    TypeOption {
+                        name: identifier("output"),
+                        value: Some(TypeOptionValue::Expression(column("int4vec_out"))),
+                    },
+                    TypeOption {
+                        name: identifier("element"),
+                        value: Some(TypeOptionValue::Type(type_name("int4"))),
+                    },
+                    TypeOption {
+                        name: identifier("passedbyvalue"),
+                        value: None,
+                    },
+                ])),
+            })),
+        ],
+    );
+}
+
+#[test]
+fn create_domain() {
+    test_parse(
+        "CREATE DOMAIN us_postal_code AS text DEFAULT '00000' CONSTRAINT valid_code CHECK (value <> '') NOT NULL;",
+        vec![Command::DataDefinition(DataDefinitionCommand::CreateDomain(
+            CreateDomainCommand {
+                name: table_name("us_postal_code"),
+                type_name: type_name("text"),
+                collation: None,
+                default: Some(Expression::Literal(Literal::String(String::from("00000")))),
+                constraints: vec![
+                    DomainConstraint {
+                        name: Some(identifier("valid_code")),
+                        kind: DomainConstraintKind::Check(Expression::BinaryOperation(
+                            BinaryOperation {
+                                left: Box::new(column("value")),
+                                operator: String::from("<>"),
+                                right: Box::new(Expression::Literal(Literal::String(
+                                    String::new(),
+                                ))),
+                            },
+                        )),
+                    },
+                    DomainConstraint {
+                        name: None,
+                        kind: DomainConstraintKind::NotNull,
+                    },
+                ],
+            },
+        ))],
+    );
+}
+
+#[test]
+fn alter_type_enum_values() {
+    test_parse(
+        "ALTER TYPE mood ADD VALUE IF NOT EXISTS 'meh' BEFORE 'ok'; alter type mood add value 'ecstatic'; alter type mood rename value 'sad' to 'blue';",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::AlterType(AlterTypeCommand {
+                name: table_name("mood"),
+                action: AlterTypeAction::AddValue {
+                    if_not_exists: true,
+                    value: String::from("meh"),
+                    position: Some(EnumValuePosition::Before(String::from("ok"))),
+                },
+            })),
+            Command::DataDefinition(DataDefinitionCommand::AlterType(AlterTypeCommand {
+                name: table_name("mood"),
+                action: AlterTypeAction::AddValue {
+                    if_not_exists: false,
+                    value: String::from("ecstatic"),
+                    position: None,
+                },
+            })),
+            Command::DataDefinition(DataDefinitionCommand::AlterType(AlterTypeCommand {
+                name: table_name("mood"),
+                action: AlterTypeAction::RenameValue {
+                    value: String::from("sad"),
+                    new_value: String::from("blue"),
+                },
+            })),
+        ],
+    );
+}
+
+#[test]
+fn create_domain_invalid_no_inherit_check() {
+    test_parse(
+        "create domain positive as integer check (value > 0) no inherit;",
+        vec![],
+    );
+}
+
+#[test]
+fn create_sequence() {
+    test_parse(
+        "CREATE TEMP SEQUENCE IF NOT EXISTS app.order_ids AS bigint INCREMENT BY 10 NO MAXVALUE START WITH 1000 CACHE 20 CYCLE OWNED BY app.orders.id; create sequence ids;",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::CreateSequence(
+                CreateSequenceCommand {
+                    persistence: Some(TablePersistence::Temporary),
+                    if_not_exists: true,
+                    name: QualifiedName {
+                        schema_name: Some(identifier("app")),
+                        name: identifier("order_ids"),
+                    },
+                    options: vec![
+                        SequenceOption::As(type_name("bigint")),
+                        SequenceOption::IncrementBy(number("10")),
+                        SequenceOption::MaxValue(None),
+                        SequenceOption::StartWith(number("1000")),
+                        SequenceOption::Cache(number("20")),
+                        SequenceOption::Cycle(true),
+                        SequenceOption::OwnedBy(Some(ColumnSelectedExpression {
+                            schema_name: Some(identifier("app")),
+                            table_name: Some(identifier("orders")),
+                            column_name: identifier("id"),
+                        })),
+                    ],
+                },
+            )),
+            Command::DataDefinition(DataDefinitionCommand::CreateSequence(
+                CreateSequenceCommand {
+                    persistence: None,
+                    if_not_exists: false,
+                    name: table_name("ids"),
+                    options: vec![],
+                },
+            )),
+        ],
+    );
+}
+
+#[test]
+fn alter_sequence() {
+    test_parse(
+        "alter sequence if exists order_ids restart with 1 owned by none;",
+        vec![Command::DataDefinition(
+            DataDefinitionCommand::AlterSequence(AlterSequenceCommand {
+                if_exists: true,
+                name: table_name("order_ids"),
+                options: vec![
+                    SequenceOption::Restart(Some(number("1"))),
+                    SequenceOption::OwnedBy(None),
+                ],
+            }),
+        )],
+    );
+}
+
+#[test]
+fn alter_sequence_invalid_without_options() {
+    test_parse("alter sequence order_ids;", vec![]);
+}
+
+#[test]
+fn create_schema() {
+    test_parse(
+        "create schema if not exists app authorization admin create sequence ids create sequence app.order_ids; create schema authorization current_user;",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::CreateSchema(
+                CreateSchemaCommand {
+                    if_not_exists: true,
+                    name: Some(identifier("app")),
+                    authorization: Some(RoleSpecification::Name(identifier("admin"))),
+                    elements: vec![
+                        Command::DataDefinition(DataDefinitionCommand::CreateSequence(
+                            CreateSequenceCommand {
+                                persistence: None,
+                                if_not_exists: false,
+                                name: table_name("ids"),
+                                options: vec![],
+                            },
+                        )),
+                        Command::DataDefinition(DataDefinitionCommand::CreateSequence(
+                            CreateSequenceCommand {
+                                persistence: None,
+                                if_not_exists: false,
+                                name: QualifiedName {
+                                    schema_name: Some(identifier("app")),
+                                    name: identifier("order_ids"),
+                                },
+                                options: vec![],
+                            },
+                        )),
+                    ],
+                },
+            )),
+            Command::DataDefinition(DataDefinitionCommand::CreateSchema(
+                CreateSchemaCommand {
+                    if_not_exists: false,
+                    name: None,
+                    authorization: Some(RoleSpecification::CurrentUser),
+                    elements: vec![],
+                },
+            )),
+        ],
+    );
+}
+
+#[test]
+fn create_schema_invalid_function_element() {
+    test_parse(
+        "create schema app create function f() returns int as 'select 1' language sql;",
+        vec![],
+    );
+}
+
+#[test]
+fn create_and_alter_extension() {
+    test_parse(
+        "create extension if not exists pgcrypto with schema ext version '1.3' cascade; alter extension pgcrypto update to '1.4'; alter extension hstore update;",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::CreateExtension(
+                CreateExtensionCommand {
+                    if_not_exists: true,
+                    name: identifier("pgcrypto"),
+                    schema: Some(identifier("ext")),
+                    version: Some(String::from("1.3")),
+                    cascade: true,
+                },
+            )),
+            Command::DataDefinition(DataDefinitionCommand::AlterExtension(
+                AlterExtensionCommand {
+                    name: identifier("pgcrypto"),
+                    action: AlterExtensionAction::Update(Some(String::from("1.4"))),
+                },
+            )),
+            Command::DataDefinition(DataDefinitionCommand::AlterExtension(
+                AlterExtensionCommand {
+                    name: identifier("hstore"),
+                    action: AlterExtensionAction::Update(None),
+                },
+            )),
+        ],
+    );
+}
+
+#[test]
+fn create_alter_and_drop_database() {
+    test_parse(
+        "create database app with owner = admin encoding 'UTF8' connection limit 10 is_template default; alter database app set search_path to public; alter database app reset all; drop database if exists app with (force);",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::CreateDatabase(
+                CreateDatabaseCommand {
+                    name: identifier("app"),
+                    options: vec![
+                        DatabaseOption {
+                            name: String::from("owner"),
+                            value: Some(column("admin")),
+                        },
+                        DatabaseOption {
+                            name: String::from("encoding"),
+                            value: Some(Expression::Literal(Literal::String(String::from(
+                                "UTF8",
+                            )))),
+                        },
+                        DatabaseOption {
+                            name: String::from("connection limit"),
+                            value: Some(number("10")),
+                        },
+                        DatabaseOption {
+                            name: String::from("is_template"),
+                            value: None,
+                        },
+                    ],
+                },
+            )),
+            Command::DataDefinition(DataDefinitionCommand::AlterDatabase(
+                AlterDatabaseCommand {
+                    name: identifier("app"),
+                    action: AlterDatabaseAction::Set(ConfigurationSetting {
+                        name: table_name("search_path"),
+                        value: ConfigurationValue::Values(vec![column("public")]),
+                    }),
+                },
+            )),
+            Command::DataDefinition(DataDefinitionCommand::AlterDatabase(
+                AlterDatabaseCommand {
+                    name: identifier("app"),
+                    action: AlterDatabaseAction::Reset(None),
+                },
+            )),
+            Command::DataDefinition(DataDefinitionCommand::Drop(DropCommand {
+                object_type: DropObjectType::Database,
+                concurrently: false,
+                if_exists: true,
+                objects: vec![DropObject {
+                    name: table_name("app"),
+                    parameters: None,
+                    table_name: None,
+                }],
+                behavior: None,
+                force: true,
+            })),
+        ],
+    );
+}
+
+#[test]
+fn create_and_alter_tablespace() {
+    test_parse(
+        "create tablespace fast owner admin location '/mnt/ssd' with (random_page_cost = 1.1); alter tablespace fast reset (random_page_cost); alter tablespace fast rename to faster;",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::CreateTablespace(
+                CreateTablespaceCommand {
+                    name: identifier("fast"),
+                    owner: Some(RoleSpecification::Name(identifier("admin"))),
+                    location: String::from("/mnt/ssd"),
+                    options: vec![StorageParameter {
+                        namespace: None,
+                        name: identifier("random_page_cost"),
+                        value: Some(number("1.1")),
+                    }],
+                },
+            )),
+            Command::DataDefinition(DataDefinitionCommand::AlterTablespace(
+                AlterTablespaceCommand {
+                    name: identifier("fast"),
+                    action: AlterTablespaceAction::ResetOptions(vec![StorageParameter {
+                        namespace: None,
+                        name: identifier("random_page_cost"),
+                        value: None,
+                    }]),
+                },
+            )),
+            Command::DataDefinition(DataDefinitionCommand::AlterTablespace(
+                AlterTablespaceCommand {
+                    name: identifier("fast"),
+                    action: AlterTablespaceAction::RenameTo(identifier("faster")),
+                },
+            )),
+        ],
+    );
+}
+
+#[test]
+fn grant_privileges() {
+    test_parse(
+        "GRANT SELECT, UPDATE (name, email) ON TABLE users, app.orders TO bob, GROUP staff, PUBLIC WITH GRANT OPTION GRANTED BY admin; grant all privileges on all tables in schema app to reader;",
+        vec![
+            Command::DataControl(DataControlCommand::Grant(GrantCommand {
+                privileges: Privileges::List(vec![
+                    Privilege {
+                        kind: PrivilegeKind::Select,
+                        columns: vec![],
+                    },
+                    Privilege {
+                        kind: PrivilegeKind::Update,
+                        columns: vec![identifier("name"), identifier("email")],
+                    },
+                ]),
+                target: PrivilegeTarget::Objects {
+                    object_type: PrivilegeObjectType::Table,
+                    objects: vec![
+                        PrivilegeObject {
+                            name: table_name("users"),
+                            parameters: None,
+                        },
+                        PrivilegeObject {
+                            name: QualifiedName {
+                                schema_name: Some(identifier("app")),
+                                name: identifier("orders"),
+                            },
+                            parameters: None,
+                        },
+                    ],
+                },
+                grantees: vec![
+                    RoleSpecification::Name(identifier("bob")),
+                    RoleSpecification::Name(identifier("staff")),
+                    RoleSpecification::Public,
+                ],
+                with_grant_option: true,
+                granted_by: Some(RoleSpecification::Name(identifier("admin"))),
+            })),
+            Command::DataControl(DataControlCommand::Grant(GrantCommand {
+                privileges: Privileges::All(vec![]),
+                target: PrivilegeTarget::AllInSchema {
+                    object_type: PrivilegeObjectType::Table,
+                    schemas: vec![identifier("app")],
+                },
+                grantees: vec![RoleSpecification::Name(identifier("reader"))],
+                with_grant_option: false,
+                granted_by: None,
+            })),
+        ],
+    );
+}
+
+#[test]
+fn grant_on_function_and_revoke() {
+    test_parse(
+        "grant execute on function add(int) to bob; revoke grant option for usage on schema app, audit from bob cascade;",
+        vec![
+            Command::DataControl(DataControlCommand::Grant(GrantCommand {
+                privileges: Privileges::List(vec![Privilege {
+                    kind: PrivilegeKind::Execute,
+                    columns: vec![],
+                }]),
+                target: PrivilegeTarget::Objects {
+                    object_type: PrivilegeObjectType::Function,
+                    objects: vec![PrivilegeObject {
+                        name: table_name("add"),
+                        parameters: Some(vec![FunctionParameter {
+                            mode: None,
+                            name: None,
+                            type_name: type_name("int"),
+                            default: None,
+                        }]),
+                    }],
+                },
+                grantees: vec![RoleSpecification::Name(identifier("bob"))],
+                with_grant_option: false,
+                granted_by: None,
+            })),
+            Command::DataControl(DataControlCommand::Revoke(RevokeCommand {
+                grant_option_for: true,
+                privileges: Privileges::List(vec![Privilege {
+                    kind: PrivilegeKind::Usage,
+                    columns: vec![],
+                }]),
+                target: PrivilegeTarget::Objects {
+                    object_type: PrivilegeObjectType::Schema,
+                    objects: vec![
+                        PrivilegeObject {
+                            name: table_name("app"),
+                            parameters: None,
+                        },
+                        PrivilegeObject {
+                            name: table_name("audit"),
+                            parameters: None,
+                        },
+                    ],
+                },
+                grantees: vec![RoleSpecification::Name(identifier("bob"))],
+                granted_by: None,
+                behavior: Some(DropBehavior::Cascade),
+            })),
+        ],
+    );
+}
+
+#[test]
+fn grant_and_revoke_role_membership() {
+    test_parse(
+        "grant admin, ops to alice with admin option, inherit false; revoke admin option for ops from alice granted by current_user;",
+        vec![
+            Command::DataControl(DataControlCommand::GrantRole(GrantRoleCommand {
+                roles: vec![identifier("admin"), identifier("ops")],
+                grantees: vec![RoleSpecification::Name(identifier("alice"))],
+                options: vec![
+                    RoleGrantOption {
+                        kind: RoleGrantOptionKind::Admin,
+                        value: true,
+                    },
+                    RoleGrantOption {
+                        kind: RoleGrantOptionKind::Inherit,
+                        value: false,
+                    },
+                ],
+                granted_by: None,
+            })),
+            Command::DataControl(DataControlCommand::RevokeRole(RevokeRoleCommand {
+                option_for: Some(RoleGrantOptionKind::Admin),
+                roles: vec![identifier("ops")],
+                grantees: vec![RoleSpecification::Name(identifier("alice"))],
+                granted_by: Some(RoleSpecification::CurrentUser),
+                behavior: None,
+            })),
+        ],
+    );
+}
+
+#[test]
+fn create_and_alter_roles() {
+    test_parse(
+        "create role alice with login password 'secret' valid until '2030-01-01' in role staff connection limit 3; create user bob nosuperuser encrypted password null; alter role alice nologin; alter role all in database app set work_mem = '64MB';",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::CreateRole(CreateRoleCommand {
+                kind: RoleKind::Role,
+                name: identifier("alice"),
+                options: vec![
+                    RoleOption::Login(true),
+                    RoleOption::Password(Some(String::from("secret"))),
+                    RoleOption::ValidUntil(String::from("2030-01-01")),
+                    RoleOption::InRole(vec![identifier("staff")]),
+                    RoleOption::ConnectionLimit(number("3")),
+                ],
+            })),
+            Command::DataDefinition(DataDefinitionCommand::CreateRole(CreateRoleCommand {
+                kind: RoleKind::User,
+                name: identifier("bob"),
+                options: vec![RoleOption::Superuser(false), RoleOption::Password(None)],
+            })),
+            Command::DataDefinition(DataDefinitionCommand::AlterRole(AlterRoleCommand {
+                kind: RoleKind::Role,
+                role: Some(RoleSpecification::Name(identifier("alice"))),
+                action: AlterRoleAction::Options(vec![RoleOption::Login(false)]),
+            })),
+            Command::DataDefinition(DataDefinitionCommand::AlterRole(AlterRoleCommand {
+                kind: RoleKind::Role,
+                role: None,
+                action: AlterRoleAction::Set {
+                    database: Some(identifier("app")),
+                    setting: ConfigurationSetting {
+                        name: table_name("work_mem"),
+                        value: ConfigurationValue::Values(vec![Expression::Literal(
+                            Literal::String(String::from("64MB")),
+                        )]),
+                    },
+                },
+            })),
+        ],
+    );
+}
+
+#[test]
+fn alter_role_all_invalid_without_set_or_reset() {
+    test_parse("alter role all nologin;", vec![]);
+}
+
+#[test]
+fn alter_default_privileges() {
+    test_parse(
+        "alter default privileges for role admin in schema app grant select on tables to reader; alter default privileges revoke execute on functions from public;",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::AlterDefaultPrivileges(
+                AlterDefaultPrivilegesCommand {
+                    roles: vec![RoleSpecification::Name(identifier("admin"))],
+                    schemas: vec![identifier("app")],
+                    action: DefaultPrivilegesAction::Grant {
+                        privileges: Privileges::List(vec![Privilege {
+                            kind: PrivilegeKind::Select,
+                            columns: vec![],
+                        }]),
+                        object_type: PrivilegeObjectType::Table,
+                        grantees: vec![RoleSpecification::Name(identifier("reader"))],
+                        with_grant_option: false,
+                    },
+                },
+            )),
+            Command::DataDefinition(DataDefinitionCommand::AlterDefaultPrivileges(
+                AlterDefaultPrivilegesCommand {
+                    roles: vec![],
+                    schemas: vec![],
+                    action: DefaultPrivilegesAction::Revoke {
+                        grant_option_for: false,
+                        privileges: Privileges::List(vec![Privilege {
+                            kind: PrivilegeKind::Execute,
+                            columns: vec![],
+                        }]),
+                        object_type: PrivilegeObjectType::Function,
+                        grantees: vec![RoleSpecification::Public],
+                        behavior: None,
+                    },
+                },
+            )),
+        ],
+    );
+}
+
+#[test]
+fn create_policy() {
+    test_parse(
+        "create policy active_accounts on app.accounts as restrictive for select to reader, public using (active) with check (balance > 0); create policy p on accounts;",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::CreatePolicy(CreatePolicyCommand {
+                name: identifier("active_accounts"),
+                table_name: QualifiedName {
+                    schema_name: Some(identifier("app")),
+                    name: identifier("accounts"),
+                },
+                kind: Some(PolicyKind::Restrictive),
+                command: Some(PolicyCommand::Select),
+                roles: vec![
+                    RoleSpecification::Name(identifier("reader")),
+                    RoleSpecification::Public,
+                ],
+                using: Some(column("active")),
+                with_check: Some(Expression::BinaryOperation(BinaryOperation {
+                    left: Box::new(column("balance")),
+                    operator: String::from(">"),
+                    right: Box::new(number("0")),
+                })),
+            })),
+            Command::DataDefinition(DataDefinitionCommand::CreatePolicy(CreatePolicyCommand {
+                name: identifier("p"),
+                table_name: table_name("accounts"),
+                kind: None,
+                command: None,
+                roles: vec![],
+                using: None,
+                with_check: None,
+            })),
+        ],
+    );
+}
+
+#[test]
+fn alter_and_drop_policy() {
+    test_parse(
+        "alter policy p on accounts rename to q; alter policy q on accounts to reader using (active); drop policy if exists q on accounts;",
+        vec![
+            Command::DataDefinition(DataDefinitionCommand::AlterPolicy(AlterPolicyCommand {
+                name: identifier("p"),
+                table_name: table_name("accounts"),
+                action: AlterPolicyAction::RenameTo(identifier("q")),
+            })),
+            Command::DataDefinition(DataDefinitionCommand::AlterPolicy(AlterPolicyCommand {
+                name: identifier("q"),
+                table_name: table_name("accounts"),
+                action: AlterPolicyAction::Update {
+                    roles: vec![RoleSpecification::Name(identifier("reader"))],
+                    using: Some(column("active")),
+                    with_check: None,
+                },
+            })),
+            Command::DataDefinition(DataDefinitionCommand::Drop(DropCommand {
+                object_type: DropObjectType::Policy,
+                concurrently: false,
+                if_exists: true,
+                objects: vec![DropObject {
+                    name: table_name("q"),
+                    parameters: None,
+                    table_name: Some(table_name("accounts")),
+                }],
+                behavior: None,
+                force: false,
+            })),
+        ],
+    );
+}
+
+#[test]
+fn alter_policy_invalid_without_action() {
+    test_parse("alter policy p on accounts;", vec![]);
+}
+
+#[test]
+fn transaction_blocks() {
+    test_parse(
+        "begin isolation level repeatable read, read only not deferrable; savepoint s1; rollback to savepoint s1; release s1; commit and chain; start transaction; end; abort;",
+        vec![
+            Command::TransactionControl(TransactionControlCommand::Begin(vec![
+                TransactionMode::IsolationLevel(IsolationLevel::RepeatableRead),
+                TransactionMode::ReadOnly(true),
+                TransactionMode::Deferrable(false),
+            ])),
+            Command::TransactionControl(TransactionControlCommand::Savepoint(identifier("s1"))),
+            Command::TransactionControl(TransactionControlCommand::RollbackToSavepoint(
+                identifier("s1"),
+            )),
+            Command::TransactionControl(TransactionControlCommand::ReleaseSavepoint(identifier(
+                "s1",
+            ))),
+            Command::TransactionControl(TransactionControlCommand::Commit { and_chain: true }),
+            Command::TransactionControl(TransactionControlCommand::Begin(vec![])),
+            Command::TransactionControl(TransactionControlCommand::Commit { and_chain: false }),
+            Command::TransactionControl(TransactionControlCommand::Rollback { and_chain: false }),
+        ],
+    );
+}
+
+#[test]
+fn set_transaction_and_constraints() {
+    test_parse(
+        "set transaction isolation level serializable; set session characteristics as transaction read write; set constraints all deferred; set constraints app.fk_owner immediate;",
+        vec![
+            Command::TransactionControl(TransactionControlCommand::SetTransaction {
+                session: false,
+                modes: vec![TransactionMode::IsolationLevel(IsolationLevel::Serializable)],
+            }),
+            Command::TransactionControl(TransactionControlCommand::SetTransaction {
+                session: true,
+                modes: vec![TransactionMode::ReadOnly(false)],
+            }),
+            Command::TransactionControl(TransactionControlCommand::SetConstraints {
+                constraints: None,
+                deferred: true,
+            }),
+            Command::TransactionControl(TransactionControlCommand::SetConstraints {
+                constraints: Some(vec![QualifiedName {
+                    schema_name: Some(identifier("app")),
+                    name: identifier("fk_owner"),
+                }]),
+                deferred: false,
+            }),
+        ],
+    );
+}
+
+#[test]
+fn two_phase_commit() {
+    test_parse(
+        "prepare transaction 'migration_42'; commit prepared 'migration_42'; rollback prepared 'migration_43';",
+        vec![
+            Command::TransactionControl(TransactionControlCommand::PrepareTransaction(
+                String::from("migration_42"),
+            )),
+            Command::TransactionControl(TransactionControlCommand::CommitPrepared(String::from(
+                "migration_42",
+            ))),
+            Command::TransactionControl(TransactionControlCommand::RollbackPrepared(
+                String::from("migration_43"),
+            )),
+        ],
+    );
+}
+
+#[test]
+fn transaction_invalid_incomplete_chain() {
+    test_parse("commit and;", vec![]);
+}
+
+#[test]
+fn set_configuration_parameters() {
+    test_parse(
+        "set search_path to app, public; set local statement_timeout = '5s'; set session work_mem to default;",
+        vec![
+            Command::Utility(UtilityCommand::Set(SetCommand {
+                scope: None,
+                action: SetAction::Configuration(ConfigurationSetting {
+                    name: table_name("search_path"),
+                    value: ConfigurationValue::Values(vec![column("app"), column("public")]),
+                }),
+            })),
+            Command::Utility(UtilityCommand::Set(SetCommand {
+                scope: Some(SetScope::Local),
+                action: SetAction::Configuration(ConfigurationSetting {
+                    name: table_name("statement_timeout"),
+                    value: ConfigurationValue::Values(vec![Expression::Literal(Literal::String(
+                        String::from("5s"),
+                    ))]),
+                }),
+            })),
+            Command::Utility(UtilityCommand::Set(SetCommand {
+                scope: Some(SetScope::Session),
+                action: SetAction::Configuration(ConfigurationSetting {
+                    name: table_name("work_mem"),
+                    value: ConfigurationValue::Default,
+                }),
+            })),
+        ],
+    );
+}
+
+#[test]
+fn set_time_zone_role_and_session_authorization() {
+    test_parse(
+        "set time zone 'UTC'; set time zone local; set role admin; set role 'admin'; set role none; set session authorization default; set session authorization 'bob';",
+        vec![
+            Command::Utility(UtilityCommand::Set(SetCommand {
+                scope: None,
+                action: SetAction::TimeZone(Some(Expression::Literal(Literal::String(
+                    String::from("UTC"),
+                )))),
+            })),
+            Command::Utility(UtilityCommand::Set(SetCommand {
+                scope: None,
+                action: SetAction::TimeZone(None),
+            })),
+            Command::Utility(UtilityCommand::Set(SetCommand {
+                scope: None,
+                action: SetAction::Role(Some(IdentifierOrString::Identifier(identifier(
+                    "admin",
+                )))),
+            })),
+            Command::Utility(UtilityCommand::Set(SetCommand {
+                scope: None,
+                action: SetAction::Role(Some(IdentifierOrString::String(String::from("admin")))),
+            })),
+            Command::Utility(UtilityCommand::Set(SetCommand {
+                scope: None,
+                action: SetAction::Role(None),
+            })),
+            Command::Utility(UtilityCommand::Set(SetCommand {
+                scope: None,
+                action: SetAction::SessionAuthorization(None),
+            })),
+            Command::Utility(UtilityCommand::Set(SetCommand {
+                scope: None,
+                action: SetAction::SessionAuthorization(Some(IdentifierOrString::String(
+                    String::from("bob"),
+                ))),
+            })),
+        ],
+    );
+}
+
+#[test]
+fn set_from_current_invalid() {
+    test_parse("set search_path from current;", vec![]);
+}
+
+#[test]
+fn reset_and_show() {
+    test_parse(
+        "reset search_path; reset all; show time zone; show transaction isolation level; show all;",
+        vec![
+            Command::Utility(UtilityCommand::Reset(ConfigurationParameter::Name(
+                table_name("search_path"),
+            ))),
+            Command::Utility(UtilityCommand::Reset(ConfigurationParameter::All)),
+            Command::Utility(UtilityCommand::Show(ConfigurationParameter::TimeZone)),
+            Command::Utility(UtilityCommand::Show(
+                ConfigurationParameter::TransactionIsolationLevel,
+            )),
+            Command::Utility(UtilityCommand::Show(ConfigurationParameter::All)),
+        ],
+    );
+}
+
+#[test]
+fn explain_legacy_options() {
+    test_parse(
+        "explain analyze verbose delete from t;",
+        vec![Command::Utility(UtilityCommand::Explain(ExplainCommand {
+            options: vec![ExplainOption::Analyze(true), ExplainOption::Verbose(true)],
+            statement: Box::new(Command::DataManipulation(DataManipulationCommand::Delete(
+                DeleteCommand {
+                    target: TargetTable {
+                        only: false,
+                        table_name: table_name("t"),
+                        alias: None,
+                    },
+                    using_items: vec![],
+                    where_clause: None,
+                    returning: vec![],
+                },
+            ))),
+        }))],
+    );
+}
+
+#[test]
+fn explain_parenthesized_options() {
+    let command = parse_single_command(
+        "explain (analyze, buffers off, format json, settings, wal 1, generic_plan true, serialize binary, memory) select a from t;",
+    );
+    let explain_command = extract!(
+        command,
+        Command::Utility(UtilityCommand::Explain(explain_command)) => explain_command
+    );
+    assert_eq!(
+        explain_command.options,
+        vec![
+            ExplainOption::Analyze(true),
+            ExplainOption::Buffers(false),
+            ExplainOption::Format(ExplainFormat::Json),
+            ExplainOption::Settings(true),
+            ExplainOption::Wal(true),
+            ExplainOption::GenericPlan(true),
+            ExplainOption::Serialize(ExplainSerialize::Binary),
+            ExplainOption::Memory(true),
+        ]
+    );
+    assert!(matches!(
+        *explain_command.statement,
+        Command::DataManipulation(DataManipulationCommand::Select(_))
+    ));
+}
+
+#[test]
+fn explain_parameters() {
+    let parsed_tree = parse_postgresql("explain (generic_plan) select $1 from t;");
+    assert_eq!(
+        parsed_tree
+            .parameters()
+            .into_iter()
+            .map(|parameter| parameter.kind.clone())
+            .collect::<Vec<ParameterKind>>(),
+        vec![ParameterKind::Positional(1)]
+    );
+}
+
+#[test]
+fn create_table_parameters() {
+    assert_eq!(
+        parameter_kinds("create table t (a int default :'x' check (a > :lim));"),
+        vec![
+            ParameterKind::NamedAsLiteral(String::from("x")),
+            ParameterKind::Named(String::from("lim")),
+        ]
+    );
+}
+
+#[test]
+fn create_view_parameters() {
+    assert_eq!(
+        parameter_kinds("create view v as select :col from t;"),
+        vec![ParameterKind::Named(String::from("col"))]
+    );
+}
+
+#[test]
+fn alter_table_parameters() {
+    assert_eq!(
+        parameter_kinds("alter table t alter a set default :'d';"),
+        vec![ParameterKind::NamedAsLiteral(String::from("d"))]
+    );
+}
+
+#[test]
+fn create_policy_parameters() {
+    assert_eq!(
+        parameter_kinds(
+            "create policy tenant_isolation on accounts using (tenant_id = :'tenant');"
+        ),
+        vec![ParameterKind::NamedAsLiteral(String::from("tenant"))]
+    );
+}
+
+#[test]
+fn set_parameters() {
+    assert_eq!(
+        parameter_kinds("set search_path to :schema;"),
+        vec![ParameterKind::Named(String::from("schema"))]
+    );
+}
+
+#[test]
+fn copy_query_parameters() {
+    assert_eq!(
+        parameter_kinds("copy (select :a from t) to stdout;"),
+        vec![ParameterKind::Named(String::from("a"))]
+    );
+}
+
+#[test]
+fn explain_invalid_without_statement() {
+    test_parse("explain analyze;", vec![]);
+}
+
+#[test]
+fn explain_create_materialized_view() {
+    let command = parse_single_command(
+        "explain analyze create materialized view totals as select * from orders;",
+    );
+    let explain_command = extract!(
+        command,
+        Command::Utility(UtilityCommand::Explain(explain_command)) => explain_command
+    );
+    assert!(matches!(
+        *explain_command.statement,
+        Command::DataDefinition(DataDefinitionCommand::CreateMaterializedView(_))
+    ));
+}
+
+#[test]
+fn explain_invalid_statements_without_a_plan() {
+    test_parse("explain drop table t;", vec![]);
+    test_parse("explain begin;", vec![]);
+    test_parse("explain grant select on t to r;", vec![]);
+    test_parse("explain set x = 1;", vec![]);
+    test_parse("explain explain select 1;", vec![]);
+    test_parse(
+        "explain drop table t; select * from t;",
+        vec![parse_single_command("select * from t;")],
+    );
+}
+
+#[test]
+fn copy_from_stdin_skips_inline_data() {
+    test_parse(
+        "COPY public.users (id, name) FROM stdin;\n1\tO'Brien\n2\t\\N\n\\.\n\nselect id from users;",
+        vec![
+            Command::Utility(UtilityCommand::Copy(CopyCommand {
+                relation: CopyRelation::Table {
+                    table_name: QualifiedName {
+                        schema_name: Some(identifier("public")),
+                        name: identifier("users"),
+                    },
+                    columns: vec![identifier("id"), identifier("name")],
+                },
+                direction: CopyDirection::From,
+                target: CopyTarget::Stdin,
+                options: vec![],
+            })),
+            parse_single_command("select id from users;"),
+        ],
+    );
+}
+
+// pg_dump output, which has comment headers between the statements and the
+// data of each table inline after its COPY ... FROM stdin
+const PG_DUMP_OUTPUT: &str = "--
+-- PostgreSQL database dump
+--
+
+-- Dumped from database version 16.2
+-- Dumped by pg_dump version 16.2
+
+SET statement_timeout = 0;
+SET lock_timeout = 0;
+SET idle_in_transaction_session_timeout = 0;
+SET client_encoding = 'UTF8';
+SET standard_conforming_strings = on;
+SELECT pg_catalog.set_config('search_path', '', false);
+SET check_function_bodies = false;
+SET xmloption = content;
+SET client_min_messages = warning;
+SET row_security = off;
+
+SET default_tablespace = '';
+
+SET default_table_access_method = heap;
+
+--
+-- Name: users; Type: TABLE; Schema: public; Owner: postgres
+--
+
+CREATE TABLE public.users (
+    id integer NOT NULL,
+    name text
+);
+
+
+ALTER TABLE public.users OWNER TO postgres;
+
+--
+-- Name: users_id_seq; Type: SEQUENCE; Schema: public; Owner: postgres
+--
+
+CREATE SEQUENCE public.users_id_seq
+    AS integer
+    START WITH 1
+    INCREMENT BY 1
+    NO MINVALUE
+    NO MAXVALUE
+    CACHE 1;
+
+
+ALTER TABLE public.users_id_seq OWNER TO postgres;
+
+--
+-- Name: users_id_seq; Type: SEQUENCE OWNED BY; Schema: public; Owner: postgres
+--
+
+ALTER SEQUENCE public.users_id_seq OWNED BY public.users.id;
+
+
+--
+-- Name: users id; Type: DEFAULT; Schema: public; Owner: postgres
+--
+
+ALTER TABLE ONLY public.users ALTER COLUMN id SET DEFAULT nextval('public.users_id_seq'::regclass);
+
+
+--
+-- Data for Name: users; Type: TABLE DATA; Schema: public; Owner: postgres
+--
+
+COPY public.users (id, name) FROM stdin;
+1\tAnn; -- not SQL
+2\t\\N
+\\.
+
+
+--
+-- Name: users_id_seq; Type: SEQUENCE SET; Schema: public; Owner: postgres
+--
+
+SELECT pg_catalog.setval('public.users_id_seq', 2, true);
+
+
+--
+-- Name: users users_pkey; Type: CONSTRAINT; Schema: public; Owner: postgres
+--
+
+ALTER TABLE ONLY public.users
+    ADD CONSTRAINT users_pkey PRIMARY KEY (id);
+
+
+--
+-- PostgreSQL database dump complete
+--
+
+";
+
+#[test]
+fn pg_dump_output_with_comments_and_copy_data() {
+    test_parse(
+        PG_DUMP_OUTPUT,
+        [
+            "SET statement_timeout = 0;",
+            "SET lock_timeout = 0;",
+            "SET idle_in_transaction_session_timeout = 0;",
+            "SET client_encoding = 'UTF8';",
+            "SET standard_conforming_strings = on;",
+            "SELECT pg_catalog.set_config('search_path', '', false);",
+            "SET check_function_bodies = false;",
+            "SET xmloption = content;",
+            "SET client_min_messages = warning;",
+            "SET row_security = off;",
+            "SET default_tablespace = '';",
+            "SET default_table_access_method = heap;",
+            "CREATE TABLE public.users (id integer NOT NULL, name text);",
+            "ALTER TABLE public.users OWNER TO postgres;",
+            "CREATE SEQUENCE public.users_id_seq AS integer START WITH 1 INCREMENT BY 1 NO MINVALUE NO MAXVALUE CACHE 1;",
+            "ALTER TABLE public.users_id_seq OWNER TO postgres;",
+            "ALTER SEQUENCE public.users_id_seq OWNED BY public.users.id;",
+            "ALTER TABLE ONLY public.users ALTER COLUMN id SET DEFAULT nextval('public.users_id_seq'::regclass);",
+            "COPY public.users (id, name) FROM stdin;",
+            "SELECT pg_catalog.setval('public.users_id_seq', 2, true);",
+            "ALTER TABLE ONLY public.users ADD CONSTRAINT users_pkey PRIMARY KEY (id);",
+        ]
+        .into_iter()
+        .map(parse_single_command)
+        .collect(),
+    );
+}
+
+#[test]
+fn copy_parenthesized_options() {
+    test_parse(
+        "copy t from '/tmp/t.csv' with (format csv, header match, delimiter ';', force_not_null (a, b), on_error ignore); copy t to stdout (force_quote *, encoding 'UTF8');",
+        vec![
+            Command::Utility(UtilityCommand::Copy(CopyCommand {
+                relation: CopyRelation::Table {
+                    table_name: table_name("t"),
+                    columns: vec![],
+                },
+                direction: CopyDirection::From,
+                target: CopyTarget::File(String::from("/tmp/t.csv")),
+                options: vec![
+                    CopyOption::Format(CopyFormat::Csv),
+                    CopyOption::Header(CopyHeader::Match),
+                    CopyOption::Delimiter(String::from(";")),
+                    CopyOption::ForceNotNull(Some(vec![identifier("a"), identifier("b")])),
+                    CopyOption::OnError(identifier("ignore")),
+                ],
+            })),
+            Command::Utility(UtilityCommand::Copy(CopyCommand {
+                relation: CopyRelation::Table {
+                    table_name: table_name("t"),
+                    columns: vec![],
+                },
+                direction: CopyDirection::To,
+                target: CopyTarget::Stdout,
+                options: vec![
+                    CopyOption::ForceQuote(None),
+                    CopyOption::Encoding(String::from("UTF8")),
+                ],
+            })),
+        ],
+    );
+}
+
+#[test]
+fn copy_query_with_legacy_options() {
+    let command = parse_single_command(
+        "copy (select a from t) to program 'gzip > /tmp/t.csv.gz' csv header null as '' force quote a;",
+    );
+    let copy_command =
+        extract!(command, Command::Utility(UtilityCommand::Copy(copy_command)) => copy_command);
+    assert!(matches!(copy_command.relation, CopyRelation::Query(_)));
+    assert_eq!(copy_command.direction, CopyDirection::To);
+    assert_eq!(
+        copy_command.target,
+        CopyTarget::Program(String::from("gzip > /tmp/t.csv.gz"))
+    );
+    assert_eq!(
+        copy_command.options,
+        vec![
+            CopyOption::Format(CopyFormat::Csv),
+            CopyOption::Header(CopyHeader::True),
+            CopyOption::Null(String::from("")),
+            CopyOption::ForceQuote(Some(vec![identifier("a")])),
+        ]
+    );
+}
+
+#[test]
+fn copy_invalid_directions() {
+    test_parse("copy t to stdin;", vec![]);
+    test_parse("copy t from stdout;", vec![]);
+}